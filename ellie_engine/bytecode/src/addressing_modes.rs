@@ -129,24 +129,29 @@ impl core::fmt::Display for AddressingModes {
                             isize::from_le_bytes(array)
                         }
                         .to_string(),
+                        Types::FixedInteger(size) => {
+                            let mut array = [0; mem::size_of::<i128>()];
+                            array[0..value.len()].copy_from_slice(&value[..]);
+                            if size.is_signed() && value.last().is_some_and(|e| e & 0x80 != 0) {
+                                array[value.len()..].fill(0xff);
+                            }
+                            i128::from_le_bytes(array)
+                        }
+                        .to_string(),
                         Types::Float =>
                             f32::from_le_bytes(value[0..mem::size_of::<f32>()].try_into().unwrap())
                                 .to_string(),
                         Types::Double =>
                             f64::from_le_bytes(value[0..mem::size_of::<f64>()].try_into().unwrap())
                                 .to_string(),
-                        Types::Byte => format!("0x{}", value[0]),
+                        Types::Byte => format!("{:#04x}", value[0]),
                         Types::Bool =>
                             if value[0] == 1 {
                                 "true".to_string()
                             } else {
                                 "false".to_string()
                             },
-                        Types::String(_) => format!("string[{:?}]", {
-                            let mut array = [0; mem::size_of::<isize>()];
-                            array.copy_from_slice(&value[0..mem::size_of::<isize>()]);
-                            isize::from_le_bytes(array)
-                        }),
+                        Types::String(_) => format!("{:?}", String::from_utf8_lossy(value)),
                         Types::Char => {
                             format!(
                                 "'{:?}'",
@@ -154,7 +159,11 @@ impl core::fmt::Display for AddressingModes {
                                     .unwrap()
                             )
                         }
-                        Types::StaticArray => "static_array".to_string(),
+                        Types::StaticArray => format!("static_array[{}]", {
+                            let mut array = [0; mem::size_of::<usize>()];
+                            array[0..value.len()].copy_from_slice(&value[..]);
+                            usize::from_le_bytes(array)
+                        }),
                         Types::Array(_) => format!("array[{:?}]", {
                             let mut array = [0; mem::size_of::<isize>()];
                             array.copy_from_slice(&value[0..mem::size_of::<isize>()]);
@@ -0,0 +1,593 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::str::FromStr;
+use ellie_core::defs::{Cursor, DebugHeader, DebugHeaderType, IntegerSize, PlatformArchitecture};
+
+use crate::{
+    addressing_modes::AddressingModes,
+    assembler::{AssembleResult, LocalHeader, MainFunction, ModuleInfo, PlatformAttributes},
+    instruction_table::Instructions,
+    instructions::Instruction,
+    types::Types,
+    utils::{isize_to_le_bytes, usize_to_le_bytes},
+};
+
+/// Error returned when byteCodeAsm text cannot be parsed
+#[derive(Clone, Debug, PartialEq)]
+pub struct AsmParseError {
+    /// Line of the error, starts from 1
+    pub line: usize,
+    /// Column of the token causing the error, starts from 1
+    pub column: usize,
+    pub reason: String,
+}
+
+impl core::fmt::Display for AsmParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "line {}:{}: {}", self.line, self.column, self.reason)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Section {
+    Header,
+    Locals,
+    DebugHeaders,
+    Instructions,
+}
+
+/// Character level reader over a single line
+struct LineReader<'a> {
+    line: &'a str,
+    rest: &'a str,
+    /// Start of the last read token, errors point to it
+    token: &'a str,
+}
+
+impl<'a> LineReader<'a> {
+    fn new(line: &'a str) -> Self {
+        let rest = line.trim();
+        LineReader {
+            line,
+            rest,
+            token: rest,
+        }
+    }
+
+    /// Column of the last read token, starts from 1
+    fn column(&self) -> usize {
+        let offset = self.token.as_ptr() as usize - self.line.as_ptr() as usize;
+        self.line[..offset].chars().count() + 1
+    }
+
+    /// Mark the current position as the start of a token
+    fn mark(&mut self) {
+        self.skip_whitespace();
+        self.token = self.rest;
+    }
+
+    fn skip_whitespace(&mut self) {
+        self.rest = self.rest.trim_start();
+    }
+
+    fn is_empty(&self) -> bool {
+        self.rest.is_empty()
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest.chars().next()
+    }
+
+    fn eat(&mut self, prefix: &str) -> bool {
+        match self.rest.strip_prefix(prefix) {
+            Some(rest) => {
+                self.rest = rest;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn expect(&mut self, prefix: &str) -> Result<(), String> {
+        self.mark();
+        if self.eat(prefix) {
+            Ok(())
+        } else {
+            Err(format!("expected `{}` found `{}`", prefix, self.rest))
+        }
+    }
+
+    fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> &'a str {
+        let end = self
+            .rest
+            .char_indices()
+            .find(|(_, c)| !predicate(*c))
+            .map(|(idx, _)| idx)
+            .unwrap_or(self.rest.len());
+        let (taken, rest) = self.rest.split_at(end);
+        self.rest = rest;
+        taken
+    }
+
+    fn word(&mut self) -> &'a str {
+        self.mark();
+        self.take_while(|c| c.is_ascii_alphanumeric() || c == '_')
+    }
+
+    fn number<T: FromStr>(&mut self) -> Result<T, String> {
+        self.mark();
+        let literal =
+            self.take_while(|c| c.is_ascii_alphanumeric() || c == '-' || c == '+' || c == '.');
+        literal
+            .parse::<T>()
+            .map_err(|_| format!("invalid number `{}`", literal))
+    }
+
+    /// Read a quoted literal written by `{:?}`, `quote` is either `"` or `'`
+    fn quoted(&mut self, quote: char) -> Result<String, String> {
+        self.mark();
+        if !self.eat(&quote.to_string()) {
+            return Err(format!("expected `{}` found `{}`", quote, self.rest));
+        }
+        let mut value = String::new();
+        let mut chars = self.rest.char_indices();
+        while let Some((idx, c)) = chars.next() {
+            if c == quote {
+                self.rest = &self.rest[idx + c.len_utf8()..];
+                return Ok(value);
+            } else if c == '\\' {
+                value.push(match chars.next() {
+                    Some((_, 'n')) => '\n',
+                    Some((_, 'r')) => '\r',
+                    Some((_, 't')) => '\t',
+                    Some((_, '0')) => '\0',
+                    Some((_, 'u')) => {
+                        let mut code = String::new();
+                        if !matches!(chars.next(), Some((_, '{'))) {
+                            return Err("invalid unicode escape".to_string());
+                        }
+                        for (_, c) in chars.by_ref() {
+                            if c == '}' {
+                                break;
+                            }
+                            code.push(c);
+                        }
+                        u32::from_str_radix(&code, 16)
+                            .ok()
+                            .and_then(char::from_u32)
+                            .ok_or_else(|| format!("invalid unicode escape `{}`", code))?
+                    }
+                    Some((_, escaped)) => escaped,
+                    None => break,
+                });
+            } else {
+                value.push(c);
+            }
+        }
+        Err("unterminated literal".to_string())
+    }
+}
+
+fn parse_arch(reader: &mut LineReader) -> Result<PlatformArchitecture, String> {
+    match reader.word() {
+        "16" => Ok(PlatformArchitecture::B16),
+        "32" => Ok(PlatformArchitecture::B32),
+        "64" => Ok(PlatformArchitecture::B64),
+        arch => Err(format!("unknown architecture `{}`", arch)),
+    }
+}
+
+/// Parse `name(value)` or `name[value]` wrapped immediate values
+fn wrapped_number<T: FromStr>(
+    reader: &mut LineReader,
+    name: &str,
+    open: &str,
+    close: &str,
+) -> Result<T, String> {
+    reader.expect(name)?;
+    reader.expect(open)?;
+    let value = reader.number::<T>()?;
+    reader.expect(close)?;
+    Ok(value)
+}
+
+fn parse_immediate(
+    reader: &mut LineReader,
+    arch: PlatformArchitecture,
+) -> Result<AddressingModes, String> {
+    reader.expect("(")?;
+    reader.mark();
+    let rtype = match reader.rest.find(')') {
+        Some(end) => {
+            let rtype = &reader.rest[..end];
+            reader.rest = &reader.rest[end + 1..];
+            rtype.trim()
+        }
+        None => return Err("unterminated immediate type".to_string()),
+    };
+    let type_name = rtype.split(['[', '<']).next().unwrap_or_default();
+
+    let (rtype, bytes) = match type_name {
+        "int" => (Types::Integer, isize_to_le_bytes(reader.number()?, arch)),
+        "float" => (Types::Float, reader.number::<f32>()?.to_le_bytes().to_vec()),
+        "double" => (
+            Types::Double,
+            reader.number::<f64>()?.to_le_bytes().to_vec(),
+        ),
+        "byte" => {
+            let literal = reader.word();
+            let byte = match literal.strip_prefix("0x") {
+                Some(hex) => u8::from_str_radix(hex, 16),
+                None => literal.parse::<u8>(),
+            }
+            .map_err(|_| format!("invalid byte `{}`", literal))?;
+            (Types::Byte, vec![byte])
+        }
+        "bool" => match reader.word() {
+            "true" => (Types::Bool, vec![1]),
+            "false" => (Types::Bool, vec![0]),
+            e => return Err(format!("invalid bool `{}`", e)),
+        },
+        "string" => {
+            let value = reader.quoted('"')?;
+            (Types::String(value.len()), value.into_bytes())
+        }
+        "char" => {
+            // Listings wrap the debug formatted char in another pair of quotes
+            reader.skip_whitespace();
+            let wrapped = reader.rest.starts_with("''");
+            if wrapped {
+                reader.eat("'");
+            }
+            let value = reader.quoted('\'')?;
+            if wrapped {
+                reader.expect("'")?;
+            }
+            let mut chars = value.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => (Types::Char, (c as u32).to_le_bytes().to_vec()),
+                _ => return Err(format!("invalid char `{}`", value)),
+            }
+        }
+        "staticArray" => (
+            Types::StaticArray,
+            usize_to_le_bytes(wrapped_number(reader, "static_array", "[", "]")?, arch),
+        ),
+        "array" => {
+            let len = rtype
+                .trim_start_matches("array<")
+                .split(['/', '>'])
+                .next()
+                .unwrap_or_default()
+                .trim()
+                .parse::<usize>()
+                .map_err(|_| format!("invalid array type `{}`", rtype))?;
+            (
+                Types::Array(len),
+                isize_to_le_bytes(wrapped_number(reader, "array", "[", "]")?, arch),
+            )
+        }
+        "class" => {
+            let len = rtype
+                .trim_start_matches("class<")
+                .trim_end_matches('>')
+                .parse::<usize>()
+                .map_err(|_| format!("invalid class type `{}`", rtype))?;
+            (
+                Types::Class(len),
+                usize_to_le_bytes(wrapped_number(reader, "class", "(", ")")?, arch),
+            )
+        }
+        "function" => (
+            Types::Function,
+            usize_to_le_bytes(wrapped_number(reader, "fn", "(", ")")?, arch),
+        ),
+        "heapReference" => (
+            Types::HeapReference,
+            usize_to_le_bytes(wrapped_number(reader, "href", "(", ")")?, arch),
+        ),
+        "stackReference" => (
+            Types::StackReference,
+            usize_to_le_bytes(wrapped_number(reader, "sref", "(", ")")?, arch),
+        ),
+        "void" => (Types::Void, Vec::new()),
+        "null" => (Types::Null, Vec::new()),
+        _ => match IntegerSize::from_type_name(type_name) {
+            Some(size) if size.is_fixed() => {
+                let value = reader.number::<i128>()?;
+                if !size.fits(value) {
+                    return Err(format!("`{}` does not fit to {}", value, type_name));
+                }
+                (
+                    Types::FixedInteger(size),
+                    value.to_le_bytes()[..size.byte_size()].to_vec(),
+                )
+            }
+            _ => return Err(format!("unknown immediate type `{}`", rtype)),
+        },
+    };
+    Ok(AddressingModes::Immediate(rtype, bytes))
+}
+
+/// Parse operand of an instruction, empty operand is implicit addressing
+fn parse_operand(
+    reader: &mut LineReader,
+    arch: PlatformArchitecture,
+) -> Result<AddressingModes, String> {
+    reader.mark();
+    if reader.is_empty() || reader.peek() == Some('=') {
+        Ok(AddressingModes::Implicit)
+    } else if reader.eat("#") {
+        parse_immediate(reader, arch)
+    } else if reader.eat("$x") {
+        Ok(AddressingModes::AbsoluteStatic(reader.number()?))
+    } else if reader.eat("$") {
+        let pointer = reader.number()?;
+        if reader.eat("[") {
+            reader.expect("$")?;
+            let index_pointer = reader.number()?;
+            reader.expect("]")?;
+            Ok(AddressingModes::AbsoluteIndex(pointer, index_pointer))
+        } else {
+            Ok(AddressingModes::Absolute(pointer))
+        }
+    } else if reader.eat("@") {
+        match reader.peek() {
+            Some(c) if c.is_ascii_digit() => {
+                let pointer = reader.number()?;
+                reader.expect("[")?;
+                let index = reader.number()?;
+                reader.expect("]")?;
+                Ok(AddressingModes::AbsoluteProperty(pointer, index))
+            }
+            _ => match reader.word() {
+                "A" => Ok(AddressingModes::IndirectA),
+                "B" => Ok(AddressingModes::IndirectB),
+                "C" => Ok(AddressingModes::IndirectC),
+                "X" => Ok(AddressingModes::IndirectX),
+                "Y" => Ok(AddressingModes::IndirectY),
+                e => Err(format!("unknown register `{}`", e)),
+            },
+        }
+    } else {
+        Err(format!("invalid operand `{}`", reader.rest))
+    }
+}
+
+/// Parse `idx: MNEMONIC operand = op_code : [bytes]`, index and the part after `=` are optional
+fn parse_instruction(
+    reader: &mut LineReader,
+    location: usize,
+    arch: PlatformArchitecture,
+) -> Result<Instructions, String> {
+    reader.mark();
+    if let Some((index, rest)) = reader.rest.split_once(':') {
+        if !index.is_empty() && index.chars().all(|c| c.is_ascii_digit()) {
+            let index = index
+                .parse::<usize>()
+                .map_err(|_| format!("invalid instruction index `{}`", index))?;
+            if index != location {
+                return Err(format!(
+                    "instruction index {} does not match its location {}",
+                    index, location
+                ));
+            }
+            reader.rest = rest;
+        }
+    }
+
+    let mnemonic = reader.word();
+    let mnemonic_token = reader.token;
+    let addressing_mode = parse_operand(reader, arch)?;
+    reader.mark();
+    // Op code and bytes are recomputed from the instruction
+    if !reader.is_empty() && reader.peek() != Some('=') {
+        return Err(format!("unexpected `{}`", reader.rest));
+    }
+
+    reader.token = mnemonic_token;
+    let instruction = Instructions::from_mnemonic(mnemonic, Instruction { addressing_mode })
+        .ok_or_else(|| format!("unknown instruction `{}`", mnemonic))?;
+    if instruction.is_addressing_mode_supported() {
+        Ok(instruction)
+    } else {
+        Err(format!(
+            "{} does not support {} addressing",
+            mnemonic,
+            instruction
+                .get_addressing_mode_ref()
+                .addressing_mode
+                .to_string()
+        ))
+    }
+}
+
+/// Parse `cursor: name = addressing_mode(hash)`
+fn parse_local(reader: &mut LineReader) -> Result<LocalHeader, String> {
+    let cursor = reader.number::<usize>()?;
+    reader.expect(":")?;
+    reader.mark();
+    let (name, reference) = reader
+        .rest
+        .rsplit_once(" = ")
+        .ok_or_else(|| format!("invalid local `{}`", reader.rest))?;
+    let (mode, hash) = match reference.split_once('(') {
+        Some((mode, hash)) => {
+            reader.token = hash;
+            (
+                mode,
+                Some(
+                    hash.trim_end_matches(')')
+                        .parse::<usize>()
+                        .map_err(|_| format!("invalid local hash `{}`", hash))?,
+                ),
+            )
+        }
+        None => (reference, None),
+    };
+    reader.token = mode;
+    Ok(LocalHeader {
+        name: name.trim().to_string(),
+        cursor,
+        reference: match mode.trim() {
+            "implicit" => Instruction::implicit(),
+            "absolute" => Instruction::absolute(cursor),
+            "absolute_static" => Instruction::absolute_static(cursor),
+            e => return Err(format!("unsupported local reference `{}`", e)),
+        },
+        hash,
+        page_hash: 0,
+        borrowed: None,
+    })
+}
+
+/// Parse `Type = start~end : hash @ "module_name" "name"`, single instruction ranges are written as `start`
+fn parse_debug_header(reader: &mut LineReader) -> Result<DebugHeader, String> {
+    let rtype = match reader.word() {
+        "Variable" => DebugHeaderType::Variable,
+        "SetterCall" => DebugHeaderType::SetterCall,
+        "GetterCall" => DebugHeaderType::GetterCall,
+        "Class" => DebugHeaderType::Class,
+        "Parameter" => DebugHeaderType::Parameter,
+        "Function" => DebugHeaderType::Function,
+        "NativeFunction" => DebugHeaderType::NativeFunction,
+        "Condition" => DebugHeaderType::Condition,
+        "Ret" => DebugHeaderType::Ret,
+        e => return Err(format!("unknown debug header type `{}`", e)),
+    };
+    reader.expect("=")?;
+    let start = reader.number::<usize>()?;
+    let end = if reader.eat("~") {
+        reader.number::<usize>()?
+    } else {
+        start + 1
+    };
+    reader.expect(":")?;
+    let hash = reader.number::<usize>()?;
+    reader.skip_whitespace();
+    let (module_name, name) = if reader.eat("@") {
+        (reader.quoted('"')?, reader.quoted('"')?)
+    } else {
+        (String::new(), String::new())
+    };
+    Ok(DebugHeader {
+        rtype,
+        hash,
+        module_name,
+        module_hash: 0,
+        name,
+        start_end: (start, end),
+        pos: Cursor::default(),
+    })
+}
+
+/// Parse `.main start: end @ hash`
+fn parse_main(reader: &mut LineReader) -> Result<MainFunction, String> {
+    let start = reader.number()?;
+    reader.expect(":")?;
+    let end = reader.number()?;
+    reader.expect("@")?;
+    let hash = reader.number()?;
+    Ok(MainFunction { hash, start, end })
+}
+
+/// Parse byteCodeAsm text written by [`AssembleResult::alternate_render`] back into an [`AssembleResult`]
+///
+/// Text is read line by line, blank lines and lines starting with `;` are ignored.
+/// * `.arch 16|32|64` - Target architecture, defaults to 64 and has to come before instructions
+/// * `.main start: end @ hash` - Main function
+/// * `.locals`, `.debugHeader`, `.instructions` - Starts a section, each following line is an entry of it
+///
+/// Instruction index prefix and the op code dump after `=` are optional, so instructions can be
+/// written by hand as `LDA #(int)1`. Debug headers only keep the module and element name, so
+/// a debug file rendered from the result has no source positions. Operands are not marked as
+/// relocatable, so parsed programs are not meant to be optimized.
+/// ## Arguments
+/// * `code` - byteCodeAsm text
+/// ## Returns
+/// [`AssembleResult`] or [`AsmParseError`] with the line and column of the first error
+pub fn parse_asm(code: &str) -> Result<AssembleResult, AsmParseError> {
+    let mut result = AssembleResult {
+        module_info: ModuleInfo {
+            name: String::new(),
+            module_maps: Vec::new(),
+            is_library: false,
+            main_function: None,
+            platform_attributes: PlatformAttributes {
+                architecture: PlatformArchitecture::B64,
+                memory_size: 512000,
+            },
+        },
+        debug_headers: Vec::new(),
+        locals: Vec::new(),
+        instructions: Vec::new(),
+        native_exports: Vec::new(),
+        relocations: Vec::new(),
+    };
+
+    let mut section = Section::Header;
+    for (idx, line) in code.lines().enumerate() {
+        let mut reader = LineReader::new(line);
+        if let Err(reason) = parse_line(&mut reader, &mut section, &mut result) {
+            return Err(AsmParseError {
+                line: idx + 1,
+                column: reader.column(),
+                reason,
+            });
+        }
+    }
+    Ok(result)
+}
+
+/// Parse a line of the given section, directives change the section
+fn parse_line(
+    reader: &mut LineReader,
+    section: &mut Section,
+    result: &mut AssembleResult,
+) -> Result<(), String> {
+    if reader.is_empty() || reader.rest.starts_with(';') {
+        return Ok(());
+    }
+
+    if reader.eat(".") {
+        match reader.word() {
+            "arch" => {
+                if !result.instructions.is_empty() {
+                    return Err("`.arch` has to come before instructions".to_string());
+                }
+                result.module_info.platform_attributes.architecture = parse_arch(reader)?;
+            }
+            "main" => result.module_info.main_function = Some(parse_main(reader)?),
+            "locals" => *section = Section::Locals,
+            "debugHeader" => *section = Section::DebugHeaders,
+            "instructions" => *section = Section::Instructions,
+            name => return Err(format!("unknown directive `.{}`", name)),
+        }
+        return Ok(());
+    }
+
+    match section {
+        Section::Header => Err(format!("`{}` is outside of a section", reader.rest)),
+        Section::Locals => {
+            result.locals.push(parse_local(reader)?);
+            Ok(())
+        }
+        Section::DebugHeaders => {
+            result.debug_headers.push(parse_debug_header(reader)?);
+            Ok(())
+        }
+        Section::Instructions => {
+            let instruction = parse_instruction(
+                reader,
+                result.instructions.len(),
+                result.module_info.platform_attributes.architecture,
+            )?;
+            result.instructions.push(instruction);
+            Ok(())
+        }
+    }
+}
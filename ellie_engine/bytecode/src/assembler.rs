@@ -1,4 +1,5 @@
 use crate::{
+    addressing_modes::AddressingModes,
     instruction_table,
     instructions::Instruction,
     transpiler::Transpiler,
@@ -10,6 +11,7 @@ use alloc::{
     vec::Vec,
 };
 use ellie_core::{
+    bytecode_format::{checksum, ExportedSymbol, SectionKind, BYTECODE_MAGIC, BYTECODE_REVISION},
     defs::{DebugHeader, DebugHeaderType, ModuleMap, NativeCallTrace, PlatformArchitecture},
     utils::ExportPage,
 };
@@ -23,6 +25,10 @@ pub struct Assembler {
     pub(crate) instructions: Vec<instruction_table::Instructions>,
     pub(crate) locals: Vec<LocalHeader>,
     pub(crate) debug_headers: Vec<DebugHeader>,
+    /// `JMP` locations emitted by `brk`, grouped per enclosing loop. Patched to the loop's exit once its body is assembled.
+    pub(crate) loop_exits: Vec<Vec<usize>>,
+    /// Locations of instructions whose immediate operand is a location in the program
+    pub(crate) relocations: Vec<usize>,
 }
 
 #[derive(Clone, Debug)]
@@ -35,6 +41,16 @@ pub struct LocalHeader {
     pub borrowed: Option<Vec<usize>>,
 }
 
+/// A class member that satisfies an interface member
+#[derive(Clone, Debug)]
+pub struct InterfaceImplementation {
+    pub class_hash: usize,
+    pub class_page: usize,
+    pub member_hash: usize,
+    /// Index of the property in the class instance, excluding the class hash header
+    pub property_idx: usize,
+}
+
 #[derive(Clone, Debug)]
 pub struct InstructionPage {
     pub is_main: bool,
@@ -104,213 +120,175 @@ pub struct AssembleResult {
     pub locals: Vec<LocalHeader>,
     pub instructions: Vec<instruction_table::Instructions>,
     pub native_exports: Vec<NativeCall>,
+    /// Locations of instructions whose immediate operand is a location in the program, these
+    /// are relocated by the optimizer when instructions are removed
+    pub relocations: Vec<usize>,
 }
 
 impl AssembleResult {
-    pub fn render_binary_to_vector(&self) -> Vec<u8> {
-        let native_calls: Vec<NativeCallTrace> = self
-            .debug_headers
+    /// Native functions called by the program, collected from debug headers
+    fn native_call_traces(&self) -> Vec<NativeCallTrace> {
+        self.debug_headers
             .iter()
             .filter(|x| x.rtype == DebugHeaderType::NativeFunction)
             .map(|x| NativeCallTrace {
-                module_name: if x.module_name.contains(">") {
+                module_name: if !x.module_name.contains('>') {
                     x.module_name.to_string()
                 } else {
-                    x.module_name.clone().split(">").collect::<Vec<_>>()[0]
+                    x.module_name
+                        .split('>')
+                        .next()
+                        .unwrap()
                         .split('_')
-                        .last()
+                        .next_back()
                         .unwrap()
                         .to_string()
                 },
                 function_hash: x.hash,
                 function_name: x.name.clone(),
             })
-            .collect();
-        let arch = self.module_info.platform_attributes.architecture;
-        let mut binary = Vec::new();
-        binary
-            .write(&[match self.module_info.platform_attributes.architecture {
-                PlatformArchitecture::B16 => 16_u8,
-                PlatformArchitecture::B32 => 32_u8,
-                PlatformArchitecture::B64 => 64_u8,
-            }])
-            .unwrap();
-        binary
-            .write(&[if self.module_info.main_function.is_some() {
-                1
-            } else {
-                0
-            }])
-            .unwrap();
-        if let Some(main_fn) = &self.module_info.main_function {
-            binary
-                .write_all(&usize_to_le_bytes(main_fn.start, arch))
-                .unwrap();
-            binary
-                .write_all(&usize_to_le_bytes(main_fn.end, arch))
-                .unwrap();
-            binary
-                .write_all(&usize_to_le_bytes(main_fn.hash, arch))
-                .unwrap();
+            .collect()
+    }
+
+    /// Functions of the program, a function is a local with a hash referenced statically
+    fn exported_symbols(&self) -> Vec<ExportedSymbol> {
+        let mut symbols: Vec<ExportedSymbol> = Vec::new();
+        for local in &self.locals {
+            if let (Some(hash), AddressingModes::AbsoluteStatic(_)) =
+                (local.hash, &local.reference.addressing_mode)
+            {
+                if !symbols.iter().any(|symbol| symbol.hash == hash) {
+                    symbols.push(ExportedSymbol {
+                        name: local.name.clone(),
+                        hash,
+                        location: local.cursor,
+                    });
+                }
+            }
         }
+        symbols
+    }
 
-        binary
-            .write_all(&usize_to_le_bytes(native_calls.len(), arch))
-            .unwrap();
+    /// Module maps and debug headers, strings are written as length and utf8 bytes
+    fn debug_info_section(&self) -> Vec<u8> {
+        let arch = self.module_info.platform_attributes.architecture;
+        let write_str = |section: &mut Vec<u8>, string: &str| {
+            section.extend(usize_to_le_bytes(string.len(), arch));
+            section.extend(string.as_bytes());
+        };
 
-        for native_call in &native_calls {
-            binary
-                .write_all(&usize_to_le_bytes(native_call.module_name.len(), arch))
-                .unwrap();
-            binary
-                .write_all(native_call.module_name.as_bytes())
-                .unwrap();
-            binary
-                .write_all(&usize_to_le_bytes(native_call.function_hash, arch))
-                .unwrap();
-            binary
-                .write_all(&usize_to_le_bytes(native_call.function_name.len(), arch))
-                .unwrap();
-            binary
-                .write_all(native_call.function_name.as_bytes())
-                .unwrap();
+        let mut section = usize_to_le_bytes(self.module_info.module_maps.len(), arch);
+        for module_map in &self.module_info.module_maps {
+            write_str(&mut section, &module_map.module_name);
+            section.extend(usize_to_le_bytes(module_map.module_hash, arch));
+            match &module_map.module_path {
+                Some(module_path) => {
+                    section.push(1);
+                    write_str(&mut section, module_path);
+                }
+                None => section.push(0),
+            }
         }
 
-        for instruction in &self.instructions {
-            binary
-                .write(&instruction.op_code(self.module_info.platform_attributes.architecture))
-                .unwrap();
+        section.extend(usize_to_le_bytes(self.debug_headers.len(), arch));
+        for header in &self.debug_headers {
+            section.push(header.rtype.to_byte());
+            section.extend(usize_to_le_bytes(header.hash, arch));
+            write_str(&mut section, &header.module_name);
+            section.extend(usize_to_le_bytes(header.module_hash, arch));
+            write_str(&mut section, &header.name);
+            section.extend(usize_to_le_bytes(header.start_end.0, arch));
+            section.extend(usize_to_le_bytes(header.start_end.1, arch));
+            section.extend(usize_to_le_bytes(header.pos.range_start.0, arch));
+            section.extend(usize_to_le_bytes(header.pos.range_start.1, arch));
+            section.extend(usize_to_le_bytes(header.pos.range_end.0, arch));
+            section.extend(usize_to_le_bytes(header.pos.range_end.1, arch));
         }
-        binary
+        section
     }
 
-    pub fn render_binary<T: Write, E: Write>(&self, writer: &mut T, dbg_w: &mut E) {
-        let mut native_calls: Vec<NativeCallTrace> = Vec::new();
+    /// Render program as a bytecode file, see [`SectionKind`] for the layout
+    /// ## Arguments
+    /// * `strip_debug_info` - Leave debug info out of the file
+    pub fn render_binary_to_vector(&self, strip_debug_info: bool) -> Vec<u8> {
         let arch = self.module_info.platform_attributes.architecture;
-        for module_map in &self.module_info.module_maps {
-            dbg_w
-                .write_all(
-                    format!(
-                        "{}E-E{}F:F {}\n",
-                        module_map.module_name,
-                        module_map.module_hash,
-                        module_map.module_path.clone().unwrap_or("-".to_string())
-                    )
-                    .as_bytes(),
-                )
-                .unwrap();
-        }
-        dbg_w.write_all(b"---\n").unwrap();
-        for (idx, header) in self.debug_headers.iter().enumerate() {
-            if header.rtype == DebugHeaderType::NativeFunction {
-                native_calls.push(NativeCallTrace {
-                    module_name: if !header.module_name.contains(">") {
-                        header.module_name.to_string()
-                    } else {
-                        header
-                            .module_name
-                            .clone()
-                            .split(">")
-                            .next()
-                            .unwrap()
-                            .split('_')
-                            .last()
-                            .unwrap()
-                            .to_string()
-                    },
-                    function_hash: header.hash,
-                    function_name: header.name.clone(),
-                });
-            }
-            dbg_w
-                .write_all(
-                    format!(
-                        "{}F:F{}F:F{}F:F{}F:F{}F:F{}F:F{}F:F{}F:F{}F:F{}F:F{}{}",
-                        header.start_end.0,
-                        header.start_end.1,
-                        header.module_name,
-                        header.module_hash,
-                        header.name,
-                        header.pos.range_start.0,
-                        header.pos.range_start.1,
-                        header.pos.range_end.0,
-                        header.pos.range_end.1,
-                        header.hash,
-                        {
-                            match header.rtype {
-                                DebugHeaderType::Variable => 0,
-                                DebugHeaderType::SetterCall => 1,
-                                DebugHeaderType::GetterCall => 2,
-                                DebugHeaderType::Class => 3,
-                                DebugHeaderType::Parameter => 4,
-                                DebugHeaderType::Function => 5,
-                                DebugHeaderType::NativeFunction => 6,
-                                DebugHeaderType::Condition => 7,
-                            }
-                        },
-                        if idx != self.debug_headers.len() - 1 {
-                            "\n"
-                        } else {
-                            ""
-                        },
-                    )
-                    .as_bytes(),
-                )
-                .unwrap();
-        }
-        writer
-            .write(&[match self.module_info.platform_attributes.architecture {
-                PlatformArchitecture::B16 => 16_u8,
-                PlatformArchitecture::B32 => 32_u8,
-                PlatformArchitecture::B64 => 64_u8,
-            }])
-            .unwrap();
-        writer
-            .write(&[if self.module_info.main_function.is_some() {
-                1
-            } else {
-                0
-            }])
-            .unwrap();
+        let mut sections: Vec<(SectionKind, Vec<u8>)> = Vec::new();
+
         if let Some(main_fn) = &self.module_info.main_function {
-            writer
-                .write_all(&usize_to_le_bytes(main_fn.start, arch))
-                .unwrap();
-            writer
-                .write_all(&usize_to_le_bytes(main_fn.end, arch))
-                .unwrap();
-            writer
-                .write_all(&usize_to_le_bytes(main_fn.hash, arch))
-                .unwrap();
+            let mut section = Vec::new();
+            section.extend(usize_to_le_bytes(main_fn.start, arch));
+            section.extend(usize_to_le_bytes(main_fn.end, arch));
+            section.extend(usize_to_le_bytes(main_fn.hash, arch));
+            sections.push((SectionKind::Main, section));
         }
 
-        writer
-            .write_all(&usize_to_le_bytes(native_calls.len(), arch))
-            .unwrap();
-
+        let native_calls = self.native_call_traces();
+        let mut section = usize_to_le_bytes(native_calls.len(), arch);
         for native_call in &native_calls {
-            writer
-                .write_all(&usize_to_le_bytes(native_call.module_name.len(), arch))
-                .unwrap();
-            writer
-                .write_all(native_call.module_name.as_bytes())
-                .unwrap();
-            writer
-                .write_all(&usize_to_le_bytes(native_call.function_hash, arch))
-                .unwrap();
-            writer
-                .write_all(&usize_to_le_bytes(native_call.function_name.len(), arch))
-                .unwrap();
-            writer
-                .write_all(native_call.function_name.as_bytes())
-                .unwrap();
+            section.extend(usize_to_le_bytes(native_call.module_name.len(), arch));
+            section.extend(native_call.module_name.as_bytes());
+            section.extend(usize_to_le_bytes(native_call.function_hash, arch));
+            section.extend(usize_to_le_bytes(native_call.function_name.len(), arch));
+            section.extend(native_call.function_name.as_bytes());
+        }
+        sections.push((SectionKind::NativeCalls, section));
+
+        let symbols = self.exported_symbols();
+        let mut section = usize_to_le_bytes(symbols.len(), arch);
+        for symbol in &symbols {
+            section.extend(usize_to_le_bytes(symbol.name.len(), arch));
+            section.extend(symbol.name.as_bytes());
+            section.extend(usize_to_le_bytes(symbol.hash, arch));
+            section.extend(usize_to_le_bytes(symbol.location, arch));
         }
+        sections.push((SectionKind::Symbols, section));
 
+        let mut section = Vec::new();
         for instruction in &self.instructions {
-            writer
-                .write(&instruction.op_code(self.module_info.platform_attributes.architecture))
-                .unwrap();
+            section.extend(instruction.op_code(arch));
         }
+        sections.push((SectionKind::Code, section));
+
+        if !strip_debug_info {
+            sections.push((SectionKind::Debug, self.debug_info_section()));
+        }
+
+        let mut body = Vec::new();
+        for (kind, section) in &sections {
+            body.push(kind.to_byte());
+            body.extend(usize_to_le_bytes(section.len(), arch));
+            body.extend(section);
+        }
+
+        let compiler_version = env!("CARGO_PKG_VERSION");
+        let mut header = Vec::new();
+        header.extend(BYTECODE_REVISION.to_le_bytes());
+        header.push(match arch {
+            PlatformArchitecture::B16 => 16_u8,
+            PlatformArchitecture::B32 => 32_u8,
+            PlatformArchitecture::B64 => 64_u8,
+        });
+        header.push(compiler_version.len() as u8);
+        header.extend(compiler_version.as_bytes());
+        header.push(sections.len() as u8);
+        header.extend(usize_to_le_bytes(body.len(), arch));
+
+        let mut checked = header.clone();
+        checked.extend(&body);
+
+        let mut binary = Vec::new();
+        binary.extend(BYTECODE_MAGIC);
+        binary.extend(header);
+        binary.extend(checksum(&checked).to_le_bytes());
+        binary.extend(body);
+        binary
+    }
+
+    /// Write program as a bytecode file, see [`AssembleResult::render_binary_to_vector`]
+    pub fn render_binary<T: Write>(&self, writer: &mut T, strip_debug_info: bool) {
+        writer
+            .write_all(&self.render_binary_to_vector(strip_debug_info))
+            .unwrap();
     }
 
     pub fn alternate_render<T: Write>(&self, output: &mut T) {
@@ -382,7 +360,7 @@ impl AssembleResult {
             output
                 .write_all(
                     format!(
-                        "\n{:?} = {} : {}",
+                        "\n{:?} = {} : {} @ {:?} {:?}",
                         debug_header.rtype,
                         {
                             if debug_header.start_end.1 == (debug_header.start_end.0 + 1) {
@@ -391,7 +369,9 @@ impl AssembleResult {
                                 format!("{}~{}", debug_header.start_end.0, debug_header.start_end.1)
                             }
                         },
-                        debug_header.hash
+                        debug_header.hash,
+                        debug_header.module_name,
+                        debug_header.name,
                     )
                     .as_bytes(),
                 )
@@ -435,9 +415,161 @@ impl Assembler {
             instructions: Vec::new(),
             locals: Vec::new(),
             debug_headers: Vec::new(),
+            loop_exits: Vec::new(),
+            relocations: Vec::new(),
         }
     }
 
+    /// Whether the class with given hash declares a constructor
+    pub fn class_has_constructor(&self, class_hash: usize) -> bool {
+        let inner_page_id = self.module.pages.iter().find_map(|page| {
+            page.items.iter().find_map(|item| match item {
+                ellie_core::definite::items::Collecting::Class(class)
+                    if class.hash == class_hash =>
+                {
+                    Some(class.inner_page_id)
+                }
+                _ => None,
+            })
+        });
+        match inner_page_id {
+            Some(inner_page_id) => self.module.pages.iter().any(|page| {
+                page.hash == inner_page_id
+                    && page.items.iter().any(|item| {
+                        matches!(
+                            item,
+                            ellie_core::definite::items::Collecting::Constructor(_)
+                        )
+                    })
+            }),
+            None => false,
+        }
+    }
+
+    /// Find the index of an enum variant, enum values carry it as their first element
+    /// ## Arguments
+    /// * `enum_hash` - Hash of the enum
+    /// * `variant` - Name of the variant
+    pub fn find_enum_variant(&self, enum_hash: usize, variant: &str) -> Option<usize> {
+        self.module
+            .pages
+            .iter()
+            .flat_map(|page| page.items.iter())
+            .find_map(|item| match item {
+                ellie_core::definite::items::Collecting::Enum(enum_type)
+                    if enum_type.hash == enum_hash =>
+                {
+                    enum_type
+                        .items
+                        .iter()
+                        .position(|item| item.identifier == variant)
+                }
+                _ => None,
+            })
+    }
+
+    /// Find the class members implementing an interface member
+    /// ## Arguments
+    /// * `interface_page` - Inner page of the interface
+    /// * `member_hash` - Hash of the interface member
+    /// ## Returns
+    /// `None` if the page is not an interface body, otherwise a [`InterfaceImplementation`] for
+    /// every class implementing the interface
+    pub fn find_interface_implementations(
+        &self,
+        interface_page: usize,
+        member_hash: usize,
+    ) -> Option<Vec<InterfaceImplementation>> {
+        let interface_hash = self
+            .module
+            .pages
+            .iter()
+            .flat_map(|page| page.items.iter())
+            .find_map(|item| match item {
+                ellie_core::definite::items::Collecting::Interface(interface)
+                    if interface.inner_page_id == interface_page =>
+                {
+                    Some(interface.hash)
+                }
+                _ => None,
+            })?;
+        let page = self
+            .module
+            .pages
+            .iter()
+            .find(|page| page.hash == interface_page)?;
+        let member_name = page.items.iter().find_map(|item| match item {
+            ellie_core::definite::items::Collecting::Variable(e) if e.hash == member_hash => {
+                Some(e.name.clone())
+            }
+            ellie_core::definite::items::Collecting::NativeFunction(e) if e.hash == member_hash => {
+                Some(e.name.clone())
+            }
+            _ => None,
+        })?;
+
+        let classes = self
+            .module
+            .pages
+            .iter()
+            .flat_map(|page| page.items.iter())
+            .filter_map(|item| match item {
+                ellie_core::definite::items::Collecting::Class(class)
+                    if class.implements.iter().any(|x| x.hash == interface_hash) =>
+                {
+                    Some((class.hash, class.inner_page_id))
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        Some(
+            classes
+                .into_iter()
+                .filter_map(|(class_hash, class_page)| {
+                    let class_page = self.module.pages.iter().find(|x| x.hash == class_page)?;
+                    let properties = class_page.items.iter().filter_map(|item| match item {
+                        ellie_core::definite::items::Collecting::Variable(e) => Some(e),
+                        _ => None,
+                    });
+                    let (member_hash, property_idx) = class_page
+                        .items
+                        .iter()
+                        .find_map(|item| match item {
+                            ellie_core::definite::items::Collecting::Function(e)
+                                if e.name == member_name =>
+                            {
+                                Some((e.hash, 0))
+                            }
+                            ellie_core::definite::items::Collecting::NativeFunction(e)
+                                if e.name == member_name =>
+                            {
+                                Some((e.hash, 0))
+                            }
+                            _ => None,
+                        })
+                        .or_else(|| {
+                            properties
+                                .enumerate()
+                                .find(|(_, e)| e.name == member_name)
+                                .map(|(idx, e)| (e.hash, idx))
+                        })?;
+                    Some(InterfaceImplementation {
+                        class_hash,
+                        class_page: class_page.hash,
+                        member_hash,
+                        property_idx,
+                    })
+                })
+                .collect(),
+        )
+    }
+
+    /// Mark the immediate operand of the instruction at given location as a location in the program
+    pub(crate) fn mark_relocation(&mut self, location: usize) {
+        self.relocations.push(location);
+    }
+
     pub fn location(&self) -> usize {
         if self.instructions.is_empty() {
             0
@@ -623,6 +755,20 @@ impl Assembler {
                                     None
                                 }
                             }
+                            ellie_core::definite::items::Collecting::Class(class) => {
+                                if &class.name == name {
+                                    Some(LocalHeader {
+                                        name: class.name.clone(),
+                                        cursor: 0,
+                                        reference: Instruction::absolute_static(0),
+                                        hash: Some(class.hash),
+                                        page_hash: x.hash,
+                                        borrowed: Some(Vec::new()),
+                                    })
+                                } else {
+                                    None
+                                }
+                            }
                             ellie_core::definite::items::Collecting::NativeFunction(nfunction) => {
                                 if &nfunction.name == name {
                                     Some(LocalHeader {
@@ -653,6 +799,23 @@ impl Assembler {
         }
     }
 
+    /// Assembles a loop body, any `brk` inside it jumps to the location returned by `exit`
+    /// * `inner_page_id` - Hash of the loop's body page
+    /// * `exit` - Emits the rest of the loop and returns its exit location
+    pub(crate) fn assemble_loop_body<F: FnOnce(&mut Assembler) -> usize>(
+        &mut self,
+        inner_page_id: &usize,
+        exit: F,
+    ) {
+        self.loop_exits.push(Vec::new());
+        self.assemble_dependency(inner_page_id);
+        let exit_location = exit(self);
+        for location in self.loop_exits.pop().unwrap() {
+            self.instructions[location] =
+                instruction_table::Instructions::JMP(Instruction::absolute(exit_location));
+        }
+    }
+
     pub(crate) fn assemble_dependency(&mut self, hash: &usize) -> Option<MainFunction> {
         if self.processed.contains(hash) {
             return None;
@@ -699,6 +862,9 @@ impl Assembler {
                 ellie_core::definite::items::Collecting::Condition(condition) => {
                     condition.transpile(self, processed_page.hash, &processed_page)
                 }
+                ellie_core::definite::items::Collecting::Match(match_item) => {
+                    match_item.transpile(self, processed_page.hash, &processed_page)
+                }
                 ellie_core::definite::items::Collecting::Class(class) => {
                     class.transpile(self, processed_page.hash, &processed_page)
                 }
@@ -719,12 +885,22 @@ impl Assembler {
                 ellie_core::definite::items::Collecting::SetterCall(setter_call) => {
                     setter_call.transpile(self, processed_page.hash, &processed_page)
                 }
-                ellie_core::definite::items::Collecting::Enum(_) => todo!(),
+                ellie_core::definite::items::Collecting::Enum(_) => true,
                 ellie_core::definite::items::Collecting::NativeFunction(native_function) => {
                     native_function.transpile(self, processed_page.hash, &processed_page)
                 }
                 ellie_core::definite::items::Collecting::None => todo!(),
-                ellie_core::definite::items::Collecting::Brk(_) => todo!(),
+                ellie_core::definite::items::Collecting::Brk(_) => {
+                    self.instructions.push(instruction_table::Instructions::JMP(
+                        Instruction::absolute(0),
+                    )); //To be changed to loops exit point
+                    let location = self.location();
+                    self.loop_exits
+                        .last_mut()
+                        .expect("Brk used outside of a loop")
+                        .push(location);
+                    true
+                }
                 ellie_core::definite::items::Collecting::Go(_) => todo!(),
                 ellie_core::definite::items::Collecting::FunctionParameter(function_parameter) => {
                     function_parameter.transpile(self, processed_page.hash, &processed_page)
@@ -733,7 +909,12 @@ impl Assembler {
                 ellie_core::definite::items::Collecting::SelfItem(self_item) => {
                     self_item.transpile(self, processed_page.hash, &processed_page)
                 }
-                ellie_core::definite::items::Collecting::Extend(_) => true,
+                ellie_core::definite::items::Collecting::Extend(extend) => {
+                    self.assemble_dependency(&extend.inner_page_id);
+                    true
+                }
+                ellie_core::definite::items::Collecting::TypeDef(_) => true,
+                ellie_core::definite::items::Collecting::Interface(_) => true,
                 ellie_core::definite::items::Collecting::Loop(loop_type) => {
                     loop_type.transpile(self, processed_page.hash, &processed_page)
                 }
@@ -746,6 +927,7 @@ impl Assembler {
     }
 
     pub fn assemble(&mut self, module_maps: Vec<ModuleMap>) -> AssembleResult {
+        crate::optimizer::item_pruning::prune_module(&mut self.module);
         let main_function = self.assemble_dependency(&self.module.initial_page.clone());
         let mut native_exports = Vec::new();
 
@@ -773,6 +955,7 @@ impl Assembler {
             debug_headers: self.debug_headers.clone(),
             instructions: self.instructions.clone(),
             native_exports,
+            relocations: self.relocations.clone(),
         }
     }
 }
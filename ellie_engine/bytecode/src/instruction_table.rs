@@ -1,5 +1,5 @@
-//Auto builded from `instructions.json` by `reAssembler.js` rev: 3
-use crate::instructions::Instruction;
+//Auto builded from `instructions.json` by `reAssembler.js` rev: 6
+use crate::{addressing_modes::AddressingModes, instructions::Instruction};
 use alloc::{string::String, vec, vec::Vec};
 use ellie_core::defs::PlatformArchitecture;
 
@@ -52,6 +52,9 @@ pub enum Instructions {
     CO(Instruction),
     FN(Instruction),
     DEA(Instruction),
+    A2N(Instruction),
+    A2BI(Instruction),
+    JMPT(Instruction),
 }
 
 impl Instructions {
@@ -527,6 +530,297 @@ impl Instructions {
                 op_code.extend(e.addressing_mode.arg(platform_size));
                 op_code
             }
+            Instructions::A2N(e) => {
+                let op_code_list: [isize; 11] = [-1, 120, -1, -1, -1, -1, -1, -1, -1, -1, -1];
+                let real_op_code: isize = op_code_list[e.addressing_mode.idx()];
+                if real_op_code == -1 {
+                    panic!("Wrong addresing_mode accessed");
+                }
+                let mut op_code = vec![real_op_code as u8];
+                op_code.extend(e.addressing_mode.arg(platform_size));
+                op_code
+            }
+            Instructions::A2BI(e) => {
+                let op_code_list: [isize; 11] = [121, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1];
+                let real_op_code: isize = op_code_list[e.addressing_mode.idx()];
+                if real_op_code == -1 {
+                    panic!("Wrong addresing_mode accessed");
+                }
+                let mut op_code = vec![real_op_code as u8];
+                op_code.extend(e.addressing_mode.arg(platform_size));
+                op_code
+            }
+            Instructions::JMPT(e) => {
+                let op_code_list: [isize; 11] = [-1, 122, -1, -1, -1, -1, -1, -1, -1, -1, -1];
+                let real_op_code: isize = op_code_list[e.addressing_mode.idx()];
+                if real_op_code == -1 {
+                    panic!("Wrong addresing_mode accessed");
+                }
+                let mut op_code = vec![real_op_code as u8];
+                op_code.extend(e.addressing_mode.arg(platform_size));
+                op_code
+            }
+        }
+    }
+
+    /// Whether the instruction supports its addressing mode
+    pub fn is_addressing_mode_supported(&self) -> bool {
+        let (op_code_list, addressing_mode): ([isize; 11], &AddressingModes) = match &self {
+            Instructions::LDA(e) => ([-1, 1, 2, 3, 4, 5, -1, 6, 7, 8, 9], &e.addressing_mode),
+            Instructions::LDB(e) => (
+                [-1, 10, 11, 12, 13, 14, 15, -1, 16, 17, 18],
+                &e.addressing_mode,
+            ),
+            Instructions::LDC(e) => (
+                [-1, 19, 20, 21, 22, 23, 24, 25, -1, 26, 27],
+                &e.addressing_mode,
+            ),
+            Instructions::LDX(e) => (
+                [-1, 28, 29, 30, 31, 32, 33, 34, 35, -1, 36],
+                &e.addressing_mode,
+            ),
+            Instructions::LDY(e) => (
+                [-1, 37, 38, 39, 40, 41, 42, 43, 44, 45, -1],
+                &e.addressing_mode,
+            ),
+            Instructions::STA(e) => (
+                [46, 47, 48, 49, 50, -1, -1, -1, -1, -1, -1],
+                &e.addressing_mode,
+            ),
+            Instructions::STB(e) => (
+                [51, 52, 53, 54, 55, -1, -1, -1, -1, -1, -1],
+                &e.addressing_mode,
+            ),
+            Instructions::STC(e) => (
+                [56, 57, 58, 59, 60, -1, -1, -1, -1, -1, -1],
+                &e.addressing_mode,
+            ),
+            Instructions::STX(e) => (
+                [61, 62, 63, 64, 65, -1, -1, -1, -1, -1, -1],
+                &e.addressing_mode,
+            ),
+            Instructions::STY(e) => (
+                [66, 67, 68, 69, 70, -1, -1, -1, -1, -1, -1],
+                &e.addressing_mode,
+            ),
+            Instructions::EQ(e) => (
+                [71, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+                &e.addressing_mode,
+            ),
+            Instructions::NE(e) => (
+                [72, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+                &e.addressing_mode,
+            ),
+            Instructions::GT(e) => (
+                [73, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+                &e.addressing_mode,
+            ),
+            Instructions::LT(e) => (
+                [74, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+                &e.addressing_mode,
+            ),
+            Instructions::GQ(e) => (
+                [75, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+                &e.addressing_mode,
+            ),
+            Instructions::LQ(e) => (
+                [76, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+                &e.addressing_mode,
+            ),
+            Instructions::AND(e) => (
+                [77, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+                &e.addressing_mode,
+            ),
+            Instructions::OR(e) => (
+                [78, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+                &e.addressing_mode,
+            ),
+            Instructions::ADD(e) => (
+                [79, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+                &e.addressing_mode,
+            ),
+            Instructions::SUB(e) => (
+                [80, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+                &e.addressing_mode,
+            ),
+            Instructions::MUL(e) => (
+                [81, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+                &e.addressing_mode,
+            ),
+            Instructions::EXP(e) => (
+                [82, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+                &e.addressing_mode,
+            ),
+            Instructions::DIV(e) => (
+                [83, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+                &e.addressing_mode,
+            ),
+            Instructions::MOD(e) => (
+                [84, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+                &e.addressing_mode,
+            ),
+            Instructions::JMP(e) => (
+                [-1, -1, 85, -1, -1, -1, -1, -1, -1, -1, -1],
+                &e.addressing_mode,
+            ),
+            Instructions::JMPA(e) => (
+                [-1, -1, 86, -1, -1, -1, -1, -1, -1, -1, -1],
+                &e.addressing_mode,
+            ),
+            Instructions::CALL(e) => (
+                [-1, -1, 87, -1, -1, -1, -1, -1, -1, -1, -1],
+                &e.addressing_mode,
+            ),
+            Instructions::RET(e) => (
+                [88, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+                &e.addressing_mode,
+            ),
+            Instructions::PUSH(e) => (
+                [-1, -1, 89, 90, -1, -1, 91, 92, 93, 94, 95],
+                &e.addressing_mode,
+            ),
+            Instructions::SPUS(e) => (
+                [-1, -1, 96, 97, -1, -1, 98, 99, 100, 101, 102],
+                &e.addressing_mode,
+            ),
+            Instructions::LEN(e) => (
+                [-1, -1, 103, -1, -1, -1, -1, -1, -1, -1, -1],
+                &e.addressing_mode,
+            ),
+            Instructions::A2I(e) => (
+                [104, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+                &e.addressing_mode,
+            ),
+            Instructions::A2F(e) => (
+                [105, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+                &e.addressing_mode,
+            ),
+            Instructions::A2D(e) => (
+                [106, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+                &e.addressing_mode,
+            ),
+            Instructions::A2B(e) => (
+                [107, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+                &e.addressing_mode,
+            ),
+            Instructions::A2S(e) => (
+                [108, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+                &e.addressing_mode,
+            ),
+            Instructions::A2C(e) => (
+                [109, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+                &e.addressing_mode,
+            ),
+            Instructions::A2O(e) => (
+                [110, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+                &e.addressing_mode,
+            ),
+            Instructions::ARR(e) => (
+                [111, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+                &e.addressing_mode,
+            ),
+            Instructions::STR(e) => (
+                [112, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+                &e.addressing_mode,
+            ),
+            Instructions::SAR(e) => (
+                [-1, 113, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+                &e.addressing_mode,
+            ),
+            Instructions::POPS(e) => (
+                [-1, -1, 114, -1, -1, -1, -1, -1, -1, -1, -1],
+                &e.addressing_mode,
+            ),
+            Instructions::BRK(e) => (
+                [115, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+                &e.addressing_mode,
+            ),
+            Instructions::CALLN(e) => (
+                [-1, -1, 116, -1, -1, -1, -1, -1, -1, -1, -1],
+                &e.addressing_mode,
+            ),
+            Instructions::CO(e) => (
+                [-1, -1, 117, -1, -1, -1, -1, -1, -1, -1, -1],
+                &e.addressing_mode,
+            ),
+            Instructions::FN(e) => (
+                [-1, 118, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+                &e.addressing_mode,
+            ),
+            Instructions::DEA(e) => (
+                [-1, -1, 119, -1, -1, -1, -1, -1, -1, -1, -1],
+                &e.addressing_mode,
+            ),
+            Instructions::A2N(e) => (
+                [-1, 120, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+                &e.addressing_mode,
+            ),
+            Instructions::A2BI(e) => (
+                [121, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+                &e.addressing_mode,
+            ),
+            Instructions::JMPT(e) => (
+                [-1, 122, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+                &e.addressing_mode,
+            ),
+        };
+        op_code_list[addressing_mode.idx()] != -1
+    }
+
+    /// Build instruction from its mnemonic
+    pub fn from_mnemonic(mnemonic: &str, instruction: Instruction) -> Option<Instructions> {
+        match mnemonic {
+            "LDA" => Some(Instructions::LDA(instruction)),
+            "LDB" => Some(Instructions::LDB(instruction)),
+            "LDC" => Some(Instructions::LDC(instruction)),
+            "LDX" => Some(Instructions::LDX(instruction)),
+            "LDY" => Some(Instructions::LDY(instruction)),
+            "STA" => Some(Instructions::STA(instruction)),
+            "STB" => Some(Instructions::STB(instruction)),
+            "STC" => Some(Instructions::STC(instruction)),
+            "STX" => Some(Instructions::STX(instruction)),
+            "STY" => Some(Instructions::STY(instruction)),
+            "EQ" => Some(Instructions::EQ(instruction)),
+            "NE" => Some(Instructions::NE(instruction)),
+            "GT" => Some(Instructions::GT(instruction)),
+            "LT" => Some(Instructions::LT(instruction)),
+            "GQ" => Some(Instructions::GQ(instruction)),
+            "LQ" => Some(Instructions::LQ(instruction)),
+            "AND" => Some(Instructions::AND(instruction)),
+            "OR" => Some(Instructions::OR(instruction)),
+            "ADD" => Some(Instructions::ADD(instruction)),
+            "SUB" => Some(Instructions::SUB(instruction)),
+            "MUL" => Some(Instructions::MUL(instruction)),
+            "EXP" => Some(Instructions::EXP(instruction)),
+            "DIV" => Some(Instructions::DIV(instruction)),
+            "MOD" => Some(Instructions::MOD(instruction)),
+            "JMP" => Some(Instructions::JMP(instruction)),
+            "JMPA" => Some(Instructions::JMPA(instruction)),
+            "CALL" => Some(Instructions::CALL(instruction)),
+            "RET" => Some(Instructions::RET(instruction)),
+            "PUSH" => Some(Instructions::PUSH(instruction)),
+            "SPUS" => Some(Instructions::SPUS(instruction)),
+            "LEN" => Some(Instructions::LEN(instruction)),
+            "A2I" => Some(Instructions::A2I(instruction)),
+            "A2F" => Some(Instructions::A2F(instruction)),
+            "A2D" => Some(Instructions::A2D(instruction)),
+            "A2B" => Some(Instructions::A2B(instruction)),
+            "A2S" => Some(Instructions::A2S(instruction)),
+            "A2C" => Some(Instructions::A2C(instruction)),
+            "A2O" => Some(Instructions::A2O(instruction)),
+            "ARR" => Some(Instructions::ARR(instruction)),
+            "STR" => Some(Instructions::STR(instruction)),
+            "SAR" => Some(Instructions::SAR(instruction)),
+            "POPS" => Some(Instructions::POPS(instruction)),
+            "BRK" => Some(Instructions::BRK(instruction)),
+            "CALLN" => Some(Instructions::CALLN(instruction)),
+            "CO" => Some(Instructions::CO(instruction)),
+            "FN" => Some(Instructions::FN(instruction)),
+            "DEA" => Some(Instructions::DEA(instruction)),
+            "A2N" => Some(Instructions::A2N(instruction)),
+            "A2BI" => Some(Instructions::A2BI(instruction)),
+            "JMPT" => Some(Instructions::JMPT(instruction)),
+            _ => None,
         }
     }
 
@@ -579,6 +873,64 @@ impl Instructions {
             Instructions::CO(e) => e,
             Instructions::FN(e) => e,
             Instructions::DEA(e) => e,
+            Instructions::A2N(e) => e,
+            Instructions::A2BI(e) => e,
+            Instructions::JMPT(e) => e,
+        }
+    }
+
+    pub fn get_addressing_mode_ref(&self) -> &Instruction {
+        match self {
+            Instructions::LDA(e) => e,
+            Instructions::LDB(e) => e,
+            Instructions::LDC(e) => e,
+            Instructions::LDX(e) => e,
+            Instructions::LDY(e) => e,
+            Instructions::STA(e) => e,
+            Instructions::STB(e) => e,
+            Instructions::STC(e) => e,
+            Instructions::STX(e) => e,
+            Instructions::STY(e) => e,
+            Instructions::EQ(e) => e,
+            Instructions::NE(e) => e,
+            Instructions::GT(e) => e,
+            Instructions::LT(e) => e,
+            Instructions::GQ(e) => e,
+            Instructions::LQ(e) => e,
+            Instructions::AND(e) => e,
+            Instructions::OR(e) => e,
+            Instructions::ADD(e) => e,
+            Instructions::SUB(e) => e,
+            Instructions::MUL(e) => e,
+            Instructions::EXP(e) => e,
+            Instructions::DIV(e) => e,
+            Instructions::MOD(e) => e,
+            Instructions::JMP(e) => e,
+            Instructions::JMPA(e) => e,
+            Instructions::CALL(e) => e,
+            Instructions::RET(e) => e,
+            Instructions::PUSH(e) => e,
+            Instructions::SPUS(e) => e,
+            Instructions::LEN(e) => e,
+            Instructions::A2I(e) => e,
+            Instructions::A2F(e) => e,
+            Instructions::A2D(e) => e,
+            Instructions::A2B(e) => e,
+            Instructions::A2S(e) => e,
+            Instructions::A2C(e) => e,
+            Instructions::A2O(e) => e,
+            Instructions::ARR(e) => e,
+            Instructions::STR(e) => e,
+            Instructions::SAR(e) => e,
+            Instructions::POPS(e) => e,
+            Instructions::BRK(e) => e,
+            Instructions::CALLN(e) => e,
+            Instructions::CO(e) => e,
+            Instructions::FN(e) => e,
+            Instructions::DEA(e) => e,
+            Instructions::A2N(e) => e,
+            Instructions::A2BI(e) => e,
+            Instructions::JMPT(e) => e,
         }
     }
 
@@ -631,6 +983,9 @@ impl Instructions {
             Instructions::CO(e) => e.addressing_mode.clone(),
             Instructions::FN(e) => e.addressing_mode.clone(),
             Instructions::DEA(e) => e.addressing_mode.clone(),
+            Instructions::A2N(e) => e.addressing_mode.clone(),
+            Instructions::A2BI(e) => e.addressing_mode.clone(),
+            Instructions::JMPT(e) => e.addressing_mode.clone(),
         }
         .to_string()
     }
@@ -684,6 +1039,9 @@ impl Instructions {
             Instructions::CO(e) => e.addressing_mode.arg(platform_size),
             Instructions::FN(e) => e.addressing_mode.arg(platform_size),
             Instructions::DEA(e) => e.addressing_mode.arg(platform_size),
+            Instructions::A2N(e) => e.addressing_mode.arg(platform_size),
+            Instructions::A2BI(e) => e.addressing_mode.arg(platform_size),
+            Instructions::JMPT(e) => e.addressing_mode.arg(platform_size),
         }
     }
 }
@@ -738,6 +1096,9 @@ impl core::fmt::Display for Instructions {
             Instructions::CO(e) => write!(f, "CO {}", e.addressing_mode),
             Instructions::FN(e) => write!(f, "FN {}", e.addressing_mode),
             Instructions::DEA(e) => write!(f, "DEA {}", e.addressing_mode),
+            Instructions::A2N(e) => write!(f, "A2N {}", e.addressing_mode),
+            Instructions::A2BI(e) => write!(f, "A2BI {}", e.addressing_mode),
+            Instructions::JMPT(e) => write!(f, "JMPT {}", e.addressing_mode),
         }
     }
 }
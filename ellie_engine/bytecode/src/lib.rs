@@ -14,12 +14,18 @@ extern crate std;
 /// Assembles ellie elements
 pub mod assembler;
 
+/// Parses byteCodeAsm text back into assembled instructions
+pub mod asm_parser;
+
 /// Instruction table
 pub mod instruction_table;
 
 /// Bytecode instructions
 pub mod instructions;
 
+/// Optimization passes
+pub mod optimizer;
+
 /// Utils
 pub mod utils;
 
@@ -4,6 +4,8 @@ use alloc::{
     vec::Vec,
 };
 
+use ellie_core::defs::IntegerSize;
+
 use crate::{
     addressing_modes::AddressingModes, instruction_table::Instructions, instructions::Instruction,
     types::Types,
@@ -18,6 +20,7 @@ fn parse_type_text(text: String) -> Option<(Types, Vec<u8>)> {
     //#(bool)true
     //#(string)"Hello World"
     //#(char)'a'
+    //#(u8)255
     //#(void)
     //#(null)
 
@@ -40,7 +43,12 @@ fn parse_type_text(text: String) -> Option<(Types, Vec<u8>)> {
             .to_vec(),
         "void" => vec![0],
         "null" => vec![0],
-        _ => panic!("Unknown type"),
+        _ => match IntegerSize::from_type_name(_type) {
+            Some(size) if size.is_fixed() => {
+                _value.parse::<i128>().unwrap().to_le_bytes()[..size.byte_size()].to_vec()
+            }
+            _ => panic!("Unknown type"),
+        },
     }
     .try_into()
     .unwrap();
@@ -53,7 +61,7 @@ fn parse_type_text(text: String) -> Option<(Types, Vec<u8>)> {
         "char" => Some((Types::Char, value)),
         "void" => Some((Types::Void, value)),
         "null" => Some((Types::Null, value)),
-        _ => None,
+        _ => IntegerSize::from_type_name(_type).map(|size| (Types::FixedInteger(size), value)),
     }
 }
 
@@ -137,7 +145,10 @@ pub fn parse_instruction_text(text: String) -> Option<Instructions> {
         "A2S" => Some(Instructions::A2S(Instruction { addressing_mode })),
         "A2C" => Some(Instructions::A2C(Instruction { addressing_mode })),
         "A2O" => Some(Instructions::A2O(Instruction { addressing_mode })),
+        "A2N" => Some(Instructions::A2N(Instruction { addressing_mode })),
+        "A2BI" => Some(Instructions::A2BI(Instruction { addressing_mode })),
         "JMPA" => Some(Instructions::JMPA(Instruction { addressing_mode })),
+        "JMPT" => Some(Instructions::JMPT(Instruction { addressing_mode })),
         "POPS" => Some(Instructions::POPS(Instruction { addressing_mode })),
         "BRK" => Some(Instructions::BRK(Instruction { addressing_mode })),
         "CO" => Some(Instructions::CO(Instruction { addressing_mode })),
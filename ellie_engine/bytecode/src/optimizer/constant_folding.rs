@@ -0,0 +1,521 @@
+use alloc::{boxed::Box, collections::BTreeMap, format, string::String, vec, vec::Vec};
+use ellie_core::{
+    definite::{
+        items::{condition::ConditionType, match_type::MatchPattern, Collecting},
+        types::{
+            bool::BoolType,
+            decimal::{DecimalType, DecimalTypeEnum},
+            integer::{IntegerSize, IntegerType},
+            operator::{
+                ArithmeticOperators, ComparisonOperators, LogicalOperators, OperatorType, Operators,
+            },
+            string::StringType,
+            Types,
+        },
+    },
+    defs::{Cursor, PlatformArchitecture},
+};
+use ellie_parser::parser::Module;
+
+/// Constant folder, holds values of `c` constants that can be inlined
+pub struct ConstantFolder {
+    constants: BTreeMap<usize, Types>,
+    /// Target architecture, `int` has its width
+    architecture: PlatformArchitecture,
+}
+
+/// Literals that are cheap enough to be copied to every place they're used
+fn is_inlineable(value: &Types) -> bool {
+    matches!(
+        value,
+        Types::Byte(_) | Types::Integer(_) | Types::Decimal(_) | Types::Bool(_) | Types::Char(_)
+    )
+}
+
+/// Single element cloaks are parentheses around the value, see through them
+fn peel(value: &Types) -> &Types {
+    match value {
+        Types::Cloak(e) if e.collective.len() == 1 => peel(&e.collective[0].value),
+        e => e,
+    }
+}
+
+/// `int` of the target architecture, values that don't fit to its width overflow on runtime and
+/// are not folded
+fn integer(value: isize, architecture: PlatformArchitecture, pos: Cursor) -> Option<Types> {
+    let fits = match architecture {
+        PlatformArchitecture::B16 => i16::try_from(value).is_ok(),
+        PlatformArchitecture::B32 => i32::try_from(value).is_ok(),
+        PlatformArchitecture::B64 => true,
+    };
+    if fits {
+        Some(Types::Integer(IntegerType {
+            value,
+            size: IntegerSize::Int,
+            pos,
+        }))
+    } else {
+        None
+    }
+}
+
+/// Fixed width integer, values that don't fit to the width overflow on runtime and are not folded
+fn fixed_integer(value: i128, size: IntegerSize, pos: Cursor) -> Option<Types> {
+    if size.fits(value) {
+        Some(Types::Integer(IntegerType {
+            value: value as isize,
+            size,
+            pos,
+        }))
+    } else {
+        None
+    }
+}
+
+fn decimal(value: DecimalTypeEnum, pos: Cursor) -> Option<Types> {
+    let finite = match value {
+        DecimalTypeEnum::Float(e) => e.is_finite(),
+        DecimalTypeEnum::Double(e) => e.is_finite(),
+    };
+    // Non finite results panic in vm, keep them to runtime
+    if finite {
+        Some(Types::Decimal(DecimalType {
+            is_double: matches!(value, DecimalTypeEnum::Double(_)),
+            value,
+            pos,
+        }))
+    } else {
+        None
+    }
+}
+
+fn boolean(value: bool) -> Types {
+    Types::Bool(BoolType { value })
+}
+
+fn string(value: String, pos: Cursor) -> Types {
+    Types::String(StringType { value, pos })
+}
+
+/// Fold an arithmetic operation, follows the rules of the vm instructions. Operations that
+/// would panic on runtime such as overflows or division by zero are not folded
+fn fold_arithmetic(
+    first: &Types,
+    second: &Types,
+    operator: &ArithmeticOperators,
+    architecture: PlatformArchitecture,
+    pos: Cursor,
+) -> Option<Types> {
+    match (first, second) {
+        // Result has the width of the first operand, vm only accepts `int` as the other side
+        (Types::Integer(first), Types::Integer(second))
+            if first.size.is_fixed()
+                && (second.size == first.size || second.size == IntegerSize::Int) =>
+        {
+            let size = first.size;
+            let (first, second) = (first.wide_value(), second.wide_value());
+            match operator {
+                ArithmeticOperators::Addition => first.checked_add(second),
+                ArithmeticOperators::Subtraction => first.checked_sub(second),
+                ArithmeticOperators::Multiplication => first.checked_mul(second),
+                ArithmeticOperators::Division => first.checked_div(second),
+                ArithmeticOperators::Modulus => first.checked_rem(second),
+                ArithmeticOperators::Exponentiation => u32::try_from(second)
+                    .ok()
+                    .and_then(|second| first.checked_pow(second)),
+                ArithmeticOperators::Null => None,
+            }
+            .and_then(|value| fixed_integer(value, size, pos))
+        }
+        (Types::Integer(first), Types::Integer(second))
+            if first.size == IntegerSize::Int && second.size == IntegerSize::Int =>
+        {
+            let (first, second) = (first.value, second.value);
+            match operator {
+                ArithmeticOperators::Addition => first.checked_add(second),
+                ArithmeticOperators::Subtraction => first.checked_sub(second),
+                ArithmeticOperators::Multiplication => first.checked_mul(second),
+                ArithmeticOperators::Division => first.checked_div(second),
+                ArithmeticOperators::Modulus => first.checked_rem(second),
+                ArithmeticOperators::Exponentiation => u32::try_from(second)
+                    .ok()
+                    .and_then(|second| first.checked_pow(second)),
+                ArithmeticOperators::Null => None,
+            }
+            .and_then(|value| integer(value, architecture, pos))
+        }
+        (Types::Decimal(first), Types::Decimal(second)) => match (&first.value, &second.value) {
+            (DecimalTypeEnum::Float(first), DecimalTypeEnum::Float(second)) => {
+                let value = match operator {
+                    ArithmeticOperators::Addition => first + second,
+                    ArithmeticOperators::Subtraction => first - second,
+                    ArithmeticOperators::Multiplication => first * second,
+                    ArithmeticOperators::Division => first / second,
+                    ArithmeticOperators::Modulus => first % second,
+                    _ => return None,
+                };
+                decimal(DecimalTypeEnum::Float(value), pos)
+            }
+            (DecimalTypeEnum::Double(first), DecimalTypeEnum::Double(second)) => {
+                let value = match operator {
+                    ArithmeticOperators::Addition => first + second,
+                    ArithmeticOperators::Subtraction => first - second,
+                    ArithmeticOperators::Multiplication => first * second,
+                    ArithmeticOperators::Division => first / second,
+                    ArithmeticOperators::Modulus => first % second,
+                    _ => return None,
+                };
+                decimal(DecimalTypeEnum::Double(value), pos)
+            }
+            _ => None,
+        },
+        (Types::String(first), Types::String(second))
+            if *operator == ArithmeticOperators::Addition =>
+        {
+            Some(string(format!("{}{}", first.value, second.value), pos))
+        }
+        (Types::String(first), Types::Integer(second))
+            if *operator == ArithmeticOperators::Addition =>
+        {
+            Some(string(
+                format!("{}{}", first.value, second.wide_value()),
+                pos,
+            ))
+        }
+        (Types::Integer(first), Types::String(second))
+            if *operator == ArithmeticOperators::Addition && first.size == IntegerSize::Int =>
+        {
+            Some(string(format!("{}{}", first.value, second.value), pos))
+        }
+        (Types::String(first), Types::Char(second))
+            if *operator == ArithmeticOperators::Addition =>
+        {
+            Some(string(format!("{}{}", first.value, second.value), pos))
+        }
+        _ => None,
+    }
+}
+
+fn compare<T: PartialOrd>(first: T, second: T, operator: &ComparisonOperators) -> Option<bool> {
+    match operator {
+        ComparisonOperators::Equal => Some(first == second),
+        ComparisonOperators::NotEqual => Some(first != second),
+        ComparisonOperators::GreaterThan => Some(first > second),
+        ComparisonOperators::LessThan => Some(first < second),
+        ComparisonOperators::GreaterThanOrEqual => Some(first >= second),
+        ComparisonOperators::LessThanOrEqual => Some(first <= second),
+        ComparisonOperators::Null => None,
+    }
+}
+
+fn fold_comparison(first: &Types, second: &Types, operator: &ComparisonOperators) -> Option<Types> {
+    let equality = matches!(
+        operator,
+        ComparisonOperators::Equal | ComparisonOperators::NotEqual
+    );
+    match (first, second) {
+        (Types::Integer(first), Types::Integer(second)) => {
+            compare(first.wide_value(), second.wide_value(), operator)
+        }
+        (Types::Byte(first), Types::Byte(second)) => compare(first.value, second.value, operator),
+        (Types::Decimal(first), Types::Decimal(second)) => match (&first.value, &second.value) {
+            (DecimalTypeEnum::Float(first), DecimalTypeEnum::Float(second)) => {
+                compare(first, second, operator)
+            }
+            (DecimalTypeEnum::Double(first), DecimalTypeEnum::Double(second)) => {
+                compare(first, second, operator)
+            }
+            _ => None,
+        },
+        (Types::Bool(first), Types::Bool(second)) if equality => {
+            compare(first.value, second.value, operator)
+        }
+        (Types::Char(first), Types::Char(second)) if equality => {
+            compare(first.value, second.value, operator)
+        }
+        (Types::String(first), Types::String(second)) if equality => {
+            compare(&first.value, &second.value, operator)
+        }
+        _ => None,
+    }
+    .map(boolean)
+}
+
+fn fold_logical(first: &Types, second: &Types, operator: &LogicalOperators) -> Option<Types> {
+    match (first, second) {
+        (Types::Bool(first), Types::Bool(second)) => match operator {
+            LogicalOperators::And => Some(boolean(first.value && second.value)),
+            LogicalOperators::Or => Some(boolean(first.value || second.value)),
+            LogicalOperators::Null => None,
+        },
+        _ => None,
+    }
+}
+
+impl ConstantFolder {
+    pub fn new(architecture: PlatformArchitecture) -> Self {
+        ConstantFolder {
+            constants: BTreeMap::new(),
+            architecture,
+        }
+    }
+
+    /// Collect values of constants that fold into a literal, constants referencing other
+    /// constants are resolved by collecting until nothing new is found
+    pub fn collect_constants(&mut self, module: &Module) {
+        //For loop and match variables hold a placeholder value, their real value is bound by the loop or match
+        let bound_variables = module
+            .pages
+            .iter()
+            .flat_map(|page| page.items.iter())
+            .flat_map(|item| match item {
+                Collecting::ForLoop(for_loop) => vec![for_loop.inner_page_id],
+                Collecting::Match(match_item) => match_item
+                    .arms
+                    .iter()
+                    .filter(|arm| {
+                        matches!(arm.pattern, MatchPattern::EnumVariant { binds: true, .. })
+                    })
+                    .map(|arm| arm.inner_page_id)
+                    .collect(),
+                _ => Vec::new(),
+            })
+            .filter_map(|inner_page_id| {
+                module
+                    .pages
+                    .iter()
+                    .find(|page| page.hash == inner_page_id)
+                    .and_then(|page| match page.items.first() {
+                        Some(Collecting::Variable(variable)) => Some(variable.hash),
+                        _ => None,
+                    })
+            })
+            .collect::<Vec<_>>();
+        loop {
+            let found = self.constants.len();
+            for page in module.pages.iter() {
+                for item in &page.items {
+                    if let Collecting::Variable(variable) = item {
+                        if variable.constant
+                            && !bound_variables.contains(&variable.hash)
+                            && !self.constants.contains_key(&variable.hash)
+                        {
+                            let value = self.fold(variable.value.clone());
+                            if is_inlineable(&value) {
+                                self.constants.insert(variable.hash, value);
+                            }
+                        }
+                    }
+                }
+            }
+            if self.constants.len() == found {
+                break;
+            }
+        }
+    }
+
+    /// Fold given type, returns the type untouched if it can't be folded
+    pub fn fold(&self, types: Types) -> Types {
+        match types {
+            Types::Operator(e) => {
+                let first = self.fold(*e.first);
+                let second = self.fold(*e.second);
+                let folded = match &e.operator {
+                    Operators::ArithmeticType(operator) => fold_arithmetic(
+                        peel(&first),
+                        peel(&second),
+                        operator,
+                        self.architecture,
+                        e.pos,
+                    ),
+                    Operators::ComparisonType(operator) => {
+                        fold_comparison(peel(&first), peel(&second), operator)
+                    }
+                    Operators::LogicalType(operator) => {
+                        fold_logical(peel(&first), peel(&second), operator)
+                    }
+                    _ => None,
+                };
+                folded.unwrap_or_else(|| {
+                    Types::Operator(OperatorType {
+                        first: Box::new(first),
+                        second: Box::new(second),
+                        ..e
+                    })
+                })
+            }
+            Types::Negative(mut e) => {
+                let value = self.fold(*e.value);
+                match peel(&value) {
+                    Types::Integer(integer_type) if integer_type.size.is_fixed() => {
+                        if let Some(negated) =
+                            fixed_integer(-integer_type.wide_value(), integer_type.size, e.pos)
+                        {
+                            return negated;
+                        }
+                    }
+                    Types::Integer(integer_type) => {
+                        if let Some(negated) = integer_type
+                            .value
+                            .checked_neg()
+                            .and_then(|negated| integer(negated, self.architecture, e.pos))
+                        {
+                            return negated;
+                        }
+                    }
+                    Types::Decimal(decimal_type) => {
+                        return Types::Decimal(DecimalType {
+                            value: match decimal_type.value {
+                                DecimalTypeEnum::Float(e) => DecimalTypeEnum::Float(-e),
+                                DecimalTypeEnum::Double(e) => DecimalTypeEnum::Double(-e),
+                            },
+                            pos: e.pos,
+                            is_double: decimal_type.is_double,
+                        })
+                    }
+                    _ => (),
+                }
+                e.value = Box::new(value);
+                Types::Negative(e)
+            }
+            Types::VariableType(e) => match self.constants.get(&e.reference) {
+                Some(value) => value.clone(),
+                None => Types::VariableType(e),
+            },
+            Types::Cloak(mut e) => {
+                for entry in e.collective.iter_mut() {
+                    entry.value = self.fold(core::mem::take(&mut entry.value));
+                }
+                Types::Cloak(e)
+            }
+            Types::Array(mut e) => {
+                for entry in e.collective.iter_mut() {
+                    entry.value = self.fold(core::mem::take(&mut entry.value));
+                }
+                Types::Array(e)
+            }
+            Types::Collective(mut e) => {
+                for entry in e.entries.iter_mut() {
+                    entry.value = self.fold(core::mem::take(&mut entry.value));
+                }
+                Types::Collective(e)
+            }
+            Types::FunctionCall(mut e) => {
+                for param in e.params.iter_mut() {
+                    param.value = self.fold(core::mem::take(&mut param.value));
+                }
+                Types::FunctionCall(e)
+            }
+            Types::ClassCall(mut e) => {
+                for param in e.params.iter_mut() {
+                    param.value = self.fold(core::mem::take(&mut param.value));
+                }
+                Types::ClassCall(e)
+            }
+            Types::BraceReference(mut e) => {
+                e.value = Box::new(self.fold(*e.value));
+                Types::BraceReference(e)
+            }
+            Types::AsKeyword(mut e) => {
+                e.target = Box::new(self.fold(*e.target));
+                Types::AsKeyword(e)
+            }
+            e => e,
+        }
+    }
+
+    /// Fold types of an item, returns [`None`] if item is never executed and can be removed
+    pub fn fold_item(&self, item: Collecting) -> Option<Collecting> {
+        match item {
+            Collecting::Variable(mut e) => {
+                e.value = self.fold(e.value);
+                Some(Collecting::Variable(e))
+            }
+            Collecting::SetterCall(mut e) => {
+                e.value = self.fold(e.value);
+                Some(Collecting::SetterCall(e))
+            }
+            Collecting::GetterCall(mut e) => {
+                e.data = self.fold(e.data);
+                Some(Collecting::GetterCall(e))
+            }
+            Collecting::Ret(mut e) => {
+                e.value = self.fold(e.value);
+                Some(Collecting::Ret(e))
+            }
+            Collecting::ForLoop(mut e) => {
+                e.iterator = self.fold(e.iterator);
+                Some(Collecting::ForLoop(e))
+            }
+            Collecting::Match(mut e) => {
+                e.target = self.fold(e.target);
+                for arm in e.arms.iter_mut() {
+                    if let MatchPattern::Value(value) = &arm.pattern {
+                        arm.pattern = MatchPattern::Value(self.fold(value.clone()));
+                    }
+                }
+                Some(Collecting::Match(e))
+            }
+            Collecting::Loop(mut e) => {
+                e.condition = self.fold(e.condition);
+                match peel(&e.condition) {
+                    Types::Bool(condition) if !condition.value => None,
+                    _ => Some(Collecting::Loop(e)),
+                }
+            }
+            Collecting::Condition(mut e) => {
+                let mut chains = Vec::new();
+                for mut chain in e.chains {
+                    if chain.rtype != ConditionType::Else {
+                        chain.condition = Box::new(self.fold(*chain.condition));
+                    }
+                    match peel(&chain.condition) {
+                        // Branch is never taken
+                        Types::Bool(condition) if !condition.value => continue,
+                        // Branch is always taken, rest of the chains are unreachable
+                        Types::Bool(_) => {
+                            chain.rtype = ConditionType::Else;
+                            chain.condition = Box::new(Types::Void);
+                            chains.push(chain);
+                            break;
+                        }
+                        _ => {
+                            let is_else = chain.rtype == ConditionType::Else;
+                            chains.push(chain);
+                            if is_else {
+                                break;
+                            }
+                        }
+                    }
+                }
+                if chains.is_empty() {
+                    None
+                } else {
+                    // First chain must be an if
+                    if chains[0].rtype == ConditionType::ElseIf {
+                        chains[0].rtype = ConditionType::If;
+                    }
+                    e.chains = chains;
+                    Some(Collecting::Condition(e))
+                }
+            }
+            e => Some(e),
+        }
+    }
+}
+
+/// Fold constant expressions in every page of the module, inline values of `c` constants and
+/// remove branches with constant conditions
+/// ## Arguments
+/// * `module` - [`Module`] to optimize
+/// * `architecture` - Target [`PlatformArchitecture`], integers are folded in its width
+pub fn fold_module(module: &mut Module, architecture: PlatformArchitecture) {
+    let mut folder = ConstantFolder::new(architecture);
+    folder.collect_constants(module);
+    for page in module.pages.iter_mut() {
+        page.items = core::mem::take(&mut page.items)
+            .into_iter()
+            .filter_map(|item| folder.fold_item(item))
+            .collect();
+    }
+}
@@ -0,0 +1,189 @@
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    format,
+    string::String,
+    vec::Vec,
+};
+use ellie_core::definite::{
+    items::{match_type::MatchPattern, Collecting},
+    types::{enum_data::Pointer, Types},
+};
+use ellie_parser::parser::Module;
+
+/// Owner of the references that are not made in a module function
+const PROGRAM_SCOPE: usize = 0;
+
+/// Collects the items referenced by each module function and by rest of the program
+#[derive(Default)]
+struct ReferenceCollector {
+    references: BTreeMap<usize, BTreeSet<usize>>,
+}
+
+impl ReferenceCollector {
+    fn walk_items(&mut self, items: &[Collecting], owner: usize) {
+        for item in items {
+            match item {
+                Collecting::Variable(e) => self.walk_type(&e.value, owner),
+                Collecting::Condition(e) => {
+                    for chain in &e.chains {
+                        self.walk_type(&chain.condition, owner);
+                    }
+                }
+                Collecting::Match(e) => {
+                    self.walk_type(&e.target, owner);
+                    for arm in &e.arms {
+                        if let MatchPattern::Value(value) = &arm.pattern {
+                            self.walk_type(value, owner);
+                        }
+                    }
+                }
+                Collecting::Loop(e) => self.walk_type(&e.condition, owner),
+                Collecting::ForLoop(e) => self.walk_type(&e.iterator, owner),
+                Collecting::Ret(e) => self.walk_type(&e.value, owner),
+                Collecting::GetterCall(e) => self.walk_type(&e.data, owner),
+                Collecting::SetterCall(e) => {
+                    self.walk_type(&e.target, owner);
+                    self.walk_type(&e.value, owner);
+                }
+                _ => (),
+            }
+        }
+    }
+
+    fn walk_type(&mut self, rtype: &Types, owner: usize) {
+        match rtype {
+            Types::Collective(e) => {
+                for entry in &e.entries {
+                    self.walk_type(&entry.value, owner);
+                }
+            }
+            Types::Reference(e) => {
+                self.walk_type(&e.reference, owner);
+                let references = self.references.entry(owner).or_default();
+                references.extend(e.index_chain.iter().map(|chain| chain.hash));
+            }
+            Types::BraceReference(e) => {
+                self.walk_type(&e.reference, owner);
+                self.walk_type(&e.value, owner);
+            }
+            Types::EnumData(e) => {
+                self.walk_type(&e.reference, owner);
+                if let Pointer::Data(data) = &e.value {
+                    self.walk_type(data, owner);
+                }
+            }
+            Types::Operator(e) => {
+                self.walk_type(&e.first, owner);
+                self.walk_type(&e.second, owner);
+            }
+            Types::Cloak(e) => {
+                for entry in &e.collective {
+                    self.walk_type(&entry.value, owner);
+                }
+            }
+            Types::Array(e) => {
+                for entry in &e.collective {
+                    self.walk_type(&entry.value, owner);
+                }
+            }
+            Types::Function(e) => self.walk_items(&e.inside_code, owner),
+            Types::ClassCall(e) => {
+                self.walk_type(&e.target, owner);
+                for parameter in &e.params {
+                    self.walk_type(&parameter.value, owner);
+                }
+            }
+            Types::FunctionCall(e) => {
+                self.walk_type(&e.target, owner);
+                for parameter in &e.params {
+                    self.walk_type(&parameter.value, owner);
+                }
+            }
+            Types::NullResolver(e) => self.walk_type(&e.target, owner),
+            Types::Negative(e) => self.walk_type(&e.value, owner),
+            Types::AsKeyword(e) => self.walk_type(&e.target, owner),
+            Types::VariableType(e) => {
+                self.references
+                    .entry(owner)
+                    .or_default()
+                    .insert(e.reference);
+            }
+            _ => (),
+        }
+    }
+
+    fn reachable(&self) -> BTreeSet<usize> {
+        let mut reachable = BTreeSet::new();
+        let mut queue = alloc::vec![PROGRAM_SCOPE];
+        while let Some(owner) = queue.pop() {
+            if !reachable.insert(owner) {
+                continue;
+            }
+            if let Some(references) = self.references.get(&owner) {
+                queue.extend(references.iter().filter(|x| !reachable.contains(x)));
+            }
+        }
+        reachable
+    }
+}
+
+/// Remove functions of linked modules that the program never calls. Modules are linked as a
+/// whole, without pruning every program would carry all functions of the modules it links.
+/// Classes and their members are kept as they are.
+/// ## Parameters
+/// * `module` - [`Module`] that is going to be assembled
+pub fn prune_module(module: &mut Module) {
+    let module_paths: Vec<String> = module
+        .modules
+        .iter()
+        .map(|linked| format!("<ellie_module_{}>/", linked.name))
+        .collect();
+    let is_module_page = |path: &String| module_paths.iter().any(|prefix| path.starts_with(prefix));
+
+    // Body page of each top level module function
+    let function_pages: BTreeMap<usize, usize> = module
+        .pages
+        .iter()
+        .filter(|page| page.inner.is_none() && is_module_page(&page.path))
+        .flat_map(|page| page.items.iter())
+        .filter_map(|item| match item {
+            Collecting::Function(e) => Some((e.inner_page_id, e.hash)),
+            _ => None,
+        })
+        .collect();
+    let parents: BTreeMap<usize, Option<usize>> = module
+        .pages
+        .iter()
+        .map(|page| (page.hash, page.inner))
+        .collect();
+
+    let mut collector = ReferenceCollector::default();
+    for page in module.pages.iter() {
+        // Pages inherit the owner of the function they are nested in
+        let mut current = Some(page.hash);
+        let mut owner = PROGRAM_SCOPE;
+        while let Some(page_hash) = current {
+            if let Some(function_hash) = function_pages.get(&page_hash) {
+                owner = *function_hash;
+                break;
+            }
+            current = match parents.get(&page_hash) {
+                Some(Some(parent)) if *parent != page_hash => Some(*parent),
+                _ => None,
+            };
+        }
+        collector.walk_items(&page.items, owner);
+    }
+
+    let reachable = collector.reachable();
+    for page in module.pages.iter_mut() {
+        if page.inner.is_some() || !is_module_page(&page.path) {
+            continue;
+        }
+        page.items.retain(|item| match item {
+            Collecting::Function(e) => reachable.contains(&e.hash),
+            Collecting::NativeFunction(e) => reachable.contains(&e.hash),
+            _ => true,
+        });
+    }
+}
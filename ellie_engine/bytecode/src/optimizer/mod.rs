@@ -0,0 +1,8 @@
+/// Constant folding and dead branch elimination, runs on processed pages before assembly
+pub mod constant_folding;
+
+/// Removal of linked module functions that are never called, runs on processed pages before assembly
+pub mod item_pruning;
+
+/// Peephole optimizations over assembled instructions
+pub mod peephole;
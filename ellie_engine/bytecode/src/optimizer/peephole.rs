@@ -0,0 +1,350 @@
+use alloc::{collections::BTreeSet, vec, vec::Vec};
+use ellie_core::defs::PlatformArchitecture;
+
+use crate::{
+    addressing_modes::AddressingModes, assembler::AssembleResult, instruction_table::Instructions,
+    instructions::Instruction, utils::usize_to_le_bytes,
+};
+
+fn immediate_to_usize(bytes: &[u8]) -> usize {
+    bytes
+        .iter()
+        .rev()
+        .fold(0_usize, |acc, byte| (acc << 8) | *byte as usize)
+}
+
+/// Number of entries of a jump table, entries follow the `JMPT` instruction
+fn jump_table_len(instruction: &Instructions) -> Option<usize> {
+    match instruction {
+        Instructions::JMPT(e) => match &e.addressing_mode {
+            AddressingModes::Immediate(_, bytes) => Some(immediate_to_usize(bytes)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn jump_target(instruction: &Instructions) -> Option<usize> {
+    match instruction {
+        Instructions::JMP(e) | Instructions::JMPA(e) => match e.addressing_mode {
+            AddressingModes::Absolute(target) => Some(target),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Register that is loaded by the instruction and the addressing of the load
+fn load_of(instruction: &Instructions) -> Option<(u8, &Instruction)> {
+    match instruction {
+        Instructions::LDA(e) => Some((0, e)),
+        Instructions::LDB(e) => Some((1, e)),
+        Instructions::LDC(e) => Some((2, e)),
+        Instructions::LDX(e) => Some((3, e)),
+        Instructions::LDY(e) => Some((4, e)),
+        _ => None,
+    }
+}
+
+/// Register that is stored by the instruction and the addressing of the store
+fn store_of(instruction: &Instructions) -> Option<(u8, &Instruction)> {
+    match instruction {
+        Instructions::STA(e) => Some((0, e)),
+        Instructions::STB(e) => Some((1, e)),
+        Instructions::STC(e) => Some((2, e)),
+        Instructions::STX(e) => Some((3, e)),
+        Instructions::STY(e) => Some((4, e)),
+        _ => None,
+    }
+}
+
+/// Peephole optimizer, works on the flat instruction stream of an [`AssembleResult`]
+///
+/// Every instruction owns the stack slot at its own location, so instructions referenced by
+/// an address are never removed. Removing an instruction shifts the ones after it, every
+/// absolute address, immediate marked by the assembler in [`AssembleResult::relocations`], local
+/// and debug header is relocated to the new locations.
+struct PeepholeOptimizer<'a> {
+    result: &'a mut AssembleResult,
+    /// Locations that are used as data, these cannot be removed
+    referenced: BTreeSet<usize>,
+    /// Locations that are jumped to
+    jump_targets: BTreeSet<usize>,
+    /// Parameter slots, these are written by the caller instead of their instruction
+    parameters: BTreeSet<usize>,
+}
+
+impl PeepholeOptimizer<'_> {
+    fn arch(&self) -> PlatformArchitecture {
+        self.result.module_info.platform_attributes.architecture
+    }
+
+    /// Point jumps that land on an unconditional jump to its final target
+    fn thread_jumps(&mut self) {
+        let instructions = &self.result.instructions;
+        let mut threaded = Vec::new();
+        for (idx, instruction) in instructions.iter().enumerate() {
+            if let Some(mut target) = jump_target(instruction) {
+                let mut steps = 0;
+                while let Some(Instructions::JMP(next)) = instructions.get(target) {
+                    match next.addressing_mode {
+                        AddressingModes::Absolute(next) if next != target => target = next,
+                        _ => break,
+                    }
+                    steps += 1;
+                    // Jump cycle, leave it as is
+                    if steps > instructions.len() {
+                        break;
+                    }
+                }
+                threaded.push((idx, target));
+            }
+        }
+        for (idx, target) in threaded {
+            self.result.instructions[idx]
+                .get_addressing_mode_mut()
+                .addressing_mode = AddressingModes::Absolute(target);
+        }
+    }
+
+    fn collect_references(&mut self) {
+        let instructions = &self.result.instructions;
+        for (idx, instruction) in instructions.iter().enumerate() {
+            let is_jump = matches!(instruction, Instructions::JMP(_) | Instructions::JMPA(_));
+            match instruction.get_addressing_mode_ref().addressing_mode {
+                AddressingModes::Absolute(e) | AddressingModes::AbsoluteStatic(e) => {
+                    if is_jump {
+                        self.jump_targets.insert(e);
+                    } else {
+                        self.referenced.insert(e);
+                    }
+                }
+                AddressingModes::AbsoluteIndex(e, i) => {
+                    self.referenced.insert(e);
+                    self.referenced.insert(i);
+                }
+                AddressingModes::AbsoluteProperty(e, _) => {
+                    self.referenced.insert(e);
+                }
+                _ => (),
+            }
+
+            // Entries and the instruction after them are jumped to by their position
+            if let Some(len) = jump_table_len(instruction) {
+                self.referenced.extend(idx + 1..=idx + len + 1);
+            }
+
+            // Function header, escape position and parameter count follows it
+            if let Instructions::FN(_) = instruction {
+                self.referenced.extend([idx, idx + 1, idx + 2]);
+                if let Some(Instructions::STA(count)) = instructions.get(idx + 2) {
+                    if let AddressingModes::Immediate(_, bytes) = &count.addressing_mode {
+                        let start = idx + 3;
+                        self.parameters
+                            .extend(start..start + immediate_to_usize(bytes));
+                    }
+                }
+            }
+        }
+
+        // Immediates marked by the assembler as locations, escape positions, call parameter
+        // pointers and static array entries
+        for relocation in &self.result.relocations {
+            if let Some(instruction) = instructions.get(*relocation) {
+                if let AddressingModes::Immediate(_, bytes) =
+                    &instruction.get_addressing_mode_ref().addressing_mode
+                {
+                    self.referenced.insert(immediate_to_usize(bytes));
+                }
+            }
+        }
+
+        self.referenced.insert(0);
+        for local in &self.result.locals {
+            self.referenced.insert(local.cursor);
+        }
+        if let Some(main_function) = &self.result.module_info.main_function {
+            self.referenced.insert(main_function.start);
+        }
+    }
+
+    /// Walk the control flow from program start and every function
+    fn reachable(&self) -> Vec<bool> {
+        let instructions = &self.result.instructions;
+        let mut reachable = vec![false; instructions.len()];
+        let mut queue: Vec<usize> = instructions
+            .iter()
+            .enumerate()
+            .filter(|(_, instruction)| matches!(instruction, Instructions::FN(_)))
+            .map(|(idx, _)| idx)
+            .collect();
+        queue.push(0);
+
+        while let Some(idx) = queue.pop() {
+            if idx >= instructions.len() || reachable[idx] {
+                continue;
+            }
+            reachable[idx] = true;
+            match &instructions[idx] {
+                Instructions::JMP(_) => queue.extend(jump_target(&instructions[idx])),
+                Instructions::JMPA(_) => {
+                    queue.extend(jump_target(&instructions[idx]));
+                    queue.push(idx + 1);
+                }
+                Instructions::RET(_) => (),
+                Instructions::JMPT(_) => {
+                    let len = jump_table_len(&instructions[idx]).unwrap_or(0);
+                    queue.extend(idx + 1..=idx + len + 1);
+                }
+                Instructions::FN(_) => {
+                    queue.push(idx + 1);
+                    if let Some(Instructions::STA(escape)) = instructions.get(idx + 1) {
+                        if let AddressingModes::Immediate(_, bytes) = &escape.addressing_mode {
+                            queue.push(immediate_to_usize(bytes) + 1);
+                        }
+                    }
+                }
+                _ => queue.push(idx + 1),
+            }
+        }
+        reachable
+    }
+
+    fn removable(&self) -> Vec<bool> {
+        let reachable = self.reachable();
+        let instructions = &self.result.instructions;
+        instructions
+            .iter()
+            .enumerate()
+            .map(|(idx, instruction)| {
+                if self.referenced.contains(&idx) {
+                    return false;
+                }
+                if !reachable[idx] {
+                    return true;
+                }
+                // Jump to next instruction
+                if jump_target(instruction) == Some(idx + 1) {
+                    return true;
+                }
+                // Load of the value that is just stored from the same register
+                if idx > 0
+                    && !self.jump_targets.contains(&idx)
+                    && !self.parameters.contains(&(idx - 1))
+                {
+                    if let (Some(load), Some(store)) =
+                        (load_of(instruction), store_of(&instructions[idx - 1]))
+                    {
+                        let stored_to = match store.1.addressing_mode {
+                            AddressingModes::Implicit => Some(idx - 1),
+                            AddressingModes::Absolute(e) => Some(e),
+                            _ => None,
+                        };
+                        return load.0 == store.0
+                            && matches!(
+                                (&load.1.addressing_mode, stored_to),
+                                (AddressingModes::Absolute(loaded), Some(stored)) if *loaded == stored
+                            );
+                    }
+                }
+                false
+            })
+            .collect()
+    }
+
+    fn compact(&mut self, removed: Vec<bool>) {
+        let arch = self.arch();
+        let len = removed.len();
+        // Location of the first kept instruction at or after the index
+        let mut forward: Vec<usize> = vec![0; len + 1];
+        let mut kept = 0;
+        for idx in 0..len {
+            forward[idx] = kept;
+            if !removed[idx] {
+                kept += 1;
+            }
+        }
+        forward[len] = kept;
+        // Location of the last kept instruction at or before the index
+        let backward = |idx: usize| forward[(idx + 1).min(len)].saturating_sub(1);
+        let relocate = |idx: usize| forward[idx.min(len)];
+
+        let relocations: BTreeSet<usize> = self.result.relocations.iter().copied().collect();
+        let instructions = core::mem::take(&mut self.result.instructions);
+        for (idx, mut instruction) in instructions.into_iter().enumerate() {
+            if removed[idx] {
+                continue;
+            }
+            let addressing = instruction.get_addressing_mode_mut();
+            addressing.addressing_mode = match &addressing.addressing_mode {
+                AddressingModes::Absolute(e) => AddressingModes::Absolute(relocate(*e)),
+                AddressingModes::AbsoluteStatic(e) => AddressingModes::AbsoluteStatic(relocate(*e)),
+                AddressingModes::AbsoluteIndex(e, i) => {
+                    AddressingModes::AbsoluteIndex(relocate(*e), relocate(*i))
+                }
+                AddressingModes::AbsoluteProperty(e, i) => {
+                    AddressingModes::AbsoluteProperty(relocate(*e), *i)
+                }
+                AddressingModes::Immediate(rtype, bytes) if relocations.contains(&idx) => {
+                    AddressingModes::Immediate(
+                        *rtype,
+                        usize_to_le_bytes(relocate(immediate_to_usize(bytes)), arch),
+                    )
+                }
+                e => e.clone(),
+            };
+            self.result.instructions.push(instruction);
+        }
+
+        self.result.relocations = relocations
+            .into_iter()
+            .filter(|idx| !removed[*idx])
+            .map(relocate)
+            .collect();
+
+        for local in self.result.locals.iter_mut() {
+            local.cursor = relocate(local.cursor);
+            local.reference.addressing_mode = match &local.reference.addressing_mode {
+                AddressingModes::Absolute(e) => AddressingModes::Absolute(relocate(*e)),
+                AddressingModes::AbsoluteStatic(e) => AddressingModes::AbsoluteStatic(relocate(*e)),
+                e => e.clone(),
+            };
+        }
+
+        for debug_header in self.result.debug_headers.iter_mut() {
+            let start = relocate(debug_header.start_end.0);
+            let end = backward(debug_header.start_end.1).max(start);
+            debug_header.start_end = (start, end);
+        }
+
+        if let Some(main_function) = &mut self.result.module_info.main_function {
+            main_function.start = relocate(main_function.start);
+            main_function.end = backward(main_function.end);
+        }
+    }
+}
+
+/// Run peephole optimizations over assembled instructions
+///
+/// * Jumps landing on an unconditional jump are threaded to the final target, jump table entries
+///   included
+/// * Jumps to the next instruction are removed
+/// * Loads of a value which is stored from the same register right before are removed
+/// * Instructions that can't be reached from program start or any function are removed
+///
+/// ## Arguments
+/// * `result` - [`AssembleResult`] to optimize
+pub fn optimize(result: &mut AssembleResult) {
+    let mut optimizer = PeepholeOptimizer {
+        result,
+        referenced: BTreeSet::new(),
+        jump_targets: BTreeSet::new(),
+        parameters: BTreeSet::new(),
+    };
+    optimizer.thread_jumps();
+    optimizer.collect_references();
+    let removed = optimizer.removable();
+    if removed.iter().any(|removed| *removed) {
+        optimizer.compact(removed);
+    }
+}
@@ -1,6 +1,9 @@
-use ellie_core::definite::items::class;
+use ellie_core::{
+    definite::items::class,
+    defs::{DebugHeader, DebugHeaderType},
+};
 
-use crate::{assembler::LocalHeader, instructions::Instruction};
+use crate::{assembler::LocalHeader, instructions::Instruction, utils::limit_platform_size};
 
 impl super::Transpiler for class::Class {
     fn transpile(
@@ -13,7 +16,10 @@ impl super::Transpiler for class::Class {
             assembler.assemble_dependency(&dependency.hash);
         }
 
-        assembler.locals.push(LocalHeader {
+        let debug_header_start = assembler.location();
+
+        //Classes constructed before their definition are already borrowed
+        assembler.add_local(LocalHeader {
             name: self.name.clone(),
             cursor: assembler.location(),
             page_hash: processed_page.hash,
@@ -23,6 +29,17 @@ impl super::Transpiler for class::Class {
         });
 
         assembler.assemble_dependency(&self.inner_page_id);
+
+        //Instances carry the class hash, their class is found by it
+        assembler.debug_headers.push(DebugHeader {
+            rtype: DebugHeaderType::Class,
+            hash: limit_platform_size(self.hash, assembler.platform_attributes.architecture),
+            module_name: processed_page.path.clone(),
+            module_hash: processed_page.hash,
+            name: self.name.clone(),
+            start_end: (debug_header_start, assembler.location()),
+            pos: self.pos,
+        });
         true
     }
 }
@@ -2,9 +2,13 @@ use crate::{
     instruction_table,
     instructions::{self, Instruction},
     types::Types,
+    utils::limit_platform_size,
+};
+use alloc::{string::ToString, vec, vec::Vec};
+use ellie_core::{
+    definite::items::condition,
+    defs::{DebugHeader, DebugHeaderType},
 };
-use alloc::{vec, vec::Vec};
-use ellie_core::definite::items::condition;
 
 use super::type_resolver::resolve_type;
 
@@ -23,6 +27,7 @@ impl super::Transpiler for condition::Condition {
         let mut condition_body_start_requests = Vec::new();
 
         for (_, chain) in self.chains.iter().enumerate() {
+            let debug_header_start = assembler.location();
             if chain.rtype != ellie_core::definite::items::condition::ConditionType::Else {
                 resolve_type(
                     assembler,
@@ -44,6 +49,20 @@ impl super::Transpiler for condition::Condition {
                     Instruction::absolute(144),
                 ));
             condition_body_start_requests.push(assembler.location());
+            if chain.rtype != condition::ConditionType::Else {
+                assembler.debug_headers.push(DebugHeader {
+                    rtype: DebugHeaderType::Condition,
+                    hash: limit_platform_size(
+                        self.hash,
+                        assembler.platform_attributes.architecture,
+                    ),
+                    module_name: processed_page.path.clone(),
+                    module_hash: processed_page.hash,
+                    name: "<condition>".to_string(),
+                    start_end: (debug_header_start, assembler.location()),
+                    pos: chain.keyword_pos,
+                });
+            }
         }
 
         if self.chains.last().unwrap().rtype != condition::ConditionType::Else {
@@ -103,6 +103,7 @@ impl super::Transpiler for constructor::Constructor {
                     assembler.platform_attributes.architecture,
                 ),
             ));
+        assembler.mark_relocation(escape_pos_instruction_location);
 
         assembler.debug_headers.push(DebugHeader {
             rtype: DebugHeaderType::Function,
@@ -1,12 +1,205 @@
-use ellie_core::definite::items::for_loop;
+use alloc::{string::ToString, vec};
+use ellie_core::{
+    definite::{definers::DefinerCollecting, items::for_loop, items::Collecting},
+    defs::{DebugHeader, DebugHeaderType},
+};
+
+use crate::{
+    assembler::LocalHeader,
+    instruction_table,
+    instructions::{self, Instruction},
+    types::Types,
+    utils::{isize_to_le_bytes, limit_platform_size},
+};
+
+use super::type_resolver::resolve_type;
 
 impl super::Transpiler for for_loop::ForLoop {
     fn transpile(
         &self,
-        _assembler: &mut crate::assembler::Assembler,
-        _hash: usize,
-        _processed_page: &ellie_parser::parser::ProcessedPage,
+        assembler: &mut crate::assembler::Assembler,
+        hash: usize,
+        processed_page: &ellie_parser::parser::ProcessedPage,
     ) -> bool {
+        for dependency in &processed_page.dependencies {
+            assembler.assemble_dependency(&dependency.hash);
+        }
+
+        let mut dependencies = vec![processed_page.hash];
+        dependencies.extend(processed_page.dependencies.iter().map(|d| d.hash));
+
+        //Integers are iterated from 0 to given number, others are iterated by their entries
+        let is_range =
+            matches!(&self.iterator_type, DefinerCollecting::Generic(e) if e.rtype == "int");
+
+        resolve_type(
+            assembler,
+            &self.iterator,
+            instructions::Registers::A,
+            &hash,
+            Some(dependencies),
+        );
+        assembler
+            .instructions
+            .push(instruction_table::Instructions::STA(Instruction::implicit()));
+        let iterator_pos = assembler.location();
+
+        let length_pos = if is_range {
+            iterator_pos
+        } else {
+            assembler
+                .instructions
+                .push(instruction_table::Instructions::LEN(Instruction::absolute(
+                    iterator_pos,
+                )));
+            assembler
+                .instructions
+                .push(instruction_table::Instructions::STA(Instruction::implicit()));
+            assembler.location()
+        };
+
+        assembler
+            .instructions
+            .push(instruction_table::Instructions::STA(
+                Instruction::immediate(
+                    Types::Integer,
+                    isize_to_le_bytes(0, assembler.platform_attributes.architecture),
+                ),
+            ));
+        let index_pos = assembler.location();
+
+        //We need to get back at this point every time we jump to the end of the loop.
+        let start_pos = assembler.location() + 1;
+
+        assembler
+            .instructions
+            .push(instruction_table::Instructions::LDB(Instruction::absolute(
+                index_pos,
+            )));
+        assembler
+            .instructions
+            .push(instruction_table::Instructions::LDC(Instruction::absolute(
+                length_pos,
+            )));
+        assembler
+            .instructions
+            .push(instruction_table::Instructions::LT(Instruction::implicit()));
+
+        assembler
+            .instructions
+            .push(instruction_table::Instructions::JMPA(
+                Instruction::absolute(
+                    assembler.location() + 3, //Skip the next JMP instruction to the loop's body start
+                ),
+            ));
+
+        //If previous instruction which is JMPA is not executed, we need to jump to the end of the loop. to end it
+        assembler
+            .instructions
+            .push(instruction_table::Instructions::JMP(Instruction::absolute(
+                0,
+            ))); //To be changed to loops exit point
+
+        //We're saving location of JMP instruction, because end of the loop location is unknown until body is assembled.
+        let escaper_pos = assembler.location();
+
+        //Load current entry to loop's variable
+        assembler
+            .instructions
+            .push(instruction_table::Instructions::LDA(if is_range {
+                Instruction::absolute(index_pos)
+            } else {
+                Instruction::absolute_index(iterator_pos, index_pos)
+            }));
+        assembler
+            .instructions
+            .push(instruction_table::Instructions::STA(Instruction::implicit()));
+
+        //Loop variable is the first item of the inner page, it's bound here instead of its own declaration
+        let inner_page = assembler
+            .module
+            .pages
+            .iter()
+            .find(|page| page.hash == self.inner_page_id)
+            .unwrap_or_else(|| {
+                panic!(
+                    "Unexpected assembler error, cannot find page {:?}",
+                    self.inner_page_id
+                )
+            });
+        let variable = match inner_page.items.first() {
+            Some(Collecting::FunctionParameter(variable)) => variable.clone(),
+            _ => unreachable!("For loop's inner page must start with its variable"),
+        };
+
+        assembler.debug_headers.push(DebugHeader {
+            rtype: DebugHeaderType::Variable,
+            hash: limit_platform_size(variable.hash, assembler.platform_attributes.architecture),
+            start_end: (escaper_pos + 1, assembler.location()),
+            module_name: processed_page.path.clone(),
+            module_hash: self.inner_page_id,
+            name: variable.name.clone(),
+            pos: variable.name_pos,
+        });
+
+        assembler.locals.push(LocalHeader {
+            name: variable.name,
+            cursor: assembler.location(),
+            page_hash: self.inner_page_id,
+            hash: Some(variable.hash),
+            reference: Instruction::absolute(assembler.location()),
+            borrowed: None,
+        });
+
+        assembler.assemble_loop_body(&self.inner_page_id, |assembler| {
+            //Increase index by one
+            assembler
+                .instructions
+                .push(instruction_table::Instructions::LDB(Instruction::absolute(
+                    index_pos,
+                )));
+            assembler
+                .instructions
+                .push(instruction_table::Instructions::LDC(
+                    Instruction::immediate(
+                        Types::Integer,
+                        isize_to_le_bytes(1, assembler.platform_attributes.architecture),
+                    ),
+                ));
+            assembler
+                .instructions
+                .push(instruction_table::Instructions::ADD(Instruction::implicit()));
+            assembler
+                .instructions
+                .push(instruction_table::Instructions::STA(Instruction::absolute(
+                    index_pos,
+                )));
+            assembler
+                .instructions
+                .push(instruction_table::Instructions::JMP(Instruction::absolute(
+                    start_pos,
+                ))); //Jump to the start of the loop again
+            assembler.location() + 1
+        });
+
+        let end_of_loop_pos = assembler.location() + 1;
+
+        //set the escaper position to the end of the loop
+        assembler.instructions[escaper_pos] =
+            instruction_table::Instructions::JMP(Instruction::absolute(end_of_loop_pos));
+
+        assembler.debug_headers.push(DebugHeader {
+            rtype: DebugHeaderType::Condition,
+            hash: limit_platform_size(
+                self.inner_page_id,
+                assembler.platform_attributes.architecture,
+            ),
+            module_name: processed_page.path.clone(),
+            module_hash: processed_page.hash,
+            name: "<for>".to_string(),
+            start_end: (start_pos, assembler.location()),
+            pos: self.pos,
+        });
         true
     }
 }
@@ -104,6 +104,7 @@ impl super::Transpiler for function::Function {
                     assembler.platform_attributes.architecture,
                 ),
             ));
+        assembler.mark_relocation(escape_pos_instruction_location);
 
         assembler.debug_headers.push(DebugHeader {
             rtype: DebugHeaderType::Function,
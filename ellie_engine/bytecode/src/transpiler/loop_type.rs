@@ -55,13 +55,14 @@ impl super::Transpiler for loop_type::Loop {
         //We're saving location of JMP instruction, because end of the loop location is unknown until body is assembled.
         let escaper_pos = assembler.location();
 
-        assembler.assemble_dependency(&self.inner_page_id);
-
-        assembler
-            .instructions
-            .push(instruction_table::Instructions::JMP(Instruction::absolute(
-                start_pos,
-            ))); //Jump to the start of the loop again
+        assembler.assemble_loop_body(&self.inner_page_id, |assembler| {
+            assembler
+                .instructions
+                .push(instruction_table::Instructions::JMP(Instruction::absolute(
+                    start_pos,
+                ))); //Jump to the start of the loop again
+            assembler.location() + 1
+        });
 
         let end_of_loop_pos = assembler.location() + 1;
 
@@ -0,0 +1,325 @@
+use alloc::{collections::BTreeMap, vec, vec::Vec};
+use ellie_core::{
+    definite::{
+        definers::DefinerCollecting,
+        items::{
+            match_type::{self, MatchArm, MatchPattern},
+            Collecting,
+        },
+        types::Types as CoreTypes,
+    },
+    defs::{DebugHeader, DebugHeaderType, IntegerSize},
+};
+
+use crate::{
+    assembler::LocalHeader,
+    instruction_table,
+    instructions::{self, Instruction},
+    types::Types,
+    utils::{isize_to_le_bytes, limit_platform_size, usize_to_le_bytes},
+};
+
+use super::type_resolver::resolve_type;
+
+/// Least number of values a jump table is built for, fewer values are compared one by one
+const JUMP_TABLE_MIN_VALUES: usize = 3;
+
+/// Lowest value and the arm of every value from it, if patterns are integers or enum variants
+/// that are dense enough for a jump table. Values without an arm are [`None`], they go to the
+/// wildcard arm
+fn jump_table(arms: &[MatchArm]) -> Option<(isize, Vec<Option<usize>>)> {
+    let mut values: BTreeMap<isize, usize> = BTreeMap::new();
+    for (arm_idx, arm) in arms.iter().enumerate() {
+        let value = match &arm.pattern {
+            MatchPattern::Null => continue,
+            // Arms after the wildcard are never taken
+            MatchPattern::Wildcard => break,
+            MatchPattern::EnumVariant { variant_idx, .. } => *variant_idx as isize,
+            MatchPattern::Value(CoreTypes::Integer(e)) if e.size == IntegerSize::Int => e.value,
+            MatchPattern::Value(_) => return None,
+        };
+        values.entry(value).or_insert(arm_idx);
+    }
+
+    let (lowest, highest) = (*values.keys().next()?, *values.keys().next_back()?);
+    let range = usize::try_from(highest.checked_sub(lowest)?).ok()? + 1;
+    if values.len() < JUMP_TABLE_MIN_VALUES || range > values.len() * 2 {
+        return None;
+    }
+    Some((
+        lowest,
+        (0..range)
+            .map(|offset| values.get(&(lowest + offset as isize)).copied())
+            .collect(),
+    ))
+}
+
+impl super::Transpiler for match_type::Match {
+    fn transpile(
+        &self,
+        assembler: &mut crate::assembler::Assembler,
+        hash: usize,
+        processed_page: &ellie_parser::parser::ProcessedPage,
+    ) -> bool {
+        let mut dependencies = vec![processed_page.hash];
+        dependencies.extend(processed_page.dependencies.iter().map(|d| d.hash));
+
+        resolve_type(
+            assembler,
+            &self.target,
+            instructions::Registers::A,
+            &hash,
+            Some(dependencies.clone()),
+        );
+        assembler
+            .instructions
+            .push(instruction_table::Instructions::STA(Instruction::implicit()));
+        let subject = assembler.location();
+
+        //(arm index, location of the JMPA instruction to be patched with the arm's body)
+        let mut arm_requests: Vec<(usize, usize)> = Vec::new();
+        //(arm index, location of the JMP instruction to be patched with the arm's body)
+        let mut jump_requests: Vec<(usize, usize)> = Vec::new();
+
+        //Null goes to the first arm that accepts it, it's checked before reading the enum tag
+        let null_arm = match &self.target_type {
+            DefinerCollecting::ParentGeneric(e) if e.rtype == "nullAble" => self
+                .arms
+                .iter()
+                .position(|arm| matches!(arm.pattern, MatchPattern::Null | MatchPattern::Wildcard)),
+            _ => None,
+        };
+
+        if let Some(arm_idx) = null_arm {
+            assembler
+                .instructions
+                .push(instruction_table::Instructions::LDB(Instruction::absolute(
+                    subject,
+                )));
+            assembler
+                .instructions
+                .push(instruction_table::Instructions::LDC(
+                    Instruction::immediate(Types::Null, Vec::new()),
+                ));
+            assembler
+                .instructions
+                .push(instruction_table::Instructions::EQ(Instruction::implicit()));
+            assembler
+                .instructions
+                .push(instruction_table::Instructions::JMPA(
+                    Instruction::absolute(0),
+                ));
+            arm_requests.push((arm_idx, assembler.location()));
+        }
+
+        //Enum values are arrays starting with their variant index, variants are compared with it
+        let tag = if self
+            .arms
+            .iter()
+            .any(|arm| matches!(arm.pattern, MatchPattern::EnumVariant { .. }))
+        {
+            assembler
+                .instructions
+                .push(instruction_table::Instructions::LDB(
+                    Instruction::absolute_property(subject, 0),
+                ));
+            assembler
+                .instructions
+                .push(instruction_table::Instructions::STB(Instruction::implicit()));
+            assembler.location()
+        } else {
+            subject
+        };
+
+        let mut end_requests = Vec::new();
+        let wildcard_arm = self
+            .arms
+            .iter()
+            .position(|arm| matches!(arm.pattern, MatchPattern::Wildcard));
+
+        if let Some((lowest, entries)) = jump_table(&self.arms) {
+            //Jump table, entry of the subject's offset from the lowest value jumps to its arm
+            assembler
+                .instructions
+                .push(instruction_table::Instructions::LDA(Instruction::absolute(
+                    tag,
+                )));
+            assembler
+                .instructions
+                .push(instruction_table::Instructions::LDB(
+                    Instruction::immediate(
+                        Types::Integer,
+                        isize_to_le_bytes(lowest, assembler.platform_attributes.architecture),
+                    ),
+                ));
+            assembler
+                .instructions
+                .push(instruction_table::Instructions::JMPT(
+                    Instruction::immediate(
+                        Types::Integer,
+                        usize_to_le_bytes(
+                            entries.len(),
+                            assembler.platform_attributes.architecture,
+                        ),
+                    ),
+                ));
+            //Values without an arm and values out of the table continue after the last entry
+            for arm_idx in entries.into_iter().chain([None]) {
+                assembler
+                    .instructions
+                    .push(instruction_table::Instructions::JMP(Instruction::absolute(
+                        0,
+                    )));
+                match arm_idx.or(wildcard_arm) {
+                    Some(arm_idx) => jump_requests.push((arm_idx, assembler.location())),
+                    None => end_requests.push(assembler.location()),
+                }
+            }
+        } else {
+            //Every arm compares its pattern and jumps to its body on match
+            for (arm_idx, arm) in self.arms.iter().enumerate() {
+                match &arm.pattern {
+                    MatchPattern::Null => continue,
+                    MatchPattern::EnumVariant { variant_idx, .. } => {
+                        assembler
+                            .instructions
+                            .push(instruction_table::Instructions::LDB(Instruction::absolute(
+                                tag,
+                            )));
+                        assembler
+                            .instructions
+                            .push(instruction_table::Instructions::LDC(
+                                Instruction::immediate(
+                                    Types::Integer,
+                                    usize_to_le_bytes(
+                                        *variant_idx,
+                                        assembler.platform_attributes.architecture,
+                                    ),
+                                ),
+                            ));
+                        assembler
+                            .instructions
+                            .push(instruction_table::Instructions::EQ(Instruction::implicit()));
+                    }
+                    MatchPattern::Value(value) => {
+                        resolve_type(
+                            assembler,
+                            value,
+                            instructions::Registers::C,
+                            &hash,
+                            Some(dependencies.clone()),
+                        );
+                        assembler
+                            .instructions
+                            .push(instruction_table::Instructions::LDB(Instruction::absolute(
+                                subject,
+                            )));
+                        assembler
+                            .instructions
+                            .push(instruction_table::Instructions::EQ(Instruction::implicit()));
+                    }
+                    MatchPattern::Wildcard => {
+                        assembler
+                            .instructions
+                            .push(instruction_table::Instructions::LDA(
+                                Instruction::immediate(Types::Bool, vec![1]),
+                            ));
+                    }
+                }
+                assembler
+                    .instructions
+                    .push(instruction_table::Instructions::JMPA(
+                        Instruction::absolute(0),
+                    ));
+                arm_requests.push((arm_idx, assembler.location()));
+            }
+
+            assembler
+                .instructions
+                .push(instruction_table::Instructions::JMP(Instruction::absolute(
+                    0,
+                )));
+            end_requests.push(assembler.location());
+        }
+
+        let mut arm_starts = Vec::new();
+        for arm in &self.arms {
+            arm_starts.push(assembler.location() + 1);
+
+            //Bound variable is the first item of the arm's page, it's bound to the variant's data here
+            if matches!(arm.pattern, MatchPattern::EnumVariant { binds: true, .. }) {
+                let inner_page = assembler
+                    .module
+                    .pages
+                    .iter()
+                    .find(|page| page.hash == arm.inner_page_id)
+                    .unwrap_or_else(|| {
+                        panic!(
+                            "Unexpected assembler error, cannot find page {:?}",
+                            arm.inner_page_id
+                        )
+                    });
+                let variable = match inner_page.items.first() {
+                    Some(Collecting::Variable(variable)) => variable.clone(),
+                    _ => unreachable!("Match arm's inner page must start with its variable"),
+                };
+
+                assembler
+                    .instructions
+                    .push(instruction_table::Instructions::LDA(
+                        Instruction::absolute_property(subject, 1),
+                    ));
+                assembler
+                    .instructions
+                    .push(instruction_table::Instructions::STA(Instruction::implicit()));
+
+                assembler.debug_headers.push(DebugHeader {
+                    rtype: DebugHeaderType::Variable,
+                    hash: limit_platform_size(
+                        variable.hash,
+                        assembler.platform_attributes.architecture,
+                    ),
+                    start_end: (assembler.location(), assembler.location()),
+                    module_name: processed_page.path.clone(),
+                    module_hash: arm.inner_page_id,
+                    name: variable.name.clone(),
+                    pos: variable.pos,
+                });
+
+                assembler.locals.push(LocalHeader {
+                    name: variable.name,
+                    cursor: assembler.location(),
+                    page_hash: arm.inner_page_id,
+                    hash: Some(variable.hash),
+                    reference: Instruction::absolute(assembler.location()),
+                    borrowed: None,
+                });
+            }
+
+            assembler.assemble_dependency(&arm.inner_page_id);
+            assembler
+                .instructions
+                .push(instruction_table::Instructions::JMP(Instruction::absolute(
+                    0,
+                )));
+            end_requests.push(assembler.location());
+        }
+        let end = assembler.location() + 1;
+
+        for (arm_idx, location) in arm_requests {
+            assembler.instructions[location] =
+                instruction_table::Instructions::JMPA(Instruction::absolute(arm_starts[arm_idx]));
+        }
+
+        for (arm_idx, location) in jump_requests {
+            assembler.instructions[location] =
+                instruction_table::Instructions::JMP(Instruction::absolute(arm_starts[arm_idx]));
+        }
+
+        for location in end_requests {
+            assembler.instructions[location] =
+                instruction_table::Instructions::JMP(Instruction::absolute(end));
+        }
+        true
+    }
+}
@@ -7,6 +7,7 @@ mod function;
 mod function_parameter;
 mod getter_call;
 mod loop_type;
+mod match_type;
 mod native_function;
 mod ret;
 mod self_item;
@@ -1,3 +1,5 @@
+use alloc::format;
+
 use crate::{
     assembler::LocalHeader,
     instruction_table::{self, Instructions},
@@ -9,6 +11,7 @@ use ellie_core::{
     definite::items::native_function,
     defs::{Cursor, DebugHeader, DebugHeaderType},
 };
+use ellie_tokenizer::tokenizer::PageType;
 
 impl super::Transpiler for native_function::NativeFunction {
     fn transpile(
@@ -103,13 +106,21 @@ impl super::Transpiler for native_function::NativeFunction {
                     assembler.platform_attributes.architecture,
                 )
             }));
+        assembler.mark_relocation(escape_pos_instruction_location);
+
+        //Natives declared in class bodies are qualified with class name (array.push)
+        //so they don't collide with top level natives
+        let native_name = match &processed_page.page_type {
+            PageType::ClassBody(class_page) => format!("{}.{}", class_page.name, self.name),
+            _ => self.name.clone(),
+        };
 
         assembler.debug_headers.push(DebugHeader {
             rtype: DebugHeaderType::NativeFunction,
             hash: limit_platform_size(self.hash, assembler.platform_attributes.architecture),
             module_name: processed_page.path.clone(),
             module_hash: processed_page.hash,
-            name: self.name.clone(),
+            name: native_name,
             start_end: (debug_header_start, assembler.location()),
             pos: self.pos,
         });
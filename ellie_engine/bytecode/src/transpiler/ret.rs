@@ -1,9 +1,13 @@
-use alloc::vec;
-use ellie_core::definite::items::ret;
+use alloc::{string::ToString, vec};
+use ellie_core::{
+    definite::items::ret,
+    defs::{DebugHeader, DebugHeaderType},
+};
 
 use crate::{
     instruction_table,
     instructions::{self, Instruction},
+    utils::limit_platform_size,
 };
 
 use super::type_resolver::resolve_type;
@@ -15,6 +19,7 @@ impl super::Transpiler for ret::Ret {
         hash: usize,
         processed_page: &ellie_parser::parser::ProcessedPage,
     ) -> bool {
+        let debug_header_start = assembler.location();
         let mut dependencies = vec![processed_page.hash];
         dependencies.extend(processed_page.dependencies.iter().map(|d| d.hash));
 
@@ -28,6 +33,15 @@ impl super::Transpiler for ret::Ret {
         assembler
             .instructions
             .push(instruction_table::Instructions::RET(Instruction::implicit()));
+        assembler.debug_headers.push(DebugHeader {
+            rtype: DebugHeaderType::Ret,
+            hash: limit_platform_size(99999999, assembler.platform_attributes.architecture),
+            module_name: processed_page.path.clone(),
+            module_hash: processed_page.hash,
+            name: "<ret>".to_string(),
+            start_end: (debug_header_start, assembler.location()),
+            pos: self.pos,
+        });
         true
     }
 }
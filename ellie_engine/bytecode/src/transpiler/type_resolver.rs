@@ -1,11 +1,17 @@
-use alloc::{vec, vec::Vec};
+use alloc::{boxed::Box, string::ToString, vec, vec::Vec};
 use ellie_core::{
-    definite::types::{class_instance::AttributeType, operator, Types as CoreTypes},
+    definite::{
+        definers::{DefinerCollecting, GenericType},
+        types::{
+            as_keyword::AsKeyword, class_instance::AttributeType, integer::IntegerSize, operator,
+            string::StringType, Types as CoreTypes,
+        },
+    },
     defs::PlatformArchitecture,
 };
 
 use crate::{
-    assembler::{Assembler, LocalHeader},
+    assembler::{Assembler, InterfaceImplementation, LocalHeader},
     instruction_table,
     instructions::{self, Instruction},
     types::Types,
@@ -19,13 +25,17 @@ pub fn convert_type(
 ) -> (Types, Vec<u8>) {
     match types {
         CoreTypes::Byte(byte) => (Types::Byte, byte.value.to_le_bytes().to_vec()),
+        CoreTypes::Integer(integer) if integer.size.is_fixed() => (
+            Types::FixedInteger(integer.size),
+            integer.wide_value().to_le_bytes()[..integer.size.byte_size()].to_vec(),
+        ),
         CoreTypes::Integer(integer) => (Types::Integer, isize_to_le_bytes(integer.value, arch)),
         CoreTypes::Decimal(decimal) => match decimal.value {
             ellie_core::definite::types::decimal::DecimalTypeEnum::Float(float_value) => {
-                (Types::Float, f32_to_le_bytes(float_value, arch))
+                (Types::Float, f32_to_le_bytes(float_value))
             }
             ellie_core::definite::types::decimal::DecimalTypeEnum::Double(double_value) => {
-                (Types::Double, f64_to_le_bytes(double_value, arch))
+                (Types::Double, f64_to_le_bytes(double_value))
             }
         },
         CoreTypes::Bool(bool) => (Types::Bool, (bool.value as u8).to_le_bytes().to_vec()),
@@ -34,6 +44,139 @@ pub fn convert_type(
     }
 }
 
+/// Emits a switch over the class hash header of the receiver, calling the method of the
+/// matching class
+/// * `assembler` - Assembler instance
+/// * `receiver` - Location of the class instance
+/// * `implementations` - Methods of the classes implementing the interface
+fn dispatch_interface_call(
+    assembler: &mut Assembler,
+    receiver: usize,
+    implementations: Vec<InterfaceImplementation>,
+) {
+    let class_tag = dispatch_class_tag(assembler, receiver, &implementations);
+    let mut end_requests = Vec::new();
+
+    for (implementation, jump_location) in implementations.iter().zip(class_tag) {
+        assembler.instructions[jump_location] =
+            instruction_table::Instructions::JMPA(Instruction::absolute(assembler.location() + 1));
+        let target = assembler
+            .find_local_by_hash(
+                implementation.member_hash,
+                Some(vec![implementation.class_page]),
+                true,
+            )
+            .unwrap();
+        assembler
+            .instructions
+            .push(instruction_table::Instructions::CALL(
+                Instruction::absolute(target.cursor),
+            ));
+        assembler.add_borrow_to_local(implementation.member_hash, assembler.location());
+        assembler
+            .instructions
+            .push(instruction_table::Instructions::JMP(Instruction::absolute(
+                0,
+            )));
+        end_requests.push(assembler.location());
+    }
+
+    let end = assembler.location() + 1;
+    for location in end_requests {
+        assembler.instructions[location] =
+            instruction_table::Instructions::JMP(Instruction::absolute(end));
+    }
+}
+
+/// Emits a switch over the class hash header of the receiver, loading the property of the
+/// matching class to `target_register`
+/// * `assembler` - Assembler instance
+/// * `receiver` - Location of the class instance
+/// * `implementations` - Properties of the classes implementing the interface
+/// * `target_register` - Target register to store the value
+fn dispatch_interface_property(
+    assembler: &mut Assembler,
+    receiver: usize,
+    implementations: Vec<InterfaceImplementation>,
+    target_register: &instructions::Registers,
+) {
+    let class_tag = dispatch_class_tag(assembler, receiver, &implementations);
+    let mut end_requests = Vec::new();
+
+    for (implementation, jump_location) in implementations.iter().zip(class_tag) {
+        assembler.instructions[jump_location] =
+            instruction_table::Instructions::JMPA(Instruction::absolute(assembler.location() + 1));
+        let property = Instruction::absolute_property(receiver, implementation.property_idx + 1);
+        assembler.instructions.push(match target_register {
+            instructions::Registers::A => instruction_table::Instructions::LDA(property),
+            instructions::Registers::B => instruction_table::Instructions::LDB(property),
+            instructions::Registers::C => instruction_table::Instructions::LDC(property),
+            instructions::Registers::X => instruction_table::Instructions::LDX(property),
+            instructions::Registers::Y => instruction_table::Instructions::LDY(property),
+        });
+        assembler
+            .instructions
+            .push(instruction_table::Instructions::JMP(Instruction::absolute(
+                0,
+            )));
+        end_requests.push(assembler.location());
+    }
+
+    let end = assembler.location() + 1;
+    for location in end_requests {
+        assembler.instructions[location] =
+            instruction_table::Instructions::JMP(Instruction::absolute(end));
+    }
+}
+
+/// Compares the class hash header of the receiver with every implementing class, returns the
+/// locations of the `JMPA` instructions to be patched with the class blocks
+fn dispatch_class_tag(
+    assembler: &mut Assembler,
+    receiver: usize,
+    implementations: &[InterfaceImplementation],
+) -> Vec<usize> {
+    assembler
+        .instructions
+        .push(instruction_table::Instructions::LDB(
+            Instruction::absolute_property(receiver, 0),
+        ));
+    assembler
+        .instructions
+        .push(instruction_table::Instructions::STB(Instruction::implicit()));
+    let class_tag = assembler.location();
+
+    let mut jump_locations = Vec::new();
+    for implementation in implementations {
+        assembler
+            .instructions
+            .push(instruction_table::Instructions::LDB(Instruction::absolute(
+                class_tag,
+            )));
+        assembler
+            .instructions
+            .push(instruction_table::Instructions::LDC(
+                Instruction::immediate(
+                    Types::Integer,
+                    usize_to_le_bytes(
+                        implementation.class_hash,
+                        assembler.platform_attributes.architecture,
+                    ),
+                ),
+            ));
+        assembler
+            .instructions
+            .push(instruction_table::Instructions::EQ(Instruction::implicit()));
+        assembler
+            .instructions
+            .push(instruction_table::Instructions::JMPA(
+                Instruction::absolute(0),
+            ));
+        jump_locations.push(assembler.location());
+    }
+    jump_locations
+}
+
 /// Resolves type to instructions
 /// * `assembler` - Assembler instance
 /// * `types` - Type to resolve
@@ -64,51 +207,67 @@ pub fn resolve_type(
             for (idx, chain) in e.index_chain.iter().enumerate() {
                 match chain.rtype {
                     ellie_core::definite::types::class_instance::AttributeType::Property => {
-                        match target_register {
-                            instructions::Registers::A => {
-                                assembler
-                                    .instructions
-                                    .push(instruction_table::Instructions::LDA(
-                                        instructions::Instruction::absolute_property(
-                                            last_pos, chain.idx,
+                        if let Some(implementations) =
+                            assembler.find_interface_implementations(chain.page_hash, chain.hash)
+                        {
+                            dispatch_interface_property(
+                                assembler,
+                                last_pos,
+                                implementations,
+                                &target_register,
+                            );
+                        } else {
+                            match target_register {
+                                instructions::Registers::A => {
+                                    assembler.instructions.push(
+                                        instruction_table::Instructions::LDA(
+                                            instructions::Instruction::absolute_property(
+                                                last_pos,
+                                                chain.class_attribute_idx + 1,
+                                            ),
                                         ),
-                                    ));
-                            }
-                            instructions::Registers::B => {
-                                assembler
-                                    .instructions
-                                    .push(instruction_table::Instructions::LDB(
-                                        instructions::Instruction::absolute_property(
-                                            last_pos, chain.idx,
+                                    );
+                                }
+                                instructions::Registers::B => {
+                                    assembler.instructions.push(
+                                        instruction_table::Instructions::LDB(
+                                            instructions::Instruction::absolute_property(
+                                                last_pos,
+                                                chain.class_attribute_idx + 1,
+                                            ),
                                         ),
-                                    ));
-                            }
-                            instructions::Registers::C => {
-                                assembler
-                                    .instructions
-                                    .push(instruction_table::Instructions::LDC(
-                                        instructions::Instruction::absolute_property(
-                                            last_pos, chain.idx,
+                                    );
+                                }
+                                instructions::Registers::C => {
+                                    assembler.instructions.push(
+                                        instruction_table::Instructions::LDC(
+                                            instructions::Instruction::absolute_property(
+                                                last_pos,
+                                                chain.class_attribute_idx + 1,
+                                            ),
                                         ),
-                                    ));
-                            }
-                            instructions::Registers::X => {
-                                assembler
-                                    .instructions
-                                    .push(instruction_table::Instructions::LDX(
-                                        instructions::Instruction::absolute_property(
-                                            last_pos, chain.idx,
+                                    );
+                                }
+                                instructions::Registers::X => {
+                                    assembler.instructions.push(
+                                        instruction_table::Instructions::LDX(
+                                            instructions::Instruction::absolute_property(
+                                                last_pos,
+                                                chain.class_attribute_idx + 1,
+                                            ),
                                         ),
-                                    ));
-                            }
-                            instructions::Registers::Y => {
-                                assembler
-                                    .instructions
-                                    .push(instruction_table::Instructions::LDY(
-                                        instructions::Instruction::absolute_property(
-                                            last_pos, chain.idx,
+                                    );
+                                }
+                                instructions::Registers::Y => {
+                                    assembler.instructions.push(
+                                        instruction_table::Instructions::LDY(
+                                            instructions::Instruction::absolute_property(
+                                                last_pos,
+                                                chain.class_attribute_idx + 1,
+                                            ),
                                         ),
-                                    ));
+                                    );
+                                }
                             }
                         }
                     }
@@ -580,7 +739,8 @@ pub fn resolve_type(
                 }
             }
         }
-        CoreTypes::Array(e) => {
+        //Arrays declared with a size are static, others are stored in heap so they can grow
+        CoreTypes::Array(e) if e.fixed => {
             assembler
                 .instructions
                 .push(instruction_table::Instructions::SAR(
@@ -592,6 +752,7 @@ pub fn resolve_type(
                         ),
                     ),
                 ));
+            assembler.mark_relocation(assembler.location());
             assembler
                 .instructions
                 .push(instruction_table::Instructions::STA(
@@ -660,7 +821,8 @@ pub fn resolve_type(
                         )))
                 }
             }
-            /*
+        }
+        CoreTypes::Array(e) => {
             assembler
                 .instructions
                 .push(instruction_table::Instructions::ARR(Instruction::implicit()));
@@ -721,7 +883,6 @@ pub fn resolve_type(
                         )))
                 }
             }
-            */
         }
         CoreTypes::Function(_) => todo!(),
         CoreTypes::ClassCall(class_call) => {
@@ -733,28 +894,51 @@ pub fn resolve_type(
                 _ => unreachable!("Unexpected target type"),
             };
 
+            let class_hash = target.hash.unwrap();
+            let has_constructor = assembler.class_has_constructor(class_hash);
+
             assembler
                 .instructions
                 .push(instruction_table::Instructions::ARR(Instruction::implicit()));
             let class_location = assembler.location();
-            if !class_call.params.is_empty() {
-                for (_idx, param) in class_call.params.iter().enumerate() {
-                    resolve_type(
-                        assembler,
-                        &param.value,
-                        instructions::Registers::A,
-                        target_page,
-                        dependencies.clone(),
-                    );
-                    assembler
-                        .instructions
-                        .push(instruction_table::Instructions::STA(Instruction::implicit()));
-                    assembler
-                        .instructions
-                        .push(instruction_table::Instructions::PUSH(
-                            Instruction::absolute(class_location),
-                        ));
-                }
+
+            // Instances carry their class hash as the first element, interface members are
+            // dispatched by it at runtime
+            assembler
+                .instructions
+                .push(instruction_table::Instructions::LDA(
+                    Instruction::immediate(
+                        Types::Integer,
+                        usize_to_le_bytes(class_hash, assembler.platform_attributes.architecture),
+                    ),
+                ));
+            assembler
+                .instructions
+                .push(instruction_table::Instructions::STA(Instruction::implicit()));
+            assembler
+                .instructions
+                .push(instruction_table::Instructions::PUSH(
+                    Instruction::absolute(class_location),
+                ));
+
+            let mut param_locations = Vec::new();
+            for param in &class_call.params {
+                resolve_type(
+                    assembler,
+                    &param.value,
+                    instructions::Registers::A,
+                    target_page,
+                    dependencies.clone(),
+                );
+                assembler
+                    .instructions
+                    .push(instruction_table::Instructions::STA(Instruction::implicit()));
+                param_locations.push(assembler.location());
+                assembler
+                    .instructions
+                    .push(instruction_table::Instructions::PUSH(
+                        Instruction::absolute(class_location),
+                    ));
             }
 
             let self_location = assembler.location() + 1;
@@ -765,38 +949,53 @@ pub fn resolve_type(
                     class_location,
                 )));
 
-            let previous_params_location = assembler.location() + 1;
+            // The class local points to its constructor once the constructor is assembled
+            if has_constructor {
+                let previous_params_location = assembler.location() + 1;
 
-            // Reserve parameters
-            assembler
-                .instructions
-                .push(instruction_table::Instructions::STB(Instruction::implicit()));
+                // Reserve parameters
+                for _ in &param_locations {
+                    assembler
+                        .instructions
+                        .push(instruction_table::Instructions::STB(Instruction::implicit()));
+                }
+                //-
+
+                for (idx, param_location) in param_locations.iter().enumerate() {
+                    assembler
+                        .instructions
+                        .push(instruction_table::Instructions::LDB(Instruction::absolute(
+                            *param_location,
+                        )));
+                    assembler
+                        .instructions
+                        .push(instruction_table::Instructions::STB(Instruction::absolute(
+                            previous_params_location + idx,
+                        )));
+                }
 
-            for _ in class_call.params.iter().enumerate() {
                 assembler
                     .instructions
-                    .push(instruction_table::Instructions::STB(Instruction::implicit()));
-            }
-            //-
-
-            // Insert self
-            assembler
-                .instructions
-                .push(instruction_table::Instructions::LDB(Instruction::absolute(
-                    self_location,
-                )));
-            assembler
-                .instructions
-                .push(instruction_table::Instructions::STB(Instruction::absolute(
-                    previous_params_location,
-                )));
-            //-
+                    .push(instruction_table::Instructions::LDX(
+                        Instruction::immediate(
+                            Types::Integer,
+                            usize_to_le_bytes(
+                                previous_params_location,
+                                assembler.platform_attributes.architecture,
+                            ),
+                        ),
+                    ));
+                assembler.mark_relocation(assembler.location());
 
-            assembler
-                .instructions
-                .push(instruction_table::Instructions::CALL(
-                    Instruction::absolute(target.cursor),
-                ));
+                assembler
+                    .instructions
+                    .push(instruction_table::Instructions::CALL(
+                        Instruction::absolute(target.cursor),
+                    ));
+                if target.borrowed.is_some() {
+                    assembler.add_borrow_to_local(class_hash, assembler.location());
+                }
+            }
 
             match target_register {
                 instructions::Registers::A => {
@@ -838,11 +1037,14 @@ pub fn resolve_type(
         }
         CoreTypes::FunctionCall(function_call) => {
             let mut is_reference: Option<usize> = None;
-            let target: LocalHeader = match *function_call.target.clone() {
-                CoreTypes::VariableType(e) => assembler
-                    .find_local(&e.value, dependencies.clone(), true)
-                    .unwrap()
-                    .clone(),
+            let mut interface_implementations = None;
+            let target: Option<LocalHeader> = match *function_call.target.clone() {
+                CoreTypes::VariableType(e) => Some(
+                    assembler
+                        .find_local(&e.value, dependencies.clone(), true)
+                        .unwrap()
+                        .clone(),
+                ),
                 CoreTypes::Reference(e) => {
                     let mut _pos = assembler.location();
 
@@ -863,14 +1065,25 @@ pub fn resolve_type(
                         if e.index_chain.len() - 1 != idx {
                             match chain.rtype {
                                 AttributeType::Property => {
-                                    assembler.instructions.push(
-                                        instruction_table::Instructions::LDA(
-                                            instructions::Instruction::absolute_property(
-                                                last_pos,
-                                                chain.class_attribute_idx,
+                                    if let Some(implementations) = assembler
+                                        .find_interface_implementations(chain.page_hash, chain.hash)
+                                    {
+                                        dispatch_interface_property(
+                                            assembler,
+                                            last_pos,
+                                            implementations,
+                                            &instructions::Registers::A,
+                                        );
+                                    } else {
+                                        assembler.instructions.push(
+                                            instruction_table::Instructions::LDA(
+                                                instructions::Instruction::absolute_property(
+                                                    last_pos,
+                                                    chain.class_attribute_idx + 1,
+                                                ),
                                             ),
-                                        ),
-                                    );
+                                        );
+                                    }
 
                                     assembler.instructions.push(
                                         instruction_table::Instructions::STA(
@@ -888,13 +1101,18 @@ pub fn resolve_type(
                         }
                     }
                     let last_chain = e.index_chain.last().unwrap();
-                    found = assembler.find_local_by_hash(
-                        last_chain.hash,
-                        Some(vec![last_chain.page_hash]),
-                        true,
-                    );
                     is_reference = Some(last_pos);
-                    found.unwrap()
+                    // Methods called through an interface are resolved by the class of the receiver
+                    interface_implementations = assembler
+                        .find_interface_implementations(last_chain.page_hash, last_chain.hash);
+                    if interface_implementations.is_none() {
+                        found = assembler.find_local_by_hash(
+                            last_chain.hash,
+                            Some(vec![last_chain.page_hash]),
+                            true,
+                        );
+                    }
+                    found
                 }
                 _ => unreachable!("Unexpected target type"),
             };
@@ -954,13 +1172,22 @@ pub fn resolve_type(
                         ),
                     ),
                 ));
+            assembler.mark_relocation(assembler.location());
 
-            assembler
-                .instructions
-                .push(instruction_table::Instructions::CALL(
-                    Instruction::absolute(target.cursor),
-                ));
-            assembler.add_borrow_to_local(target.hash.unwrap(), assembler.location());
+            match (target, interface_implementations) {
+                (Some(target), _) => {
+                    assembler
+                        .instructions
+                        .push(instruction_table::Instructions::CALL(
+                            Instruction::absolute(target.cursor),
+                        ));
+                    assembler.add_borrow_to_local(target.hash.unwrap(), assembler.location());
+                }
+                (None, Some(implementations)) => {
+                    dispatch_interface_call(assembler, is_reference.unwrap(), implementations)
+                }
+                (None, None) => unreachable!("Unexpected target type"),
+            }
 
             match target_register {
                 instructions::Registers::A => {
@@ -1107,6 +1334,27 @@ pub fn resolve_type(
                         assembler
                             .instructions
                             .push(instruction_table::Instructions::A2B(Instruction::implicit()));
+                    } else if e.rtype == "bigint" {
+                        assembler
+                            .instructions
+                            .push(instruction_table::Instructions::A2BI(
+                                Instruction::implicit(),
+                            ));
+                    } else if let Some(size) = IntegerSize::from_type_name(&e.rtype) {
+                        // Immediate is the type id of the fixed width integer
+                        let (type_id, _) = Types::FixedInteger(size)
+                            .code(assembler.platform_attributes.architecture);
+                        assembler
+                            .instructions
+                            .push(instruction_table::Instructions::A2N(
+                                Instruction::immediate(
+                                    Types::Integer,
+                                    usize_to_le_bytes(
+                                        type_id as usize,
+                                        assembler.platform_attributes.architecture,
+                                    ),
+                                ),
+                            ));
                     }
                 }
                 _ => panic!("As conv parent generic not implemented yet"),
@@ -1386,6 +1634,29 @@ pub fn resolve_type(
                 }
             }
         }
+        CoreTypes::BigInteger(e) => {
+            // Digits are loaded as string and converted in place, same as `"10" as bigint`
+            resolve_type(
+                assembler,
+                &CoreTypes::AsKeyword(AsKeyword {
+                    target: Box::new(CoreTypes::String(StringType {
+                        value: e.value.clone(),
+                        pos: e.pos,
+                    })),
+                    pos: e.pos,
+                    target_pos: e.pos,
+                    type_pos: e.pos,
+                    rtype: DefinerCollecting::Generic(GenericType {
+                        rtype: "bigint".to_string(),
+                        pos: e.pos,
+                        hash: 0,
+                    }),
+                }),
+                target_register,
+                target_page,
+                dependencies,
+            );
+        }
         CoreTypes::Char(_) => {
             let converted_type = convert_type(
                 types,
@@ -1470,7 +1741,65 @@ pub fn resolve_type(
         },
         CoreTypes::Dynamic => todo!(),
         CoreTypes::SetterCall(_) => todo!(),
-        CoreTypes::EnumData(_) => todo!(),
+        CoreTypes::EnumData(enum_data) => {
+            let enum_hash = match *enum_data.reference.clone() {
+                CoreTypes::VariableType(e) => e.reference,
+                _ => unreachable!("Unexpected enum reference"),
+            };
+            let variant_idx = assembler
+                .find_enum_variant(enum_hash, &enum_data.field_name)
+                .unwrap_or_else(|| panic!("Enum variant not found: {}", enum_data.field_name));
+
+            assembler
+                .instructions
+                .push(instruction_table::Instructions::ARR(Instruction::implicit()));
+            let enum_location = assembler.location();
+
+            // Enum values are arrays of their variant index followed by the variant's data
+            assembler
+                .instructions
+                .push(instruction_table::Instructions::LDA(
+                    Instruction::immediate(
+                        Types::Integer,
+                        usize_to_le_bytes(variant_idx, assembler.platform_attributes.architecture),
+                    ),
+                ));
+            assembler
+                .instructions
+                .push(instruction_table::Instructions::STA(Instruction::implicit()));
+            assembler
+                .instructions
+                .push(instruction_table::Instructions::PUSH(
+                    Instruction::absolute(enum_location),
+                ));
+
+            if let ellie_core::definite::types::enum_data::Pointer::Data(value) = &enum_data.value {
+                resolve_type(
+                    assembler,
+                    value,
+                    instructions::Registers::A,
+                    target_page,
+                    dependencies,
+                );
+                assembler
+                    .instructions
+                    .push(instruction_table::Instructions::STA(Instruction::implicit()));
+                assembler
+                    .instructions
+                    .push(instruction_table::Instructions::PUSH(
+                        Instruction::absolute(enum_location),
+                    ));
+            }
+
+            let enum_pointer = Instruction::absolute(enum_location);
+            assembler.instructions.push(match target_register {
+                instructions::Registers::A => instruction_table::Instructions::LDA(enum_pointer),
+                instructions::Registers::B => instruction_table::Instructions::LDB(enum_pointer),
+                instructions::Registers::C => instruction_table::Instructions::LDC(enum_pointer),
+                instructions::Registers::X => instruction_table::Instructions::LDX(enum_pointer),
+                instructions::Registers::Y => instruction_table::Instructions::LDY(enum_pointer),
+            });
+        }
         CoreTypes::ClassInstance(_) => todo!(),
         CoreTypes::FunctionParameter(e) => {
             let pos = match assembler.find_local(&e.name, dependencies, false) {
@@ -18,6 +18,15 @@ impl super::Transpiler for variable::Variable {
         hash: usize,
         processed_page: &ellie_parser::parser::ProcessedPage,
     ) -> bool {
+        //Variable is already bound by the enclosing item, such as for loop's variable
+        if assembler
+            .locals
+            .iter()
+            .any(|local| local.hash == Some(self.hash) && local.borrowed.is_none())
+        {
+            return true;
+        }
+
         let mut dependencies = vec![processed_page.hash];
         dependencies.extend(processed_page.dependencies.iter().map(|d| d.hash));
 
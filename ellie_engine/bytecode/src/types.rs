@@ -1,13 +1,13 @@
 use alloc::string::{String, ToString};
-use ellie_core::defs::PlatformArchitecture;
+use ellie_core::defs::{IntegerSize, PlatformArchitecture};
 
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub enum Types {
     // 8 bit integer
     Integer,
-    // 4 bit decimal
+    // 32 bit decimal, same width on every platform
     Float,
-    // 8 bit decimal
+    // 64 bit decimal, same width on every platform
     Double,
     // 1 bit integer
     Byte,
@@ -33,6 +33,8 @@ pub enum Types {
     HeapReference,
     // Pointer to stack reference
     StackReference,
+    // Fixed width integer, same width on every platform
+    FixedInteger(IntegerSize),
 }
 
 impl Types {
@@ -41,8 +43,8 @@ impl Types {
     pub fn code(&self, platform_size: PlatformArchitecture) -> (u8, usize) {
         match &self {
             Types::Integer => (1, platform_size.usize_len() as usize),
-            Types::Float => (2, platform_size.usize_len() as usize),
-            Types::Double => (3, platform_size.usize_len() as usize),
+            Types::Float => (2, 4),
+            Types::Double => (3, 8),
             Types::Byte => (4, 1),
             Types::Bool => (5, 1),
             Types::String(str_len) => (6, *str_len),
@@ -55,6 +57,14 @@ impl Types {
             Types::HeapReference => (13, platform_size.usize_len() as usize),
             Types::StackReference => (14, platform_size.usize_len() as usize),
             Types::StaticArray => (15, platform_size.usize_len() as usize),
+            // Ids start from 16 in IntegerSize::FIXED order
+            Types::FixedInteger(size) => (
+                16 + IntegerSize::FIXED
+                    .iter()
+                    .position(|fixed| fixed == size)
+                    .unwrap_or_default() as u8,
+                size.byte_size(),
+            ),
         }
     }
 
@@ -77,6 +87,7 @@ impl Types {
             Types::Function => "function".to_string(),
             Types::HeapReference => "heapReference".to_string(),
             Types::StackReference => "stackReference".to_string(),
+            Types::FixedInteger(size) => size.type_name().to_string(),
         }
     }
 }
@@ -35,22 +35,12 @@ pub fn isize_to_le_bytes(integer: isize, arch: PlatformArchitecture) -> Vec<u8>
     bytes
 }
 
-pub fn f32_to_le_bytes(float: f32, arch: PlatformArchitecture) -> Vec<u8> {
-    let mut bytes = Vec::new();
-    let int_bytes = float.to_le_bytes();
-    for i in 0..arch.usize_len() as usize {
-        bytes.push(int_bytes[i]);
-    }
-    bytes
+pub fn f32_to_le_bytes(float: f32) -> Vec<u8> {
+    float.to_le_bytes().to_vec()
 }
 
-pub fn f64_to_le_bytes(float: f64, arch: PlatformArchitecture) -> Vec<u8> {
-    let mut bytes = Vec::new();
-    let int_bytes = float.to_le_bytes();
-    for i in 0..arch.usize_len() as usize {
-        bytes.push(int_bytes[i]);
-    }
-    bytes
+pub fn f64_to_le_bytes(float: f64) -> Vec<u8> {
+    float.to_le_bytes().to_vec()
 }
 
 pub fn limit_platform_size(integer: usize, arch: PlatformArchitecture) -> usize {
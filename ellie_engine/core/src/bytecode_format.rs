@@ -0,0 +1,89 @@
+use alloc::string::String;
+
+/// Magic bytes every ellie bytecode file starts with
+pub const BYTECODE_MAGIC: [u8; 4] = [0x7F, b'E', b'I', b'C'];
+
+/// Instruction set revision, files with a different revision are rejected
+///
+/// Bump this whenever op codes or their encoding changes
+pub const BYTECODE_REVISION: u16 = 6;
+
+/// Sections of a bytecode file
+///
+/// ## Layout
+/// | Field            | Size                                 |
+/// |------------------|--------------------------------------|
+/// | magic            | 4                                    |
+/// | revision         | 2                                    |
+/// | architecture     | 1                                    |
+/// | compiler version | 1 byte length + utf8 bytes           |
+/// | section count    | 1                                    |
+/// | body length      | platform size                        |
+/// | checksum         | 4                                    |
+/// | sections         | kind: 1, length: platform size, data |
+///
+/// Checksum is the [`checksum`] of every field from revision to body length followed by the
+/// body, so a corrupted header is caught as well. Readers stop after body length bytes of
+/// sections.
+///
+/// Readers skip sections they don't know, so new optional sections can be added without bumping
+/// the revision.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SectionKind {
+    /// Main function start, end and hash
+    Main,
+    /// Native functions called by the program
+    NativeCalls,
+    /// Functions exported by the program, see [`ExportedSymbol`]
+    Symbols,
+    /// Instructions
+    Code,
+    /// Module maps and debug headers, see [`crate::defs::DebugInfo`]. Not written when the
+    /// program is stripped
+    Debug,
+}
+
+impl SectionKind {
+    pub fn to_byte(&self) -> u8 {
+        match self {
+            SectionKind::Main => 1,
+            SectionKind::NativeCalls => 2,
+            SectionKind::Symbols => 3,
+            SectionKind::Code => 4,
+            SectionKind::Debug => 5,
+        }
+    }
+
+    pub fn from_byte(byte: u8) -> Option<SectionKind> {
+        match byte {
+            1 => Some(SectionKind::Main),
+            2 => Some(SectionKind::NativeCalls),
+            3 => Some(SectionKind::Symbols),
+            4 => Some(SectionKind::Code),
+            5 => Some(SectionKind::Debug),
+            _ => None,
+        }
+    }
+}
+
+/// Function exported by a program
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExportedSymbol {
+    pub name: String,
+    pub hash: usize,
+    /// Location of the function's `FN` instruction
+    pub location: usize,
+}
+
+/// CRC-32 (IEEE) checksum of given bytes
+pub fn checksum(data: &[u8]) -> u32 {
+    let mut crc = 0xFFFF_FFFF_u32;
+    for byte in data {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+        }
+    }
+    !crc
+}
@@ -2,13 +2,14 @@ use crate::defs;
 use alloc::{string::String, vec::Vec};
 use serde::{Deserialize, Serialize};
 
-use super::file_key::FileKey;
+use super::{file_key::FileKey, interface::InterfaceReference};
 
 #[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
 pub struct GenericDefining {
     pub name: String,
     pub hash: usize,
     pub pos: defs::Cursor,
+    pub bound: Option<InterfaceReference>,
 }
 
 #[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
@@ -17,6 +18,7 @@ pub struct Class {
     pub public: bool,
     pub inner_page_id: usize,
     pub generic_definings: Vec<GenericDefining>,
+    pub implements: Vec<InterfaceReference>,
     pub name_pos: defs::Cursor,
     pub pos: defs::Cursor,
     pub file_keys: Vec<FileKey>,
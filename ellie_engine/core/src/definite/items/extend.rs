@@ -15,12 +15,6 @@ pub struct Extend {
     pub target_name: String,
     pub target_generics: Vec<GenericDefining>,
     pub target_pos: defs::Cursor,
-
-    pub from: usize,
-    pub from_name: String,
-    pub from_generics: Vec<GenericDefining>,
-    pub from_pos: defs::Cursor,
-
     pub pos: defs::Cursor,
     pub inner_page_id: usize,
 }
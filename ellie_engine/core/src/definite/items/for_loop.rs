@@ -1,10 +1,14 @@
-use crate::{definite::types, defs};
+use crate::{
+    definite::{definers, types},
+    defs,
+};
 use serde::{Deserialize, Serialize};
 
 #[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
 pub struct ForLoop {
     pub variable: types::Types,
     pub iterator: types::Types,
+    pub iterator_type: definers::DefinerCollecting,
     pub parameter: defs::Cursor,
     pub variable_pos: defs::Cursor,
     pub iterator_pos: defs::Cursor,
@@ -2,7 +2,7 @@ use crate::{definite::definers, defs};
 use alloc::{string::String, vec::Vec};
 use serde::{Deserialize, Serialize};
 
-use super::file_key::FileKey;
+use super::{file_key::FileKey, type_def::GenericDefining};
 
 #[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
 pub struct FunctionParameter {
@@ -17,6 +17,7 @@ pub struct FunctionParameter {
 #[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
 pub struct Function {
     pub name: String,
+    pub generic_definings: Vec<GenericDefining>,
     pub parameters: Vec<FunctionParameter>,
     pub return_type: definers::DefinerCollecting,
     pub inner_page_id: usize,
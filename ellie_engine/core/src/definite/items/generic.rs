@@ -2,9 +2,12 @@ use crate::defs;
 use alloc::string::String;
 use serde::{Deserialize, Serialize};
 
+use super::interface::InterfaceReference;
+
 #[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
 pub struct Generic {
     pub name: String,
     pub pos: defs::Cursor,
     pub hash: usize,
+    pub bound: Option<InterfaceReference>,
 }
@@ -0,0 +1,21 @@
+use crate::defs;
+use alloc::string::String;
+use serde::{Deserialize, Serialize};
+
+/// A resolved reference to an interface, used by `impl` lists and generic bounds
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+pub struct InterfaceReference {
+    pub name: String,
+    pub hash: usize,
+    pub pos: defs::Cursor,
+}
+
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+pub struct Interface {
+    pub name: String,
+    pub public: bool,
+    pub inner_page_id: usize,
+    pub name_pos: defs::Cursor,
+    pub pos: defs::Cursor,
+    pub hash: usize,
+}
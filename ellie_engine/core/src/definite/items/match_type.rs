@@ -0,0 +1,37 @@
+use crate::{
+    alloc::{string::String, vec::Vec},
+    definite::{definers::DefinerCollecting, types},
+    defs,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+pub enum MatchPattern {
+    EnumVariant {
+        enum_hash: usize,
+        variant: String,
+        variant_idx: usize,
+        binds: bool,
+    },
+    Value(types::Types),
+    Null,
+    Wildcard,
+}
+
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+pub struct MatchArm {
+    pub pattern: MatchPattern,
+    pub pattern_pos: defs::Cursor,
+    pub body_pos: defs::Cursor,
+    pub inner_page_id: usize,
+}
+
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+pub struct Match {
+    pub target: types::Types,
+    pub target_type: DefinerCollecting,
+    pub target_pos: defs::Cursor,
+    pub arms: Vec<MatchArm>,
+    pub hash: usize,
+    pub pos: defs::Cursor,
+}
@@ -15,9 +15,12 @@ pub mod generic;
 pub mod getter;
 pub mod getter_call;
 pub mod import;
+pub mod interface;
+pub mod match_type;
 pub mod native_function;
 pub mod setter;
 pub mod setter_call;
+pub mod type_def;
 pub mod variable;
 
 pub mod constructor_parameter;
@@ -36,8 +39,11 @@ pub enum Collecting {
     Loop(loop_type::Loop),
     ForLoop(for_loop::ForLoop),
     Condition(condition::Condition),
+    Match(match_type::Match),
     Class(class::Class),
     Extend(extend::Extend),
+    TypeDef(type_def::TypeDef),
+    Interface(interface::Interface),
     Ret(ret::Ret),
     Brk(brk::Brk),
     Go(go::Go),
@@ -71,6 +77,7 @@ impl Collecting {
             Collecting::Function(e) => e.pos,
             Collecting::ForLoop(e) => e.pos,
             Collecting::Condition(e) => e.pos,
+            Collecting::Match(e) => e.pos,
             Collecting::Class(e) => e.pos,
             Collecting::Ret(e) => e.pos,
             Collecting::Constructor(e) => e.pos,
@@ -96,6 +103,8 @@ impl Collecting {
             Collecting::Go(e) => e.pos,
             Collecting::SelfItem(_) => unreachable!(),
             Collecting::Extend(e) => e.pos,
+            Collecting::TypeDef(e) => e.pos,
+            Collecting::Interface(e) => e.pos,
             Collecting::Loop(e) => e.pos,
             Collecting::ClassInstance(_) => unreachable!(),
         }
@@ -109,6 +118,8 @@ impl Collecting {
             Collecting::Getter(e) => Some(e.hash),
             Collecting::Setter(e) => Some(e.hash),
             Collecting::Enum(e) => Some(e.hash),
+            Collecting::TypeDef(e) => Some(e.hash),
+            Collecting::Interface(e) => Some(e.hash),
             Collecting::NativeFunction(e) => Some(e.hash),
             _ => None,
         }
@@ -120,6 +131,7 @@ impl Collecting {
             Collecting::Function(e) => e.public,
             Collecting::ForLoop(_) => false,
             Collecting::Condition(_) => false,
+            Collecting::Match(_) => false,
             Collecting::Class(e) => e.public,
             Collecting::Ret(_) => false,
             Collecting::Constructor(_) => false,
@@ -139,6 +151,8 @@ impl Collecting {
             Collecting::FunctionParameter(_) => false,
             Collecting::ConstructorParameter(_) => false,
             Collecting::Extend(_) => false,
+            Collecting::TypeDef(e) => e.public,
+            Collecting::Interface(e) => e.public,
             Collecting::Loop(_) => false,
             Collecting::ClassInstance(_) => true,
         }
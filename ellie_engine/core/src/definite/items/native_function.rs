@@ -2,11 +2,12 @@ use crate::{definite::definers, defs};
 use alloc::{string::String, vec::Vec};
 use serde::{Deserialize, Serialize};
 
-use super::file_key::FileKey;
+use super::{file_key::FileKey, type_def::GenericDefining};
 
 #[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
 pub struct NativeFunction {
     pub name: String,                                        //NativeFunction Name string
+    pub generic_definings: Vec<GenericDefining>,             //Generic parameters <T, ...>
     pub parameters: Vec<super::function::FunctionParameter>, //Parameter vector
     pub return_type: definers::DefinerCollecting,            //Return type from enum
     pub public: bool,
@@ -1,8 +1,22 @@
-use alloc::string::String;
+use crate::{definite::definers::DefinerCollecting, defs};
+use alloc::{string::String, vec::Vec};
 use serde::{Deserialize, Serialize};
 
-#[derive(PartialEq, Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+pub struct GenericDefining {
+    pub name: String,
+    pub hash: usize,
+    pub pos: defs::Cursor,
+}
+
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
 pub struct TypeDef {
     pub name: String,
+    pub public: bool,
+    pub generic_definings: Vec<GenericDefining>,
+    pub rtype: DefinerCollecting,
+    pub name_pos: defs::Cursor,
+    pub rtype_pos: defs::Cursor,
+    pub pos: defs::Cursor,
     pub hash: usize,
-}
\ No newline at end of file
+}
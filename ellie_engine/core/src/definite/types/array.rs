@@ -11,5 +11,7 @@ pub struct ArrayEntry {
 #[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
 pub struct ArrayType {
     pub collective: Vec<ArrayEntry>,
+    /// Arrays declared with a size `[T, N]` can't grow, they're stored in stack memory
+    pub fixed: bool,
     pub pos: defs::Cursor,
 }
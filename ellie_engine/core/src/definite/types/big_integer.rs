@@ -0,0 +1,10 @@
+use crate::defs;
+use alloc::string::String;
+use serde::{Deserialize, Serialize};
+
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+pub struct BigIntegerType {
+    /// Decimal digits with an optional `-` sign
+    pub value: String,
+    pub pos: defs::Cursor,
+}
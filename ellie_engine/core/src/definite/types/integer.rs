@@ -1,9 +1,19 @@
 use serde::{Deserialize, Serialize};
 
 use crate::defs;
+pub use crate::defs::IntegerSize;
 
 #[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
 pub struct IntegerType {
+    /// See [`IntegerSize::widen`]
     pub value: isize,
+    pub size: IntegerSize,
     pub pos: defs::Cursor,
 }
+
+impl IntegerType {
+    /// Value of the integer interpreted by its size
+    pub fn wide_value(&self) -> i128 {
+        self.size.widen(self.value)
+    }
+}
@@ -1,5 +1,6 @@
 pub mod array;
 pub mod as_keyword;
+pub mod big_integer;
 pub mod bool;
 pub mod brace_reference;
 pub mod byte;
@@ -27,6 +28,7 @@ use serde::{Deserialize, Serialize};
 pub enum Types {
     Byte(byte::ByteType),
     Integer(integer::IntegerType),
+    BigInteger(big_integer::BigIntegerType),
     Decimal(decimal::DecimalType),
     Bool(bool::BoolType),
     String(string::StringType),
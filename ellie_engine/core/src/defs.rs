@@ -299,6 +299,115 @@ impl Version {
     }
 }
 
+/// Width of an integer, `Int` is platform sized `int`, others are fixed width integers
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub enum IntegerSize {
+    #[default]
+    Int,
+    I8,
+    I16,
+    I32,
+    I64,
+    U8,
+    U16,
+    U32,
+    U64,
+}
+
+impl IntegerSize {
+    /// Fixed width integer sizes
+    pub const FIXED: [IntegerSize; 8] = [
+        IntegerSize::I8,
+        IntegerSize::I16,
+        IntegerSize::I32,
+        IntegerSize::I64,
+        IntegerSize::U8,
+        IntegerSize::U16,
+        IntegerSize::U32,
+        IntegerSize::U64,
+    ];
+
+    /// Name of the type in ellieCore, also used as literal suffix (`10u8`)
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            IntegerSize::Int => "int",
+            IntegerSize::I8 => "i8",
+            IntegerSize::I16 => "i16",
+            IntegerSize::I32 => "i32",
+            IntegerSize::I64 => "i64",
+            IntegerSize::U8 => "u8",
+            IntegerSize::U16 => "u16",
+            IntegerSize::U32 => "u32",
+            IntegerSize::U64 => "u64",
+        }
+    }
+
+    pub fn from_type_name(name: &str) -> Option<IntegerSize> {
+        if name == "int" {
+            Some(IntegerSize::Int)
+        } else {
+            IntegerSize::FIXED
+                .into_iter()
+                .find(|size| size.type_name() == name)
+        }
+    }
+
+    pub fn is_fixed(&self) -> bool {
+        *self != IntegerSize::Int
+    }
+
+    pub fn is_signed(&self) -> bool {
+        matches!(
+            self,
+            IntegerSize::Int
+                | IntegerSize::I8
+                | IntegerSize::I16
+                | IntegerSize::I32
+                | IntegerSize::I64
+        )
+    }
+
+    /// Size in bytes, `int` is sized by the host platform
+    pub fn byte_size(&self) -> usize {
+        match self {
+            IntegerSize::Int => core::mem::size_of::<isize>(),
+            IntegerSize::I8 | IntegerSize::U8 => 1,
+            IntegerSize::I16 | IntegerSize::U16 => 2,
+            IntegerSize::I32 | IntegerSize::U32 => 4,
+            IntegerSize::I64 | IntegerSize::U64 => 8,
+        }
+    }
+
+    pub fn min(&self) -> i128 {
+        if self.is_signed() {
+            -(1_i128 << (self.byte_size() * 8 - 1))
+        } else {
+            0
+        }
+    }
+
+    pub fn max(&self) -> i128 {
+        if self.is_signed() {
+            (1_i128 << (self.byte_size() * 8 - 1)) - 1
+        } else {
+            (1_i128 << (self.byte_size() * 8)) - 1
+        }
+    }
+
+    pub fn fits(&self, value: i128) -> bool {
+        value >= self.min() && value <= self.max()
+    }
+
+    /// Interpret a stored `isize` by this size, `u64` values above `isize::MAX` are kept as their bit pattern
+    pub fn widen(&self, value: isize) -> i128 {
+        if *self == IntegerSize::U64 {
+            value as usize as i128
+        } else {
+            value as i128
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Copy)]
 pub enum PlatformArchitecture {
     B16,
@@ -375,6 +484,38 @@ pub enum DebugHeaderType {
     Function,
     NativeFunction,
     Condition,
+    Ret,
+}
+
+impl DebugHeaderType {
+    pub fn to_byte(&self) -> u8 {
+        match self {
+            DebugHeaderType::Variable => 0,
+            DebugHeaderType::SetterCall => 1,
+            DebugHeaderType::GetterCall => 2,
+            DebugHeaderType::Class => 3,
+            DebugHeaderType::Parameter => 4,
+            DebugHeaderType::Function => 5,
+            DebugHeaderType::NativeFunction => 6,
+            DebugHeaderType::Condition => 7,
+            DebugHeaderType::Ret => 8,
+        }
+    }
+
+    pub fn from_byte(byte: u8) -> Option<DebugHeaderType> {
+        match byte {
+            0 => Some(DebugHeaderType::Variable),
+            1 => Some(DebugHeaderType::SetterCall),
+            2 => Some(DebugHeaderType::GetterCall),
+            3 => Some(DebugHeaderType::Class),
+            4 => Some(DebugHeaderType::Parameter),
+            5 => Some(DebugHeaderType::Function),
+            6 => Some(DebugHeaderType::NativeFunction),
+            7 => Some(DebugHeaderType::Condition),
+            8 => Some(DebugHeaderType::Ret),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -413,4 +413,65 @@ lazy_static! {
         message: "Cannot apply unary operator".to_owned(),
         ..Default::default()
     };
+    pub static ref ERROR_S67: error::Error = error::Error {
+        code: 0x66,
+        title: "ReferenceError".to_owned(),
+        message: "Variable '$token' is used before being assigned".to_owned(),
+        semi_assist: true,
+        ..Default::default()
+    };
+    pub static ref ERROR_S68: error::Error = error::Error {
+        code: 0x67,
+        title: "ReferenceError".to_owned(),
+        message: "'$token' is not a class, only classes can be extended".to_owned(),
+        ..Default::default()
+    };
+    pub static ref ERROR_S69: error::Error = error::Error {
+        code: 0x68,
+        title: "TypeError".to_owned(),
+        message: "'$token' is a type and cannot be used as a value".to_owned(),
+        ..Default::default()
+    };
+    pub static ref ERROR_S70: error::Error = error::Error {
+        code: 0x69,
+        title: "ReferenceError".to_owned(),
+        message: "'$token' is not an interface, only interfaces can be implemented".to_owned(),
+        ..Default::default()
+    };
+    pub static ref ERROR_S71: error::Error = error::Error {
+        code: 0x6A,
+        title: "TypeError".to_owned(),
+        message: "Class '$token' is missing '$token1' required by interface '$token2'".to_owned(),
+        semi_assist: true,
+        ..Default::default()
+    };
+    pub static ref ERROR_S72: error::Error = error::Error {
+        code: 0x6B,
+        title: "TypeError".to_owned(),
+        message: "'$token' does not match interface '$token1', expected '$token2' found '$token3'"
+            .to_owned(),
+        semi_assist: true,
+        ..Default::default()
+    };
+    pub static ref ERROR_S73: error::Error = error::Error {
+        code: 0x6C,
+        title: "TypeError".to_owned(),
+        message: "Type '$token' does not implement interface '$token1'".to_owned(),
+        semi_assist: true,
+        ..Default::default()
+    };
+    pub static ref ERROR_S74: error::Error = error::Error {
+        code: 0x6D,
+        title: "TypeError".to_owned(),
+        message: "Match on '$token' is not exhaustive, missing '$token1'".to_owned(),
+        semi_assist: true,
+        ..Default::default()
+    };
+    pub static ref ERROR_S75: error::Error = error::Error {
+        code: 0x6E,
+        title: "TypeError".to_owned(),
+        message: "Usage of '$token' is disabled by parser settings".to_owned(),
+        semi_assist: true,
+        ..Default::default()
+    };
 }
@@ -15,6 +15,8 @@ extern crate std;
 #[cfg(feature = "compiler_utils")]
 #[macro_use]
 extern crate lazy_static;
+/// Bytecode file format shared by the assembler and the vm
+pub mod bytecode_format;
 #[doc(hidden)]
 #[cfg(feature = "compiler_utils")]
 pub mod definite;
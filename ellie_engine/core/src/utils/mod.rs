@@ -10,6 +10,7 @@ use rand;
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    definite::types::integer::IntegerSize,
     definite::types::operator::{
         assignment_operator_to_string, comparison_operator_to_string, logical_operator_to_string,
         ArithmeticOperators, AssignmentOperators, Operators,
@@ -70,6 +71,9 @@ pub fn is_reserved(value: &str, allow_core_naming: bool) -> bool {
         || (value == "void" && !allow_core_naming)
         || (value == "null" && !allow_core_naming)
         || (value == "nullAble" && !allow_core_naming)
+        || (value == "bigint" && !allow_core_naming)
+        || (matches!(IntegerSize::from_type_name(value), Some(size) if size.is_fixed())
+            && !allow_core_naming)
 }
 
 pub fn generate_hash_usize() -> usize {
@@ -345,6 +349,17 @@ pub fn operator_priority(operator: &str) -> usize {
     }
 }
 
+/// Fixed width integers operate with same width or `int`, result has the width of `first`
+fn is_fixed_integer_operation(first: &str, second: &str) -> bool {
+    matches!(IntegerSize::from_type_name(first), Some(size) if size.is_fixed())
+        && (first == second || second == "int")
+}
+
+/// Big integers operate with `bigint` or `int`, result is a `bigint`
+fn is_big_integer_operation(first: &str, second: &str) -> bool {
+    first == "bigint" && (second == "bigint" || second == "int")
+}
+
 pub fn operator_control(
     operator: Operators,
     first: String,
@@ -368,6 +383,15 @@ pub fn operator_control(
                     | ("double", "float") => None,
                     ("dyn", _) => None,
                     (_, "dyn") => None,
+                    ("null", a) | (a, "null") if a == "null" || a.starts_with("nullAble<") => None,
+                    (a, b)
+                        if is_fixed_integer_operation(a, b) || is_fixed_integer_operation(b, a) =>
+                    {
+                        None
+                    }
+                    (a, b) if is_big_integer_operation(a, b) || is_big_integer_operation(b, a) => {
+                        None
+                    }
                     (a, b) => {
                         if a == b {
                             return None;
@@ -386,6 +410,14 @@ pub fn operator_control(
                     | ("float", "double")
                     | ("double", "float")
                     | ("double", "double") => None,
+                    (a, b)
+                        if is_fixed_integer_operation(a, b) || is_fixed_integer_operation(b, a) =>
+                    {
+                        None
+                    }
+                    (a, b) if is_big_integer_operation(a, b) || is_big_integer_operation(b, a) => {
+                        None
+                    }
                     _ => Some(comparison_operator_to_string(operator)),
                 }
             }
@@ -413,6 +445,10 @@ pub fn operator_control(
                     | ("string", "double")
                     | ("string", "bool")
                     | ("string", "byte") => None,
+                    (a, b) if is_fixed_integer_operation(a, b) => None,
+                    ("string", b) if is_fixed_integer_operation(b, b) => None,
+                    (a, b) if is_big_integer_operation(a, b) => None,
+                    ("string", "bigint") => None,
                     _ => Some("Addition"),
                 }
             }
@@ -426,6 +462,8 @@ pub fn operator_control(
                     ("float", "float") | ("float", "double") | ("float", "int") => None,
                     ("double", "double") | ("double", "int") | ("double", "float") => None,
                     ("byte", "byte") | ("byte", "int") => None,
+                    (a, b) if is_fixed_integer_operation(a, b) => None,
+                    (a, b) if is_big_integer_operation(a, b) => None,
                     _ => Some("Assignment"),
                 }
             }
@@ -452,6 +490,10 @@ pub fn operator_control(
                     | ("string", "float")
                     | ("string", "double")
                     | ("string", "byte") => None,
+                    (a, b) if is_fixed_integer_operation(a, b) => None,
+                    ("string", b) if is_fixed_integer_operation(b, b) => None,
+                    (a, b) if is_big_integer_operation(a, b) => None,
+                    ("string", "bigint") => None,
                     _ => Some("AdditionAssignment"),
                 }
             }
@@ -470,6 +512,8 @@ pub fn operator_control(
                     | ("double", "int")
                     | ("byte", "byte")
                     | ("byte", "int") => None,
+                    (a, b) if is_fixed_integer_operation(a, b) => None,
+                    (a, b) if is_big_integer_operation(a, b) => None,
                     _ => Some(assignment_operator_to_string(operator)),
                 }
             }
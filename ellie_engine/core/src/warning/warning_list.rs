@@ -58,4 +58,58 @@ lazy_static! {
         ..Default::default()
     };
 
+    pub static ref WARNING_S8: warning::Warning = warning::Warning {
+        code: 0x07,
+        title: "UnusedVariable".to_owned(),
+        message: "Variable '$name' is declared but never used".to_owned(),
+        full_assist: true,
+        ..Default::default()
+    };
+
+    pub static ref WARNING_S9: warning::Warning = warning::Warning {
+        code: 0x08,
+        title: "UnusedParameter".to_owned(),
+        message: "Parameter '$name' is never used".to_owned(),
+        semi_assist: true,
+        ..Default::default()
+    };
+
+    pub static ref WARNING_S10: warning::Warning = warning::Warning {
+        code: 0x09,
+        title: "UnusedProperty".to_owned(),
+        message: "Private property '$name' is never used".to_owned(),
+        full_assist: true,
+        ..Default::default()
+    };
+
+    pub static ref WARNING_S11: warning::Warning = warning::Warning {
+        code: 0x0A,
+        title: "UnusedImport".to_owned(),
+        message: "Import '$path' is never used".to_owned(),
+        full_assist: true,
+        ..Default::default()
+    };
+
+    pub static ref WARNING_S12: warning::Warning = warning::Warning {
+        code: 0x0B,
+        title: "UnreachableFunction".to_owned(),
+        message: "Function '$name' is unreachable from $root".to_owned(),
+        full_assist: true,
+        ..Default::default()
+    };
+
+    pub static ref WARNING_S13: warning::Warning = warning::Warning {
+        code: 0x0C,
+        title: "UnnecessaryNullResolver".to_owned(),
+        message: "'$name' is already checked against null, resolver is unnecessary".to_owned(),
+        full_assist: true,
+        ..Default::default()
+    };
+
+    pub static ref WARNING_S14: warning::Warning = warning::Warning {
+        code: 0x0D,
+        title: "NullResolveOnNull".to_owned(),
+        message: "'$name' is always null here, resolving it will fail".to_owned(),
+        ..Default::default()
+    };
 }
@@ -25,11 +25,20 @@ impl CodeRenderer for ArrayType {
                 }
                 _ => unreachable!("Array size must be integer"),
             },
+            None if self.raw_size == "*" => {
+                if options.leave_space_after_comma {
+                    ", *".to_string()
+                } else {
+                    ",*".to_string()
+                }
+            }
             None => String::new(),
         };
 
         //[[type], [size]]
         //OR
+        //[[type], *]
+        //OR
         //[[type]]
         format!("{state_scope_length}[{type_of_value}{size_of_value}]{state_ending_token}")
     }
@@ -13,6 +13,9 @@ impl CodeRenderer for Class {
             let mut generic_definings = "<".to_string();
             for (index, generic_defining) in self.generic_definings.iter().enumerate() {
                 let mut _generic_defining = generic_defining.name.to_string();
+                if !generic_defining.bound.is_empty() {
+                    _generic_defining += &format!(": {}", generic_defining.bound);
+                }
 
                 if index != self.generic_definings.len() - 1 {
                     if options.leave_space_after_comma {
@@ -27,6 +30,22 @@ impl CodeRenderer for Class {
             class_input += &generic_definings;
         }
 
+        if !self.implements.is_empty() {
+            let separator = if options.leave_space_after_comma {
+                ", "
+            } else {
+                ","
+            };
+            class_input += &format!(
+                " impl {}",
+                self.implements
+                    .iter()
+                    .map(|implement| implement.name.clone())
+                    .collect::<Vec<_>>()
+                    .join(separator)
+            );
+        }
+
         if self.body.is_empty() {
             class_input += &format!("{{}}{}", options.render_line_ending());
             class_input
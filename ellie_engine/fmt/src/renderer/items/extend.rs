@@ -0,0 +1,59 @@
+use super::super::{CodeRenderer, State};
+use crate::fmt::FormatterOptions;
+use ellie_tokenizer::syntax::items::extend::Extend;
+
+impl CodeRenderer for Extend {
+    //Renderer Options
+    fn render(&self, state: &State, options: &FormatterOptions) -> String {
+        let state_scope_length = state.render_scope_space(options);
+        let mut extend_input = format!("{state_scope_length}extend {}", self.target);
+
+        if !self.generic_definings.is_empty() {
+            let mut generic_definings = "<".to_string();
+            for (index, generic_defining) in self.generic_definings.iter().enumerate() {
+                let mut _generic_defining = generic_defining.name.to_string();
+
+                if index != self.generic_definings.len() - 1 {
+                    if options.leave_space_after_comma {
+                        _generic_defining += ", "
+                    } else {
+                        _generic_defining += ","
+                    }
+                }
+                generic_definings += &_generic_defining;
+            }
+            generic_definings += ">";
+            extend_input += &generic_definings;
+        }
+
+        if self.body.is_empty() {
+            extend_input += &format!("{{}}{}", options.render_line_ending());
+            extend_input
+        } else {
+            if options.render_brace_next_line {
+                extend_input += &format!(
+                    "{line_ending}{state_scope_length}{{{line_ending}",
+                    line_ending = options.render_line_ending()
+                );
+            } else {
+                extend_input += &format!(
+                    " {{{line_ending}",
+                    line_ending = options.render_line_ending()
+                );
+            }
+
+            let mut child_state = state.clone();
+            child_state.scope_length += 1;
+            child_state.ending_token = options.render_line_ending();
+            for line in self.body.iter() {
+                extend_input += &line.render(&child_state, options);
+            }
+            extend_input += format!(
+                "{state_scope_length}}}{line_ending}",
+                line_ending = options.render_line_ending()
+            )
+            .as_str();
+            extend_input
+        }
+    }
+}
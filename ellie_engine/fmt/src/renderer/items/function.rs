@@ -8,6 +8,26 @@ impl CodeRenderer for Function {
         let state_scope_length = state.render_scope_space(options);
         let is_public = if self.public { "pub " } else { "" };
 
+        let generics = if self.generic_definings.is_empty() {
+            String::new()
+        } else {
+            let mut generic_definings = "<".to_string();
+            for (index, generic_defining) in self.generic_definings.iter().enumerate() {
+                let mut _generic_defining = generic_defining.name.to_string();
+
+                if index != self.generic_definings.len() - 1 {
+                    if options.leave_space_after_comma {
+                        _generic_defining += ", "
+                    } else {
+                        _generic_defining += ","
+                    }
+                }
+                generic_definings += &_generic_defining;
+            }
+            generic_definings += ">";
+            generic_definings
+        };
+
         let params = {
             let mut params = "(".to_string();
             for (index, param) in self.parameters.iter().enumerate() {
@@ -56,7 +76,7 @@ impl CodeRenderer for Function {
         };
 
         let mut fn_input = format!(
-            "{state_scope_length}{is_public}fn {fn_name}{params}{rtype}",
+            "{state_scope_length}{is_public}fn {fn_name}{generics}{params}{rtype}",
             fn_name = &self.name
         );
 
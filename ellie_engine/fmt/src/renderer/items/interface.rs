@@ -0,0 +1,42 @@
+use super::super::{CodeRenderer, State};
+use crate::fmt::FormatterOptions;
+use ellie_tokenizer::syntax::items::interface::Interface;
+
+impl CodeRenderer for Interface {
+    //Renderer Options
+    fn render(&self, state: &State, options: &FormatterOptions) -> String {
+        let state_scope_length = state.render_scope_space(options);
+        let is_public = if self.public { "pub " } else { "" };
+        let mut interface_input = format!("{state_scope_length}{is_public}interface {}", self.name);
+
+        if self.body.is_empty() {
+            interface_input += &format!("{{}}{}", options.render_line_ending());
+            interface_input
+        } else {
+            if options.render_brace_next_line {
+                interface_input += &format!(
+                    "{line_ending}{state_scope_length}{{{line_ending}",
+                    line_ending = options.render_line_ending()
+                );
+            } else {
+                interface_input += &format!(
+                    " {{{line_ending}",
+                    line_ending = options.render_line_ending()
+                );
+            }
+
+            let mut child_state = state.clone();
+            child_state.scope_length += 1;
+            child_state.ending_token = options.render_line_ending();
+            for line in self.body.iter() {
+                interface_input += &line.render(&child_state, options);
+            }
+            interface_input += format!(
+                "{state_scope_length}}}{line_ending}",
+                line_ending = options.render_line_ending()
+            )
+            .as_str();
+            interface_input
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use super::super::{CodeRenderer, State};
+use crate::fmt::FormatterOptions;
+use ellie_tokenizer::syntax::items::match_type::Match;
+
+impl CodeRenderer for Match {
+    //Renderer Options
+    fn render(&self, state: &State, options: &FormatterOptions) -> String {
+        let state_scope_length = state.render_scope_space(options);
+
+        let target = self.target.current.render(&State::empty_state(), options);
+
+        let mut match_input = format!("{state_scope_length}match {target}");
+
+        if options.render_brace_next_line {
+            match_input += &format!(
+                "{line_ending}{state_scope_length}{{{line_ending}",
+                line_ending = options.render_line_ending()
+            );
+        } else {
+            match_input += &format!(
+                " {{{line_ending}",
+                line_ending = options.render_line_ending()
+            );
+        }
+
+        let mut arm_state = state.clone();
+        arm_state.scope_length += 1;
+        let arm_scope_length = arm_state.render_scope_space(options);
+
+        let mut child_state = arm_state.clone();
+        child_state.scope_length += 1;
+        child_state.ending_token = options.render_line_ending();
+
+        for arm in self.arms.iter() {
+            let pattern = arm.pattern.current.render(&State::empty_state(), options);
+            if arm.body.is_empty() {
+                match_input += &format!(
+                    "{arm_scope_length}{pattern} => {{}}{line_ending}",
+                    line_ending = options.render_line_ending()
+                );
+                continue;
+            }
+            match_input += &format!(
+                "{arm_scope_length}{pattern} => {{{line_ending}",
+                line_ending = options.render_line_ending()
+            );
+            for line in arm.body.iter() {
+                match_input += &line.render(&child_state, options);
+            }
+            match_input += &format!(
+                "{arm_scope_length}}}{line_ending}",
+                line_ending = options.render_line_ending()
+            );
+        }
+
+        match_input += &format!(
+            "{state_scope_length}}}{line_ending}",
+            line_ending = options.render_line_ending()
+        );
+        match_input
+    }
+}
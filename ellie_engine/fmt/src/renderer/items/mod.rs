@@ -10,6 +10,7 @@ pub mod comment;
 pub mod condition;
 pub mod constructor;
 pub mod enum_type;
+pub mod extend;
 pub mod file_key;
 pub mod for_loop;
 pub mod function;
@@ -17,10 +18,13 @@ pub mod getter;
 pub mod getter_call;
 pub mod go;
 pub mod import;
+pub mod interface;
 pub mod loop_type;
+pub mod match_type;
 pub mod ret;
 pub mod setter;
 pub mod setter_call;
+pub mod type_def;
 pub mod variable;
 
 impl CodeRenderer for Processors {
@@ -35,8 +39,12 @@ impl CodeRenderer for Processors {
             Processors::Loop(e) => e.render(state, options),
             Processors::ForLoop(e) => e.render(state, options),
             Processors::Condition(e) => e.render(state, options),
+            Processors::Match(e) => e.render(state, options),
             Processors::Constructor(e) => e.render(state, options),
             Processors::Class(e) => e.render(state, options),
+            Processors::Extend(e) => e.render(state, options),
+            Processors::Interface(e) => e.render(state, options),
+            Processors::TypeDef(e) => e.render(state, options),
             Processors::Ret(e) => e.render(state, options),
             Processors::Brk(e) => e.render(state, options),
             Processors::Go(e) => e.render(state, options),
@@ -0,0 +1,38 @@
+use super::super::{CodeRenderer, State};
+use crate::fmt::FormatterOptions;
+use ellie_tokenizer::syntax::items::type_def::TypeDef;
+
+impl CodeRenderer for TypeDef {
+    //Renderer Options
+    fn render(&self, state: &State, options: &FormatterOptions) -> String {
+        let state_scope_length = state.render_scope_space(options);
+        let line_ending = &options.render_line_ending();
+        let is_public = if self.public { "pub " } else { "" };
+        let mut type_def_input = format!("{state_scope_length}{is_public}type {}", self.name);
+
+        if !self.generic_definings.is_empty() {
+            let mut generic_definings = "<".to_string();
+            for (index, generic_defining) in self.generic_definings.iter().enumerate() {
+                let mut _generic_defining = generic_defining.name.to_string();
+
+                if index != self.generic_definings.len() - 1 {
+                    if options.leave_space_after_comma {
+                        _generic_defining += ", "
+                    } else {
+                        _generic_defining += ","
+                    }
+                }
+                generic_definings += &_generic_defining;
+            }
+            generic_definings += ">";
+            type_def_input += &generic_definings;
+        }
+
+        let rtype = self
+            .rtype
+            .definer_type
+            .render(&State::empty_state(), options);
+        // [pub] type [name][<generics>] = [type]
+        format!("{type_def_input} = {rtype};{line_ending}")
+    }
+}
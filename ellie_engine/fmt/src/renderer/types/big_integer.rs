@@ -0,0 +1,13 @@
+use super::super::{CodeRenderer, State};
+use crate::fmt::FormatterOptions;
+use ellie_tokenizer::syntax::types::big_integer_type::BigIntegerType;
+
+impl CodeRenderer for BigIntegerType {
+    //Renderer Options
+    fn render(&self, state: &State, options: &FormatterOptions) -> String {
+        let state_scope_length = state.render_scope_space(options);
+        let state_ending_token = &state.ending_token;
+        let value = &self.value;
+        format!("{state_scope_length}{value}n{state_ending_token}")
+    }
+}
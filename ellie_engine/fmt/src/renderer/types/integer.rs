@@ -7,7 +7,11 @@ impl CodeRenderer for IntegerType {
     fn render(&self, state: &State, options: &FormatterOptions) -> String {
         let state_scope_length = state.render_scope_space(options);
         let state_ending_token = &state.ending_token;
-        let value = self.value;
+        let value = if self.size.is_fixed() {
+            format!("{}{}", self.size.widen(self.value), self.size.type_name())
+        } else {
+            self.value.to_string()
+        };
         format!("{state_scope_length}{value}{state_ending_token}")
     }
 }
@@ -1,5 +1,6 @@
 pub mod array;
 pub mod as_keyword;
+pub mod big_integer;
 pub mod brace_reference;
 pub mod byte;
 pub mod char;
@@ -25,6 +26,7 @@ impl CodeRenderer for Processors {
     fn render(&self, state: &State, options: &FormatterOptions) -> String {
         match self {
             Processors::Integer(e) => e.data.render(state, options),
+            Processors::BigInteger(e) => e.render(state, options),
             Processors::Byte(e) => e.render(state, options),
             Processors::Decimal(e) => e.data.render(state, options),
             Processors::Char(e) => e.render(state, options),
@@ -20,7 +20,7 @@ use ellie_core::{
     },
     defs, error,
 };
-use ellie_tokenizer::tokenizer::Dependency;
+use ellie_tokenizer::tokenizer::{Dependency, PageType};
 use enum_as_inner::EnumAsInner;
 
 /*
@@ -34,10 +34,20 @@ pub fn generate_type_from_defining(
 ) -> Option<Types> {
     match rtype {
         definers::DefinerCollecting::Generic(generic) => {
-            if generic.rtype == "int" {
+            if let Some(size) =
+                ellie_core::definite::types::integer::IntegerSize::from_type_name(&generic.rtype)
+            {
                 Some(Types::Integer(
                     ellie_core::definite::types::integer::IntegerType {
                         value: 0,
+                        size,
+                        pos: defs::Cursor::default(),
+                    },
+                ))
+            } else if generic.rtype == "bigint" {
+                Some(Types::BigInteger(
+                    ellie_core::definite::types::big_integer::BigIntegerType {
+                        value: "0".to_owned(),
                         pos: defs::Cursor::default(),
                     },
                 ))
@@ -155,6 +165,7 @@ pub fn generate_type_from_defining(
                             value: t,
                             location: defs::Cursor::default(),
                         }],
+                        fixed: false,
                         pos: defs::Cursor::default(),
                     })
                 })
@@ -302,6 +313,7 @@ pub fn generate_type_from_defining(
 #[derive(Debug)]
 pub enum DeepTypeResult {
     Integer(ellie_core::definite::types::integer::IntegerType),
+    BigInteger(ellie_core::definite::types::big_integer::BigIntegerType),
     Byte(ellie_core::definite::types::byte::ByteType),
     Decimal(ellie_core::definite::types::decimal::DecimalType),
     Bool(ellie_core::definite::types::bool::BoolType),
@@ -335,6 +347,7 @@ fn iterate_deep_type(
 ) -> DeepTypeResult {
     match rtype.clone() {
         Types::Integer(integer) => DeepTypeResult::Integer(integer),
+        Types::BigInteger(big_integer) => DeepTypeResult::BigInteger(big_integer),
         Types::Decimal(float) => DeepTypeResult::Decimal(float),
         Types::String(string) => DeepTypeResult::String(string),
         Types::Char(char) => DeepTypeResult::Char(char),
@@ -368,7 +381,7 @@ fn iterate_deep_type(
                                         .cloned()
                                     {
                                         Some(class_inner_page) => {
-                                            let attributes = class_inner_page.items.iter().filter_map(|item| {
+                                            let mut attributes = class_inner_page.items.iter().filter_map(|item| {
                                                         match item.clone() {
                                                             Collecting::Variable(e) => {
                                                                 let resolved_type = if e.has_type { e.rtype } else { match resolve_type(e.value, class_inner_page.hash, parser, &mut errors, Some(reference_pos)) {
@@ -433,6 +446,26 @@ fn iterate_deep_type(
                                                             _ => None,
                                                         }
                                                     }).collect::<Vec<_>>();
+                                            attributes.extend(find_extension_methods(parser, page_id, class_page.hash).into_iter().filter_map(|(extend_page, item)| {
+                                                let (name, parameters, return_type) = match item {
+                                                    Collecting::Function(e) => (e.name, e.parameters, e.return_type),
+                                                    Collecting::NativeFunction(e) => (e.name, e.parameters, e.return_type),
+                                                    _ => return None,
+                                                };
+                                                Some(Attribute {
+                                                    _rtype: AttributeType::Method,
+                                                    name,
+                                                    value: definers::DefinerCollecting::Function(
+                                                        ellie_core::definite::definers::FunctionType {
+                                                            params: parameters.iter().skip(1).map(|param| {
+                                                                param.rtype.clone()
+                                                            }).collect::<Vec<_>>(),
+                                                            returning: Box::new(return_type),
+                                                        }
+                                                    ),
+                                                    page: extend_page,
+                                                })
+                                            }));
                                             Ok(attributes)
                                         }
                                         None => {
@@ -465,8 +498,43 @@ fn iterate_deep_type(
                                         }).collect()
                                     )
                                 }
-                                ProcessedDeepSearchItems::GenericItem(_) => {
-                                    Ok(Vec::new())
+                                ProcessedDeepSearchItems::Interface(interface) => {
+                                    Ok(find_interface_members(parser, interface.inner_page_id).into_iter().filter_map(|item| match item {
+                                        Collecting::Variable(e) => Some(Attribute {
+                                            _rtype: AttributeType::Property,
+                                            name: e.name,
+                                            value: e.rtype,
+                                            page: interface.inner_page_id,
+                                        }),
+                                        Collecting::NativeFunction(e) => Some(Attribute {
+                                            _rtype: AttributeType::Method,
+                                            name: e.name,
+                                            value: definers::DefinerCollecting::Function(
+                                                ellie_core::definite::definers::FunctionType {
+                                                    params: e.parameters.iter().map(|param| {
+                                                        param.rtype.clone()
+                                                    }).collect::<Vec<_>>(),
+                                                    returning: Box::new(e.return_type),
+                                                }
+                                            ),
+                                            page: interface.inner_page_id,
+                                        }),
+                                        _ => None,
+                                    }).collect())
+                                }
+                                // Bounded generics expose the members of their interface
+                                ProcessedDeepSearchItems::GenericItem(generic) => match generic.bound {
+                                    Some(bound) => resolve_chain(
+                                        definers::DefinerCollecting::Generic(definers::GenericType {
+                                            rtype: bound.name,
+                                            hash: bound.hash,
+                                            pos: bound.pos,
+                                        }),
+                                        reference_pos,
+                                        page_id,
+                                        parser,
+                                    ),
+                                    None => Ok(Vec::new()),
                                 },
                                 _ => unreachable!(),
                             }
@@ -504,25 +572,32 @@ fn iterate_deep_type(
                                                 page_id,
                                                 parser,
                                             ) {
-                                                Ok(e) => {
-                                                    Ok(e.clone()
-                                                        .iter_mut()
-                                                        .map(|attr| {
-                                                            for (i, generic_defining) in class_item
+                                                Ok(e) => Ok(e
+                                                    .clone()
+                                                    .iter_mut()
+                                                    .map(|attr| {
+                                                        let generic_hashes = member_generic_hashes(
+                                                            parser,
+                                                            attr.page,
+                                                            class_item
                                                                 .generic_definings
                                                                 .iter()
-                                                                .enumerate()
-                                                            {
-                                                                attr.value.convert_generic(
-                                                                    generic_defining.hash,
-                                                                    rtype.generics[i].value.clone(),
-                                                                );
-                                                                // = rtype.generics[i].value.clone();
-                                                            }
-                                                            attr.clone()
-                                                        })
-                                                        .collect::<Vec<_>>())
-                                                }
+                                                                .map(|x| x.hash)
+                                                                .collect(),
+                                                        );
+                                                        for (generic_hash, generic) in
+                                                            generic_hashes
+                                                                .iter()
+                                                                .zip(rtype.generics.iter())
+                                                        {
+                                                            attr.value.convert_generic(
+                                                                *generic_hash,
+                                                                generic.value.clone(),
+                                                            );
+                                                        }
+                                                        attr.clone()
+                                                    })
+                                                    .collect::<Vec<_>>()),
                                                 Err(e) => {
                                                     errors.extend(e);
                                                     Err(errors)
@@ -608,20 +683,9 @@ fn iterate_deep_type(
                             Err(errors)
                         }
                     }
+                    //Enum fields don't expose any attributes
                     ellie_core::definite::definers::DefinerCollecting::EnumField(_) => {
-                        let rtype = find_type("enum".to_owned(), page_id, parser);
-                        match resolve_chain(
-                            definers::DefinerCollecting::Generic(rtype.unwrap()),
-                            defs::Cursor::default(),
-                            page_id,
-                            parser,
-                        ) {
-                            Ok(e) => Ok(e),
-                            Err(e) => {
-                                errors.extend(e);
-                                Err(errors)
-                            }
-                        }
+                        Ok(Vec::new())
                     }
                     ellie_core::definite::definers::DefinerCollecting::ClassInstance(
                         class_instance,
@@ -823,6 +887,7 @@ fn iterate_deep_type(
                     ellie_core::definite::types::brace_reference::BraceReferenceType {
                         reference: Box::new(match resolved_reference {
                             DeepTypeResult::Integer(e) => Types::Integer(e),
+                            DeepTypeResult::BigInteger(e) => Types::BigInteger(e),
                             DeepTypeResult::Byte(e) => Types::Byte(e),
                             DeepTypeResult::Decimal(e) => Types::Decimal(e),
                             DeepTypeResult::Bool(e) => Types::Bool(e),
@@ -855,6 +920,7 @@ fn iterate_deep_type(
                         brace_pos: e.brace_pos,
                         value: Box::new(match resolved_index {
                             DeepTypeResult::Integer(e) => Types::Integer(e),
+                            DeepTypeResult::BigInteger(e) => Types::BigInteger(e),
                             DeepTypeResult::Byte(e) => Types::Byte(e),
                             DeepTypeResult::Decimal(e) => Types::Decimal(e),
                             DeepTypeResult::Bool(e) => Types::Bool(e),
@@ -889,6 +955,7 @@ fn iterate_deep_type(
         Types::Operator(e) => {
             let first = match resolve_deep_type(parser, page_id, *e.first, errors) {
                 DeepTypeResult::Integer(e) => Types::Integer(e),
+                DeepTypeResult::BigInteger(e) => Types::BigInteger(e),
                 DeepTypeResult::Byte(e) => Types::Byte(e),
                 DeepTypeResult::Decimal(e) => Types::Decimal(e),
                 DeepTypeResult::Bool(e) => Types::Bool(e),
@@ -916,6 +983,7 @@ fn iterate_deep_type(
 
             let second = match resolve_deep_type(parser, page_id, *e.second, errors) {
                 DeepTypeResult::Integer(e) => Types::Integer(e),
+                DeepTypeResult::BigInteger(e) => Types::BigInteger(e),
                 DeepTypeResult::Byte(e) => Types::Byte(e),
                 DeepTypeResult::Decimal(e) => Types::Decimal(e),
                 DeepTypeResult::Bool(e) => Types::Bool(e),
@@ -975,6 +1043,12 @@ fn iterate_deep_type(
                             location: i.location,
                         });
                     }
+                    DeepTypeResult::BigInteger(big_integer_type) => {
+                        collective.push(ellie_core::definite::types::array::ArrayEntry {
+                            value: Types::BigInteger(big_integer_type),
+                            location: i.location,
+                        });
+                    }
                     DeepTypeResult::Byte(byte_type) => {
                         collective.push(ellie_core::definite::types::array::ArrayEntry {
                             value: Types::Byte(byte_type),
@@ -1094,6 +1168,7 @@ fn iterate_deep_type(
             }
             DeepTypeResult::Array(ellie_core::definite::types::array::ArrayType {
                 collective,
+                fixed: array.fixed,
                 pos: array.pos,
             })
         }
@@ -1121,6 +1196,7 @@ fn iterate_deep_type(
                         match resolved_type {
                             Some(types) => match types {
                                 Types::Integer(e) => DeepTypeResult::Integer(e),
+                                Types::BigInteger(e) => DeepTypeResult::BigInteger(e),
                                 Types::Decimal(e) => DeepTypeResult::Decimal(e),
                                 Types::Bool(e) => DeepTypeResult::Bool(e),
                                 Types::String(e) => DeepTypeResult::String(e),
@@ -1220,13 +1296,23 @@ fn iterate_deep_type(
                         }
                     }
                     ProcessedDeepSearchItems::Variable(e) => {
-                        iterate_deep_type(parser, page_id, e.value, errors)
+                        //Arrays declared without value start empty and nullables hold null,
+                        //their types come from the definer
+                        let value = if (!e.has_value && matches!(e.value, Types::Array(_)))
+                            || matches!(e.value, Types::Null)
+                        {
+                            generate_type_from_defining(e.rtype, page_id, parser).unwrap_or(e.value)
+                        } else {
+                            e.value
+                        };
+                        iterate_deep_type(parser, page_id, value, errors)
                     }
                     ProcessedDeepSearchItems::FunctionParameter(e) => {
                         match generate_type_from_defining(e.rtype, page_id, parser) {
                             Some(e) => match e {
                                 Types::Byte(e) => DeepTypeResult::Byte(e),
                                 Types::Integer(e) => DeepTypeResult::Integer(e),
+                                Types::BigInteger(e) => DeepTypeResult::BigInteger(e),
                                 Types::Decimal(e) => DeepTypeResult::Decimal(e),
                                 Types::Bool(e) => DeepTypeResult::Bool(e),
                                 Types::String(e) => DeepTypeResult::String(e),
@@ -1252,6 +1338,7 @@ fn iterate_deep_type(
                             Some(e) => match e {
                                 Types::Byte(e) => DeepTypeResult::Byte(e),
                                 Types::Integer(e) => DeepTypeResult::Integer(e),
+                                Types::BigInteger(e) => DeepTypeResult::BigInteger(e),
                                 Types::Decimal(e) => DeepTypeResult::Decimal(e),
                                 Types::Bool(e) => DeepTypeResult::Bool(e),
                                 Types::String(e) => DeepTypeResult::String(e),
@@ -1277,6 +1364,7 @@ fn iterate_deep_type(
                             Some(e) => match e {
                                 Types::Byte(e) => DeepTypeResult::Byte(e),
                                 Types::Integer(e) => DeepTypeResult::Integer(e),
+                                Types::BigInteger(e) => DeepTypeResult::BigInteger(e),
                                 Types::Decimal(e) => DeepTypeResult::Decimal(e),
                                 Types::Bool(e) => DeepTypeResult::Bool(e),
                                 Types::String(e) => DeepTypeResult::String(e),
@@ -1302,6 +1390,7 @@ fn iterate_deep_type(
                             Some(e) => match e {
                                 Types::Byte(e) => DeepTypeResult::Byte(e),
                                 Types::Integer(e) => DeepTypeResult::Integer(e),
+                                Types::BigInteger(e) => DeepTypeResult::BigInteger(e),
                                 Types::Decimal(e) => DeepTypeResult::Decimal(e),
                                 Types::Bool(e) => DeepTypeResult::Bool(e),
                                 Types::String(e) => DeepTypeResult::String(e),
@@ -1369,6 +1458,8 @@ fn iterate_deep_type(
                     ProcessedDeepSearchItems::ImportReference(_) => todo!(),
                     ProcessedDeepSearchItems::GenericItem(_) => todo!(),
                     ProcessedDeepSearchItems::None => todo!(),
+                    // Interfaces are rejected as values while parsing (S69)
+                    ProcessedDeepSearchItems::Interface(_) => DeepTypeResult::NotFound,
                     ProcessedDeepSearchItems::Enum(enum_data) => DeepTypeResult::Enum(enum_data),
                     ProcessedDeepSearchItems::SelfItem(self_item) => {
                         DeepTypeResult::SelfItem(self_item)
@@ -1461,9 +1552,26 @@ fn iterate_deep_type(
                                     ))
                                 }
                                 definers::DefinerCollecting::Generic(generic) => {
-                                    if generic.rtype == "int" {
+                                    if let Some(size) =
+                                    ellie_core::definite::types::integer::IntegerSize::from_type_name(&generic.rtype)
+                                {
                                         Some(Types::Integer(
                                             ellie_core::definite::types::integer::IntegerType {
+                                                value: 0,
+                                                size,
+                                                pos: defs::Cursor::default(),
+                                            },
+                                        ))
+                                    } else if generic.rtype == "bigint" {
+                                        Some(Types::BigInteger(
+                                            ellie_core::definite::types::big_integer::BigIntegerType {
+                                                value: "0".to_owned(),
+                                                pos: defs::Cursor::default(),
+                                            },
+                                        ))
+                                    } else if generic.rtype == "byte" {
+                                        Some(Types::Byte(
+                                            ellie_core::definite::types::byte::ByteType {
                                                 value: 0,
                                                 pos: defs::Cursor::default(),
                                             },
@@ -1525,6 +1633,7 @@ fn iterate_deep_type(
                                                             location: defs::Cursor::default(),
                                                         },
                                                     ],
+                                                    fixed: false,
                                                     pos: defs::Cursor::default(),
                                                 },
                                             ))
@@ -1596,6 +1705,8 @@ fn iterate_deep_type(
                         match resolved_type {
                             Some(types) => match types {
                                 Types::Integer(e) => DeepTypeResult::Integer(e),
+                                Types::BigInteger(e) => DeepTypeResult::BigInteger(e),
+                                Types::Byte(e) => DeepTypeResult::Byte(e),
                                 Types::Decimal(e) => DeepTypeResult::Decimal(e),
                                 Types::Bool(e) => DeepTypeResult::Bool(e),
                                 Types::String(e) => DeepTypeResult::String(e),
@@ -1877,6 +1988,7 @@ pub enum ProcessedDeepSearchItems {
     Variable(ellie_core::definite::items::variable::Variable),
     Function(ellie_core::definite::items::function::Function),
     Enum(ellie_core::definite::items::enum_type::EnumType),
+    Interface(ellie_core::definite::items::interface::Interface),
     NativeFunction(ellie_core::definite::items::native_function::NativeFunction),
     Getter(ellie_core::definite::items::getter::Getter),
     Setter(ellie_core::definite::items::setter::Setter),
@@ -1898,6 +2010,7 @@ impl ProcessedDeepSearchItems {
             ProcessedDeepSearchItems::Variable(e) => e.pos,
             ProcessedDeepSearchItems::Function(e) => e.pos,
             ProcessedDeepSearchItems::Enum(e) => e.pos,
+            ProcessedDeepSearchItems::Interface(e) => e.pos,
             ProcessedDeepSearchItems::NativeFunction(e) => e.pos,
             ProcessedDeepSearchItems::Getter(e) => e.pos,
             ProcessedDeepSearchItems::Setter(e) => e.pos,
@@ -2003,6 +2116,15 @@ pub fn deep_search_hash(
                                     found_type = ProcessedDeepSearchItems::Function(e);
                                 }
                             }
+                            Collecting::NativeFunction(e)
+                                if e.hash == target_hash
+                                    && (e.public || level == 0 || dep.deep_link.is_some()) =>
+                            {
+                                found_pos = Some(e.pos);
+                                found = true;
+                                found_page = FoundPage::fill_from_processed(page);
+                                found_type = ProcessedDeepSearchItems::NativeFunction(e);
+                            }
                             Collecting::Enum(e) => {
                                 if e.hash == target_hash
                                     && (e.public || level == 0 || dep.deep_link.is_some())
@@ -2040,6 +2162,18 @@ pub fn deep_search_hash(
                                     found_type = ProcessedDeepSearchItems::Class(e);
                                 }
                             }
+                            Collecting::Interface(e)
+                                if e.hash == target_hash
+                                    && (e.public
+                                        || level == 0
+                                        || dep.deep_link.is_some()
+                                        || matches!(inner_page, Some(ref parent_page_hash) if parent_page_hash == &page.hash)) =>
+                            {
+                                found_pos = Some(e.pos);
+                                found = true;
+                                found_page = FoundPage::fill_from_processed(page);
+                                found_type = ProcessedDeepSearchItems::Interface(e);
+                            }
                             Collecting::Generic(e) => {
                                 if e.hash == target_hash
                                     && (level == 0
@@ -2096,6 +2230,157 @@ pub fn deep_search_hash(
     }
 }
 
+/// Find methods added to a class by `extend` blocks visible from the target page
+/// ## Arguments
+/// * `parser` - [`Parser`]
+/// * `target_page` - Page to start searching from
+/// * `class_hash` - Hash of the extended class
+/// ## Returns
+/// [`Vec<(usize, Collecting)>`] - Inner page hash of the extend block and the method item
+pub fn find_extension_methods(
+    parser: &mut Parser,
+    target_page: usize,
+    class_hash: usize,
+) -> Vec<(usize, Collecting)> {
+    let mut methods = Vec::new();
+    let mut searched: Vec<usize> = Vec::new();
+    let mut dependencies = vec![target_page];
+
+    match parser.find_processed_page(target_page) {
+        Some(page) => dependencies.extend(
+            page.dependencies
+                .iter()
+                .filter(|x| x.processed)
+                .map(|x| x.hash),
+        ),
+        None => return methods,
+    }
+
+    let mut i = 0;
+    while i < dependencies.len() {
+        let dependency = dependencies[i];
+        i += 1;
+        if searched.contains(&dependency) {
+            continue;
+        }
+        searched.push(dependency);
+
+        let extend_pages = match parser.find_processed_page(dependency) {
+            Some(page) => {
+                dependencies.extend(page.dependencies.iter().filter_map(|x| {
+                    if x.public || x.deep_link.is_some() {
+                        Some(x.hash)
+                    } else {
+                        None
+                    }
+                }));
+                page.items
+                    .iter()
+                    .filter_map(|item| match item {
+                        Collecting::Extend(e) if e.target == class_hash => Some(e.inner_page_id),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>()
+            }
+            None => continue,
+        };
+
+        for extend_page in extend_pages {
+            if let Some(page) = parser.find_processed_page(extend_page) {
+                methods.extend(page.items.iter().filter_map(|item| match item {
+                    Collecting::Function(_) | Collecting::NativeFunction(_) => {
+                        Some((extend_page, item.clone()))
+                    }
+                    _ => None,
+                }));
+            }
+        }
+    }
+    methods
+}
+
+/// Find the members declared by an interface, properties are returned as variables and
+/// method signatures as native functions
+/// ## Arguments
+/// * `parser` - [`Parser`]
+/// * `interface_page` - Inner page of the interface
+pub fn find_interface_members(parser: &mut Parser, interface_page: usize) -> Vec<Collecting> {
+    match parser.find_processed_page(interface_page) {
+        Some(page) => page
+            .items
+            .iter()
+            .filter(|item| {
+                matches!(
+                    item,
+                    Collecting::Variable(_) | Collecting::NativeFunction(_)
+                )
+            })
+            .cloned()
+            .collect(),
+        None => Vec::new(),
+    }
+}
+
+/// Find the enum variant a reference points to, such as `Shape.Circle`
+/// ## Arguments
+/// * `parser` - [`Parser`]
+/// * `page_id` - Page hash of the reference
+/// * `reference` - Processed reference
+/// ## Returns
+/// [`Option<(EnumType, usize)>`] - Enum and the index of the variant
+pub fn find_enum_variant(
+    parser: &mut Parser,
+    page_id: usize,
+    reference: &ellie_core::definite::types::reference::ReferenceType,
+) -> Option<(ellie_core::definite::items::enum_type::EnumType, usize)> {
+    match reference.index_chain.as_slice() {
+        [attribute]
+            if matches!(
+                attribute.rtype,
+                AttributeType::EnumItemData | AttributeType::EnumItemNoData
+            ) =>
+        {
+            let search = deep_search_hash(parser, page_id, attribute.page_hash, vec![], 0);
+            match search.found_item {
+                ProcessedDeepSearchItems::Enum(enum_type) if search.found => {
+                    Some((enum_type, attribute.class_attribute_idx))
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Find the generic hashes a class member is defined with, methods added with `extend`
+/// are defined with the generics of their extend block instead of the class generics
+/// ## Arguments
+/// * `parser` - [`Parser`]
+/// * `member_page` - Page hash of the member
+/// * `class_generics` - Generic hashes of the class
+/// ## Returns
+/// [`Vec<usize>`] - Generic hashes in definition order
+pub fn member_generic_hashes(
+    parser: &mut Parser,
+    member_page: usize,
+    class_generics: Vec<usize>,
+) -> Vec<usize> {
+    match parser
+        .find_processed_page(member_page)
+        .map(|page| &page.page_type)
+    {
+        Some(PageType::ExtendBody(extend_page)) => match &extend_page.target {
+            DefinerCollecting::ParentGeneric(target) => target
+                .generics
+                .iter()
+                .filter_map(|generic| generic.value.as_generic().map(|x| x.hash))
+                .collect(),
+            _ => Vec::new(),
+        },
+        _ => class_generics,
+    }
+}
+
 pub fn deep_search(
     parser: &mut Parser,
     target_page: usize,
@@ -2290,6 +2575,18 @@ pub fn deep_search(
                                     found_type = ProcessedDeepSearchItems::Class(e.clone());
                                 }
                             }
+                            Collecting::Interface(e)
+                                if e.name == name
+                                    && (e.public
+                                        || level == 0
+                                        || dep.deep_link.is_some()
+                                        || matches!(inner_page, Some(ref parent_page_hash) if parent_page_hash == &page.hash)) =>
+                            {
+                                found_pos = Some(e.pos);
+                                found = true;
+                                found_page = FoundPage::fill_from_processed(page);
+                                found_type = ProcessedDeepSearchItems::Interface(e.clone());
+                            }
                             Collecting::ClassInstance(e) => {
                                 if name == "self" {
                                     found_pos = None;
@@ -2317,7 +2614,10 @@ pub fn deep_search(
                     }
                 }
                 None => {
-                    panic!("Broken Page structure; Failed to find page {}", dep.hash);
+                    //Pages of items defined after the searching one are not processed yet
+                    if parser.find_page(dep.hash).is_none() {
+                        panic!("Broken Page structure; Failed to find page {}", dep.hash);
+                    }
                 }
             }
 
@@ -2395,8 +2695,9 @@ pub fn resolve_type(
     );
 
     match deep_type {
-        DeepTypeResult::Integer(_) => {
-            let int_type = find_type("int".to_string(), target_page, parser);
+        DeepTypeResult::Integer(integer) => {
+            let type_name = integer.size.type_name();
+            let int_type = find_type(type_name.to_string(), target_page, parser);
 
             match int_type {
                 Some(e) => Some(definers::DefinerCollecting::Generic(e)),
@@ -2405,7 +2706,7 @@ pub fn resolve_type(
                         errors.push(error::error_list::ERROR_S38.clone().build_with_path(
                             vec![error::ErrorBuildField {
                                 key: "token".to_owned(),
-                                value: "int".to_string(),
+                                value: type_name.to_string(),
                             }],
                             alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
                             parser.find_page(target_page).unwrap().path.clone(),
@@ -2418,6 +2719,28 @@ pub fn resolve_type(
                 }
             }
         }
+        DeepTypeResult::BigInteger(_) => {
+            let big_integer_type = find_type("bigint".to_string(), target_page, parser);
+            match big_integer_type {
+                Some(e) => Some(definers::DefinerCollecting::Generic(e)),
+                None => {
+                    if let Some(pos) = pos {
+                        errors.push(error::error_list::ERROR_S38.clone().build_with_path(
+                            vec![error::ErrorBuildField {
+                                key: "token".to_owned(),
+                                value: "bigint".to_string(),
+                            }],
+                            alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
+                            parser.find_page(target_page).unwrap().path.clone(),
+                            pos,
+                        ));
+                        None
+                    } else {
+                        panic!("Unhandled behaviour, failed to find bigint type");
+                    }
+                }
+            }
+        }
         DeepTypeResult::Byte(_) => {
             let byte_type = find_type("byte".to_string(), target_page, parser);
             match byte_type {
@@ -2889,28 +3212,14 @@ pub fn resolve_type(
                 }
             }
         }
-        DeepTypeResult::Null => {
-            let null_type = find_type("Null".to_string(), target_page, parser);
-            match null_type {
-                Some(e) => Some(definers::DefinerCollecting::Generic(e)),
-                None => {
-                    if let Some(pos) = pos {
-                        errors.push(error::error_list::ERROR_S38.clone().build_with_path(
-                            vec![error::ErrorBuildField {
-                                key: "token".to_owned(),
-                                value: "null".to_string(),
-                            }],
-                            alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
-                            parser.find_page(target_page).unwrap().path.clone(),
-                            pos,
-                        ));
-                        None
-                    } else {
-                        panic!("Unhandled behaviour, failed to find null type");
-                    }
-                }
-            }
-        }
+        //Null has no type definition, it's only comparable with and assignable to nullables
+        DeepTypeResult::Null => Some(definers::DefinerCollecting::Generic(
+            definers::GenericType {
+                rtype: "null".to_string(),
+                pos: pos.unwrap_or_default(),
+                hash: 0,
+            },
+        )),
         DeepTypeResult::NotFound => None,
         DeepTypeResult::BraceReference(e) => {
             let nullable_type = find_type("nullAble".to_string(), target_page, parser);
@@ -2952,6 +3261,39 @@ pub fn resolve_type(
                             DefinerCollecting::ParentGeneric(e) => e.generics[0].value.clone(),
                             _ => unreachable!(),
                         },
+                        Types::FunctionCall(_) => {
+                            let returning_type = resolve_type(
+                                *e.reference,
+                                target_page,
+                                parser,
+                                errors,
+                                Some(e.reference_pos),
+                            )?;
+                            match returning_type {
+                                DefinerCollecting::ParentGeneric(e) if e.rtype == "array" => {
+                                    e.generics[0].value.clone()
+                                }
+                                returning_type => {
+                                    errors.push(
+                                        error::error_list::ERROR_S48.clone().build_with_path(
+                                            vec![error::ErrorBuildField {
+                                                key: "token".to_owned(),
+                                                value: returning_type.to_string(),
+                                            }],
+                                            alloc::format!(
+                                                "{}:{}:{}",
+                                                file!().to_owned(),
+                                                line!(),
+                                                column!()
+                                            ),
+                                            parser.find_page(target_page).unwrap().path.clone(),
+                                            e.reference_pos,
+                                        ),
+                                    );
+                                    return None;
+                                }
+                            }
+                        }
                         e => {
                             unimplemented!("Custom index queries are not yet supported for {:?}", e)
                         }
@@ -3023,35 +3365,17 @@ pub fn resolve_type(
                 returning: Box::new(e.return_type),
             },
         )),
-        DeepTypeResult::EnumData(e) => {
-            let (enum_hash, enum_name) = match *e.reference {
-                ellie_core::definite::types::Types::VariableType(e) => (e.reference, e.value),
-                _ => unreachable!("Parser should have prevented this"),
-            };
-
-            Some(definers::DefinerCollecting::EnumField(
-                definers::EnumField {
-                    field_name: e.field_name,
-                    field_data: match e.value {
-                        ellie_core::definite::types::enum_data::Pointer::NoData => {
-                            definers::EnumFieldData::NoData
-                        }
-                        ellie_core::definite::types::enum_data::Pointer::Data(pointer_data) => {
-                            match resolve_type(*pointer_data, target_page, parser, errors, pos) {
-                                Some(data_type) => {
-                                    definers::EnumFieldData::Data(Box::new(data_type))
-                                }
-                                None => {
-                                    return None;
-                                }
-                            }
-                        }
-                    },
-                    name: enum_name,
-                    hash: enum_hash,
-                },
-            ))
-        }
+        //Enum values are typed as their enum regardless of the variant
+        DeepTypeResult::EnumData(e) => match *e.reference {
+            ellie_core::definite::types::Types::VariableType(e) => Some(
+                definers::DefinerCollecting::Generic(definers::GenericType {
+                    rtype: e.value,
+                    pos: defs::Cursor::default(),
+                    hash: e.reference,
+                }),
+            ),
+            _ => unreachable!("Parser should have prevented this"),
+        },
         DeepTypeResult::Enum(e) => Some(definers::DefinerCollecting::Generic(
             definers::GenericType {
                 rtype: e.name,
@@ -0,0 +1,526 @@
+use crate::parser::Parser;
+use crate::utils::ProcessedPage;
+use alloc::{
+    borrow::ToOwned,
+    collections::{BTreeMap, BTreeSet},
+    string::String,
+    vec,
+    vec::Vec,
+};
+use ellie_core::{
+    definite::{
+        definers::DefinerCollecting,
+        items::{condition::ConditionType, match_type::MatchPattern, Collecting},
+        types::{
+            enum_data::Pointer,
+            operator::{AssignmentOperators, ComparisonOperators, LogicalOperators, Operators},
+            Types,
+        },
+    },
+    error::{self, ErrorBuildField},
+    warning::{self, WarningBuildField},
+};
+use ellie_tokenizer::tokenizer::PageType;
+
+/*
+    Flow analysis walks function bodies in order and follows conditions and loops to track
+    whether a variable is assigned and whether a nullable value is known to be null or not.
+    Conditions like `x != null` narrow the variable in the branch they guard, `ret` in a
+    branch narrows the code after the condition. Reads before assignment are reported as
+    errors, null resolvers (`x!`) on already checked values are reported as warnings.
+*/
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum FlowKey {
+    Variable(usize),
+    /// Parameters are keyed with their declaration position, they don't have a hash on reads
+    Parameter((usize, usize)),
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum NullState {
+    Null,
+    NonNull,
+    Unknown,
+}
+
+#[derive(Clone)]
+struct VariableState {
+    name: String,
+    assigned: bool,
+    nullable: bool,
+    null_state: NullState,
+}
+
+type FlowState = BTreeMap<FlowKey, VariableState>;
+
+struct FlowAnalyzer<'a> {
+    pages: BTreeMap<usize, &'a ProcessedPage>,
+    /// Loop bodies are walked twice, diagnostics are only collected when this is true
+    report: bool,
+    errors: Vec<error::Error>,
+    warnings: Vec<warning::Warning>,
+}
+
+fn is_nullable(rtype: &DefinerCollecting) -> bool {
+    matches!(rtype, DefinerCollecting::ParentGeneric(e) if e.rtype == "nullAble")
+        || matches!(rtype, DefinerCollecting::Nullable(_))
+}
+
+fn flow_key(rtype: &Types) -> Option<FlowKey> {
+    match rtype {
+        Types::VariableType(e) => Some(FlowKey::Variable(e.reference)),
+        Types::FunctionParameter(e) => Some(FlowKey::Parameter((
+            e.name_pos.range_start.0,
+            e.name_pos.range_start.1,
+        ))),
+        Types::Cloak(e) if e.collective.len() == 1 => flow_key(&e.collective[0].value),
+        _ => None,
+    }
+}
+
+/// Merge states of two paths that meet, only variables of `base` survives the merge
+fn merge(base: &FlowState, other: &FlowState) -> FlowState {
+    let mut merged = base.clone();
+    for (key, state) in merged.iter_mut() {
+        if let Some(other) = other.get(key) {
+            state.assigned = state.assigned && other.assigned;
+            if state.null_state != other.null_state {
+                state.null_state = NullState::Unknown;
+            }
+        }
+    }
+    merged
+}
+
+fn restrict(base: &FlowState, state: FlowState) -> FlowState {
+    state
+        .into_iter()
+        .filter(|(key, _)| base.contains_key(key))
+        .collect()
+}
+
+impl FlowAnalyzer<'_> {
+    fn null_state_of(&self, value: &Types, state: &FlowState) -> NullState {
+        match value {
+            Types::Null => NullState::Null,
+            Types::NullResolver(_) => NullState::NonNull,
+            Types::ClassCall(e) => match &*e.target {
+                // Variables without value are initialized with an empty nullAble
+                Types::VariableType(target) if target.value == "nullAble" => NullState::Null,
+                _ => NullState::NonNull,
+            },
+            Types::Byte(_)
+            | Types::Integer(_)
+            | Types::BigInteger(_)
+            | Types::Decimal(_)
+            | Types::Bool(_)
+            | Types::String(_)
+            | Types::Char(_)
+            | Types::Array(_)
+            | Types::Collective(_)
+            | Types::Operator(_)
+            | Types::Negative(_)
+            | Types::Function(_) => NullState::NonNull,
+            Types::Cloak(e) if e.collective.len() == 1 => {
+                self.null_state_of(&e.collective[0].value, state)
+            }
+            e => match flow_key(e).and_then(|key| state.get(&key)) {
+                Some(variable) => variable.null_state,
+                None => NullState::Unknown,
+            },
+        }
+    }
+
+    /// Apply what is known when `condition` evaluates to `truth`
+    fn narrow(&self, condition: &Types, state: &mut FlowState, truth: bool) {
+        match condition {
+            Types::Operator(e) => match &e.operator {
+                Operators::ComparisonType(operator)
+                    if *operator == ComparisonOperators::Equal
+                        || *operator == ComparisonOperators::NotEqual =>
+                {
+                    let key = match (&*e.first, &*e.second) {
+                        (Types::Null, other) | (other, Types::Null) => flow_key(other),
+                        _ => None,
+                    };
+                    if let Some(variable) = key.and_then(|key| state.get_mut(&key)) {
+                        let non_null = (*operator == ComparisonOperators::NotEqual) == truth;
+                        variable.null_state = if non_null {
+                            NullState::NonNull
+                        } else {
+                            NullState::Null
+                        };
+                    }
+                }
+                Operators::LogicalType(LogicalOperators::And) if truth => {
+                    self.narrow(&e.first, state, true);
+                    self.narrow(&e.second, state, true);
+                }
+                Operators::LogicalType(LogicalOperators::Or) if !truth => {
+                    self.narrow(&e.first, state, false);
+                    self.narrow(&e.second, state, false);
+                }
+                _ => (),
+            },
+            Types::Cloak(e) if e.collective.len() == 1 => {
+                self.narrow(&e.collective[0].value, state, truth)
+            }
+            _ => (),
+        }
+    }
+
+    fn check_reads(&mut self, value: &Types, state: &FlowState, path: &String) {
+        match value {
+            Types::VariableType(e) => {
+                let key = FlowKey::Variable(e.reference);
+                if let Some(variable) = state.get(&key) {
+                    if !variable.assigned && self.report {
+                        self.errors
+                            .push(error::error_list::ERROR_S67.clone().build_with_path(
+                                vec![ErrorBuildField::new("token", &variable.name)],
+                                alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
+                                path.clone(),
+                                e.pos,
+                            ));
+                    }
+                }
+            }
+            Types::NullResolver(e) => {
+                self.check_reads(&e.target, state, path);
+                if let Some(variable) = flow_key(&e.target).and_then(|key| state.get(&key)) {
+                    if variable.nullable && self.report {
+                        let found_warning = match variable.null_state {
+                            NullState::NonNull => Some(warning::warning_list::WARNING_S13.clone()),
+                            NullState::Null if variable.assigned => {
+                                Some(warning::warning_list::WARNING_S14.clone())
+                            }
+                            _ => None,
+                        };
+                        if let Some(found_warning) = found_warning {
+                            self.warnings.push(found_warning.build(
+                                vec![WarningBuildField {
+                                    key: "name".to_owned(),
+                                    value: variable.name.clone(),
+                                }],
+                                path.clone(),
+                                e.pos,
+                            ));
+                        }
+                    }
+                }
+            }
+            Types::Collective(e) => {
+                for entry in &e.entries {
+                    self.check_reads(&entry.value, state, path);
+                }
+            }
+            Types::Reference(e) => self.check_reads(&e.reference, state, path),
+            Types::BraceReference(e) => {
+                self.check_reads(&e.reference, state, path);
+                self.check_reads(&e.value, state, path);
+            }
+            Types::EnumData(e) => {
+                self.check_reads(&e.reference, state, path);
+                if let Pointer::Data(data) = &e.value {
+                    self.check_reads(data, state, path);
+                }
+            }
+            Types::Operator(e) => {
+                self.check_reads(&e.first, state, path);
+                self.check_reads(&e.second, state, path);
+            }
+            Types::Cloak(e) => {
+                for entry in &e.collective {
+                    self.check_reads(&entry.value, state, path);
+                }
+            }
+            Types::Array(e) => {
+                for entry in &e.collective {
+                    self.check_reads(&entry.value, state, path);
+                }
+            }
+            Types::ClassCall(e) => {
+                for parameter in &e.params {
+                    self.check_reads(&parameter.value, state, path);
+                }
+            }
+            Types::FunctionCall(e) => {
+                self.check_reads(&e.target, state, path);
+                for parameter in &e.params {
+                    self.check_reads(&parameter.value, state, path);
+                }
+            }
+            Types::Negative(e) => self.check_reads(&e.value, state, path),
+            Types::AsKeyword(e) => self.check_reads(&e.target, state, path),
+            _ => (),
+        }
+    }
+
+    /// Walk a body page and return the state at the end of it. Second value is true if
+    /// every path of the page leaves the page early with `ret`, `brk` or `go`
+    fn walk_page(&mut self, page_hash: usize, mut state: FlowState) -> (FlowState, bool) {
+        let page = match self.pages.get(&page_hash) {
+            Some(page) => *page,
+            None => return (state, false),
+        };
+        let path = &page.path;
+
+        for item in &page.items {
+            match item {
+                Collecting::Variable(e) => {
+                    if e.has_value {
+                        self.check_reads(&e.value, &state, path);
+                    }
+                    // Nullable variables declared without a value start as null
+                    let nullable = is_nullable(&e.rtype);
+                    let null_state = self.null_state_of(&e.value, &state);
+                    state
+                        .entry(FlowKey::Variable(e.hash))
+                        .or_insert(VariableState {
+                            name: e.name.clone(),
+                            // Arrays declared without a value start empty
+                            assigned: e.has_value || nullable || matches!(e.value, Types::Array(_)),
+                            nullable: nullable || null_state == NullState::Null,
+                            null_state,
+                        });
+                }
+                Collecting::FunctionParameter(e) => {
+                    state.insert(
+                        FlowKey::Parameter((e.name_pos.range_start.0, e.name_pos.range_start.1)),
+                        VariableState {
+                            name: e.name.clone(),
+                            assigned: true,
+                            nullable: is_nullable(&e.rtype),
+                            null_state: NullState::Unknown,
+                        },
+                    );
+                }
+                Collecting::SetterCall(e) => {
+                    self.check_reads(&e.value, &state, path);
+                    match flow_key(&e.target) {
+                        Some(key) if e.operator == AssignmentOperators::Assignment => {
+                            let null_state = self.null_state_of(&e.value, &state);
+                            if let Some(variable) = state.get_mut(&key) {
+                                variable.assigned = true;
+                                variable.null_state = null_state;
+                            }
+                        }
+                        _ => self.check_reads(&e.target, &state, path),
+                    }
+                }
+                Collecting::GetterCall(e) => self.check_reads(&e.data, &state, path),
+                Collecting::Ret(e) => {
+                    self.check_reads(&e.value, &state, path);
+                    return (state, true);
+                }
+                Collecting::Brk(_) | Collecting::Go(_) => return (state, true),
+                Collecting::Condition(e) => {
+                    let mut exits = Vec::new();
+                    let mut falling = state.clone();
+                    let mut has_else = false;
+                    for chain in &e.chains {
+                        let mut branch = falling.clone();
+                        if chain.rtype == ConditionType::Else {
+                            has_else = true;
+                        } else {
+                            self.check_reads(&chain.condition, &falling, path);
+                            self.narrow(&chain.condition, &mut branch, true);
+                            self.narrow(&chain.condition, &mut falling, false);
+                        }
+                        let (exit, diverges) = self.walk_page(chain.inner_page_id, branch);
+                        if !diverges {
+                            exits.push(restrict(&state, exit));
+                        }
+                    }
+                    if !has_else {
+                        exits.push(falling);
+                    }
+                    match exits.split_first() {
+                        Some((first, rest)) => {
+                            state = rest.iter().fold(first.clone(), |acc, x| merge(&acc, x));
+                        }
+                        None => return (state, true),
+                    }
+                }
+                Collecting::Match(e) => {
+                    // Matches are exhaustive, state after the match is merged from its arms
+                    self.check_reads(&e.target, &state, path);
+                    let mut exits = Vec::new();
+                    for arm in &e.arms {
+                        let mut branch = state.clone();
+                        if let Some(variable) = flow_key(&e.target).and_then(|x| branch.get_mut(&x))
+                        {
+                            match arm.pattern {
+                                MatchPattern::Null => variable.null_state = NullState::Null,
+                                MatchPattern::Wildcard => (),
+                                _ => variable.null_state = NullState::NonNull,
+                            }
+                        }
+                        // Bound variable is the first item of the arm, it's assigned by the match
+                        if matches!(arm.pattern, MatchPattern::EnumVariant { binds: true, .. }) {
+                            if let Some(Collecting::Variable(variable)) = self
+                                .pages
+                                .get(&arm.inner_page_id)
+                                .and_then(|page| page.items.first())
+                            {
+                                branch.insert(
+                                    FlowKey::Variable(variable.hash),
+                                    VariableState {
+                                        name: variable.name.clone(),
+                                        assigned: true,
+                                        nullable: is_nullable(&variable.rtype),
+                                        null_state: NullState::Unknown,
+                                    },
+                                );
+                            }
+                        }
+                        let (exit, diverges) = self.walk_page(arm.inner_page_id, branch);
+                        if !diverges {
+                            exits.push(restrict(&state, exit));
+                        }
+                    }
+                    match exits.split_first() {
+                        Some((first, rest)) => {
+                            state = rest.iter().fold(first.clone(), |acc, x| merge(&acc, x));
+                        }
+                        None => return (state, true),
+                    }
+                }
+                Collecting::Loop(e) => {
+                    self.check_reads(&e.condition, &state, path);
+                    state = self.walk_loop(e.inner_page_id, state, Some(&e.condition), None);
+                }
+                Collecting::ForLoop(e) => {
+                    self.check_reads(&e.iterator, &state, path);
+                    // First variable of the loop body is the loop variable which is assigned
+                    // on every iteration
+                    let loop_variable = self.pages.get(&e.inner_page_id).and_then(|page| {
+                        page.items.iter().find_map(|item| match item {
+                            Collecting::Variable(e) => Some((e.hash, e.name.clone())),
+                            _ => None,
+                        })
+                    });
+                    state = self.walk_loop(e.inner_page_id, state, None, loop_variable);
+                }
+                Collecting::Function(e) if self.report => self.analyze_body(e.inner_page_id),
+                Collecting::Class(e) if self.report => self.walk_declarations(e.inner_page_id),
+                _ => (),
+            }
+        }
+        (state, false)
+    }
+
+    fn walk_loop(
+        &mut self,
+        inner_page_id: usize,
+        state: FlowState,
+        condition: Option<&Types>,
+        loop_variable: Option<(usize, String)>,
+    ) -> FlowState {
+        let enter = |analyzer: &Self, mut entry: FlowState| {
+            if let Some(condition) = condition {
+                analyzer.narrow(condition, &mut entry, true);
+            }
+            if let Some((hash, name)) = &loop_variable {
+                entry.insert(
+                    FlowKey::Variable(*hash),
+                    VariableState {
+                        name: name.clone(),
+                        assigned: true,
+                        nullable: false,
+                        null_state: NullState::NonNull,
+                    },
+                );
+            }
+            entry
+        };
+
+        // Walk once to find out what body changes, then walk again with the state of a
+        // later iteration to collect diagnostics
+        let report = self.report;
+        self.report = false;
+        let (first_exit, _) = self.walk_page(inner_page_id, enter(self, state.clone()));
+        self.report = report;
+        let iteration = merge(&state, &restrict(&state, first_exit));
+        let (exit, _) = self.walk_page(inner_page_id, enter(self, iteration.clone()));
+
+        let mut after = merge(&iteration, &restrict(&state, exit));
+        if let Some(condition) = condition {
+            self.narrow(condition, &mut after, false);
+        }
+        after
+    }
+
+    /// Analyze a function, constructor, getter or setter body from a clean state
+    fn analyze_body(&mut self, page_hash: usize) {
+        self.walk_page(page_hash, FlowState::new());
+    }
+
+    /// Walk a top level or class page and analyze bodies declared in it
+    fn walk_declarations(&mut self, page_hash: usize) {
+        let page = match self.pages.get(&page_hash) {
+            Some(page) => *page,
+            None => return,
+        };
+        for item in &page.items {
+            match item {
+                Collecting::Function(e) => self.analyze_body(e.inner_page_id),
+                Collecting::Constructor(e) => self.analyze_body(e.inner_page_id),
+                Collecting::Getter(e) => self.analyze_body(e.inner_page_id),
+                Collecting::Setter(e) => self.analyze_body(e.inner_page_id),
+                Collecting::Class(e) => self.walk_declarations(e.inner_page_id),
+                _ => (),
+            }
+        }
+    }
+}
+
+/// Run definite-assignment and nullability analysis on bodies of the module. Reads of
+/// unassigned variables are pushed as errors, unnecessary null resolvers as warnings.
+/// ## Parameters
+/// * `parser` - [`Parser`] which finished processing its pages
+pub fn analyze_flow(parser: &mut Parser) {
+    let module_pages: BTreeSet<usize> = parser
+        .modules
+        .iter()
+        .flat_map(|module| module.pages.iter().map(|page| page.hash))
+        .chain(
+            parser
+                .pages
+                .iter()
+                .filter(|page| page.module)
+                .map(|page| page.hash),
+        )
+        .collect();
+
+    let mut analyzer = FlowAnalyzer {
+        pages: parser
+            .processed_pages
+            .iter()
+            .map(|page| (page.hash, page))
+            .collect(),
+        report: true,
+        errors: Vec::new(),
+        warnings: Vec::new(),
+    };
+
+    let top_pages: Vec<usize> = analyzer
+        .pages
+        .values()
+        .filter(|page| {
+            page.inner.is_none()
+                && !module_pages.contains(&page.hash)
+                && !matches!(page.page_type, PageType::ClassBody(_))
+        })
+        .map(|page| page.hash)
+        .collect();
+
+    for page_hash in top_pages {
+        analyzer.walk_declarations(page_hash);
+    }
+
+    let (errors, warnings) = (analyzer.errors, analyzer.warnings);
+    parser.informations.extend(&errors);
+    parser.informations.extend(&warnings);
+}
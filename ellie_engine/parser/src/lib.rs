@@ -15,6 +15,8 @@ extern crate alloc;
 extern crate std;
 
 pub mod deep_search_extensions;
+pub mod flow_analysis;
 pub mod parser;
 pub mod processors;
+pub mod usage_analysis;
 pub mod utils;
@@ -59,6 +59,8 @@ pub enum DeepSearchItems {
         ellie_tokenizer::syntax::items::constructor_parameter::ConstructorParameter,
     ),
     SelfItem(ellie_core::definite::items::self_item::SelfItem),
+    TypeDef(ellie_core::definite::items::type_def::TypeDef),
+    Interface(ellie_tokenizer::syntax::items::interface::Interface),
     BrokenPageGraph,
     MixUp(Vec<(String, String)>),
     None,
@@ -76,6 +78,8 @@ impl DeepSearchItems {
             DeepSearchItems::Enum(e) => e.pos,
             DeepSearchItems::GenericItem(e) => e.pos,
             DeepSearchItems::ConstructorParameter(e) => e.pos,
+            DeepSearchItems::TypeDef(e) => e.pos,
+            DeepSearchItems::Interface(e) => e.pos,
             _ => defs::Cursor::default(),
         }
     }
@@ -135,6 +139,8 @@ impl Parser {
         let imported_dependencies: Vec<Dependency> = module
             .pages
             .iter()
+            //Inner pages hold parameters and locals of module functions, they are not in scope
+            .filter(|x| x.inner.is_none())
             .map(|x| ellie_tokenizer::tokenizer::Dependency {
                 hash: x.hash,
                 processed: true,
@@ -160,6 +166,236 @@ impl Parser {
         self.processed_pages.extend_pages(module.pages.pages);
     }
 
+    /// Find the interface declared with given hash
+    pub fn find_interface(
+        &self,
+        hash: usize,
+    ) -> Option<ellie_core::definite::items::interface::Interface> {
+        self.processed_pages
+            .iter()
+            .flat_map(|page| page.items.iter())
+            .find_map(|item| match item {
+                Collecting::Interface(interface) if interface.hash == hash => {
+                    Some(interface.clone())
+                }
+                _ => None,
+            })
+    }
+
+    /// Check whether the type defined by `found` satisfies the interface with given hash,
+    /// either by being the interface itself, a class implementing it or a generic bound to it
+    pub fn implements_interface(&self, found: &DefinerCollecting, interface_hash: usize) -> bool {
+        let hash = match found {
+            DefinerCollecting::Generic(e) => e.hash,
+            DefinerCollecting::ParentGeneric(e) => e.hash,
+            _ => return false,
+        };
+        hash == interface_hash
+            || self
+                .processed_pages
+                .iter()
+                .flat_map(|page| page.items.iter())
+                .any(|item| match item {
+                    Collecting::Class(class) => {
+                        class.hash == hash
+                            && class.implements.iter().any(|x| x.hash == interface_hash)
+                    }
+                    Collecting::Generic(generic) => {
+                        generic.hash == hash
+                            && matches!(&generic.bound, Some(bound) if bound.hash == interface_hash)
+                    }
+                    _ => false,
+                })
+    }
+
+    /// Check whether `found` can be used where `defining` is expected because `defining`
+    /// is an interface that `found` satisfies
+    pub fn satisfies_interface(
+        &self,
+        defining: &DefinerCollecting,
+        found: &DefinerCollecting,
+    ) -> bool {
+        match defining {
+            DefinerCollecting::Generic(e) => {
+                self.find_interface(e.hash).is_some() && self.implements_interface(found, e.hash)
+            }
+            _ => false,
+        }
+    }
+
+    /// Check generics given to a class against the interface bounds of its generic definings
+    /// ## Arguments
+    /// * `page_id` - Page the generics are used in
+    /// * `class` - Class the generics given to
+    /// * `generics` - Resolved generics with their positions
+    /// ## Returns
+    /// [`Vec<error::Error>`] - S73 errors for every generic that does not satisfy its bound
+    pub fn check_generic_bounds(
+        &mut self,
+        page_id: usize,
+        class: &ellie_tokenizer::syntax::items::class::Class,
+        generics: &[(DefinerCollecting, Cursor)],
+    ) -> Vec<error::Error> {
+        let mut errors = Vec::new();
+        for (defining, (generic, pos)) in class.generic_definings.iter().zip(generics.iter()) {
+            if defining.bound.is_empty() {
+                continue;
+            }
+            let bound = self.deep_search(page_id, defining.bound.clone(), None, vec![], 0, None);
+            if let DeepSearchItems::Interface(interface) = bound.found_item {
+                if !self.implements_interface(generic, interface.hash) {
+                    let path = self.find_page(page_id).unwrap().path.clone();
+                    let mut err = error::error_list::ERROR_S73.clone().build_with_path(
+                        vec![
+                            error::ErrorBuildField::new("token", &generic.to_string()),
+                            error::ErrorBuildField::new("token1", &interface.name),
+                        ],
+                        alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
+                        path,
+                        *pos,
+                    );
+                    err.reference_block = Some((interface.name_pos, bound.found_page.path));
+                    err.reference_message = "Defined here".to_owned();
+                    errors.push(err);
+                }
+            }
+        }
+        errors
+    }
+
+    /// Ensure the class owning `class_body` provides every member required by the
+    /// interfaces it implements
+    fn check_interface_conformance(
+        &mut self,
+        class_page: ellie_tokenizer::tokenizer::ClassPageType,
+        class_body: usize,
+    ) {
+        let class = match self
+            .find_processed_page(class_page.page_hash)
+            .and_then(|page| {
+                page.items.iter().find_map(|item| match item {
+                    Collecting::Class(class) if class.hash == class_page.hash => {
+                        Some(class.clone())
+                    }
+                    _ => None,
+                })
+            }) {
+            Some(class) => class,
+            None => return,
+        };
+        let path = self.find_page(class_body).unwrap().path.clone();
+
+        // Methods get an implicit `self` parameter, interface signatures don't declare it
+        fn signature(
+            parameters: &[ellie_core::definite::items::function::FunctionParameter],
+            return_type: &DefinerCollecting,
+        ) -> DefinerCollecting {
+            DefinerCollecting::Function(ellie_core::definite::definers::FunctionType {
+                params: parameters
+                    .iter()
+                    .filter(|param| !matches!(&param.rtype, DefinerCollecting::Generic(generic) if generic.rtype == "self"))
+                    .map(|param| param.rtype.clone())
+                    .collect(),
+                returning: alloc::boxed::Box::new(return_type.clone()),
+            })
+        }
+
+        for implement in &class.implements {
+            let interface = match self.find_interface(implement.hash) {
+                Some(interface) => interface,
+                None => continue,
+            };
+            if self.find_processed_page(interface.inner_page_id).is_none() {
+                self.process_page(interface.inner_page_id);
+            }
+            let interface_path = self
+                .find_page(interface.inner_page_id)
+                .unwrap()
+                .path
+                .clone();
+
+            let class_members = self.find_processed_page(class_body).unwrap().items.clone();
+
+            for member in
+                crate::deep_search_extensions::find_interface_members(self, interface.inner_page_id)
+            {
+                let (member_name, member_pos, expected) = match &member {
+                    Collecting::Variable(e) => (e.name.clone(), e.pos, e.rtype.clone()),
+                    Collecting::NativeFunction(e) => (
+                        e.name.clone(),
+                        e.pos,
+                        signature(&e.parameters, &e.return_type),
+                    ),
+                    _ => continue,
+                };
+
+                let found = class_members.iter().find_map(|item| match (item, &member) {
+                    (Collecting::Variable(e), Collecting::Variable(_)) if e.name == member_name => {
+                        let rtype = if e.has_type {
+                            Some(e.rtype.clone())
+                        } else {
+                            resolve_type(
+                                e.value.clone(),
+                                class_body,
+                                self,
+                                &mut Vec::new(),
+                                Some(e.value_pos),
+                            )
+                        };
+                        rtype.map(|rtype| (e.name_pos, rtype))
+                    }
+                    (Collecting::Function(e), Collecting::NativeFunction(_))
+                        if e.name == member_name =>
+                    {
+                        Some((e.name_pos, signature(&e.parameters, &e.return_type)))
+                    }
+                    (Collecting::NativeFunction(e), Collecting::NativeFunction(_))
+                        if e.name == member_name =>
+                    {
+                        Some((e.name_pos, signature(&e.parameters, &e.return_type)))
+                    }
+                    _ => None,
+                });
+
+                match found {
+                    Some((found_pos, found_type)) => {
+                        if !found_type.same_as(expected.clone()) {
+                            let mut err = error::error_list::ERROR_S72.clone().build_with_path(
+                                vec![
+                                    error::ErrorBuildField::new("token", &member_name),
+                                    error::ErrorBuildField::new("token1", &interface.name),
+                                    error::ErrorBuildField::new("token2", &expected.to_string()),
+                                    error::ErrorBuildField::new("token3", &found_type.to_string()),
+                                ],
+                                alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
+                                path.clone(),
+                                found_pos,
+                            );
+                            err.reference_block = Some((member_pos, interface_path.clone()));
+                            err.reference_message = "Required here".to_owned();
+                            self.informations.push(&err);
+                        }
+                    }
+                    None => {
+                        let mut err = error::error_list::ERROR_S71.clone().build_with_path(
+                            vec![
+                                error::ErrorBuildField::new("token", &class.name),
+                                error::ErrorBuildField::new("token1", &member_name),
+                                error::ErrorBuildField::new("token2", &interface.name),
+                            ],
+                            alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
+                            path.clone(),
+                            implement.pos,
+                        );
+                        err.reference_block = Some((member_pos, interface_path.clone()));
+                        err.reference_message = "Required here".to_owned();
+                        self.informations.push(&err);
+                    }
+                }
+            }
+        }
+    }
+
     pub fn compare_defining_with_type(
         &mut self,
         defining: ellie_core::definite::definers::DefinerCollecting,
@@ -180,14 +416,15 @@ impl Parser {
         }
 
         match found_type {
-            DeepTypeResult::Integer(_) => {
+            DeepTypeResult::Integer(integer) => {
+                let type_name = integer.size.type_name();
                 if let ellie_core::definite::definers::DefinerCollecting::Generic(_) = defining {
-                    if defining.to_string() == "int" {
+                    if defining.to_string() == type_name {
                         if errors.is_empty() {
                             Ok(CompareResult::result(
                                 true,
                                 defining.to_string(),
-                                "int".to_owned(),
+                                type_name.to_owned(),
                             ))
                         } else {
                             Err(errors)
@@ -196,7 +433,7 @@ impl Parser {
                         Ok(CompareResult::result(
                             false,
                             defining.to_string(),
-                            "int".to_owned(),
+                            type_name.to_owned(),
                         ))
                     } else {
                         Err(errors)
@@ -205,7 +442,38 @@ impl Parser {
                     Ok(CompareResult::result(
                         false,
                         defining.to_string(),
-                        "int".to_owned(),
+                        type_name.to_owned(),
+                    ))
+                } else {
+                    Err(errors)
+                }
+            }
+            DeepTypeResult::BigInteger(_) => {
+                if let ellie_core::definite::definers::DefinerCollecting::Generic(_) = defining {
+                    if defining.to_string() == "bigint" {
+                        if errors.is_empty() {
+                            Ok(CompareResult::result(
+                                true,
+                                defining.to_string(),
+                                "bigint".to_owned(),
+                            ))
+                        } else {
+                            Err(errors)
+                        }
+                    } else if errors.is_empty() {
+                        Ok(CompareResult::result(
+                            false,
+                            defining.to_string(),
+                            "bigint".to_owned(),
+                        ))
+                    } else {
+                        Err(errors)
+                    }
+                } else if errors.is_empty() {
+                    Ok(CompareResult::result(
+                        false,
+                        defining.to_string(),
+                        "bigint".to_owned(),
                     ))
                 } else {
                     Err(errors)
@@ -443,7 +711,9 @@ impl Parser {
                     None => return Err(errors),
                 };
                 if errors.is_empty() {
-                    if class_call_type.same_as(defining.clone()) {
+                    if class_call_type.same_as(defining.clone())
+                        || self.satisfies_interface(&defining, &class_call_type)
+                    {
                         Ok(CompareResult::result(
                             true,
                             defining.to_string(),
@@ -465,10 +735,11 @@ impl Parser {
                     resolve_deep_type(self, target_page, *e.target.clone(), &mut errors);
 
                 match resolved_target {
-                    DeepTypeResult::Function(e) => Ok(CompareResult::result(
-                        defining.same_as(e.return_type.clone()),
+                    //Call's returning has its generic parameters inferred
+                    DeepTypeResult::Function(_) => Ok(CompareResult::result(
+                        defining.same_as(e.returning.clone()),
                         defining.to_string(),
-                        e.return_type.to_string(),
+                        e.returning.to_string(),
                     )),
                     DeepTypeResult::FunctionCall(e) => Ok(CompareResult::result(
                         defining.same_as(e.returning.clone()),
@@ -480,6 +751,9 @@ impl Parser {
                             DeepTypeResult::Integer(e) => {
                                 ellie_core::definite::types::Types::Integer(e)
                             }
+                            DeepTypeResult::BigInteger(e) => {
+                                ellie_core::definite::types::Types::BigInteger(e)
+                            }
                             DeepTypeResult::Byte(e) => ellie_core::definite::types::Types::Byte(e),
                             DeepTypeResult::Decimal(e) => {
                                 ellie_core::definite::types::Types::Decimal(e)
@@ -558,6 +832,22 @@ impl Parser {
                 }
             }
             DeepTypeResult::Null => {
+                //Null is assignable to nullables
+                if let ellie_core::definite::definers::DefinerCollecting::ParentGeneric(e) =
+                    &defining
+                {
+                    if e.rtype == "nullAble" {
+                        return if errors.is_empty() {
+                            Ok(CompareResult::result(
+                                true,
+                                defining.to_string(),
+                                "null".to_owned(),
+                            ))
+                        } else {
+                            Err(errors)
+                        };
+                    }
+                }
                 if let ellie_core::definite::definers::DefinerCollecting::Generic(_) = defining {
                     if defining.to_string() == "null" {
                         if errors.is_empty() {
@@ -718,62 +1008,29 @@ impl Parser {
                     _ => unreachable!("Parser should have prevented this"),
                 };
 
-                match &e.value {
-                    ellie_core::definite::types::enum_data::Pointer::NoData => {
-                        if errors.is_empty() {
-                            match defining {
-                                ellie_core::definite::definers::DefinerCollecting::Generic(
-                                    generic,
-                                ) => Ok(CompareResult::result(
-                                    generic.hash == enum_hash,
-                                    generic.rtype,
-                                    enum_name,
-                                )),
-                                _ => Ok(CompareResult::result(
-                                    false,
-                                    defining.to_string(),
-                                    enum_name,
-                                )),
-                            }
-                        } else {
-                            Err(errors)
-                        }
-                    }
-                    ellie_core::definite::types::enum_data::Pointer::Data(q) => {
-                        let mut errors = Vec::new();
-                        match resolve_type(*q.clone(), target_page, self, &mut errors, Some(e.pos))
-                        {
-                            Some(value_defining) => {
-                                if value_defining.same_as(defining.clone()) {
-                                    Ok(CompareResult::result(
-                                        true,
-                                        defining.to_string(),
-                                        value_defining.to_string(),
-                                    ))
-                                } else {
-                                    match defining {
-                                        ellie_core::definite::definers::DefinerCollecting::Generic(
-                                            generic,
-                                        ) => Ok(CompareResult::result(
-                                            generic.hash == enum_hash,
-                                            generic.rtype,
-                                            enum_name,
-                                        )),
-                                        _ => Ok(CompareResult::result(
-                                            false,
-                                            defining.to_string(),
-                                            enum_name,
-                                        )),
-                                    }
-                                }
-                            }
-                            None => Err(errors),
+                if errors.is_empty() {
+                    match defining {
+                        ellie_core::definite::definers::DefinerCollecting::Generic(generic) => {
+                            Ok(CompareResult::result(
+                                generic.hash == enum_hash,
+                                generic.rtype,
+                                enum_name,
+                            ))
                         }
+                        _ => Ok(CompareResult::result(
+                            false,
+                            defining.to_string(),
+                            enum_name,
+                        )),
                     }
+                } else {
+                    Err(errors)
                 }
             }
             DeepTypeResult::FunctionParameter(e) => {
-                if e.rtype.as_ref().unwrap().same_as(defining.clone()) {
+                if e.rtype.as_ref().unwrap().same_as(defining.clone())
+                    || self.satisfies_interface(&defining, e.rtype.as_ref().unwrap())
+                {
                     Ok(CompareResult::result(
                         true,
                         defining.to_string(),
@@ -929,6 +1186,7 @@ impl Parser {
         let mut inner_page = None;
         let mut searched: Vec<usize> = searched;
         let mixup_hashes: Vec<(String, String)> = Vec::new();
+        let mut unprocessed_type_def = false;
         let mut self_dependencies = vec![Dependency {
             hash: target_page,
             ..Default::default()
@@ -1073,6 +1331,34 @@ impl Parser {
                                             found_type = DeepSearchItems::Class(ellie_tokenizer::syntax::items::class::Class::default().from_definite(e));
                                         }
                                     }
+                                    Collecting::TypeDef(e)
+                                        if e.name == name
+                                            && (e.public
+                                                || level == 0
+                                                || dep.deep_link.is_some()
+                                                || matches!(inner_page, Some(ref parent_page_hash) if parent_page_hash == &page.hash))
+                                            && (ignore_hash.is_none()
+                                                || matches!(ignore_hash, Some(ref t) if &e.hash != t)) =>
+                                    {
+                                        found_pos = Some(e.pos);
+                                        found = true;
+                                        found_page = FoundPage::fill(&unprocessed_page);
+                                        found_type = DeepSearchItems::TypeDef(e);
+                                    }
+                                    Collecting::Interface(e)
+                                        if e.name == name
+                                            && (e.public
+                                                || level == 0
+                                                || dep.deep_link.is_some()
+                                                || matches!(inner_page, Some(ref parent_page_hash) if parent_page_hash == &page.hash))
+                                            && (ignore_hash.is_none()
+                                                || matches!(ignore_hash, Some(ref t) if &e.hash != t)) =>
+                                    {
+                                        found_pos = Some(e.pos);
+                                        found = true;
+                                        found_page = FoundPage::fill(&unprocessed_page);
+                                        found_type = DeepSearchItems::Interface(ellie_tokenizer::syntax::items::interface::Interface::default().from_definite(e));
+                                    }
                                     Collecting::NativeFunction(e) => {
                                         if e.name == name
                                             && (e.public || level == 0 || dep.deep_link.is_some())
@@ -1087,6 +1373,14 @@ impl Parser {
                                                 name_pos: e.name_pos,
                                                 public: e.public,
                                                 defining: true,
+                                                generic_definings: e.generic_definings.into_iter()
+                                                .map(|x| ellie_tokenizer::syntax::items::class::GenericDefining {
+                                                    name: x.name,
+                                                    hash: x.hash,
+                                                    pos: x.pos,
+                                                    ..Default::default()
+                                                })
+                                                .collect::<Vec<_>>(),
                                                 parameters: e.parameters.into_iter()
                                                 .map(|x| ellie_tokenizer::syntax::items::function::FunctionParameter {
                                                     name: x.name,
@@ -1240,6 +1534,34 @@ impl Parser {
                                             found_type = DeepSearchItems::Class(ellie_tokenizer::syntax::items::class::Class::default().from_definite(e));
                                         }
                                     }
+                                    Collecting::TypeDef(e)
+                                        if e.name == name
+                                            && (e.public
+                                                || level == 0
+                                                || dep.deep_link.is_some()
+                                                || matches!(inner_page, Some(ref parent_page_hash) if parent_page_hash == &page.hash))
+                                            && (ignore_hash.is_none()
+                                                || matches!(ignore_hash, Some(ref t) if &e.hash != t)) =>
+                                    {
+                                        found_pos = Some(e.pos);
+                                        found = true;
+                                        found_page = FoundPage::fill(&unprocessed_page);
+                                        found_type = DeepSearchItems::TypeDef(e);
+                                    }
+                                    Collecting::Interface(e)
+                                        if e.name == name
+                                            && (e.public
+                                                || level == 0
+                                                || dep.deep_link.is_some()
+                                                || matches!(inner_page, Some(ref parent_page_hash) if parent_page_hash == &page.hash))
+                                            && (ignore_hash.is_none()
+                                                || matches!(ignore_hash, Some(ref t) if &e.hash != t)) =>
+                                    {
+                                        found_pos = Some(e.pos);
+                                        found = true;
+                                        found_page = FoundPage::fill(&unprocessed_page);
+                                        found_type = DeepSearchItems::Interface(ellie_tokenizer::syntax::items::interface::Interface::default().from_definite(e));
+                                    }
                                     Collecting::NativeFunction(e) => {
                                         if e.name == name
                                             && (e.public || level == 0 || dep.deep_link.is_some())
@@ -1254,6 +1576,14 @@ impl Parser {
                                                 name_pos: e.name_pos,
                                                 public: e.public,
                                                 defining: true,
+                                                generic_definings: e.generic_definings.into_iter()
+                                                .map(|x| ellie_tokenizer::syntax::items::class::GenericDefining {
+                                                    name: x.name,
+                                                    hash: x.hash,
+                                                    pos: x.pos,
+                                                    ..Default::default()
+                                                })
+                                                .collect::<Vec<_>>(),
                                                 parameters: e.parameters.into_iter()
                                                 .map(|x| ellie_tokenizer::syntax::items::function::FunctionParameter {
                                                     name: x.name,
@@ -1408,6 +1738,36 @@ impl Parser {
                                             found_type = DeepSearchItems::Class(e.clone());
                                         }
                                     }
+                                    Processors::TypeDef(e)
+                                        if e.name == name
+                                            && (e.public
+                                                || level == 0
+                                                || dep.deep_link.is_some()
+                                                || matches!(inner_page, Some(ref parent_page_hash) if parent_page_hash == &page.hash))
+                                            && (ignore_hash.is_none()
+                                                || matches!(ignore_hash, Some(ref t) if &e.hash != t)) =>
+                                    {
+                                        found_pos = Some(e.pos);
+                                        found = true;
+                                        found_page = FoundPage::fill(page);
+                                        found_type =
+                                            DeepSearchItems::TypeDef(e.clone().to_definite());
+                                        unprocessed_type_def = true;
+                                    }
+                                    Processors::Interface(e)
+                                        if e.name == name
+                                            && (e.public
+                                                || level == 0
+                                                || dep.deep_link.is_some()
+                                                || matches!(inner_page, Some(ref parent_page_hash) if parent_page_hash == &page.hash))
+                                            && (ignore_hash.is_none()
+                                                || matches!(ignore_hash, Some(ref t) if &e.hash != t)) =>
+                                    {
+                                        found_pos = Some(e.pos);
+                                        found = true;
+                                        found_page = FoundPage::fill(page);
+                                        found_type = DeepSearchItems::Interface(e.clone());
+                                    }
                                     Processors::GenericItem(e) => {
                                         if e.generic_name == name
                                             && (level == 0
@@ -1475,6 +1835,22 @@ impl Parser {
             }
         }
 
+        // Type aliases are resolved when they're processed, an alias found on an unprocessed
+        // page is only usable if its processed counterpart exists (declared before use)
+        if let (true, DeepSearchItems::TypeDef(type_def)) = (unprocessed_type_def, &found_type) {
+            let type_def_hash = type_def.hash;
+            let processed_type_def = self.find_processed_page(found_page.hash).and_then(|page| {
+                page.items.iter().find_map(|item| match item {
+                    Collecting::TypeDef(e) if e.hash == type_def_hash => Some(e.clone()),
+                    _ => None,
+                })
+            });
+            match processed_type_def {
+                Some(type_def) => found_type = DeepSearchItems::TypeDef(type_def),
+                None => found = false,
+            }
+        }
+
         if has_mixup {
             DeepSearchResult {
                 found: true,
@@ -1628,7 +2004,7 @@ impl Parser {
             let page_type = page.page_type.clone();
             let page_path = page.path.clone();
             idx += 1;
-            if page.hash == self.initial_page {
+            if page.hash == self.initial_page || self.find_processed_page(page_hash).is_some() {
                 continue;
             }
             self.process_page(page_hash);
@@ -1941,6 +2317,9 @@ impl Parser {
                         _ => (),
                     });
                 }
+                PageType::ClassBody(class_page) => {
+                    self.check_interface_conformance(class_page, page_hash);
+                }
                 _ => (),
             }
         }
@@ -1975,6 +2354,11 @@ impl Parser {
             }
         }
 
+        if self.informations.has_no_errors() {
+            crate::flow_analysis::analyze_flow(self);
+            crate::usage_analysis::analyze_usage(self);
+        }
+
         Module {
             name: self.module_info.name.clone(),
             description: self.module_info.description.clone(),
@@ -83,6 +83,42 @@ impl super::DefinerParserProcessor for GenericType {
                         pos: generic.pos,
                     });
                 }
+                crate::parser::DeepSearchItems::TypeDef(type_def) => {
+                    if type_def.generic_definings.is_empty() {
+                        found = type_def.rtype;
+                    } else {
+                        let mut err = error::error_list::ERROR_S44.clone().build_with_path(
+                            vec![
+                                error::ErrorBuildField {
+                                    key: "token".to_owned(),
+                                    value: type_def.generic_definings.len().to_string(),
+                                },
+                                error::ErrorBuildField {
+                                    key: "token2".to_owned(),
+                                    value: "0".to_string(),
+                                },
+                            ],
+                            alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
+                            options
+                                .parser
+                                .find_page(options.page_id)
+                                .unwrap()
+                                .path
+                                .clone(),
+                            self.pos,
+                        );
+                        err.reference_block =
+                            Some((type_def.name_pos, deep_search_result.found_page.path));
+                        errors.push(err);
+                    }
+                }
+                crate::parser::DeepSearchItems::Interface(interface) => {
+                    found = DefinerCollecting::Generic(definers::GenericType {
+                        rtype: interface.name,
+                        hash: interface.hash,
+                        pos: interface.pos,
+                    });
+                }
                 crate::parser::DeepSearchItems::Enum(enum_data) => {
                     found = DefinerCollecting::Generic(definers::GenericType {
                         rtype: enum_data.name,
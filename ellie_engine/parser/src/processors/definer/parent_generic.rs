@@ -60,6 +60,16 @@ impl super::DefinerParserProcessor for ParentGenericType {
                                 Err(e) => errors.extend(e),
                             }
                         }
+                        errors.extend(
+                            options.parser.check_generic_bounds(
+                                options.page_id,
+                                &e,
+                                &resolved_generics
+                                    .iter()
+                                    .map(|x| (x.value.clone(), x.pos))
+                                    .collect::<Vec<_>>(),
+                            ),
+                        );
                         found = DefinerCollecting::ParentGeneric(definers::ParentGenericType {
                             rtype: e.name,
                             hash: e.hash,
@@ -68,6 +78,46 @@ impl super::DefinerParserProcessor for ParentGenericType {
                         });
                     }
                 }
+                crate::parser::DeepSearchItems::TypeDef(type_def) => {
+                    if type_def.generic_definings.len() != self.generics.len() {
+                        let mut err = error::error_list::ERROR_S44.clone().build_with_path(
+                            vec![
+                                error::ErrorBuildField {
+                                    key: "token".to_owned(),
+                                    value: type_def.generic_definings.len().to_string(),
+                                },
+                                error::ErrorBuildField {
+                                    key: "token2".to_owned(),
+                                    value: self.generics.len().to_string(),
+                                },
+                            ],
+                            alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
+                            options
+                                .parser
+                                .find_page(options.page_id)
+                                .unwrap()
+                                .path
+                                .clone(),
+                            self.pos,
+                        );
+                        err.reference_block =
+                            Some((type_def.name_pos, deep_search_result.found_page.path));
+                        errors.push(err);
+                    } else {
+                        let mut rtype = type_def.rtype;
+                        for (generic_defining, generic) in
+                            type_def.generic_definings.iter().zip(self.generics.iter())
+                        {
+                            match generic.value.process(options) {
+                                Ok(resolved) => {
+                                    rtype.convert_generic(generic_defining.hash, resolved)
+                                }
+                                Err(e) => errors.extend(e),
+                            }
+                        }
+                        found = rtype;
+                    }
+                }
                 crate::parser::DeepSearchItems::BrokenPageGraph => todo!(),
                 crate::parser::DeepSearchItems::MixUp(_) => todo!(),
                 crate::parser::DeepSearchItems::None => {
@@ -1,13 +1,59 @@
 use alloc::{borrow::ToOwned, vec, vec::Vec};
 #[cfg(feature = "standard_rules")]
 use ellie_core::warning;
-use ellie_core::{defs, error, utils};
+use ellie_core::{definite::items::interface::InterfaceReference, defs, error, utils};
 use ellie_tokenizer::{
     processors::items::Processors,
     syntax::items::class::Class,
     tokenizer::{ClassPageType, PageType},
 };
 
+/// Resolve an interface name used in an `impl` list or a generic bound
+fn resolve_interface(
+    options: &mut super::ItemParserProcessorOptions,
+    name: &str,
+    pos: defs::Cursor,
+    path: &str,
+) -> Option<InterfaceReference> {
+    let deep_search_result =
+        options
+            .parser
+            .deep_search(options.page_hash, name.to_owned(), None, vec![], 0, None);
+
+    match deep_search_result.found_item {
+        crate::parser::DeepSearchItems::Interface(interface) if deep_search_result.found => {
+            Some(InterfaceReference {
+                name: interface.name,
+                hash: interface.hash,
+                pos,
+            })
+        }
+        crate::parser::DeepSearchItems::None => {
+            options
+                .parser
+                .informations
+                .push(&error::error_list::ERROR_S6.clone().build_with_path(
+                    vec![error::ErrorBuildField::new("token", &name.to_owned())],
+                    alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
+                    path.to_owned(),
+                    pos,
+                ));
+            None
+        }
+        _ => {
+            options.parser.informations.push(
+                &error::error_list::ERROR_S70.clone().build_with_path(
+                    vec![error::ErrorBuildField::new("token", &name.to_owned())],
+                    alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
+                    path.to_owned(),
+                    pos,
+                ),
+            );
+            None
+        }
+    }
+}
+
 impl super::ItemParserProcessor for Class {
     fn process(&self, options: &mut super::ItemParserProcessorOptions) -> bool {
         let (duplicate, found) =
@@ -185,16 +231,39 @@ impl super::ItemParserProcessor for Class {
                 }
             }
 
+            let implements = self
+                .implements
+                .iter()
+                .filter_map(|implement| {
+                    resolve_interface(options, &implement.name, implement.pos, &path)
+                })
+                .collect::<Vec<_>>();
+
+            let generic_bounds = self
+                .generic_definings
+                .iter()
+                .map(|generic| {
+                    if generic.bound.is_empty() {
+                        None
+                    } else {
+                        resolve_interface(options, &generic.bound, generic.bound_pos, &path)
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            let page = options.parser.pages.nth(options.page_idx).unwrap();
+
             let inner_page_id: usize = ellie_core::utils::generate_hash_usize();
 
             let mut items = Vec::new();
 
-            for generic in self.generic_definings.clone() {
+            for (generic, bound) in self.generic_definings.iter().zip(generic_bounds.iter()) {
                 items.push(Processors::GenericItem(
                     ellie_tokenizer::syntax::items::generic_item::GenericItem {
-                        generic_name: generic.name,
+                        generic_name: generic.name.clone(),
                         pos: generic.pos,
                         hash: generic.hash,
+                        bound: bound.clone(),
                     },
                 ));
             }
@@ -255,12 +324,17 @@ impl super::ItemParserProcessor for Class {
                     generic_definings: self
                         .generic_definings
                         .iter()
-                        .map(|x| ellie_core::definite::items::class::GenericDefining {
-                            name: x.name.clone(),
-                            hash: x.hash,
-                            pos: x.pos,
-                        })
+                        .zip(generic_bounds)
+                        .map(
+                            |(x, bound)| ellie_core::definite::items::class::GenericDefining {
+                                name: x.name.clone(),
+                                hash: x.hash,
+                                pos: x.pos,
+                                bound,
+                            },
+                        )
                         .collect::<Vec<_>>(),
+                    implements,
                 },
             );
 
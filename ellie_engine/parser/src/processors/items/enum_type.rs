@@ -1,5 +1,3 @@
-#![allow(unused_variables)]
-#![allow(unreachable_code)]
 use alloc::{borrow::ToOwned, vec, vec::Vec};
 #[cfg(feature = "standard_rules")]
 use ellie_core::warning;
@@ -20,17 +18,7 @@ impl super::ItemParserProcessor for EnumType {
             .unwrap()
             .path
             .clone();
-        options
-            .parser
-            .informations
-            .push(&error::error_list::ERROR_S59.clone().build_with_path(
-                vec![error::ErrorBuildField::new("token", &"enum".to_owned())],
-                alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
-                path,
-                self.name_pos,
-            ));
-        return false;
-        let halt = true;
+        let mut halt = true;
         let (duplicate, found) =
             options
                 .parser
@@ -122,7 +110,7 @@ impl super::ItemParserProcessor for EnumType {
             for item in &self.items {
                 let resolved_type = if item.has_type {
                     match item.enum_type.definer_type.process(
-                        DefinerParserProcessorOptions::new(options.parser, options.page_idx)
+                        DefinerParserProcessorOptions::new(options.parser, options.page_hash)
                             .build(),
                     ) {
                         Ok(e) => Some(e),
@@ -0,0 +1,240 @@
+use alloc::{borrow::ToOwned, string::ToString, vec, vec::Vec};
+use ellie_core::{
+    definite::{definers, items::Collecting},
+    defs, error,
+};
+use ellie_tokenizer::{
+    processors::items::Processors,
+    syntax::items::extend::Extend,
+    tokenizer::{ExtendPageType, PageType},
+};
+
+use crate::parser::DeepSearchItems;
+
+impl super::ItemParserProcessor for Extend {
+    fn process(&self, options: &mut super::ItemParserProcessorOptions) -> bool {
+        let page = options.parser.pages.nth(options.page_idx).unwrap().clone();
+
+        let deep_search_result = options.parser.deep_search(
+            options.page_hash,
+            self.target.clone(),
+            None,
+            vec![],
+            0,
+            None,
+        );
+
+        let target_class = match deep_search_result.found_item {
+            DeepSearchItems::Class(class) if deep_search_result.found => class,
+            DeepSearchItems::None => {
+                options.parser.informations.push(
+                    &error::error_list::ERROR_S6.clone().build_with_path(
+                        vec![error::ErrorBuildField::new("token", &self.target)],
+                        alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
+                        page.path.clone(),
+                        self.target_pos,
+                    ),
+                );
+                return false;
+            }
+            _ => {
+                options.parser.informations.push(
+                    &error::error_list::ERROR_S68.clone().build_with_path(
+                        vec![error::ErrorBuildField::new("token", &self.target)],
+                        alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
+                        page.path.clone(),
+                        self.target_pos,
+                    ),
+                );
+                return false;
+            }
+        };
+
+        // Built-in classes like `array` take their generics from the definer instead of
+        // declaring them, so only classes with generic definings are checked.
+        if !target_class.generic_definings.is_empty()
+            && target_class.generic_definings.len() != self.generic_definings.len()
+        {
+            let mut err = error::error_list::ERROR_S44.clone().build_with_path(
+                vec![
+                    error::ErrorBuildField {
+                        key: "token".to_owned(),
+                        value: target_class.generic_definings.len().to_string(),
+                    },
+                    error::ErrorBuildField {
+                        key: "token2".to_owned(),
+                        value: self.generic_definings.len().to_string(),
+                    },
+                ],
+                alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
+                page.path.clone(),
+                self.target_pos,
+            );
+            if let Some(found_pos) = deep_search_result.found_pos {
+                err.reference_block = Some((found_pos, deep_search_result.found_page.path));
+            }
+            options.parser.informations.push(&err);
+            return false;
+        }
+
+        for (index, generic) in self.generic_definings.iter().enumerate() {
+            if let Some(other_index) = self
+                .generic_definings
+                .iter()
+                .position(|g| g.name == generic.name)
+            {
+                if other_index < index {
+                    let mut err = error::error_list::ERROR_S10.clone().build_with_path(
+                        vec![],
+                        alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
+                        page.path.clone(),
+                        generic.pos,
+                    );
+                    err.reference_block =
+                        Some((self.generic_definings[other_index].pos, page.path.clone()));
+                    err.reference_message = "Prime is here".to_owned();
+                    err.semi_assist = true;
+                    options.parser.informations.push(&err);
+                }
+            }
+        }
+
+        // Extension generics share the hashes of the class generics they stand for, so
+        // members of the class typed with its generics resolve inside the extension too.
+        let generic_definings = self
+            .generic_definings
+            .iter()
+            .enumerate()
+            .map(|(index, generic)| {
+                let mut generic = generic.clone();
+                if let Some(class_generic) = target_class.generic_definings.get(index) {
+                    generic.hash = class_generic.hash;
+                }
+                generic
+            })
+            .collect::<Vec<_>>();
+
+        let mut has_unexpected_item = false;
+        for item in &self.body {
+            match item {
+                Processors::Function(_) | Processors::FileKey(_) | Processors::Comment(_) => (),
+                unexpected_element => {
+                    has_unexpected_item = true;
+                    options.parser.informations.push(
+                        &error::error_list::ERROR_S22.clone().build_with_path(
+                            vec![],
+                            alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
+                            page.path.clone(),
+                            unexpected_element.get_pos(),
+                        ),
+                    );
+                }
+            }
+        }
+
+        if has_unexpected_item {
+            return false;
+        }
+
+        let target = if generic_definings.is_empty() {
+            definers::DefinerCollecting::Generic(definers::GenericType {
+                rtype: target_class.name.clone(),
+                pos: self.target_pos,
+                hash: target_class.hash,
+            })
+        } else {
+            definers::DefinerCollecting::ParentGeneric(definers::ParentGenericType {
+                rtype: target_class.name.clone(),
+                parent_pos: self.target_pos,
+                generics: generic_definings
+                    .iter()
+                    .map(|generic| definers::GenericParameter {
+                        value: definers::DefinerCollecting::Generic(definers::GenericType {
+                            rtype: generic.name.clone(),
+                            pos: generic.pos,
+                            hash: generic.hash,
+                        }),
+                        pos: generic.pos,
+                    })
+                    .collect(),
+                hash: target_class.hash,
+            })
+        };
+
+        let inner_page_id: usize = ellie_core::utils::generate_hash_usize();
+        let mut items = Vec::new();
+
+        for generic in generic_definings.clone() {
+            items.push(Processors::GenericItem(
+                ellie_tokenizer::syntax::items::generic_item::GenericItem {
+                    generic_name: generic.name,
+                    pos: generic.pos,
+                    hash: generic.hash,
+                    bound: None,
+                },
+            ));
+        }
+
+        let mut dependencies = vec![ellie_tokenizer::tokenizer::Dependency {
+            hash: page.hash,
+            processed: false,
+            module: None,
+            deep_link: Some(page.hash),
+            public: false,
+        }];
+        dependencies.extend(page.dependencies.iter().map(|d| {
+            let mut dep = d.clone();
+            dep.deep_link = Some(page.hash);
+            dep
+        }));
+        items.extend(self.body.clone());
+
+        let inner = ellie_tokenizer::tokenizer::Page {
+            hash: inner_page_id,
+            inner: Some(page.hash),
+            path: page.path.clone(),
+            items,
+            dependents: vec![],
+            dependencies,
+            page_type: PageType::ExtendBody(ExtendPageType {
+                target,
+                pos: self.pos,
+                page_hash: options.page_hash,
+            }),
+            unreachable: false,
+            unreachable_range: defs::Cursor::default(),
+            processed: false,
+            module: false,
+        };
+        options.parser.pages.push_page(inner);
+
+        let processed_page = options
+            .parser
+            .processed_pages
+            .nth_mut(options.processed_page_idx)
+            .unwrap();
+
+        processed_page.items.push(Collecting::Extend(
+            ellie_core::definite::items::extend::Extend {
+                target: target_class.hash,
+                target_name: target_class.name.clone(),
+                target_generics: generic_definings
+                    .iter()
+                    .map(|x| ellie_core::definite::items::extend::GenericDefining {
+                        name: x.name.clone(),
+                        hash: x.hash,
+                        pos: x.pos,
+                    })
+                    .collect(),
+                target_pos: self.target_pos,
+                pos: self.pos,
+                inner_page_id,
+            },
+        ));
+
+        // Process the body right away so every page in the queue can resolve the
+        // extension methods, regardless of declaration order.
+        options.parser.process_page(inner_page_id);
+        true
+    }
+}
@@ -1,6 +1,4 @@
-#![allow(unused_variables)]
-#![allow(unreachable_code)]
-use alloc::{borrow::ToOwned, vec, vec::Vec};
+use alloc::{borrow::ToOwned, string::ToString, vec, vec::Vec};
 use ellie_core::{definite::definers::DefinerCollecting, error};
 use ellie_tokenizer::{syntax::items::for_loop::ForLoop, tokenizer::PageType};
 
@@ -8,39 +6,9 @@ use crate::processors::types::{TypeParserProcessor, TypeParserProcessorOptions};
 
 impl super::ItemParserProcessor for ForLoop {
     fn process(&self, options: &mut super::ItemParserProcessorOptions) -> bool {
-        let path = options
-            .parser
-            .pages
-            .nth(options.page_idx)
-            .unwrap()
-            .path
-            .clone();
-        options
-            .parser
-            .informations
-            .push(&error::error_list::ERROR_S59.clone().build_with_path(
-                vec![error::ErrorBuildField::new(
-                    "token",
-                    &"for loops".to_owned(),
-                )],
-                alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
-                path,
-                self.pos,
-            ));
-        return false;
         let page = options.parser.pages.nth(options.page_idx).unwrap().clone();
         let path = page.path.clone();
 
-        options
-            .parser
-            .informations
-            .push(&error::error_list::ERROR_S59.clone().build_with_path(
-                vec![error::ErrorBuildField::new("token", &"for".to_owned())],
-                alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
-                path.clone(),
-                self.pos,
-            ));
-
         if self.variable.current.as_variable().is_none() {
             options.parser.informations.push(
                 &error::error_list::ERROR_S27.clone().build_with_path(
@@ -127,25 +95,40 @@ impl super::ItemParserProcessor for ForLoop {
                 }
             };
 
-            let mut inner_type: DefinerCollecting = DefinerCollecting::Dynamic;
-
-            match &target_iterator {
-                ellie_core::definite::definers::DefinerCollecting::ParentGeneric(e) => {
-                    if e.rtype != "array" {
-                        options.parser.informations.push(
-                            &error::error_list::ERROR_S29.clone().build_with_path(
-                                vec![error::ErrorBuildField {
-                                    key: "token".to_owned(),
-                                    value: target_iterator.to_string(),
-                                }],
-                                alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
-                                path,
-                                self.iterator_pos,
-                            ),
-                        );
+            let inner_type = match &target_iterator {
+                DefinerCollecting::Generic(e) if e.rtype == "int" => target_iterator.clone(),
+                DefinerCollecting::Generic(e) if e.rtype == "string" => {
+                    match crate::deep_search_extensions::find_type(
+                        "char".to_string(),
+                        options.page_hash,
+                        options.parser,
+                    ) {
+                        Some(char_type) => DefinerCollecting::Generic(char_type),
+                        None => {
+                            options.parser.informations.push(
+                                &error::error_list::ERROR_S38.clone().build_with_path(
+                                    vec![error::ErrorBuildField {
+                                        key: "token".to_owned(),
+                                        value: "char".to_string(),
+                                    }],
+                                    alloc::format!(
+                                        "{}:{}:{}",
+                                        file!().to_owned(),
+                                        line!(),
+                                        column!()
+                                    ),
+                                    path,
+                                    self.iterator_pos,
+                                ),
+                            );
+                            return false;
+                        }
                     }
-                    inner_type = e.generics[0].clone().value
                 }
+                DefinerCollecting::ParentGeneric(e) if e.rtype == "array" => {
+                    e.generics[0].clone().value
+                }
+                DefinerCollecting::Array(e) => *e.rtype.clone(),
                 _ => {
                     options.parser.informations.push(
                         &error::error_list::ERROR_S29.clone().build_with_path(
@@ -158,8 +141,9 @@ impl super::ItemParserProcessor for ForLoop {
                             self.iterator_pos,
                         ),
                     );
+                    return false;
                 }
-            }
+            };
 
             let inner_page_id: usize = ellie_core::utils::generate_hash_usize();
             let mut dependencies = vec![ellie_tokenizer::tokenizer::Dependency {
@@ -172,29 +156,20 @@ impl super::ItemParserProcessor for ForLoop {
 
             let mut items = Vec::new();
 
-            items.push(ellie_tokenizer::processors::items::Processors::Variable(
-                ellie_tokenizer::syntax::items::variable::VariableCollector {
-                    data: ellie_tokenizer::syntax::items::variable::Variable {
+            //Loop variable's value is bound by the loop itself, it's declared like a parameter
+            //so only its type is known here
+            items.push(
+                ellie_tokenizer::processors::items::Processors::FunctionParameter(
+                    ellie_tokenizer::syntax::items::function_parameter::FunctionParameter {
                         name: variable_name,
-                        constant: true,
-                        public: false,
-                        has_type: true,
-                        has_value: false,
-                        pos: self.variable_pos,
+                        reference: false,
+                        rtype: inner_type,
                         name_pos: self.variable_pos,
-                        type_pos: self.variable_pos,
-                        rtype: ellie_tokenizer::syntax::items::definers::DefinerCollector {
-                            definer_type: ellie_core::definite::Converter::from_definite(
-                                ellie_tokenizer::syntax::items::definers::DefinerTypes::Dynamic,
-                                inner_type.clone(),
-                            ),
-                            complete: true,
-                        },
-                        ..Default::default()
+                        rtype_pos: self.variable_pos,
+                        hash: ellie_core::utils::generate_hash_usize(),
                     },
-                    ..Default::default()
-                },
-            ));
+                ),
+            );
 
             dependencies.extend(page.dependencies);
             items.extend(self.body.clone());
@@ -220,6 +195,7 @@ impl super::ItemParserProcessor for ForLoop {
                     ellie_core::definite::items::for_loop::ForLoop {
                         variable: self.variable.current.to_definite(),
                         iterator,
+                        iterator_type: target_iterator,
                         parameter: self.parameter,
                         body_pos: self.body_pos,
                         inner_page_id,
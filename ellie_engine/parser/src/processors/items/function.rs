@@ -6,13 +6,14 @@ use ellie_core::{
     utils::{self, generate_hash_usize},
 };
 use ellie_tokenizer::{
+    processors::items::Processors,
     syntax::items::function::FunctionCollector,
     tokenizer::{FunctionPageType, PageType},
 };
 
 use ellie_core::definite::{
     definers,
-    items::{function, native_function, Collecting},
+    items::{function, native_function, type_def, Collecting},
 };
 
 use crate::processors::definer::{DefinerParserProcessor, DefinerParserProcessorOptions};
@@ -81,10 +82,89 @@ impl super::ItemParserProcessor for FunctionCollector {
             let mut parameters: Vec<function::FunctionParameter> = Vec::new();
             let mut items = Vec::new();
 
+            for (index, generic) in self.data.generic_definings.iter().enumerate() {
+                if let Some(other_index) = self
+                    .data
+                    .generic_definings
+                    .iter()
+                    .position(|g| g.name == generic.name)
+                {
+                    if other_index < index {
+                        let mut err = error::error_list::ERROR_S10.clone().build_with_path(
+                            vec![],
+                            alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
+                            page.path.clone(),
+                            generic.pos,
+                        );
+                        err.reference_block = Some((
+                            self.data.generic_definings[other_index].pos,
+                            page.path.clone(),
+                        ));
+                        err.reference_message = "Prime is here".to_owned();
+                        err.semi_assist = true;
+                        options.parser.informations.push(&err);
+                        return false;
+                    }
+                }
+            }
+
+            let generic_items = self
+                .data
+                .generic_definings
+                .iter()
+                .map(|generic| {
+                    Processors::GenericItem(
+                        ellie_tokenizer::syntax::items::generic_item::GenericItem {
+                            generic_name: generic.name.clone(),
+                            pos: generic.pos,
+                            hash: generic.hash,
+                            bound: None,
+                        },
+                    )
+                })
+                .collect::<Vec<_>>();
+
+            // Parameters and the return type are resolved before the body exists,
+            // generic parameters are placed in a page of their own for them.
+            let definer_page_id = if generic_items.is_empty() {
+                options.page_hash
+            } else {
+                let generic_page_id: usize = ellie_core::utils::generate_hash_usize();
+                let mut dependencies = vec![ellie_tokenizer::tokenizer::Dependency {
+                    hash: page.hash,
+                    processed: false,
+                    module: None,
+                    deep_link: Some(page.hash),
+                    public: false,
+                }];
+                dependencies.extend(page.dependencies.iter().map(|d| {
+                    let mut dep = d.clone();
+                    dep.deep_link = Some(page.hash);
+                    dep
+                }));
+
+                let generic_page = ellie_tokenizer::tokenizer::Page {
+                    hash: generic_page_id,
+                    inner: Some(page.hash),
+                    path: page.path.clone(),
+                    items: generic_items.clone(),
+                    dependents: vec![],
+                    dependencies,
+                    page_type: PageType::RawBody,
+                    unreachable: false,
+                    unreachable_range: ellie_core::defs::Cursor::default(),
+                    processed: false,
+                    module: false,
+                };
+                options.parser.pages.push_page(generic_page);
+                options.parser.process_page(generic_page_id);
+                generic_page_id
+            };
+
             let inner_page_id: usize = ellie_core::utils::generate_hash_usize();
 
             let mut return_type = match self.data.return_type.definer_type.process(
-                DefinerParserProcessorOptions::new(options.parser, options.page_hash).build(),
+                DefinerParserProcessorOptions::new(options.parser, definer_page_id).build(),
             ) {
                 Ok(e) => e,
                 Err(e) => {
@@ -95,7 +175,7 @@ impl super::ItemParserProcessor for FunctionCollector {
 
             if !self.data.no_return {
                 match self.data.return_type.definer_type.process(
-                    DefinerParserProcessorOptions::new(options.parser, options.page_hash).build(),
+                    DefinerParserProcessorOptions::new(options.parser, definer_page_id).build(),
                 ) {
                     Ok(found_type) => {
                         return_type = found_type;
@@ -140,6 +220,28 @@ impl super::ItemParserProcessor for FunctionCollector {
                         is_mut: false,
                     });
                 }
+                PageType::ExtendBody(extend_body) => {
+                    items.push(
+                        ellie_tokenizer::processors::items::Processors::FunctionParameter(
+                            ellie_tokenizer::syntax::items::function_parameter::FunctionParameter {
+                                name: "self".to_owned(),
+                                reference: false,
+                                rtype: extend_body.target.clone(),
+                                name_pos: extend_body.pos,
+                                rtype_pos: extend_body.pos,
+                                hash: generate_hash_usize(),
+                            },
+                        ),
+                    );
+                    parameters.push(function::FunctionParameter {
+                        name: "self".to_owned(),
+                        rtype: extend_body.target,
+                        multi_capture: false,
+                        name_pos: extend_body.pos,
+                        rtype_pos: extend_body.pos,
+                        is_mut: false,
+                    });
+                }
                 _ => (),
             }
 
@@ -214,7 +316,7 @@ impl super::ItemParserProcessor for FunctionCollector {
                         }
                     } else {
                         match parameter.rtype.definer_type.process(
-                            DefinerParserProcessorOptions::new(options.parser, options.page_hash)
+                            DefinerParserProcessorOptions::new(options.parser, definer_page_id)
                                 .build(),
                         ) {
                             Ok(e) => {
@@ -315,6 +417,16 @@ impl super::ItemParserProcessor for FunctionCollector {
                 processed_page.items.push(Collecting::NativeFunction(
                     native_function::NativeFunction {
                         name: self.data.name.clone(),
+                        generic_definings: self
+                            .data
+                            .generic_definings
+                            .iter()
+                            .map(|x| type_def::GenericDefining {
+                                name: x.name.clone(),
+                                hash: x.hash,
+                                pos: x.pos,
+                            })
+                            .collect(),
                         pos: self.data.pos,
                         parameters,
                         hash: self.data.hash,
@@ -340,6 +452,7 @@ impl super::ItemParserProcessor for FunctionCollector {
                     public: false,
                 }];
                 dependencies.extend(page.dependencies);
+                items.extend(generic_items);
                 items.extend(self.data.body.clone());
 
                 let inner = ellie_tokenizer::tokenizer::Page {
@@ -367,6 +480,16 @@ impl super::ItemParserProcessor for FunctionCollector {
                     .items
                     .push(Collecting::Function(function::Function {
                         name: self.data.name.clone(),
+                        generic_definings: self
+                            .data
+                            .generic_definings
+                            .iter()
+                            .map(|x| type_def::GenericDefining {
+                                name: x.name.clone(),
+                                hash: x.hash,
+                                pos: x.pos,
+                            })
+                            .collect(),
                         pos: self.data.pos,
                         parameters,
                         hash: self.data.hash,
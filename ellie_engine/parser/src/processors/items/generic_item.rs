@@ -13,6 +13,7 @@ impl super::ItemParserProcessor for GenericItem {
                 name: self.generic_name.clone(),
                 pos: self.pos,
                 hash: self.hash,
+                bound: self.bound.clone(),
             }));
         true
     }
@@ -0,0 +1,130 @@
+use alloc::{borrow::ToOwned, vec, vec::Vec};
+use ellie_core::{definite::items::Collecting, defs, error, utils};
+use ellie_tokenizer::{
+    processors::items::Processors,
+    syntax::items::interface::Interface,
+    tokenizer::{InterfacePageType, PageType},
+};
+
+impl super::ItemParserProcessor for Interface {
+    fn process(&self, options: &mut super::ItemParserProcessorOptions) -> bool {
+        let page = options.parser.pages.nth(options.page_idx).unwrap().clone();
+
+        let (duplicate, found) =
+            options
+                .parser
+                .is_duplicate(options.page_hash, self.name.clone(), self.hash, self.pos);
+
+        if utils::is_reserved(&self.name, false) {
+            options.parser.informations.push(
+                &error::error_list::ERROR_S21.clone().build_with_path(
+                    vec![error::ErrorBuildField::new("token", &self.name)],
+                    alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
+                    page.path.clone(),
+                    self.name_pos,
+                ),
+            );
+        }
+
+        if duplicate {
+            let mut err = error::error_list::ERROR_S24.clone().build_with_path(
+                vec![error::ErrorBuildField::new("token", &self.name)],
+                alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
+                page.path.clone(),
+                self.name_pos,
+            );
+            if let Some((found_page, cursor_pos)) = found {
+                err.reference_block = Some((cursor_pos, found_page.path));
+                err.reference_message = "Prime is here".to_owned();
+                err.semi_assist = true;
+            }
+            options.parser.informations.push(&err);
+            return false;
+        }
+
+        // Interfaces only describe members, so functions must be signatures and
+        // properties must not carry a value.
+        let mut has_unexpected_item = false;
+        for item in &self.body {
+            match item {
+                Processors::Function(function) if function.data.defining => (),
+                Processors::Variable(variable)
+                    if !variable.data.has_value && variable.data.has_type => {}
+                Processors::FileKey(_) | Processors::Comment(_) => (),
+                unexpected_element => {
+                    has_unexpected_item = true;
+                    options.parser.informations.push(
+                        &error::error_list::ERROR_S22.clone().build_with_path(
+                            vec![],
+                            alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
+                            page.path.clone(),
+                            unexpected_element.get_pos(),
+                        ),
+                    );
+                }
+            }
+        }
+
+        if has_unexpected_item {
+            return false;
+        }
+
+        let inner_page_id: usize = ellie_core::utils::generate_hash_usize();
+
+        let mut dependencies = vec![ellie_tokenizer::tokenizer::Dependency {
+            hash: page.hash,
+            processed: false,
+            module: None,
+            deep_link: Some(page.hash),
+            public: false,
+        }];
+        dependencies.extend(page.dependencies.iter().map(|d| {
+            let mut dep = d.clone();
+            dep.deep_link = Some(page.hash);
+            dep
+        }));
+
+        let inner = ellie_tokenizer::tokenizer::Page {
+            hash: inner_page_id,
+            inner: Some(page.hash),
+            path: page.path.clone(),
+            items: self.body.clone(),
+            dependents: vec![],
+            dependencies,
+            page_type: PageType::InterfaceBody(InterfacePageType {
+                name: self.name.clone(),
+                hash: self.hash,
+                pos: self.pos,
+                page_hash: options.page_hash,
+            }),
+            unreachable: false,
+            unreachable_range: defs::Cursor::default(),
+            processed: false,
+            module: false,
+        };
+        options.parser.pages.push_page(inner);
+
+        let processed_page = options
+            .parser
+            .processed_pages
+            .nth_mut(options.processed_page_idx)
+            .unwrap();
+
+        processed_page.items.push(Collecting::Interface(
+            ellie_core::definite::items::interface::Interface {
+                name: self.name.clone(),
+                public: self.public,
+                inner_page_id,
+                name_pos: self.name_pos,
+                pos: self.pos,
+                hash: self.hash,
+            },
+        ));
+        processed_page.unassigned_file_keys = Vec::new();
+
+        // Classes may implement the interface before its declaration, so the
+        // members must be resolved before the queue moves on.
+        options.parser.process_page(inner_page_id);
+        true
+    }
+}
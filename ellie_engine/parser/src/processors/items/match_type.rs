@@ -0,0 +1,475 @@
+use crate::{
+    deep_search_extensions::{
+        deep_search_hash, find_enum_variant, generate_type_from_defining, resolve_type,
+        ProcessedDeepSearchItems,
+    },
+    processors::types::{TypeParserProcessor, TypeParserProcessorOptions},
+};
+use alloc::{
+    borrow::ToOwned,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use ellie_core::{
+    definite::{
+        definers::DefinerCollecting,
+        items::{
+            enum_type::{EnumType, EnumValue},
+            match_type::{self, MatchPattern},
+        },
+        types::Types,
+        Converter,
+    },
+    defs, error,
+    utils::generate_hash_usize,
+};
+use ellie_tokenizer::{
+    processors::types::Processors,
+    syntax::items::{condition::ConditionType, match_type::Match},
+    tokenizer::{ConditionPageType, PageType},
+};
+
+/// Variable bound by an enum pattern, `name` is bound to the variant's data
+struct PatternBinding {
+    name: String,
+    pos: defs::Cursor,
+    rtype: DefinerCollecting,
+}
+
+/// Find the enum a type refers to
+/// ## Arguments
+/// * `parser` - [`crate::parser::Parser`]
+/// * `page_id` - Page hash to search from
+/// * `rtype` - Type to look up
+/// ## Returns
+/// [`Option<EnumType>`] - Enum if the type is an enum
+fn find_enum(
+    parser: &mut crate::parser::Parser,
+    page_id: usize,
+    rtype: &DefinerCollecting,
+) -> Option<EnumType> {
+    match rtype {
+        DefinerCollecting::Generic(generic) => {
+            match deep_search_hash(parser, page_id, generic.hash, vec![], 0) {
+                search if search.found => match search.found_item {
+                    ProcessedDeepSearchItems::Enum(enum_type) => Some(enum_type),
+                    _ => None,
+                },
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Find the enum variant a pattern refers to, variants without data are processed as enum data
+/// ## Arguments
+/// * `parser` - [`crate::parser::Parser`]
+/// * `page_id` - Page hash to search from
+/// * `value` - Processed pattern
+/// ## Returns
+/// [`Option<(EnumType, usize)>`] - Enum and the variant index
+fn find_pattern_variant(
+    parser: &mut crate::parser::Parser,
+    page_id: usize,
+    value: &Types,
+) -> Option<(EnumType, usize)> {
+    match value {
+        Types::EnumData(enum_data) => {
+            let enum_hash = match &*enum_data.reference {
+                Types::VariableType(e) => e.reference,
+                _ => return None,
+            };
+            match deep_search_hash(parser, page_id, enum_hash, vec![], 0) {
+                search if search.found => match search.found_item {
+                    ProcessedDeepSearchItems::Enum(enum_type) => enum_type
+                        .items
+                        .iter()
+                        .position(|item| item.identifier == enum_data.field_name)
+                        .map(|variant_idx| (enum_type, variant_idx)),
+                    _ => None,
+                },
+                _ => None,
+            }
+        }
+        Types::Reference(reference) => find_enum_variant(parser, page_id, reference),
+        _ => None,
+    }
+}
+
+/// Resolve a match arm's pattern against the matched type
+/// ## Arguments
+/// * `options` - [`super::ItemParserProcessorOptions`]
+/// * `pattern` - Pattern to resolve
+/// * `pattern_pos` - Position of the pattern
+/// * `target_type` - Type of the matched value, nullAble wrapper removed
+/// * `nullable` - Whether the matched value can be null
+/// ## Returns
+/// [`Result<(MatchPattern, Option<PatternBinding>), Vec<error::Error>>`] - Resolved pattern and the variable it binds
+fn resolve_pattern(
+    options: &mut super::ItemParserProcessorOptions,
+    pattern: &Processors,
+    pattern_pos: defs::Cursor,
+    target_type: &DefinerCollecting,
+    nullable: bool,
+) -> Result<(MatchPattern, Option<PatternBinding>), Vec<error::Error>> {
+    let path = options
+        .parser
+        .pages
+        .nth(options.page_idx)
+        .unwrap()
+        .path
+        .clone();
+    let mismatch = |expected: String, found: String| {
+        vec![error::error_list::ERROR_S3.clone().build_with_path(
+            vec![
+                error::ErrorBuildField {
+                    key: "token1".to_owned(),
+                    value: expected,
+                },
+                error::ErrorBuildField {
+                    key: "token2".to_owned(),
+                    value: found,
+                },
+            ],
+            alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
+            path.clone(),
+            pattern_pos,
+        )]
+    };
+
+    //Keyword patterns are resolved here, they're not defined as values
+    if let Processors::Variable(variable) = pattern {
+        match variable.data.value.as_str() {
+            "_" => return Ok((MatchPattern::Wildcard, None)),
+            "null" if nullable => return Ok((MatchPattern::Null, None)),
+            "null" => return Err(mismatch(target_type.to_string(), "null".to_string())),
+            "true" | "false" if target_type.to_string() == "bool" => {
+                return Ok((
+                    MatchPattern::Value(Types::Bool(ellie_core::definite::types::bool::BoolType {
+                        value: variable.data.value == "true",
+                    })),
+                    None,
+                ));
+            }
+            _ => (),
+        }
+    }
+
+    let enum_variant = |options: &mut super::ItemParserProcessorOptions,
+                        enum_type: EnumType,
+                        variant_idx: usize,
+                        binds: bool| {
+        if find_enum(options.parser, options.page_hash, target_type)
+            .is_none_or(|target_enum| target_enum.hash != enum_type.hash)
+        {
+            return Err(mismatch(target_type.to_string(), enum_type.name));
+        }
+        Ok(MatchPattern::EnumVariant {
+            enum_hash: enum_type.hash,
+            variant: enum_type.items[variant_idx].identifier.clone(),
+            variant_idx,
+            binds,
+        })
+    };
+
+    //Enum variants with data bind it to a variable: `Shape.Circle(radius)`
+    if let Processors::FunctionCall(function_call) = pattern {
+        let target = function_call.data.target.process(
+            TypeParserProcessorOptions::new(options.parser, options.page_hash)
+                .variable_pos(pattern_pos)
+                .build(),
+        )?;
+        if let Some((enum_type, variant_idx)) =
+            find_pattern_variant(options.parser, options.page_hash, &target)
+        {
+            let binding = match (
+                &enum_type.items[variant_idx].value,
+                function_call.data.parameters.as_slice(),
+            ) {
+                (EnumValue::Value(rtype), [parameter]) => match &parameter.value {
+                    Processors::Variable(variable) => PatternBinding {
+                        name: variable.data.value.clone(),
+                        pos: parameter.pos,
+                        rtype: rtype.clone(),
+                    },
+                    _ => {
+                        return Err(vec![error::error_list::ERROR_S27.clone().build_with_path(
+                            vec![],
+                            alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
+                            path.clone(),
+                            parameter.pos,
+                        )])
+                    }
+                },
+                _ => {
+                    return Err(vec![error::error_list::ERROR_S7.clone().build_with_path(
+                        vec![
+                            error::ErrorBuildField::new(
+                                "name",
+                                &alloc::format!(
+                                    "{}.{}",
+                                    enum_type.name,
+                                    enum_type.items[variant_idx].identifier
+                                ),
+                            ),
+                            error::ErrorBuildField::new(
+                                "token",
+                                &match enum_type.items[variant_idx].value {
+                                    EnumValue::Value(_) => "1",
+                                    EnumValue::NoValue => "0",
+                                }
+                                .to_string(),
+                            ),
+                            error::ErrorBuildField::new(
+                                "token2",
+                                &function_call.data.parameters.len().to_string(),
+                            ),
+                        ],
+                        alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
+                        path.clone(),
+                        pattern_pos,
+                    )])
+                }
+            };
+            let pattern = enum_variant(options, enum_type, variant_idx, true)?;
+            return Ok((pattern, Some(binding)));
+        }
+    }
+
+    let value = pattern.process(
+        TypeParserProcessorOptions::new(options.parser, options.page_hash)
+            .variable_pos(pattern_pos)
+            .build(),
+    )?;
+
+    //Enum variants matched without binding their data
+    if let Some((enum_type, variant_idx)) =
+        find_pattern_variant(options.parser, options.page_hash, &value)
+    {
+        return Ok((enum_variant(options, enum_type, variant_idx, false)?, None));
+    }
+
+    let comparison = options.parser.compare_defining_with_type(
+        target_type.clone(),
+        value.clone(),
+        options.page_hash,
+    )?;
+    if !comparison.same {
+        return Err(mismatch(comparison.first, comparison.second));
+    }
+    Ok((MatchPattern::Value(value), None))
+}
+
+impl super::ItemParserProcessor for Match {
+    fn process(&self, options: &mut super::ItemParserProcessorOptions) -> bool {
+        let page = options.parser.pages.nth(options.page_idx).unwrap().clone();
+        let path = page.path.clone();
+
+        let target = match self.target.current.process(
+            TypeParserProcessorOptions::new(options.parser, options.page_hash)
+                .variable_pos(self.pos)
+                .build(),
+        ) {
+            Ok(target) => target,
+            Err(e) => {
+                options.parser.informations.extend(&e);
+                return false;
+            }
+        };
+
+        let mut errors = Vec::new();
+        let target_type = match resolve_type(
+            target.clone(),
+            options.page_hash,
+            options.parser,
+            &mut errors,
+            Some(self.target_pos),
+        ) {
+            Some(target_type) => target_type,
+            None => {
+                options.parser.informations.extend(&errors);
+                return false;
+            }
+        };
+
+        //Null is matched by its own pattern, rest of the arms match the inner type
+        let (inner_type, nullable) = match &target_type {
+            DefinerCollecting::ParentGeneric(e) if e.rtype == "nullAble" => {
+                (e.generics[0].value.clone(), true)
+            }
+            _ => (target_type.clone(), false),
+        };
+
+        let match_hash = generate_hash_usize();
+        let mut arms = Vec::new();
+        let mut has_errors = false;
+
+        for arm in &self.arms {
+            let (pattern, binding) = match resolve_pattern(
+                options,
+                &arm.pattern.current,
+                arm.pattern_pos,
+                &inner_type,
+                nullable,
+            ) {
+                Ok(resolved) => resolved,
+                Err(e) => {
+                    options.parser.informations.extend(&e);
+                    has_errors = true;
+                    continue;
+                }
+            };
+
+            let inner_page_id: usize = generate_hash_usize();
+            let mut dependencies = vec![ellie_tokenizer::tokenizer::Dependency {
+                hash: page.hash,
+                processed: false,
+                module: None,
+                deep_link: Some(page.hash),
+                public: false,
+            }];
+            dependencies.extend(page.dependencies.clone());
+
+            let mut items = Vec::new();
+
+            //Bound variable is the first item of the arm's page, its value is set by the match itself.
+            if let Some(binding) = binding {
+                let variable_value = generate_type_from_defining(
+                    binding.rtype.clone(),
+                    options.page_hash,
+                    options.parser,
+                )
+                .filter(|value| !matches!(value, Types::Dynamic));
+
+                items.push(ellie_tokenizer::processors::items::Processors::Variable(
+                    ellie_tokenizer::syntax::items::variable::VariableCollector {
+                        data: ellie_tokenizer::syntax::items::variable::Variable {
+                            name: binding.name,
+                            constant: true,
+                            public: false,
+                            has_type: true,
+                            has_value: variable_value.is_some(),
+                            value: variable_value
+                                .map(|value| Processors::default().from_definite(value))
+                                .unwrap_or_default(),
+                            pos: binding.pos,
+                            name_pos: binding.pos,
+                            type_pos: binding.pos,
+                            rtype: ellie_tokenizer::syntax::items::definers::DefinerCollector {
+                                definer_type: Converter::from_definite(
+                                    ellie_tokenizer::syntax::items::definers::DefinerTypes::Dynamic,
+                                    binding.rtype,
+                                ),
+                                complete: true,
+                            },
+                            hash: generate_hash_usize(),
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    },
+                ));
+            }
+
+            items.extend(arm.body.clone());
+            let inner = ellie_tokenizer::tokenizer::Page {
+                hash: inner_page_id,
+                inner: Some(page.hash),
+                path: page.path.clone(),
+                page_type: PageType::ConditionBody(ConditionPageType {
+                    page_hash: options.page_hash,
+                    condition_hash: match_hash,
+                    chain_type: ConditionType::If,
+                    keyword_pos: arm.pattern_pos,
+                }),
+                items,
+                dependents: vec![],
+                dependencies,
+                ..Default::default()
+            };
+            options.parser.pages.push_page(inner);
+
+            arms.push(match_type::MatchArm {
+                pattern,
+                pattern_pos: arm.pattern_pos,
+                body_pos: arm.body_pos,
+                inner_page_id,
+            });
+        }
+
+        if has_errors {
+            return false;
+        }
+
+        //Wildcard covers everything, otherwise enums need all of their variants and bools both values
+        let mut missing = Vec::new();
+        if !arms
+            .iter()
+            .any(|arm| matches!(arm.pattern, MatchPattern::Wildcard))
+        {
+            if let Some(enum_type) = find_enum(options.parser, options.page_hash, &inner_type) {
+                for (variant_idx, item) in enum_type.items.iter().enumerate() {
+                    if !arms.iter().any(|arm| {
+                        matches!(&arm.pattern, MatchPattern::EnumVariant { variant_idx: idx, .. } if *idx == variant_idx)
+                    }) {
+                        missing.push(alloc::format!("{}.{}", enum_type.name, item.identifier));
+                    }
+                }
+            } else if inner_type.to_string() == "bool" {
+                for value in [true, false] {
+                    if !arms.iter().any(|arm| {
+                        matches!(&arm.pattern, MatchPattern::Value(Types::Bool(e)) if e.value == value)
+                    }) {
+                        missing.push(value.to_string());
+                    }
+                }
+            } else {
+                missing.push("_".to_string());
+            }
+
+            if nullable && !arms.iter().any(|arm| arm.pattern == MatchPattern::Null) {
+                missing.push("null".to_string());
+            }
+        }
+
+        if !missing.is_empty() {
+            options.parser.informations.push(
+                &error::error_list::ERROR_S74.clone().build_with_path(
+                    vec![
+                        error::ErrorBuildField {
+                            key: "token".to_owned(),
+                            value: target_type.to_string(),
+                        },
+                        error::ErrorBuildField {
+                            key: "token1".to_owned(),
+                            value: missing.join(", "),
+                        },
+                    ],
+                    alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
+                    path,
+                    self.target_pos,
+                ),
+            );
+            return false;
+        }
+
+        options
+            .parser
+            .processed_pages
+            .nth_mut(options.processed_page_idx)
+            .unwrap()
+            .items
+            .push(ellie_core::definite::items::Collecting::Match(
+                match_type::Match {
+                    target,
+                    target_type,
+                    target_pos: self.target_pos,
+                    arms,
+                    hash: match_hash,
+                    pos: self.pos,
+                },
+            ));
+        true
+    }
+}
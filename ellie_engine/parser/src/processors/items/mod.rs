@@ -5,6 +5,7 @@ pub mod class;
 pub mod condition;
 pub mod constructor;
 pub mod enum_type;
+pub mod extend;
 pub mod file_key;
 pub mod for_loop;
 pub mod function;
@@ -13,10 +14,13 @@ pub mod getter;
 pub mod getter_call;
 pub mod go;
 pub mod import;
+pub mod interface;
 pub mod loop_type;
+pub mod match_type;
 pub mod ret;
 pub mod setter;
 pub mod setter_call;
+pub mod type_def;
 pub mod variable;
 
 pub struct ItemParserProcessorOptions<'a> {
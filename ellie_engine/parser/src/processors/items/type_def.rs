@@ -0,0 +1,168 @@
+use alloc::{borrow::ToOwned, vec, vec::Vec};
+use ellie_core::{definite::items::Collecting, defs, error, utils};
+use ellie_tokenizer::{processors::items::Processors, syntax::items::type_def::TypeDef};
+
+use crate::processors::definer::{DefinerParserProcessor, DefinerParserProcessorOptions};
+
+impl super::ItemParserProcessor for TypeDef {
+    fn process(&self, options: &mut super::ItemParserProcessorOptions) -> bool {
+        let (duplicate, found) =
+            options
+                .parser
+                .is_duplicate(options.page_hash, self.name.clone(), self.hash, self.pos);
+
+        let page = options.parser.pages.nth(options.page_idx).unwrap().clone();
+        let type_def_key_definings = options
+            .parser
+            .processed_pages
+            .nth_mut(options.processed_page_idx)
+            .unwrap()
+            .unassigned_file_keys
+            .clone();
+
+        if utils::is_reserved(
+            &self.name,
+            type_def_key_definings
+                .iter()
+                .any(|x| x.key_name == "dont_fix_variant"),
+        ) {
+            options.parser.informations.push(
+                &error::error_list::ERROR_S21.clone().build_with_path(
+                    vec![error::ErrorBuildField::new("token", &self.name)],
+                    alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
+                    page.path.clone(),
+                    self.name_pos,
+                ),
+            );
+        }
+
+        if duplicate {
+            let mut err = error::error_list::ERROR_S24.clone().build_with_path(
+                vec![error::ErrorBuildField::new("token", &self.name)],
+                alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
+                page.path.clone(),
+                self.name_pos,
+            );
+            if let Some((found_page, cursor_pos)) = found {
+                err.reference_block = Some((cursor_pos, found_page.path));
+                err.reference_message = "Prime is here".to_owned();
+                err.semi_assist = true;
+            }
+            options.parser.informations.push(&err);
+            return false;
+        }
+
+        for (index, generic) in self.generic_definings.iter().enumerate() {
+            if let Some(other_index) = self
+                .generic_definings
+                .iter()
+                .position(|g| g.name == generic.name)
+            {
+                if other_index < index {
+                    let mut err = error::error_list::ERROR_S10.clone().build_with_path(
+                        vec![],
+                        alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
+                        page.path.clone(),
+                        generic.pos,
+                    );
+                    err.reference_block =
+                        Some((self.generic_definings[other_index].pos, page.path.clone()));
+                    err.reference_message = "Prime is here".to_owned();
+                    err.semi_assist = true;
+                    options.parser.informations.push(&err);
+                    return false;
+                }
+            }
+        }
+
+        // Generic parameters of the alias only live while resolving its definer,
+        // they're placed in a page of their own so the definer can reference them.
+        let definer_page_id = if self.generic_definings.is_empty() {
+            options.page_hash
+        } else {
+            let inner_page_id: usize = ellie_core::utils::generate_hash_usize();
+            let mut dependencies = vec![ellie_tokenizer::tokenizer::Dependency {
+                hash: page.hash,
+                processed: false,
+                module: None,
+                deep_link: Some(page.hash),
+                public: false,
+            }];
+            dependencies.extend(page.dependencies.iter().map(|d| {
+                let mut dep = d.clone();
+                dep.deep_link = Some(page.hash);
+                dep
+            }));
+
+            let inner = ellie_tokenizer::tokenizer::Page {
+                hash: inner_page_id,
+                inner: Some(page.hash),
+                path: page.path.clone(),
+                items: self
+                    .generic_definings
+                    .iter()
+                    .map(|generic| {
+                        Processors::GenericItem(
+                            ellie_tokenizer::syntax::items::generic_item::GenericItem {
+                                generic_name: generic.name.clone(),
+                                pos: generic.pos,
+                                hash: generic.hash,
+                                bound: None,
+                            },
+                        )
+                    })
+                    .collect(),
+                dependents: vec![],
+                dependencies,
+                page_type: ellie_tokenizer::tokenizer::PageType::RawBody,
+                unreachable: false,
+                unreachable_range: defs::Cursor::default(),
+                processed: false,
+                module: false,
+            };
+            options.parser.pages.push_page(inner);
+            options.parser.process_page(inner_page_id);
+            inner_page_id
+        };
+
+        let rtype = match self.rtype.definer_type.process(
+            DefinerParserProcessorOptions::new(options.parser, definer_page_id)
+                .ignore_hash(self.hash)
+                .build(),
+        ) {
+            Ok(rtype) => rtype,
+            Err(errors) => {
+                options.parser.informations.extend(&errors);
+                return false;
+            }
+        };
+
+        let processed_page = options
+            .parser
+            .processed_pages
+            .nth_mut(options.processed_page_idx)
+            .unwrap();
+
+        processed_page.items.push(Collecting::TypeDef(
+            ellie_core::definite::items::type_def::TypeDef {
+                name: self.name.clone(),
+                public: self.public,
+                generic_definings: self
+                    .generic_definings
+                    .iter()
+                    .map(|x| ellie_core::definite::items::type_def::GenericDefining {
+                        name: x.name.clone(),
+                        hash: x.hash,
+                        pos: x.pos,
+                    })
+                    .collect::<Vec<_>>(),
+                rtype,
+                name_pos: self.name_pos,
+                rtype_pos: self.rtype_pos,
+                pos: self.pos,
+                hash: self.hash,
+            },
+        ));
+        true
+    }
+}
@@ -8,7 +8,7 @@ use ellie_core::{
     },
     defs, error,
 };
-use ellie_tokenizer::syntax::items::variable::VariableCollector;
+use ellie_tokenizer::syntax::items::{definers::DefinerTypes, variable::VariableCollector};
 
 use crate::processors::{
     definer::{DefinerParserProcessor, DefinerParserProcessorOptions},
@@ -80,6 +80,7 @@ impl super::ItemParserProcessor for VariableCollector {
                         value: match key.value {
                             Types::Byte(e) => Types::Byte(e),
                             Types::Integer(e) => Types::Integer(e),
+                            Types::BigInteger(e) => Types::BigInteger(e),
                             Types::Decimal(e) => Types::Decimal(e),
                             Types::Bool(e) => Types::Bool(e),
                             Types::String(e) => Types::String(e),
@@ -164,7 +165,23 @@ impl super::ItemParserProcessor for VariableCollector {
                 }
             };
 
-            let resolved_type = if !self.data.has_value {
+            let resolved_type = if !self.data.has_value
+                && matches!(&resolved_defining, Ok(DefinerCollecting::ParentGeneric(parent_generic)) if parent_generic.rtype == "array")
+            {
+                //Arrays without value start empty instead of null, so they can be filled with their methods
+                Ok(Types::Array(
+                    ellie_core::definite::types::array::ArrayType {
+                        collective: vec![],
+                        fixed: false,
+                        pos: self.data.pos,
+                    },
+                ))
+            } else if !self.data.has_value
+                && matches!(&resolved_defining, Ok(DefinerCollecting::ParentGeneric(parent_generic)) if parent_generic.rtype == "nullAble")
+            {
+                //Nullable variables without value start as null, so they can be compared with it
+                Ok(Types::Null)
+            } else if !self.data.has_value {
                 let null_able_class = crate::deep_search_extensions::find_type(
                     "nullAble".to_string(),
                     options.page_hash,
@@ -240,6 +257,18 @@ impl super::ItemParserProcessor for VariableCollector {
                 )
             };
 
+            //Arrays declared with a size can't grow, they're stored as static arrays
+            let resolved_type = match resolved_type {
+                Ok(Types::Array(mut array))
+                    if self.data.has_value
+                        && matches!(&self.data.rtype.definer_type, DefinerTypes::Array(array_type) if array_type.size.is_some()) =>
+                {
+                    array.fixed = true;
+                    Ok(Types::Array(array))
+                }
+                resolved_type => resolved_type,
+            };
+
             if resolved_type.is_err() || resolved_defining.is_err() {
                 let mut type_error = resolved_type.err().unwrap_or(vec![]);
                 let defining_error = resolved_defining.err().unwrap_or(vec![]);
@@ -46,6 +46,7 @@ impl super::TypeParserProcessor for array_type::ArrayTypeCollector {
 
             Ok(types::Types::Array(types::array::ArrayType {
                 collective,
+                fixed: false,
                 pos: self.data.pos,
             }))
         } else {
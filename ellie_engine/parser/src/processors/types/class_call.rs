@@ -45,6 +45,25 @@ impl super::TypeParserProcessor for class_call_type::ClassCallCollector {
                 );
                 Err(errors)
             }
+            Processors::BigInteger(_) => {
+                errors.push(
+                    error::error_list::ERROR_S11.clone().build_with_path(
+                        vec![error::ErrorBuildField {
+                            key: "token".to_string(),
+                            value: "bigint".to_string(),
+                        }],
+                        alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
+                        options
+                            .parser
+                            .find_page(options.page_id)
+                            .unwrap()
+                            .path
+                            .clone(),
+                        self.data.keyword_pos,
+                    ),
+                );
+                Err(errors)
+            }
             Processors::Byte(_) => {
                 errors.push(
                     error::error_list::ERROR_S11.clone().build_with_path(
@@ -296,6 +315,20 @@ impl super::TypeParserProcessor for class_call_type::ClassCallCollector {
                                 }
                                 Err(errors)
                             } else {
+                                let generics = resolved_generics
+                                    .iter()
+                                    .cloned()
+                                    .zip(self.data.generic_parameters.iter().map(|x| x.pos))
+                                    .collect::<Vec<_>>();
+                                let bound_errors = options.parser.check_generic_bounds(
+                                    options.page_id,
+                                    &e,
+                                    &generics,
+                                );
+                                if !bound_errors.is_empty() {
+                                    errors.extend(bound_errors);
+                                    return Err(errors);
+                                }
                                 let constructor = e.body.iter().find_map(|x| match x {
                                     ellie_tokenizer::processors::items::Processors::Constructor(
                                         e,
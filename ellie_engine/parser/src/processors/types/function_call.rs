@@ -1,13 +1,26 @@
 use alloc::{borrow::ToOwned, boxed::Box, string::ToString, vec, vec::Vec};
 use ellie_core::{
-    definite::{definers::DefinerCollecting, types},
+    definite::{
+        definers::DefinerCollecting,
+        items::{
+            enum_type::{EnumType, EnumValue},
+            type_def::GenericDefining,
+        },
+        types,
+        types::class_instance::AttributeType,
+    },
     error,
 };
 use ellie_tokenizer::syntax::types::function_call_type;
 
-use crate::deep_search_extensions::{generate_type_from_defining, resolve_type};
+use crate::{
+    deep_search_extensions::{
+        deep_search_hash, find_enum_variant, resolve_type, ProcessedDeepSearchItems,
+    },
+    parser::Parser,
+};
 
-use super::TypeParserProcessorOptions;
+use super::{TypeParserProcessor, TypeParserProcessorOptions};
 
 impl super::TypeParserProcessor for function_call_type::FunctionCallCollector {
     fn process(
@@ -26,8 +39,18 @@ impl super::TypeParserProcessor for function_call_type::FunctionCallCollector {
                 .build(),
         );
 
+        //Enum variants with data are constructed by calling them
+        if let Ok(types::Types::Reference(reference)) = &target {
+            if let Some((enum_type, variant_idx)) =
+                find_enum_variant(options.parser, options.page_id, reference)
+            {
+                return process_enum_data(self, options, reference.clone(), enum_type, variant_idx);
+            }
+        }
+
         match target {
             Ok(e) => {
+                let function_generics = find_function_generics(options.parser, options.page_id, &e);
                 let resolved = resolve_type(
                     e,
                     options.page_id,
@@ -130,10 +153,88 @@ impl super::TypeParserProcessor for function_call_type::FunctionCallCollector {
                                 return Err(errors);
                             }
 
+                            //Generic parameters are inferred from the arguments, first argument decides
+                            let mut inferred = Vec::new();
+                            if errors.is_empty() && !function_generics.is_empty() {
+                                for (param, used) in function
+                                    .params
+                                    .iter()
+                                    .filter(|x| !matches!(x, DefinerCollecting::Generic(generic) if generic.rtype == "self"))
+                                    .zip(used_params.iter())
+                                {
+                                    infer_generics(param, &used.1, &function_generics, &mut inferred);
+                                }
+
+                                for generic in &function_generics {
+                                    if !inferred.iter().any(|(hash, _)| *hash == generic.hash) {
+                                        let path = options
+                                            .parser
+                                            .find_page(options.page_id)
+                                            .unwrap()
+                                            .path
+                                            .clone();
+                                        //Point the argument that doesn't fit the parameter using the generic
+                                        let (expected, found, pos) = match function
+                                            .params
+                                            .iter()
+                                            .filter(|x| !matches!(x, DefinerCollecting::Generic(generic) if generic.rtype == "self"))
+                                            .zip(used_params.iter())
+                                            .find(|(param, _)| uses_generics(param, core::slice::from_ref(generic)))
+                                        {
+                                            Some((param, used)) => {
+                                                (param.to_string(), used.1.to_string(), used.2)
+                                            }
+                                            None => (
+                                                generic.name.clone(),
+                                                "dyn".to_owned(),
+                                                self.data.target_pos,
+                                            ),
+                                        };
+                                        let mut err =
+                                            error::error_list::ERROR_S3.clone().build_with_path(
+                                                vec![
+                                                    error::ErrorBuildField::new(
+                                                        "token1", &expected,
+                                                    ),
+                                                    error::ErrorBuildField::new("token2", &found),
+                                                ],
+                                                alloc::format!(
+                                                    "{}:{}:{}",
+                                                    file!().to_owned(),
+                                                    line!(),
+                                                    column!()
+                                                ),
+                                                path.clone(),
+                                                pos,
+                                            );
+                                        err.reference_block = Some((generic.pos, path));
+                                        err.reference_message = "Defined here".to_owned();
+                                        errors.push(err);
+                                    }
+                                }
+                            }
+                            let substitute = |defining: &DefinerCollecting| {
+                                let mut defining = defining.clone();
+                                for (hash, inferred_type) in &inferred {
+                                    defining.convert_generic(*hash, inferred_type.clone());
+                                }
+                                defining
+                            };
+
                             if errors.is_empty() {
-                                for (index, param) in function.params.iter().filter(|x| matches!(x, DefinerCollecting::Generic(generic) if generic.rtype != "self")).enumerate() {
+                                for (index, param) in function
+                                    .params
+                                    .iter()
+                                    .filter(|x| !matches!(x, DefinerCollecting::Generic(generic) if generic.rtype == "self"))
+                                    .map(|x| (uses_generics(x, &function_generics), substitute(x)))
+                                    .enumerate()
+                                    .filter(|(_, (generic_param, x))| *generic_param || matches!(x, DefinerCollecting::Generic(_)))
+                                {
+                                    let param = &param.1;
                                     let used = used_params[index].1.clone();
-                                    if !param.same_as(used.clone()) {
+                                    if !param.same_as(used.clone())
+                                        && !options.parser.satisfies_interface(param, &used)
+                                    {
                                         errors.push(
                                             error::error_list::ERROR_S3
                                                 .clone()
@@ -184,7 +285,7 @@ impl super::TypeParserProcessor for function_call_type::FunctionCallCollector {
                                             ellie_core::definite::types::function_call::FunctionCall {
                                                 target: Box::new(resolved),
                                                 target_pos: ellie_core::defs::Cursor::default(),
-                                                returning: *function.returning.clone(),
+                                                returning: substitute(&function.returning),
                                                 params: function.params.iter()
                                                 .filter(|x| match x {
                                                     DefinerCollecting::Generic(generic) => {
@@ -212,42 +313,6 @@ impl super::TypeParserProcessor for function_call_type::FunctionCallCollector {
                                 Err(errors)
                             }
                         }
-                        DefinerCollecting::EnumField(e) => {
-                            Ok(ellie_core::definite::types::Types::EnumData(
-                                ellie_core::definite::types::enum_data::EnumData {
-                                    reference: Box::new(types::Types::VariableType(
-                                        types::variable::VariableType {
-                                            value: e.name.clone(),
-                                            reference: e.hash,
-                                            pos: ellie_core::defs::Cursor::default(),
-                                        },
-                                    )),
-                                    reference_pos: ellie_core::defs::Cursor::default(),
-                                    brace_pos: ellie_core::defs::Cursor::default(),
-                                    value: match e.field_data.clone() {
-                                        ellie_core::definite::definers::EnumFieldData::NoData => {
-                                            types::enum_data::Pointer::NoData
-                                        }
-                                        ellie_core::definite::definers::EnumFieldData::Data(
-                                            rtype,
-                                        ) => {
-                                            let value = generate_type_from_defining(
-                                                *rtype,
-                                                options.page_id,
-                                                options.parser,
-                                            )
-                                            .unwrap();
-                                            ellie_core::definite::types::enum_data::Pointer::Data(
-                                                Box::new(value),
-                                            )
-                                        }
-                                    },
-                                    field_name: e.field_name.clone(),
-                                    //TODO this is not correct
-                                    pos: ellie_core::defs::Cursor::default(),
-                                },
-                            ))
-                        }
                         _ => {
                             errors.push(
                                 error::error_list::ERROR_S25.clone().build_with_path(
@@ -283,3 +348,180 @@ impl super::TypeParserProcessor for function_call_type::FunctionCallCollector {
         }
     }
 }
+
+/// Construct an enum value from a variant call such as `Shape.Circle(3)`
+fn process_enum_data(
+    function_call: &function_call_type::FunctionCallCollector,
+    options: &mut super::TypeParserProcessorOptions,
+    reference: types::reference::ReferenceType,
+    enum_type: EnumType,
+    variant_idx: usize,
+) -> Result<types::Types, Vec<error::Error>> {
+    let mut errors = Vec::new();
+    let path = options
+        .parser
+        .find_page(options.page_id)
+        .unwrap()
+        .path
+        .clone();
+    let variant = &enum_type.items[variant_idx];
+    let data_type = match &variant.value {
+        EnumValue::Value(data_type) => data_type.clone(),
+        EnumValue::NoValue => unreachable!("Variants without data are not references"),
+    };
+
+    if function_call.data.parameters.len() != 1 {
+        errors.push(error::error_list::ERROR_S7.clone().build_with_path(
+            vec![
+                error::ErrorBuildField::new(
+                    "name",
+                    &alloc::format!("{}.{}", enum_type.name, variant.identifier),
+                ),
+                error::ErrorBuildField::new("token", &"1".to_owned()),
+                error::ErrorBuildField::new(
+                    "token2",
+                    &function_call.data.parameters.len().to_string(),
+                ),
+            ],
+            alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
+            path,
+            function_call.data.target_pos,
+        ));
+        return Err(errors);
+    }
+
+    let parameter = &function_call.data.parameters[0];
+    let value = parameter.value.process(
+        options
+            .dont_include_setter()
+            .dont_exclude_getter()
+            .dont_ignore_type()
+            .build(),
+    )?;
+
+    let comparison =
+        options
+            .parser
+            .compare_defining_with_type(data_type, value.clone(), options.page_id)?;
+
+    if !comparison.same {
+        let mut err = error::error_list::ERROR_S3.clone().build_with_path(
+            vec![
+                error::ErrorBuildField::new("token1", &comparison.first),
+                error::ErrorBuildField::new("token2", &comparison.second),
+            ],
+            alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
+            path.clone(),
+            parameter.pos,
+        );
+        err.reference_block = Some((variant.type_pos, path));
+        err.reference_message = "Defined here".to_owned();
+        err.semi_assist = true;
+        errors.push(err);
+        return Err(errors);
+    }
+
+    Ok(types::Types::EnumData(types::enum_data::EnumData {
+        reference: reference.reference,
+        reference_pos: reference.reference_pos,
+        brace_pos: function_call.data.pos,
+        value: types::enum_data::Pointer::Data(Box::new(value)),
+        field_name: variant.identifier.clone(),
+        pos: function_call.data.pos,
+    }))
+}
+
+/// Find the generic parameters of a called function or method, calls to anything else have none
+fn find_function_generics(
+    parser: &mut Parser,
+    page_id: usize,
+    target: &types::Types,
+) -> Vec<GenericDefining> {
+    let (page_id, hash) = match target {
+        types::Types::VariableType(variable) => (page_id, variable.reference),
+        types::Types::Reference(reference) => match reference.index_chain.last() {
+            Some(attribute) if attribute.rtype == AttributeType::Method => {
+                (attribute.page_hash, attribute.hash)
+            }
+            _ => return Vec::new(),
+        },
+        _ => return Vec::new(),
+    };
+    let search = deep_search_hash(parser, page_id, hash, vec![], 0);
+    match search.found_item {
+        ProcessedDeepSearchItems::Function(function) if search.found => function.generic_definings,
+        ProcessedDeepSearchItems::NativeFunction(function) if search.found => {
+            function.generic_definings
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// Whether the definer refers any of the given generics
+fn uses_generics(defining: &DefinerCollecting, generics: &[GenericDefining]) -> bool {
+    match defining {
+        DefinerCollecting::Generic(generic) => generics.iter().any(|x| x.hash == generic.hash),
+        DefinerCollecting::Array(array) => uses_generics(&array.rtype, generics),
+        DefinerCollecting::Nullable(nullable) => uses_generics(&nullable.value, generics),
+        DefinerCollecting::Collective(collective) => {
+            uses_generics(&collective.key, generics) || uses_generics(&collective.value, generics)
+        }
+        DefinerCollecting::ParentGeneric(parent_generic) => parent_generic
+            .generics
+            .iter()
+            .any(|x| uses_generics(&x.value, generics)),
+        DefinerCollecting::Cloak(cloak) => cloak.rtype.iter().any(|x| uses_generics(x, generics)),
+        DefinerCollecting::Function(function) => {
+            function.params.iter().any(|x| uses_generics(x, generics))
+                || uses_generics(&function.returning, generics)
+        }
+        _ => false,
+    }
+}
+
+/// Match a parameter definer with the argument's type, recording the types its generics are used as
+fn infer_generics(
+    param: &DefinerCollecting,
+    used: &DefinerCollecting,
+    generics: &[GenericDefining],
+    inferred: &mut Vec<(usize, DefinerCollecting)>,
+) {
+    match (param, used) {
+        //Generic is inferred from its first use, later uses are compared with it
+        (DefinerCollecting::Generic(generic), _)
+            if generics.iter().any(|x| x.hash == generic.hash)
+                && !inferred.iter().any(|(hash, _)| *hash == generic.hash) =>
+        {
+            inferred.push((generic.hash, used.clone()));
+        }
+        (DefinerCollecting::Array(param), DefinerCollecting::Array(used)) => {
+            infer_generics(&param.rtype, &used.rtype, generics, inferred)
+        }
+        (DefinerCollecting::Nullable(param), DefinerCollecting::Nullable(used)) => {
+            infer_generics(&param.value, &used.value, generics, inferred)
+        }
+        (DefinerCollecting::Collective(param), DefinerCollecting::Collective(used)) => {
+            infer_generics(&param.key, &used.key, generics, inferred);
+            infer_generics(&param.value, &used.value, generics, inferred);
+        }
+        (DefinerCollecting::ParentGeneric(param), DefinerCollecting::ParentGeneric(used))
+            if param.hash == used.hash =>
+        {
+            for (param, used) in param.generics.iter().zip(used.generics.iter()) {
+                infer_generics(&param.value, &used.value, generics, inferred);
+            }
+        }
+        (DefinerCollecting::Cloak(param), DefinerCollecting::Cloak(used)) => {
+            for (param, used) in param.rtype.iter().zip(used.rtype.iter()) {
+                infer_generics(param, used, generics, inferred);
+            }
+        }
+        (DefinerCollecting::Function(param), DefinerCollecting::Function(used)) => {
+            for (param, used) in param.params.iter().zip(used.params.iter()) {
+                infer_generics(param, used, generics, inferred);
+            }
+            infer_generics(&param.returning, &used.returning, generics, inferred);
+        }
+        _ => (),
+    }
+}
@@ -11,6 +11,7 @@ impl super::TypeParserProcessor for negative_type::Negative {
             Ok(value) => match value {
                 types::Types::Byte(_)
                 | types::Types::Integer(_)
+                | types::Types::BigInteger(_)
                 | types::Types::Decimal(_)
                 | types::Types::Bool(_)
                 | types::Types::Negative(_) => {
@@ -1,4 +1,7 @@
-use crate::deep_search_extensions::{find_type, resolve_type, ProcessedDeepSearchItems};
+use crate::deep_search_extensions::{
+    find_extension_methods, find_interface_members, find_type, member_generic_hashes, resolve_type,
+    ProcessedDeepSearchItems,
+};
 use alloc::{borrow::ToOwned, boxed::Box};
 use ellie_core::definite::types::reference::IndexChainAttribute;
 
@@ -47,10 +50,15 @@ impl super::TypeParserProcessor for reference_type::ReferenceTypeCollector {
                 ) -> Option<types::Types> {
                     match rtype {
                         DefinerCollecting::Generic(generic) => {
-                            if generic.rtype == "int" {
+                            if let Some(size) =
+                                ellie_core::definite::types::integer::IntegerSize::from_type_name(
+                                    &generic.rtype,
+                                )
+                            {
                                 Some(types::Types::Integer(
                                     ellie_core::definite::types::integer::IntegerType {
                                         value: 0,
+                                        size,
                                         pos: ellie_core::defs::Cursor::default(),
                                     },
                                 ))
@@ -148,6 +156,7 @@ impl super::TypeParserProcessor for reference_type::ReferenceTypeCollector {
                                                     location: ellie_core::defs::Cursor::default(),
                                                 },
                                             ],
+                                            fixed: false,
                                             pos: ellie_core::defs::Cursor::default(),
                                         },
                                     )
@@ -280,12 +289,19 @@ impl super::TypeParserProcessor for reference_type::ReferenceTypeCollector {
                             if hash_deep_search.found {
                                 match hash_deep_search.found_item {
                                     ProcessedDeepSearchItems::Class(class_page) => {
+                                        //Body of a class defined after the current page is processed on demand
+                                        if parser
+                                            .find_processed_page(class_page.inner_page_id)
+                                            .is_none()
+                                        {
+                                            parser.process_page(class_page.inner_page_id);
+                                        }
                                         match parser
                                             .find_processed_page(class_page.inner_page_id)
                                             .cloned()
                                         {
                                             Some(class_inner_page) => {
-                                                let attributes = class_inner_page.items.iter().filter_map(|item| {
+                                                let mut attributes = class_inner_page.items.iter().filter_map(|item| {
                                                     let class_attribute_idx = class_inner_page.items.iter().filter_map(|x| match x {
                                                         Collecting::Variable(x) => Some(x),
                                                         _ => None
@@ -383,6 +399,29 @@ impl super::TypeParserProcessor for reference_type::ReferenceTypeCollector {
                                                         _ => None,
                                                     }
                                                 }).collect::<Vec<_>>();
+                                                // Methods added with `extend` carry the implicit `self` as their first parameter
+                                                attributes.extend(find_extension_methods(parser, page_id, class_page.hash).into_iter().filter_map(|(extend_page, item)| {
+                                                    let (name, hash, parameters, return_type) = match item {
+                                                        Collecting::Function(e) => (e.name, e.hash, e.parameters, e.return_type),
+                                                        Collecting::NativeFunction(e) => (e.name, e.hash, e.parameters, e.return_type),
+                                                        _ => return None,
+                                                    };
+                                                    Some(Attribute {
+                                                        rtype: AttributeType::Method,
+                                                        name,
+                                                        hash,
+                                                        page_hash: extend_page,
+                                                        class_attribute_idx: 0,
+                                                        value: DefinerCollecting::Function(
+                                                            ellie_core::definite::definers::FunctionType {
+                                                                params: parameters.iter().skip(1).map(|param| {
+                                                                    param.rtype.clone()
+                                                                }).collect::<Vec<_>>(),
+                                                                returning: Box::new(return_type),
+                                                            }
+                                                        ),
+                                                    })
+                                                }));
                                                 Ok(attributes)
                                             }
                                             None => {
@@ -390,46 +429,84 @@ impl super::TypeParserProcessor for reference_type::ReferenceTypeCollector {
                                             }
                                         }
                                     }
-                                    ProcessedDeepSearchItems::GenericItem(_) => Ok(vec![]),
+                                    ProcessedDeepSearchItems::Interface(interface) => {
+                                        let members =
+                                            find_interface_members(parser, interface.inner_page_id);
+                                        let properties = members
+                                            .iter()
+                                            .filter_map(|x| match x {
+                                                Collecting::Variable(x) => Some(x.hash),
+                                                _ => None,
+                                            })
+                                            .collect::<Vec<_>>();
+                                        Ok(members.into_iter().filter_map(|item| match item {
+                                            Collecting::Variable(e) => Some(Attribute {
+                                                rtype: AttributeType::Property,
+                                                name: e.name,
+                                                hash: e.hash,
+                                                page_hash: interface.inner_page_id,
+                                                class_attribute_idx: properties.iter().position(|x| *x == e.hash).unwrap(),
+                                                value: e.rtype,
+                                            }),
+                                            Collecting::NativeFunction(e) => Some(Attribute {
+                                                rtype: AttributeType::Method,
+                                                name: e.name,
+                                                hash: e.hash,
+                                                page_hash: interface.inner_page_id,
+                                                class_attribute_idx: 0,
+                                                value: DefinerCollecting::Function(
+                                                    ellie_core::definite::definers::FunctionType {
+                                                        params: e.parameters.iter().map(|param| {
+                                                            param.rtype.clone()
+                                                        }).collect::<Vec<_>>(),
+                                                        returning: Box::new(e.return_type),
+                                                    }
+                                                ),
+                                            }),
+                                            _ => None,
+                                        }).collect())
+                                    }
+                                    // Bounded generics expose the members of their interface
+                                    ProcessedDeepSearchItems::GenericItem(generic) => {
+                                        match generic.bound {
+                                            Some(bound) => resolve_chain(
+                                                DefinerCollecting::Generic(
+                                                    ellie_core::definite::definers::GenericType {
+                                                        rtype: bound.name,
+                                                        hash: bound.hash,
+                                                        pos: bound.pos,
+                                                    },
+                                                ),
+                                                reference_pos,
+                                                page_id,
+                                                parser,
+                                                is_setter,
+                                            ),
+                                            None => Ok(vec![]),
+                                        }
+                                    }
                                     ProcessedDeepSearchItems::Enum(enum_data) => {
-                                        Ok(enum_data.items.iter().map(| item| {
+                                        Ok(enum_data.items.iter().enumerate().map(|(idx, item)| {
                                             Attribute {
                                                 rtype: match item.value {
-                                                    ellie_core::definite::items::enum_type::EnumValue::NoValue => AttributeType::EnumItemData,
+                                                    ellie_core::definite::items::enum_type::EnumValue::NoValue => AttributeType::EnumItemNoData,
                                                     ellie_core::definite::items::enum_type::EnumValue::Value(_) => AttributeType::EnumItemData,
                                                 },
                                                 name: item.identifier.clone(),
-                                                //TODO: Fix this
                                                 hash: 0,
-                                                class_attribute_idx: 0,
+                                                class_attribute_idx: idx,
                                                 page_hash: enum_data.hash,
-                                                value:  match item.value.clone() {
-                                                    ellie_core::definite::items::enum_type::EnumValue::NoValue => {
-                                                        match find_type("void".to_string(), page_id, parser) {
-                                                            Some(e) => ellie_core::definite::definers::DefinerCollecting::Generic(e),
-                                                            None => {
-                                                                errors.push(
-                                                                    error::error_list::ERROR_S38.clone().build_with_path(
-                                                                        vec![error::ErrorBuildField {
-                                                                            key: "void".to_owned(),
-                                                                            value: reference_type.to_string(),
-                                                                        }],
-                                                                        alloc::format!(
-                                                                            "{}:{}:{}",
-                                                                            file!().to_owned(),
-                                                                            line!(),
-                                                                            column!()
-                                                                        ),
-                                                                        parser.find_page(page_id).unwrap().path.clone(),
-                                                                        reference_pos,
-                                                                    ),
-                                                                );
-                                                                DefinerCollecting::Dynamic
-                                                            }
-                                                        }
-                                                    },
-                                                    ellie_core::definite::items::enum_type::EnumValue::Value(e) => e
-                                                },
+                                                value: DefinerCollecting::EnumField(
+                                                    ellie_core::definite::definers::EnumField {
+                                                        field_name: item.identifier.clone(),
+                                                        field_data: match &item.value {
+                                                            ellie_core::definite::items::enum_type::EnumValue::NoValue => ellie_core::definite::definers::EnumFieldData::NoData,
+                                                            ellie_core::definite::items::enum_type::EnumValue::Value(e) => ellie_core::definite::definers::EnumFieldData::Data(Box::new(e.clone())),
+                                                        },
+                                                        name: enum_data.name.clone(),
+                                                        hash: enum_data.hash,
+                                                    }
+                                                ),
                                             }
                                         }).collect())
                                     }
@@ -475,28 +552,33 @@ impl super::TypeParserProcessor for reference_type::ReferenceTypeCollector {
                                                     parser,
                                                     is_setter,
                                                 ) {
-                                                    Ok(e) => {
-                                                        Ok(e.clone()
-                                                            .iter_mut()
-                                                            .map(|attr| {
-                                                                for (i, generic_defining) in
+                                                    Ok(e) => Ok(e
+                                                        .clone()
+                                                        .iter_mut()
+                                                        .map(|attr| {
+                                                            let generic_hashes =
+                                                                member_generic_hashes(
+                                                                    parser,
+                                                                    attr.page_hash,
                                                                     class_item
                                                                         .generic_definings
                                                                         .iter()
-                                                                        .enumerate()
-                                                                {
-                                                                    attr.value.convert_generic(
-                                                                        generic_defining.hash,
-                                                                        rtype.generics[i]
-                                                                            .value
-                                                                            .clone(),
-                                                                    );
-                                                                    // = rtype.generics[i].value.clone();
-                                                                }
-                                                                attr.clone()
-                                                            })
-                                                            .collect::<Vec<_>>())
-                                                    }
+                                                                        .map(|x| x.hash)
+                                                                        .collect(),
+                                                                );
+                                                            for (generic_hash, generic) in
+                                                                generic_hashes
+                                                                    .iter()
+                                                                    .zip(rtype.generics.iter())
+                                                            {
+                                                                attr.value.convert_generic(
+                                                                    *generic_hash,
+                                                                    generic.value.clone(),
+                                                                );
+                                                            }
+                                                            attr.clone()
+                                                        })
+                                                        .collect::<Vec<_>>()),
                                                     Err(e) => {
                                                         errors.extend(e);
                                                         Err(errors)
@@ -592,7 +674,8 @@ impl super::TypeParserProcessor for reference_type::ReferenceTypeCollector {
                             todo!()
                         }
                         ellie_core::definite::definers::DefinerCollecting::Dynamic => todo!(),
-                        DefinerCollecting::EnumField(_) => todo!(),
+                        //Enum fields don't expose any attributes
+                        DefinerCollecting::EnumField(_) => Ok(vec![]),
                         DefinerCollecting::ClassInstance(_) => {
                             todo!("TO BE REMOVED");
                             /*
@@ -808,6 +891,19 @@ impl super::TypeParserProcessor for reference_type::ReferenceTypeCollector {
                 }
 
                 if errors.is_empty() {
+                    //Enum variants without data are values on their own
+                    if let [attribute] = index_chain.as_slice() {
+                        if attribute.rtype == AttributeType::EnumItemNoData {
+                            return Ok(types::Types::EnumData(types::enum_data::EnumData {
+                                reference: Box::new(found_reference),
+                                reference_pos: self.data.reference_pos,
+                                brace_pos: ellie_core::defs::Cursor::default(),
+                                value: types::enum_data::Pointer::NoData,
+                                field_name: self.data.chain[0].value.clone(),
+                                pos: self.data.pos,
+                            }));
+                        }
+                    }
                     Ok(types::Types::Reference(types::reference::ReferenceType {
                         reference: Box::new(found_reference),
                         reference_pos: self.data.reference_pos,
@@ -21,6 +21,32 @@ impl super::TypeParserProcessor for variable_type::VariableTypeCollector {
         options: &mut super::TypeParserProcessorOptions,
     ) -> Result<types::Types, Vec<error::Error>> {
         let mut errors = Vec::new();
+
+        //`null` is a literal, it's comparable with nullable values when nullables are allowed
+        if self.data.value == "null" {
+            return if options.parser.parser_settings.nullables.0 {
+                Ok(types::Types::Null)
+            } else {
+                errors.push(
+                    error::error_list::ERROR_S75.clone().build_with_path(
+                        vec![error::ErrorBuildField {
+                            key: "token".to_owned(),
+                            value: self.data.value.clone(),
+                        }],
+                        alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
+                        options
+                            .parser
+                            .find_page(options.page_id)
+                            .unwrap()
+                            .path
+                            .clone(),
+                        self.data.pos,
+                    ),
+                );
+                Err(errors)
+            };
+        }
+
         let deep_search_result = options.parser.deep_search(
             options.page_id,
             self.data.value.clone(),
@@ -149,11 +175,22 @@ impl super::TypeParserProcessor for variable_type::VariableTypeCollector {
                     }
                 }
                 crate::parser::DeepSearchItems::Function(function) => {
-                    match function.return_type.definer_type.process(
-                        DefinerParserProcessorOptions::new(options.parser, options.page_id)
-                            .optional_ignore_hash(options.ignore_hash)
-                            .build(),
-                    ) {
+                    // Generic parameters only exist in the function's own pages,
+                    // generic return types are checked where the function is defined
+                    let return_type = if function.generic_definings.is_empty() {
+                        function
+                            .return_type
+                            .definer_type
+                            .process(
+                                DefinerParserProcessorOptions::new(options.parser, options.page_id)
+                                    .optional_ignore_hash(options.ignore_hash)
+                                    .build(),
+                            )
+                            .map(|_| ())
+                    } else {
+                        Ok(())
+                    };
+                    match return_type {
                         Ok(_) => {
                             match find_type("function".to_owned(), options.page_id, options.parser)
                             {
@@ -210,6 +247,25 @@ impl super::TypeParserProcessor for variable_type::VariableTypeCollector {
                     }))
                 }
                 crate::parser::DeepSearchItems::GenericItem(_) => todo!(),
+                crate::parser::DeepSearchItems::TypeDef(_)
+                | crate::parser::DeepSearchItems::Interface(_) => {
+                    let path = options
+                        .parser
+                        .find_page(options.page_id)
+                        .unwrap()
+                        .path
+                        .clone();
+                    errors.push(error::error_list::ERROR_S69.clone().build_with_path(
+                        vec![error::ErrorBuildField {
+                            key: "token".to_owned(),
+                            value: self.data.value.clone(),
+                        }],
+                        alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
+                        path,
+                        self.data.pos,
+                    ));
+                    Err(errors)
+                }
                 crate::parser::DeepSearchItems::FunctionParameter(e) => Ok(
                     types::Types::FunctionParameter(types::function::FunctionParameter {
                         name: e.name,
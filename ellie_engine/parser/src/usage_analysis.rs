@@ -0,0 +1,581 @@
+use crate::parser::Parser;
+use crate::utils::ProcessedPage;
+use alloc::{
+    borrow::ToOwned,
+    collections::{BTreeMap, BTreeSet},
+    string::String,
+    vec,
+    vec::Vec,
+};
+use ellie_core::{
+    definite::{
+        definers::DefinerCollecting,
+        items::{
+            function::Function, function_parameter::FunctionParameter, import::Import,
+            match_type::MatchPattern, variable::Variable, Collecting,
+        },
+        types::{enum_data::Pointer, operator::AssignmentOperators, Types},
+    },
+    defs,
+    warning::{self, WarningBuildField},
+};
+use ellie_tokenizer::tokenizer::PageType;
+
+/*
+    Usage analysis runs after every page is processed. It walks the processed pages of the
+    module that is being compiled and collects which items are read by which function, class or
+    global scope. Unread variables, parameters, private properties and imports are reported
+    directly, functions are reported if they cannot be reached from `main` (or from `pub`
+    items if the module is a library).
+*/
+
+/// Owner of the reads that are made on top level of a page
+const GLOBAL_SCOPE: usize = 0;
+
+#[derive(Clone, Copy, PartialEq)]
+enum VariableKind {
+    Local,
+    Global,
+    Property,
+}
+
+#[derive(Default)]
+struct UsageCollector {
+    /// Items read by each owner; owner is the hash of a function, class or [`GLOBAL_SCOPE`]
+    uses: BTreeMap<usize, BTreeSet<usize>>,
+    /// Parameter reads does not carry a hash, they're matched with their declaration position
+    parameter_reads: Vec<(String, defs::Cursor)>,
+    variables: Vec<(Variable, VariableKind, String)>,
+    parameters: Vec<(FunctionParameter, String)>,
+    imports: Vec<(Import, String)>,
+    functions: Vec<(Function, bool, String)>,
+    roots: Vec<usize>,
+    visited_pages: BTreeSet<usize>,
+    /// Class body pages and the interfaces their classes implement
+    implementations: Vec<(usize, Vec<usize>)>,
+}
+
+impl UsageCollector {
+    fn use_item(&mut self, owner: usize, hash: usize) {
+        self.uses.entry(owner).or_default().insert(hash);
+    }
+
+    fn walk_page(
+        &mut self,
+        pages: &BTreeMap<usize, &ProcessedPage>,
+        page_hash: usize,
+        owner: usize,
+        is_lib: bool,
+    ) {
+        if !self.visited_pages.insert(page_hash) {
+            return;
+        }
+        let page = match pages.get(&page_hash) {
+            Some(page) => *page,
+            None => return,
+        };
+        let is_class_body = matches!(page.page_type, PageType::ClassBody(_));
+        let is_member = is_class_body || matches!(page.page_type, PageType::ExtendBody(_));
+        let is_function_body = matches!(page.page_type, PageType::FunctionBody(_));
+
+        for item in &page.items {
+            match item {
+                Collecting::Variable(e) => {
+                    let kind = if is_class_body {
+                        VariableKind::Property
+                    } else if page.inner.is_none() {
+                        VariableKind::Global
+                    } else {
+                        VariableKind::Local
+                    };
+                    if is_lib && e.public {
+                        self.roots.push(e.hash);
+                    }
+                    self.variables.push((e.clone(), kind, page.path.clone()));
+                    self.walk_definer(&e.rtype, owner);
+                    self.walk_type(&e.value, owner);
+                }
+                Collecting::Function(e) => {
+                    if is_lib && e.public {
+                        self.roots.push(e.hash);
+                    }
+                    self.functions
+                        .push((e.clone(), is_member, page.path.clone()));
+                    for parameter in &e.parameters {
+                        self.walk_definer(&parameter.rtype, e.hash);
+                    }
+                    self.walk_definer(&e.return_type, e.hash);
+                    self.walk_page(pages, e.inner_page_id, e.hash, is_lib);
+                }
+                Collecting::Class(e) => {
+                    if is_lib && e.public {
+                        self.roots.push(e.hash);
+                    }
+                    if !e.implements.is_empty() {
+                        self.implementations.push((
+                            e.inner_page_id,
+                            e.implements.iter().map(|x| x.hash).collect(),
+                        ));
+                    }
+                    self.walk_page(pages, e.inner_page_id, e.hash, is_lib);
+                }
+                Collecting::Extend(e) => {
+                    self.walk_page(pages, e.inner_page_id, owner, is_lib);
+                }
+                Collecting::Constructor(e) => {
+                    self.walk_page(pages, e.inner_page_id, owner, is_lib);
+                }
+                Collecting::Getter(e) => {
+                    self.walk_definer(&e.return_type, owner);
+                    self.walk_page(pages, e.inner_page_id, owner, is_lib);
+                }
+                Collecting::Setter(e) => {
+                    self.walk_definer(&e.rtype, owner);
+                    self.walk_page(pages, e.inner_page_id, owner, is_lib);
+                }
+                Collecting::Condition(e) => {
+                    for chain in &e.chains {
+                        self.walk_type(&chain.condition, owner);
+                        self.walk_page(pages, chain.inner_page_id, owner, is_lib);
+                    }
+                }
+                Collecting::Match(e) => {
+                    self.walk_type(&e.target, owner);
+                    for arm in &e.arms {
+                        if let MatchPattern::Value(value) = &arm.pattern {
+                            self.walk_type(value, owner);
+                        }
+                        self.walk_page(pages, arm.inner_page_id, owner, is_lib);
+                    }
+                }
+                Collecting::Loop(e) => {
+                    self.walk_type(&e.condition, owner);
+                    self.walk_page(pages, e.inner_page_id, owner, is_lib);
+                }
+                Collecting::ForLoop(e) => {
+                    self.walk_type(&e.iterator, owner);
+                    self.walk_page(pages, e.inner_page_id, owner, is_lib);
+                }
+                Collecting::Ret(e) => self.walk_type(&e.value, owner),
+                Collecting::GetterCall(e) => self.walk_type(&e.data, owner),
+                Collecting::SetterCall(e) => {
+                    // Plain assignment to a variable or property is a write, not a read
+                    match &e.target {
+                        Types::VariableType(_) if e.operator == AssignmentOperators::Assignment => {
+                        }
+                        Types::Reference(reference)
+                            if e.operator == AssignmentOperators::Assignment =>
+                        {
+                            self.walk_type(&reference.reference, owner);
+                            let read_chain = reference.index_chain.len().saturating_sub(1);
+                            for chain in &reference.index_chain[..read_chain] {
+                                self.use_item(owner, chain.hash);
+                            }
+                        }
+                        target => self.walk_type(target, owner),
+                    }
+                    self.walk_type(&e.value, owner);
+                }
+                Collecting::Import(e) if !e.public => {
+                    self.imports.push((e.clone(), page.path.clone()));
+                }
+                Collecting::FunctionParameter(e) if is_function_body => {
+                    self.parameters.push((e.clone(), page.path.clone()));
+                }
+                _ => (),
+            }
+        }
+    }
+
+    fn walk_items(&mut self, items: &[Collecting], owner: usize) {
+        for item in items {
+            match item {
+                Collecting::Variable(e) => self.walk_type(&e.value, owner),
+                Collecting::Ret(e) => self.walk_type(&e.value, owner),
+                Collecting::GetterCall(e) => self.walk_type(&e.data, owner),
+                Collecting::SetterCall(e) => {
+                    self.walk_type(&e.target, owner);
+                    self.walk_type(&e.value, owner);
+                }
+                _ => (),
+            }
+        }
+    }
+
+    fn walk_type(&mut self, rtype: &Types, owner: usize) {
+        match rtype {
+            Types::Collective(e) => {
+                for entry in &e.entries {
+                    self.walk_type(&entry.value, owner);
+                }
+            }
+            Types::Reference(e) => {
+                self.walk_type(&e.reference, owner);
+                for chain in &e.index_chain {
+                    self.use_item(owner, chain.hash);
+                }
+            }
+            Types::BraceReference(e) => {
+                self.walk_type(&e.reference, owner);
+                self.walk_type(&e.value, owner);
+            }
+            Types::EnumData(e) => {
+                self.walk_type(&e.reference, owner);
+                if let Pointer::Data(data) = &e.value {
+                    self.walk_type(data, owner);
+                }
+            }
+            Types::Operator(e) => {
+                self.walk_type(&e.first, owner);
+                self.walk_type(&e.second, owner);
+            }
+            Types::Cloak(e) => {
+                for entry in &e.collective {
+                    self.walk_type(&entry.value, owner);
+                }
+            }
+            Types::Array(e) => {
+                for entry in &e.collective {
+                    self.walk_type(&entry.value, owner);
+                }
+            }
+            Types::Function(e) => {
+                for parameter in &e.parameters {
+                    if let Some(rtype) = &parameter.rtype {
+                        self.walk_definer(rtype, owner);
+                    }
+                }
+                self.walk_definer(&e.return_type, owner);
+                self.walk_items(&e.inside_code, owner);
+            }
+            Types::FunctionParameter(e) => {
+                self.parameter_reads.push((e.name.clone(), e.name_pos));
+            }
+            Types::ClassCall(e) => {
+                self.walk_type(&e.target, owner);
+                for generic in &e.generic_parameters {
+                    self.walk_definer(&generic.value, owner);
+                }
+                for parameter in &e.params {
+                    self.walk_type(&parameter.value, owner);
+                }
+            }
+            Types::FunctionCall(e) => {
+                self.walk_type(&e.target, owner);
+                for generic in &e.generic_parameters {
+                    self.walk_definer(&generic.value, owner);
+                }
+                for parameter in &e.params {
+                    self.walk_type(&parameter.value, owner);
+                }
+            }
+            Types::SetterCall(e) => self.walk_definer(e, owner),
+            Types::NullResolver(e) => self.walk_type(&e.target, owner),
+            Types::Negative(e) => self.walk_type(&e.value, owner),
+            Types::VariableType(e) => self.use_item(owner, e.reference),
+            Types::AsKeyword(e) => {
+                self.walk_type(&e.target, owner);
+                self.walk_definer(&e.rtype, owner);
+            }
+            Types::ClassInstance(e) => self.use_item(owner, e.class_hash),
+            _ => (),
+        }
+    }
+
+    fn walk_definer(&mut self, definer: &DefinerCollecting, owner: usize) {
+        match definer {
+            DefinerCollecting::Array(e) => {
+                self.walk_definer(&e.rtype, owner);
+                if let Some(size) = &e.size {
+                    self.walk_type(size, owner);
+                }
+            }
+            DefinerCollecting::Generic(e) => self.use_item(owner, e.hash),
+            DefinerCollecting::ParentGeneric(e) => {
+                self.use_item(owner, e.hash);
+                for generic in &e.generics {
+                    self.walk_definer(&generic.value, owner);
+                }
+            }
+            DefinerCollecting::Function(e) => {
+                for param in &e.params {
+                    self.walk_definer(param, owner);
+                }
+                self.walk_definer(&e.returning, owner);
+            }
+            DefinerCollecting::Cloak(e) => {
+                for rtype in &e.rtype {
+                    self.walk_definer(rtype, owner);
+                }
+            }
+            DefinerCollecting::Collective(e) => {
+                self.walk_definer(&e.key, owner);
+                self.walk_definer(&e.value, owner);
+            }
+            DefinerCollecting::Nullable(e) => self.walk_definer(&e.value, owner),
+            DefinerCollecting::EnumField(e) => self.use_item(owner, e.hash),
+            DefinerCollecting::ClassInstance(e) => self.use_item(owner, e.class_hash),
+            DefinerCollecting::Dynamic => (),
+        }
+    }
+
+    /// Members called through an interface are used by whoever uses the interface member
+    fn link_interface_members(&mut self, pages: &BTreeMap<usize, &ProcessedPage>) {
+        let member_name = |item: &Collecting| match item {
+            Collecting::Variable(e) => Some((e.name.clone(), e.hash)),
+            Collecting::Function(e) => Some((e.name.clone(), e.hash)),
+            Collecting::NativeFunction(e) => Some((e.name.clone(), e.hash)),
+            _ => None,
+        };
+        for (class_page, interfaces) in self.implementations.clone() {
+            let class_members: Vec<(String, usize)> = match pages.get(&class_page) {
+                Some(page) => page.items.iter().filter_map(member_name).collect(),
+                None => continue,
+            };
+            for interface in interfaces {
+                let interface_page = pages.values().find_map(|page| {
+                    page.items.iter().find_map(|item| match item {
+                        Collecting::Interface(e) if e.hash == interface => {
+                            pages.get(&e.inner_page_id)
+                        }
+                        _ => None,
+                    })
+                });
+                let interface_members: Vec<(String, usize)> = match interface_page {
+                    Some(page) => page.items.iter().filter_map(member_name).collect(),
+                    None => continue,
+                };
+                for (name, hash) in interface_members {
+                    if let Some((_, member)) = class_members.iter().find(|(x, _)| *x == name) {
+                        self.use_item(hash, *member);
+                    }
+                }
+            }
+        }
+    }
+
+    fn reachable(&self) -> BTreeSet<usize> {
+        let mut reachable = BTreeSet::new();
+        let mut queue = self.roots.clone();
+        queue.push(GLOBAL_SCOPE);
+        while let Some(owner) = queue.pop() {
+            if !reachable.insert(owner) {
+                continue;
+            }
+            if let Some(uses) = self.uses.get(&owner) {
+                queue.extend(uses.iter().filter(|x| !reachable.contains(x)));
+            }
+        }
+        reachable
+    }
+}
+
+/// Find the top level page that contains given page
+fn root_page(pages: &BTreeMap<usize, &ProcessedPage>, page_hash: usize) -> usize {
+    let mut current = page_hash;
+    while let Some(inner) = pages.get(&current).and_then(|page| page.inner) {
+        if inner == current {
+            break;
+        }
+        current = inner;
+    }
+    current
+}
+
+/// Report unused variables, parameters, private class properties, imports and functions that
+/// are not reachable from `main`. If module is a library, `pub` items are taken as roots
+/// instead of `main`.
+/// ## Parameters
+/// * `parser` - [`Parser`] which finished processing its pages
+pub fn analyze_usage(parser: &mut Parser) {
+    let is_lib = parser.module_info.is_lib;
+    let module_pages: BTreeSet<usize> = parser
+        .modules
+        .iter()
+        .flat_map(|module| module.pages.iter().map(|page| page.hash))
+        .chain(
+            parser
+                .pages
+                .iter()
+                .filter(|page| page.module)
+                .map(|page| page.hash),
+        )
+        .collect();
+    let pages: BTreeMap<usize, &ProcessedPage> = parser
+        .processed_pages
+        .iter()
+        .map(|page| (page.hash, page))
+        .collect();
+
+    let mut collector = UsageCollector::default();
+    let top_pages: Vec<usize> = pages
+        .values()
+        .filter(|page| page.inner.is_none() && !module_pages.contains(&page.hash))
+        .map(|page| page.hash)
+        .collect();
+    for page_hash in top_pages {
+        collector.walk_page(&pages, page_hash, GLOBAL_SCOPE, is_lib);
+    }
+
+    if !is_lib {
+        if let Some(main) = pages.get(&parser.initial_page).and_then(|page| {
+            page.items.iter().find_map(|item| match item {
+                Collecting::Function(e) if e.name == "main" => Some(e.hash),
+                _ => None,
+            })
+        }) {
+            collector.roots.push(main);
+        }
+    }
+
+    collector.link_interface_members(&pages);
+
+    // Item hash and top level page of the item, used for resolving import usages
+    let mut item_pages: BTreeMap<usize, usize> = BTreeMap::new();
+    for page in pages.values() {
+        for item in &page.items {
+            if let Some(hash) = item.get_hash() {
+                item_pages.insert(hash, root_page(&pages, page.hash));
+            }
+        }
+    }
+
+    let reads: BTreeSet<usize> = collector.uses.values().flatten().cloned().collect();
+    let reachable = collector.reachable();
+    let mut warnings = Vec::new();
+
+    for (variable, kind, path) in &collector.variables {
+        if variable.name.starts_with('_') || reads.contains(&variable.hash) {
+            continue;
+        }
+        match kind {
+            VariableKind::Property if !variable.public => {
+                warnings.push(warning::warning_list::WARNING_S10.clone().build(
+                    vec![WarningBuildField {
+                        key: "name".to_owned(),
+                        value: variable.name.clone(),
+                    }],
+                    path.clone(),
+                    variable.name_pos,
+                ))
+            }
+            VariableKind::Global if is_lib && variable.public => (),
+            VariableKind::Local | VariableKind::Global => {
+                warnings.push(warning::warning_list::WARNING_S8.clone().build(
+                    vec![WarningBuildField {
+                        key: "name".to_owned(),
+                        value: variable.name.clone(),
+                    }],
+                    path.clone(),
+                    variable.name_pos,
+                ))
+            }
+            _ => (),
+        }
+    }
+
+    for (parameter, path) in &collector.parameters {
+        // `self` is added to methods implicitly, it's not up to the user to read it
+        if parameter.name.starts_with('_')
+            || parameter.name == "self"
+            || collector
+                .parameter_reads
+                .iter()
+                .any(|(name, pos)| *name == parameter.name && *pos == parameter.name_pos)
+        {
+            continue;
+        }
+        warnings.push(warning::warning_list::WARNING_S9.clone().build(
+            vec![WarningBuildField {
+                key: "name".to_owned(),
+                value: parameter.name.clone(),
+            }],
+            path.clone(),
+            parameter.name_pos,
+        ));
+    }
+
+    for (import, path) in &collector.imports {
+        let mut covered_pages = BTreeSet::new();
+        let mut queue = vec![import.hash];
+        while let Some(page_hash) = queue.pop() {
+            if !covered_pages.insert(page_hash) {
+                continue;
+            }
+            if let Some(page) = pages.get(&page_hash) {
+                queue.extend(
+                    page.dependencies
+                        .iter()
+                        .filter(|dependency| dependency.public)
+                        .map(|dependency| dependency.hash),
+                );
+            }
+        }
+        if import.link_module {
+            for module in parser.modules.iter() {
+                if module.initial_page == import.hash {
+                    // Pages of a module carry the modules it's linked with, they are not its own
+                    let linked_pages: BTreeSet<usize> = parser
+                        .modules
+                        .iter()
+                        .filter(|linked| linked.initial_page != module.initial_page)
+                        .flat_map(|linked| linked.pages.iter().map(|page| page.hash))
+                        .collect();
+                    covered_pages.extend(
+                        module
+                            .pages
+                            .iter()
+                            .map(|page| page.hash)
+                            .filter(|page| !linked_pages.contains(page)),
+                    );
+                }
+            }
+        }
+
+        let used = reads.iter().any(|hash| {
+            item_pages
+                .get(hash)
+                .is_some_and(|page| covered_pages.contains(page))
+        });
+
+        if !used {
+            warnings.push(warning::warning_list::WARNING_S11.clone().build(
+                vec![WarningBuildField {
+                    key: "path".to_owned(),
+                    value: import.path.clone(),
+                }],
+                path.clone(),
+                import.pos,
+            ));
+        }
+    }
+
+    for (function, is_member, path) in &collector.functions {
+        if function.name.starts_with('_')
+            || reachable.contains(&function.hash)
+            || (*is_member && function.public)
+        {
+            continue;
+        }
+        warnings.push(warning::warning_list::WARNING_S12.clone().build(
+            vec![
+                WarningBuildField {
+                    key: "name".to_owned(),
+                    value: function.name.clone(),
+                },
+                WarningBuildField {
+                    key: "root".to_owned(),
+                    value: if is_lib {
+                        "public items".to_owned()
+                    } else {
+                        "'main'".to_owned()
+                    },
+                },
+            ],
+            path.clone(),
+            function.name_pos,
+        ));
+    }
+
+    parser.informations.extend(&warnings);
+}
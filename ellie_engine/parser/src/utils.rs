@@ -45,6 +45,7 @@ pub fn parse_page_element(
             Processors::ForLoop(e) => e.process(&mut processor_options),
             Processors::Loop(e) => e.process(&mut processor_options),
             Processors::Condition(e) => e.process(&mut processor_options),
+            Processors::Match(e) => e.process(&mut processor_options),
             Processors::Getter(e) => e.process(&mut processor_options),
             Processors::Setter(e) => e.process(&mut processor_options),
             Processors::Class(e) => e.process(&mut processor_options),
@@ -132,6 +133,7 @@ pub fn parse_page_element(
             Processors::ForLoop(e) => e.process(&mut processor_options),
             Processors::Loop(e) => e.process(&mut processor_options),
             Processors::Condition(e) => e.process(&mut processor_options),
+            Processors::Match(e) => e.process(&mut processor_options),
             Processors::Class(e) => e.process(&mut processor_options),
             Processors::Getter(e) => e.process(&mut processor_options),
             Processors::Setter(e) => e.process(&mut processor_options),
@@ -192,7 +194,11 @@ pub fn parse_page_element(
                 true
             }
             Processors::Condition(e) => e.process(&mut processor_options),
+            Processors::Match(e) => e.process(&mut processor_options),
             Processors::Class(e) => e.process(&mut processor_options),
+            Processors::Extend(e) => e.process(&mut processor_options),
+            Processors::TypeDef(e) => e.process(&mut processor_options),
+            Processors::Interface(e) => e.process(&mut processor_options),
             Processors::Getter(e) => e.process(&mut processor_options),
             Processors::Setter(e) => e.process(&mut processor_options),
             Processors::GenericItem(e) => e.process(&mut processor_options),
@@ -259,6 +265,40 @@ pub fn parse_page_element(
                 false
             }
         },
+        ellie_tokenizer::tokenizer::PageType::ExtendBody(_) => match item {
+            Processors::Function(e) => e.process(&mut processor_options),
+            Processors::FileKey(e) => e.process(&mut processor_options),
+            Processors::GenericItem(e) => e.process(&mut processor_options),
+            Processors::Comment(_) => true,
+            unexpected_element => {
+                parser
+                    .informations
+                    .push(&error::error_list::ERROR_S22.clone().build_with_path(
+                        vec![],
+                        alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
+                        unprocessed_page_path.clone(),
+                        unexpected_element.get_pos(),
+                    ));
+                false
+            }
+        },
+        ellie_tokenizer::tokenizer::PageType::InterfaceBody(_) => match item {
+            Processors::Function(e) if e.data.defining => e.process(&mut processor_options),
+            Processors::Variable(e) => e.process(&mut processor_options),
+            Processors::FileKey(e) => e.process(&mut processor_options),
+            Processors::Comment(_) => true,
+            unexpected_element => {
+                parser
+                    .informations
+                    .push(&error::error_list::ERROR_S22.clone().build_with_path(
+                        vec![],
+                        alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
+                        unprocessed_page_path.clone(),
+                        unexpected_element.get_pos(),
+                    ));
+                false
+            }
+        },
         ellie_tokenizer::tokenizer::PageType::ConditionBody(_) => match item {
             Processors::Variable(e) => e.process(&mut processor_options),
             Processors::GetterCall(e) => e.process(&mut processor_options),
@@ -269,6 +309,7 @@ pub fn parse_page_element(
             Processors::ForLoop(e) => e.process(&mut processor_options),
             Processors::Loop(e) => e.process(&mut processor_options),
             Processors::Condition(e) => e.process(&mut processor_options),
+            Processors::Match(e) => e.process(&mut processor_options),
             Processors::Class(e) => e.process(&mut processor_options),
             Processors::Getter(e) => e.process(&mut processor_options),
             Processors::Setter(e) => e.process(&mut processor_options),
@@ -325,6 +366,7 @@ pub fn parse_page_element(
             Processors::ForLoop(e) => e.process(&mut processor_options),
             Processors::Loop(e) => e.process(&mut processor_options),
             Processors::Condition(e) => e.process(&mut processor_options),
+            Processors::Match(e) => e.process(&mut processor_options),
             Processors::Getter(e) => e.process(&mut processor_options),
             Processors::Setter(e) => e.process(&mut processor_options),
             Processors::Class(e) => e.process(&mut processor_options),
@@ -142,6 +142,14 @@ pub fn generate_elliec_options() -> Command<'static> {
                         .default_value("64")
                         .possible_values(["16", "32", "64"]),
                 )
+                .arg(
+                    Arg::new("optimizationLevel")
+                        .help("Bytecode optimization level, 0 disables constant folding and peephole optimizations")
+                        .short('O')
+                        .long("--optimization-level")
+                        .default_value("0")
+                        .possible_values(["0", "1"]),
+                )
                 .arg(
                     Arg::new("performanceInfo")
                         .help("Output performance info")
@@ -215,6 +223,12 @@ pub fn generate_elliec_options() -> Command<'static> {
                         .default_value("A ellie module")
                         .takes_value(true),
                 )
+                .arg(
+                    Arg::new("stripDebugInfo")
+                        .help("Don't embed debug info in bytecode output")
+                        .short('g')
+                        .long("--strip-debug-info"),
+                )
                 .arg(
                     Arg::new("moduleName")
                         .help("Name of module")
@@ -254,6 +268,30 @@ pub fn generate_elliec_options() -> Command<'static> {
                         .takes_value(false),
                 ),
         )
+        .subcommand(
+            Command::new("assemble")
+                .about("Assemble byteCodeAsm text into byteCode")
+                .arg(
+                    Arg::new("jsonLog")
+                        .help("Output json log")
+                        .short('j')
+                        .long("-json-log"),
+                )
+                .arg(
+                    Arg::new("outputPath")
+                        .help("Output path to write")
+                        .short('p')
+                        .long("--output-path")
+                        .takes_value(true)
+                        .value_hint(ValueHint::DirPath),
+                )
+                .arg(
+                    Arg::new("target")
+                        .help("Target byteCodeAsm file to assemble")
+                        .required(true)
+                        .value_hint(ValueHint::FilePath),
+                ),
+        )
         .subcommand(
             Command::new("viewModule")
                 .about("Analyze given module information")
@@ -306,20 +344,36 @@ pub fn generate_ellievm_options() -> Command<'static> {
                         .default_values(&["64", "32", "16"])
                         .default_value("64"),
                 )
-                .arg(
-                    Arg::new("debugInfo")
-                        .help("Supply debug info file for more information")
-                        .short('d')
-                        .long("--debug-info")
-                        .takes_value(true)
-                        .value_hint(ValueHint::FilePath),
-                )
                 .arg(
                     Arg::new("heapDump")
                         .help("Dump heap to file")
                         .short('u')
                         .long("--heap-dump"),
                 )
+                .arg(
+                    Arg::new("trace")
+                        .help("Write every executed instruction and register changes to file")
+                        .short('t')
+                        .long("--trace")
+                        .takes_value(true)
+                        .value_hint(ValueHint::FilePath),
+                )
+                .arg(
+                    Arg::new("profile")
+                        .help("Profile execution and write flamegraph compatible folded stacks to file")
+                        .short('p')
+                        .long("--profile")
+                        .takes_value(true)
+                        .value_hint(ValueHint::FilePath),
+                )
+                .arg(
+                    Arg::new("coverage")
+                        .help("Write lcov coverage report of executed source lines to file")
+                        .short('v')
+                        .long("--coverage")
+                        .takes_value(true)
+                        .value_hint(ValueHint::FilePath),
+                )
                 .arg(
                     Arg::new("allowPanics")
                         .help("Allow panics")
@@ -364,6 +418,37 @@ pub fn generate_ellievm_options() -> Command<'static> {
                         .multiple_values(true),
                 ),
         )
+        .subcommand(
+            Command::new("dap")
+                .about("Run debugger as a Debug Adapter Protocol server over stdio"),
+        )
+        .subcommand(
+            Command::new("repl")
+                .about("Evaluate Ellie code interactively")
+                .arg(
+                    Arg::new("targetArchitecture")
+                        .help("Targeted architecture for bytecode")
+                        .short('c')
+                        .long("--arch")
+                        .default_values(&["64", "32", "16"])
+                        .default_value("64"),
+                )
+                .arg(
+                    Arg::new("excludeStd")
+                        .help("Don't import standard library")
+                        .short('e')
+                        .long("-exclude-std"),
+                )
+                .arg(
+                    Arg::new("insertModule")
+                        .help("Insert a module from binary")
+                        .short('i')
+                        .long("--insert-module")
+                        .takes_value(true)
+                        .multiple_values(true)
+                        .value_hint(ValueHint::FilePath),
+                ),
+        )
         .subcommand(
             Command::new("version")
                 .about("Get version")
@@ -154,4 +154,10 @@ lazy_static! {
         message: "Could not find imported module".to_string(),
         extra: Vec::new(),
     };
+    pub static ref ASSEMBLE_FAILED: CliOuput<String, String> = CliOuput {
+        code: 19,
+        rtype: CliOuputType::Error,
+        message: "Failed to assemble byte code assembly".to_string(),
+        extra: Vec::new(),
+    };
 }
@@ -0,0 +1,450 @@
+//! Ellie Core Library
+//!
+//! `ellieCore` is the standard library shipped with the engine. It has two halves:
+//! * Ellie sources in `Ellie-Core-Library/ellieCore.ei`, compiled with [`compile_core_library`]
+//! * Native functions declared in those sources, registered to vm with [`core_library_module`]
+//!
+//! Both halves carry [`ELLIE_CORE_LIBRARY_VERSION`], bump it when either side changes.
+#[cfg(feature = "compiler")]
+use alloc::{borrow::ToOwned, string::ToString, vec, vec::Vec};
+#[cfg(feature = "vm")]
+use alloc::{boxed::Box, sync::Arc};
+use alloc::{format, string::String};
+#[cfg(feature = "compiler")]
+use ellie_core::{defs::Version, error::Error};
+#[cfg(feature = "compiler")]
+use ellie_tokenizer::tokenizer::ResolvedImport;
+#[cfg(feature = "vm")]
+use ellie_vm::{
+    channel::{EllieModule, FunctionElement, ModuleElements},
+    raw_type::{RawType, StaticRawType},
+    utils::{VmNativeAnswer, VmNativeCallParameters},
+};
+
+#[cfg(feature = "compiler")]
+use crate::utils::{CompileOutput, CompilerSettings, MainProgram, ProgramRepository};
+
+/// Name of the core library module
+pub const ELLIE_CORE_LIBRARY_NAME: &str = "ellieCore";
+
+/// Version of the core library, shared by its sources and natives
+pub const ELLIE_CORE_LIBRARY_VERSION: &str = "1.0.0";
+
+/// Ellie sources of the core library
+#[cfg(feature = "compiler")]
+pub const ELLIE_CORE_LIBRARY_SOURCE: &str = include_str!("../../Ellie-Core-Library/ellieCore.ei");
+
+/// FNV-1a hash of the library source, used as page hash so it stays same across builds
+#[cfg(feature = "compiler")]
+const fn source_hash(source: &str) -> usize {
+    let bytes = source.as_bytes();
+    let mut hash: u32 = 0x811c9dc5;
+    let mut i = 0;
+    while i < bytes.len() {
+        hash ^= bytes[i] as u32;
+        hash = hash.wrapping_mul(0x01000193);
+        i += 1;
+    }
+    hash as usize
+}
+
+#[cfg(feature = "compiler")]
+struct CoreLibraryRepository;
+
+#[cfg(feature = "compiler")]
+impl ProgramRepository for CoreLibraryRepository {
+    fn read_main(&mut self) -> MainProgram {
+        MainProgram {
+            file_content: ELLIE_CORE_LIBRARY_SOURCE.to_owned(),
+            file_name: "ellieCore.ei".to_owned(),
+            file_hash: source_hash(ELLIE_CORE_LIBRARY_SOURCE),
+            start_directory: format!("<ellie_module_{}>", ELLIE_CORE_LIBRARY_NAME),
+        }
+    }
+
+    fn read_module(
+        &mut self,
+        _link_module: bool,
+        _current_path: String,
+        _requested_path: String,
+    ) -> ResolvedImport {
+        ResolvedImport {
+            found: false,
+            resolve_error: "ellieCore cannot import modules".to_owned(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Compile core library sources to a module
+/// ## Returns
+/// [`CompileOutput`] of `ellieCore` library or [`Vec<Error>`] if sources are broken
+#[cfg(feature = "compiler")]
+pub fn compile_core_library() -> Result<CompileOutput, Vec<Error>> {
+    let pages = crate::tokenizer::tokenize_file(&mut CoreLibraryRepository)?;
+    crate::compiler::parse_pages(
+        source_hash(ELLIE_CORE_LIBRARY_SOURCE),
+        vec![],
+        pages,
+        CompilerSettings {
+            name: ELLIE_CORE_LIBRARY_NAME.to_string(),
+            file_name: "ellieCore.ei".to_string(),
+            is_lib: true,
+            description: "Ellie Core Library".to_string(),
+            experimental_features: false,
+            version: Version::build_from_string(&ELLIE_CORE_LIBRARY_VERSION.to_string()),
+            byte_code_architecture: ellie_core::defs::PlatformArchitecture::B64,
+            optimization_level: 0,
+        },
+    )
+}
+
+/// Host side of core library's I/O functions
+#[cfg(feature = "vm")]
+#[derive(Clone)]
+pub struct HostCallbacks {
+    /// Write text to host output, used by `print` and `println`
+    pub write: Arc<dyn Fn(&str) + Send + Sync>,
+    /// Read a line from host input without line ending, used by `readLine`
+    pub read_line: Arc<dyn Fn() -> String + Send + Sync>,
+    /// Current timestamp of host, used by `timestamp`
+    pub timestamp: Arc<dyn Fn() -> isize + Send + Sync>,
+}
+
+#[cfg(all(feature = "vm", feature = "std"))]
+impl Default for HostCallbacks {
+    fn default() -> Self {
+        use std::io::Write;
+        HostCallbacks {
+            write: Arc::new(|text| {
+                let mut stdout = std::io::stdout();
+                let _ = stdout.write_all(text.as_bytes());
+                let _ = stdout.flush();
+            }),
+            read_line: Arc::new(|| {
+                let mut line = String::new();
+                let _ = std::io::stdin().read_line(&mut line);
+                String::from(line.trim_end_matches(['\r', '\n']))
+            }),
+            timestamp: Arc::new(|| {
+                std::time::SystemTime::now()
+                    .duration_since(std::time::SystemTime::UNIX_EPOCH)
+                    .unwrap()
+                    .as_nanos() as isize
+            }),
+        }
+    }
+}
+
+#[cfg(feature = "vm")]
+fn expect_arguments(args: &[VmNativeCallParameters], count: usize) -> Result<(), String> {
+    if args.len() == count {
+        Ok(())
+    } else {
+        Err(format!("Signature mismatch expected {} argument(s)", count))
+    }
+}
+
+#[cfg(feature = "vm")]
+fn string_argument(args: &[VmNativeCallParameters], index: usize) -> Result<String, String> {
+    match &args[index] {
+        VmNativeCallParameters::Dynamic(dynamic_value) if dynamic_value.is_string() => {
+            Ok(dynamic_value.to_string())
+        }
+        _ => Err(format!(
+            "Signature mismatch expected 'string' argument at {}",
+            index
+        )),
+    }
+}
+
+#[cfg(feature = "vm")]
+fn static_argument(
+    args: &[VmNativeCallParameters],
+    index: usize,
+    type_name: &str,
+    matches: fn(&StaticRawType) -> bool,
+) -> Result<StaticRawType, String> {
+    match &args[index] {
+        VmNativeCallParameters::Static(static_value) if matches(static_value) => Ok(*static_value),
+        _ => Err(format!(
+            "Signature mismatch expected '{}' argument at {}",
+            type_name, index
+        )),
+    }
+}
+
+#[cfg(feature = "vm")]
+fn int_argument(args: &[VmNativeCallParameters], index: usize) -> Result<isize, String> {
+    static_argument(args, index, "int", |e| e.type_id.is_int()).map(|e| e.to_int())
+}
+
+#[cfg(feature = "vm")]
+fn double_argument(args: &[VmNativeCallParameters], index: usize) -> Result<f64, String> {
+    static_argument(args, index, "double", |e| e.type_id.is_double()).map(|e| e.to_double())
+}
+
+#[cfg(feature = "vm")]
+fn char_argument(args: &[VmNativeCallParameters], index: usize) -> Result<char, String> {
+    static_argument(args, index, "char", |e| e.type_id.is_char()).map(|e| e.to_char())
+}
+
+#[cfg(feature = "vm")]
+fn string_answer(string: String) -> VmNativeCallParameters {
+    VmNativeCallParameters::Dynamic(RawType::generate_string(string))
+}
+
+#[cfg(feature = "vm")]
+fn static_answer(static_value: StaticRawType) -> VmNativeCallParameters {
+    VmNativeCallParameters::Static(static_value)
+}
+
+/// Char index of `part` in `string`
+#[cfg(feature = "vm")]
+fn char_index(string: &str, part: &str) -> Option<usize> {
+    string
+        .find(part)
+        .map(|byte_index| string[..byte_index].chars().count())
+}
+
+/// `floor` for no_std targets, `f64::floor` lives in std
+#[cfg(feature = "vm")]
+fn floor(x: f64) -> f64 {
+    let truncated = x as isize as f64;
+    if truncated > x {
+        truncated - 1.0
+    } else {
+        truncated
+    }
+}
+
+/// `sqrt` for no_std targets, Newton's method converges in a few steps for doubles
+#[cfg(feature = "vm")]
+fn sqrt(x: f64) -> f64 {
+    if x.is_nan() || x < 0.0 {
+        return f64::NAN;
+    }
+    if x == 0.0 || x.is_infinite() {
+        return x;
+    }
+    let mut guess = if x > 1.0 { x / 2.0 } else { 1.0 };
+    loop {
+        let next = (guess + x / guess) / 2.0;
+        if next >= guess {
+            return guess;
+        }
+        guess = next;
+    }
+}
+
+#[cfg(feature = "vm")]
+fn register<F>(module: &mut EllieModule, name: &'static str, mut callback: F)
+where
+    F: FnMut(&[VmNativeCallParameters]) -> Result<VmNativeCallParameters, String> + Send + 'static,
+{
+    module.register_element(ModuleElements::Function(FunctionElement::new(
+        name,
+        Box::new(move |_, args| match callback(&args) {
+            Ok(answer) => VmNativeAnswer::Ok(answer),
+            Err(error) => VmNativeAnswer::RuntimeError(format!("{}: {}", name, error)),
+        }),
+    )));
+}
+
+/// Native module of core library
+/// ## Parameters
+/// * `host` - [`HostCallbacks`] that I/O functions are routed to
+/// ## Returns
+/// [`EllieModule`] to be registered to [`ellie_vm::channel::ModuleManager`]
+#[cfg(feature = "vm")]
+pub fn core_library_module(host: HostCallbacks) -> EllieModule {
+    let mut module = EllieModule::new(ELLIE_CORE_LIBRARY_NAME.into());
+
+    //I/O
+    let write = host.write.clone();
+    register(&mut module, "print", move |args| {
+        expect_arguments(args, 1)?;
+        write(&string_argument(args, 0)?);
+        Ok(static_answer(StaticRawType::from_void()))
+    });
+    let write = host.write;
+    register(&mut module, "println", move |args| {
+        expect_arguments(args, 1)?;
+        write(&format!("{}\n", string_argument(args, 0)?));
+        Ok(static_answer(StaticRawType::from_void()))
+    });
+    let read_line = host.read_line;
+    register(&mut module, "readLine", move |args| {
+        expect_arguments(args, 0)?;
+        Ok(string_answer(read_line()))
+    });
+    let timestamp = host.timestamp;
+    register(&mut module, "timestamp", move |args| {
+        expect_arguments(args, 0)?;
+        Ok(static_answer(StaticRawType::from_int(timestamp())))
+    });
+
+    //Math
+    register(&mut module, "sqrt", |args| {
+        expect_arguments(args, 1)?;
+        Ok(static_answer(StaticRawType::from_double(sqrt(
+            double_argument(args, 0)?,
+        ))))
+    });
+    register(&mut module, "floor", |args| {
+        expect_arguments(args, 1)?;
+        Ok(static_answer(StaticRawType::from_int(
+            floor(double_argument(args, 0)?) as isize,
+        )))
+    });
+    register(&mut module, "ceil", |args| {
+        expect_arguments(args, 1)?;
+        let x = double_argument(args, 0)?;
+        Ok(static_answer(StaticRawType::from_int(
+            (0.0 - floor(0.0 - x)) as isize,
+        )))
+    });
+    register(&mut module, "round", |args| {
+        expect_arguments(args, 1)?;
+        let x = double_argument(args, 0)?;
+        //Halfway cases round away from zero like `f64::round`
+        let rounded = if x < 0.0 {
+            0.0 - floor(0.5 - x)
+        } else {
+            floor(x + 0.5)
+        };
+        Ok(static_answer(StaticRawType::from_int(rounded as isize)))
+    });
+
+    //Strings
+    register(&mut module, "stringLength", |args| {
+        expect_arguments(args, 1)?;
+        Ok(static_answer(StaticRawType::from_int(
+            string_argument(args, 0)?.chars().count() as isize,
+        )))
+    });
+    register(&mut module, "toUpperCase", |args| {
+        expect_arguments(args, 1)?;
+        Ok(string_answer(string_argument(args, 0)?.to_uppercase()))
+    });
+    register(&mut module, "toLowerCase", |args| {
+        expect_arguments(args, 1)?;
+        Ok(string_answer(string_argument(args, 0)?.to_lowercase()))
+    });
+    register(&mut module, "trim", |args| {
+        expect_arguments(args, 1)?;
+        Ok(string_answer(String::from(
+            string_argument(args, 0)?.trim(),
+        )))
+    });
+    register(&mut module, "contains", |args| {
+        expect_arguments(args, 2)?;
+        let (string, part) = (string_argument(args, 0)?, string_argument(args, 1)?);
+        Ok(static_answer(StaticRawType::from_bool(
+            string.contains(&part),
+        )))
+    });
+    register(&mut module, "startsWith", |args| {
+        expect_arguments(args, 2)?;
+        let (string, part) = (string_argument(args, 0)?, string_argument(args, 1)?);
+        Ok(static_answer(StaticRawType::from_bool(
+            string.starts_with(&part),
+        )))
+    });
+    register(&mut module, "endsWith", |args| {
+        expect_arguments(args, 2)?;
+        let (string, part) = (string_argument(args, 0)?, string_argument(args, 1)?);
+        Ok(static_answer(StaticRawType::from_bool(
+            string.ends_with(&part),
+        )))
+    });
+    register(&mut module, "indexOf", |args| {
+        expect_arguments(args, 2)?;
+        let (string, part) = (string_argument(args, 0)?, string_argument(args, 1)?);
+        Ok(static_answer(StaticRawType::from_int(
+            char_index(&string, &part).map_or(-1, |index| index as isize),
+        )))
+    });
+    register(&mut module, "substring", |args| {
+        expect_arguments(args, 3)?;
+        let string = string_argument(args, 0)?;
+        let (start, end) = (int_argument(args, 1)?, int_argument(args, 2)?);
+        let length = string.chars().count() as isize;
+        if start < 0 || end < start || end > length {
+            return Err(format!(
+                "Range {}..{} is out of bounds for length {}",
+                start, end, length
+            ));
+        }
+        Ok(string_answer(
+            string
+                .chars()
+                .skip(start as usize)
+                .take((end - start) as usize)
+                .collect(),
+        ))
+    });
+    register(&mut module, "repeat", |args| {
+        expect_arguments(args, 2)?;
+        let (string, count) = (string_argument(args, 0)?, int_argument(args, 1)?);
+        if count < 0 {
+            return Err(format!("Cannot repeat {} times", count));
+        }
+        Ok(string_answer(string.repeat(count as usize)))
+    });
+    register(&mut module, "charAt", |args| {
+        expect_arguments(args, 2)?;
+        let (string, index) = (string_argument(args, 0)?, int_argument(args, 1)?);
+        match usize::try_from(index)
+            .ok()
+            .and_then(|index| string.chars().nth(index))
+        {
+            Some(char) => Ok(static_answer(StaticRawType::from_char(char))),
+            None => Err(format!(
+                "Index {} is out of bounds for length {}",
+                index,
+                string.chars().count()
+            )),
+        }
+    });
+
+    //Conversions
+    register(&mut module, "parseInt", |args| {
+        expect_arguments(args, 1)?;
+        let string = string_argument(args, 0)?;
+        match string.trim().parse::<isize>() {
+            Ok(int) => Ok(static_answer(StaticRawType::from_int(int))),
+            Err(_) => Err(format!("Cannot convert '{}' to int", string)),
+        }
+    });
+    register(&mut module, "parseDouble", |args| {
+        expect_arguments(args, 1)?;
+        let string = string_argument(args, 0)?;
+        match string.trim().parse::<f64>() {
+            Ok(double) => Ok(static_answer(StaticRawType::from_double(double))),
+            Err(_) => Err(format!("Cannot convert '{}' to double", string)),
+        }
+    });
+    register(&mut module, "intToDouble", |args| {
+        expect_arguments(args, 1)?;
+        Ok(static_answer(StaticRawType::from_double(
+            int_argument(args, 0)? as f64,
+        )))
+    });
+    register(&mut module, "charCode", |args| {
+        expect_arguments(args, 1)?;
+        Ok(static_answer(StaticRawType::from_int(
+            char_argument(args, 0)? as isize,
+        )))
+    });
+    register(&mut module, "fromCharCode", |args| {
+        expect_arguments(args, 1)?;
+        let code = int_argument(args, 0)?;
+        match u32::try_from(code).ok().and_then(char::from_u32) {
+            Some(char) => Ok(static_answer(StaticRawType::from_char(char))),
+            None => Err(format!("{} is not a valid char code", code)),
+        }
+    });
+
+    module
+}
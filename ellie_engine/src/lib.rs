@@ -53,5 +53,9 @@ pub mod tokenizer;
 #[cfg(feature = "vm")]
 pub mod vm;
 
+/// Ellie Core Library, standard library shipped with the engine
+#[cfg(any(feature = "compiler", feature = "vm"))]
+pub mod core_library;
+
 /// Various utilities for all modules.
 pub mod utils;
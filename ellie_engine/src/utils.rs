@@ -49,6 +49,8 @@ pub struct CompilerSettings {
     pub version: Version,
     /// ByteCode architecture
     pub byte_code_architecture: ellie_core::defs::PlatformArchitecture,
+    /// ByteCode optimization level, `0` disables the peephole optimizer
+    pub optimization_level: u8,
 }
 
 /// Repository interface is channel for communication between compiler and code
@@ -0,0 +1,111 @@
+#[cfg(test)]
+mod asm_parser_tests {
+    use crate::utils::{assemble, run, run_binary};
+    use ellie_engine::ellie_bytecode::asm_parser::{parse_asm, AsmParseError};
+
+    const CODE: &str = "
+        fn add(a: int, b: int) : int {
+            ret a + b;
+        }
+
+        fn main() {
+            v total = 0;
+            for i : 3 {
+                total += add(i, 2);
+            }
+            v ar = [1, 2, 3];
+            ar.push(4);
+            println(\"Total: \" + total);
+            v last = ar[3]!;
+            println(\"Last: \" + last);
+        }
+    ";
+
+    fn render_asm(optimization_level: u8) -> String {
+        let mut asm = Vec::new();
+        assemble(CODE, optimization_level).alternate_render(&mut asm);
+        String::from_utf8(asm).unwrap()
+    }
+
+    fn parse_error(code: &str) -> AsmParseError {
+        match parse_asm(code) {
+            Ok(_) => panic!("Expected `{}` to fail", code),
+            Err(error) => error,
+        }
+    }
+
+    #[test]
+    fn round_trip_with_same_output() {
+        for optimization_level in [0, 1] {
+            let assembled = parse_asm(&render_asm(optimization_level))
+                .unwrap_or_else(|error| panic!("Expected asm to parse, found {}", error));
+            let expected = run(CODE, optimization_level);
+            assert!(!expected.is_empty());
+            assert_eq!(
+                run_binary(&assembled.render_binary_to_vector(false)),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn round_trip_renders_same_asm() {
+        let asm = render_asm(0);
+        let mut rendered = Vec::new();
+        parse_asm(&asm).unwrap().alternate_render(&mut rendered);
+        let rendered = String::from_utf8(rendered).unwrap();
+        let instructions = |asm: &str| {
+            asm.split(".instructions")
+                .nth(1)
+                .expect("Expected instructions section")
+                .to_string()
+        };
+        assert_eq!(instructions(&rendered), instructions(&asm));
+    }
+
+    #[test]
+    fn overflowing_index_with_error() {
+        let error = parse_error(".instructions\n99999999999999999999999: LDA #(int)1");
+        assert_eq!((error.line, error.column), (2, 1));
+        assert!(error.reason.contains("invalid instruction index"));
+    }
+
+    #[test]
+    fn mismatched_index_with_error() {
+        let error = parse_error(".instructions\n    0: LDA #(int)1\n    5: RET");
+        assert_eq!((error.line, error.column), (3, 5));
+    }
+
+    #[test]
+    fn unknown_instruction_with_error() {
+        let error = parse_error(".instructions\nLDA #(int)1\n  FOO #(int)1");
+        assert_eq!((error.line, error.column), (3, 3));
+        assert!(error.reason.contains("unknown instruction `FOO`"));
+    }
+
+    #[test]
+    fn bad_operand_with_error() {
+        let error = parse_error(".instructions\nLDA #(int)abc");
+        assert_eq!((error.line, error.column), (2, 11));
+        assert!(error.reason.contains("invalid number `abc`"));
+
+        let error = parse_error(".instructions\nLDA #(flt)1");
+        assert_eq!((error.line, error.column), (2, 7));
+    }
+
+    #[test]
+    fn trailing_tokens_with_error() {
+        let error = parse_error(".instructions\nLDA #(int)1 junk");
+        assert_eq!((error.line, error.column), (2, 13));
+        assert!(error.reason.contains("unexpected `junk`"), "{}", error);
+    }
+
+    #[test]
+    fn unknown_directive_and_arch_with_error() {
+        let error = parse_error(".arch 64\n.bogus");
+        assert_eq!((error.line, error.column), (2, 2));
+
+        let error = parse_error("  .arch 128");
+        assert_eq!((error.line, error.column), (1, 9));
+    }
+}
@@ -0,0 +1,31 @@
+mod utils;
+
+mod bytecode {
+    mod asm_parser_test;
+}
+
+mod optimizer {
+    mod constant_folding_test;
+    mod peephole_test;
+}
+
+mod parser {
+    mod array_methods_test;
+    mod core_library_test;
+    mod extend_test;
+    mod flow_analysis_test;
+    mod for_loop_test;
+    mod generic_function_test;
+    mod interface_test;
+    mod match_test;
+    mod type_alias_test;
+    mod usage_analysis_test;
+}
+
+mod vm {
+    mod big_integer_test;
+    mod fixed_width_integer_test;
+    mod jump_table_test;
+    mod program_reader_test;
+    mod verifier_test;
+}
@@ -0,0 +1,146 @@
+#[cfg(test)]
+mod constant_folding_tests {
+    use crate::utils::{assemble, compile, run};
+    use ellie_engine::{
+        ellie_bytecode::{
+            instruction_table::Instructions, optimizer::constant_folding::fold_module,
+        },
+        ellie_core::{
+            definite::{
+                items::{condition::ConditionType, Collecting},
+                types::Types,
+            },
+            defs::PlatformArchitecture,
+        },
+        ellie_parser::parser::Module,
+    };
+
+    /// Compile source and fold it for given architecture
+    fn fold(source: &str, architecture: PlatformArchitecture) -> Module {
+        let mut module = compile(source, true)
+            .unwrap_or_else(|errors| panic!("Expected no errors, found {:#?}", errors))
+            .module;
+        fold_module(&mut module, architecture);
+        module
+    }
+
+    /// Items of the test source, pages of ellieCore are skipped
+    fn items(module: &Module) -> impl Iterator<Item = &Collecting> {
+        module
+            .pages
+            .iter()
+            .filter(|page| page.path.starts_with("<ellie_module_test>"))
+            .flat_map(|page| page.items.iter())
+    }
+
+    fn variable_value(module: &Module, name: &str) -> Types {
+        items(module)
+            .find_map(|item| match item {
+                Collecting::Variable(variable) if variable.name == name => {
+                    Some(variable.value.clone())
+                }
+                _ => None,
+            })
+            .unwrap_or_else(|| panic!("Expected variable {}", name))
+    }
+
+    fn integer_value(value: &Types) -> Option<isize> {
+        match value {
+            Types::Integer(integer) => Some(integer.value),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn arithmetic_folded() {
+        let code = "
+            c base = 4;
+            fn main() {
+                v folded = (2 * 3) + base;
+                println(\"folded \" + folded);
+            }
+        ";
+        let module = fold(code, PlatformArchitecture::B64);
+        assert_eq!(integer_value(&variable_value(&module, "folded")), Some(10));
+        assert_eq!(run(code, 1), run(code, 0));
+    }
+
+    #[test]
+    fn constant_branch_pruned() {
+        let code = "
+            fn main() {
+                if 1 == 2 {
+                    println(\"never\");
+                } else if 2 == 2 {
+                    println(\"always\");
+                } else {
+                    println(\"unreachable\");
+                }
+                if 1 == 3 {
+                    println(\"never\");
+                }
+            }
+        ";
+        let module = fold(code, PlatformArchitecture::B64);
+        let conditions = items(&module)
+            .filter_map(|item| match item {
+                Collecting::Condition(condition) => Some(condition),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(conditions.len(), 1);
+        assert_eq!(conditions[0].chains.len(), 1);
+        assert!(conditions[0].chains[0].rtype == ConditionType::Else);
+        assert_eq!(run(code, 1), "always\n");
+    }
+
+    #[test]
+    fn overflow_of_target_width_not_folded() {
+        let code = "
+            fn main() {
+                v wide = 2147483647 + 1;
+                v narrow = 32767 + 1;
+            }
+        ";
+        let module = fold(code, PlatformArchitecture::B64);
+        assert_eq!(
+            integer_value(&variable_value(&module, "wide")),
+            Some(2147483648)
+        );
+
+        let module = fold(code, PlatformArchitecture::B32);
+        assert!(matches!(
+            variable_value(&module, "wide"),
+            Types::Operator(_)
+        ));
+        assert_eq!(
+            integer_value(&variable_value(&module, "narrow")),
+            Some(32768)
+        );
+
+        let module = fold(code, PlatformArchitecture::B16);
+        assert!(matches!(
+            variable_value(&module, "narrow"),
+            Types::Operator(_)
+        ));
+    }
+
+    #[test]
+    fn folded_only_when_optimizing() {
+        let code = "
+            fn main() {
+                v total = 2 * 3;
+                println(\"total \" + total);
+            }
+        ";
+        let multiplications = |optimization_level| {
+            assemble(code, optimization_level)
+                .instructions
+                .iter()
+                .filter(|instruction| matches!(instruction, Instructions::MUL(_)))
+                .count()
+        };
+        assert_eq!(multiplications(0), 1);
+        assert_eq!(multiplications(1), 0);
+    }
+}
@@ -0,0 +1,204 @@
+#[cfg(test)]
+mod peephole_tests {
+    use crate::utils::{assemble, run};
+    use ellie_engine::ellie_bytecode::{
+        addressing_modes::AddressingModes, assembler::AssembleResult,
+        instruction_table::Instructions,
+    };
+
+    /// Every location the result points at has to be an instruction of it
+    fn assert_in_bounds(result: &AssembleResult) {
+        let len = result.instructions.len();
+        for debug_header in &result.debug_headers {
+            let (start, end) = debug_header.start_end;
+            assert!(
+                start <= end && end < len,
+                "Debug header {} out of bounds {:?}",
+                debug_header.name,
+                debug_header.start_end
+            );
+        }
+        for (idx, instruction) in result.instructions.iter().enumerate() {
+            if let Instructions::JMP(e) | Instructions::JMPA(e) = instruction {
+                if let AddressingModes::Absolute(target) = e.addressing_mode {
+                    assert!(target < len, "Jump at {idx} out of bounds {target}");
+                }
+            }
+        }
+        for relocation in &result.relocations {
+            assert!(
+                matches!(
+                    result.instructions[*relocation]
+                        .get_addressing_mode_ref()
+                        .addressing_mode,
+                    AddressingModes::Immediate(_, _)
+                ),
+                "Relocation at {relocation} is not an immediate"
+            );
+        }
+        for local in &result.locals {
+            assert!(local.cursor < len, "Local {} out of bounds", local.name);
+        }
+    }
+
+    /// Assert optimized program writes the same, returns the count of removed instructions
+    fn assert_same_output(code: &str) -> usize {
+        let unoptimized_result = assemble(code, 0);
+        let optimized_result = assemble(code, 1);
+        assert_in_bounds(&unoptimized_result);
+        assert_in_bounds(&optimized_result);
+
+        let unoptimized = run(code, 0);
+        let optimized = run(code, 1);
+        assert!(!unoptimized.is_empty());
+        assert_eq!(unoptimized, optimized);
+        unoptimized_result.instructions.len() - optimized_result.instructions.len()
+    }
+
+    #[test]
+    fn array_index_with_same_output() {
+        assert_same_output(
+            "
+            fn main() {
+                v ar = [10, 20, 30];
+                v q1 = ar[1]!;
+                println(\"q1 \" + q1);
+            }
+            ",
+        );
+    }
+
+    #[test]
+    fn fixed_size_array_with_same_output() {
+        let removed = assert_same_output(
+            "
+            fn main() {
+                v numbers : [int, 3] = [4, 5, 6];
+                for item : numbers {
+                    println(\"\" + item);
+                }
+                v last = numbers[2]!;
+                println(\"\" + last);
+            }
+            ",
+        );
+        assert!(removed > 0);
+    }
+
+    #[test]
+    fn array_parameter_with_same_output() {
+        assert_same_output(
+            "
+            fn getAt(zz: [int], ii: int) : int {
+                ret zz[ii]!;
+            }
+            fn main() {
+                v ar = [10, 20, 30];
+                println(\"g \" + getAt(ar, 2));
+            }
+            ",
+        );
+    }
+
+    #[test]
+    fn match_on_parameter_with_same_output() {
+        let removed = assert_same_output(
+            "
+            enum Color {
+                Red,
+                Green,
+                Blue(int),
+            }
+            fn describe(cc: Color) : int {
+                match cc {
+                    Color.Red => { ret 1; }
+                    Color.Green => { ret 2; }
+                    Color.Blue(nn) => { ret nn; }
+                }
+                ret 0;
+            }
+            fn lit(qq: int) : string {
+                match qq {
+                    1 => { ret \"one\"; }
+                    _ => { ret \"many\"; }
+                }
+                ret \"none\";
+            }
+            fn main() {
+                println(\"\" + describe(Color.Red));
+                println(\"\" + describe(Color.Blue(42)));
+                println(lit(1));
+                println(lit(7));
+            }
+            ",
+        );
+        assert!(removed > 0);
+    }
+
+    #[test]
+    fn for_loop_with_same_output() {
+        let removed = assert_same_output(
+            "
+            fn main() {
+                v arr = [10, 20, 30];
+                for item : arr {
+                    println(\"\" + item);
+                }
+                v total = 0;
+                for k : 10 {
+                    if k == 4 {
+                        brk;
+                    }
+                    total += k;
+                }
+                println(\"\" + total);
+            }
+            ",
+        );
+        assert!(removed > 0);
+    }
+
+    #[test]
+    fn generic_call_with_same_output() {
+        assert_same_output(
+            "
+            fn firstOf<T>(qq: [T]) : T {
+                ret qq[0]!;
+            }
+            fn ident<T>(val: T) : T {
+                ret val;
+            }
+            fn main() {
+                v n = firstOf([4, 5]);
+                v st = ident(\"hey\");
+                println(st + n);
+            }
+            ",
+        );
+    }
+
+    #[test]
+    fn interface_call_with_same_output() {
+        let removed = assert_same_output(
+            "
+            interface Shape {
+                fn area() : int;
+            }
+            class Sq impl Shape {
+                co(side);
+                v side : int;
+                fn area() : int {
+                    ret self.side * self.side;
+                }
+            }
+            fn areaOf(sh: Shape) : int {
+                ret sh.area();
+            }
+            fn main() {
+                println(\"\" + areaOf(new Sq(3)));
+            }
+            ",
+        );
+        assert!(removed > 0);
+    }
+}
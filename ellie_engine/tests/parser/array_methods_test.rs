@@ -0,0 +1,117 @@
+#[cfg(test)]
+mod array_methods_tests {
+    use crate::utils::{error_codes, run};
+
+    const TYPE_MISMATCH: u8 = 0x02;
+
+    #[test]
+    fn array_methods_change_array() {
+        let code = "
+            fn main() {
+                v numbers : [int] = [1, 2, 3];
+                numbers.push(4);
+                v last = numbers.pop();
+                numbers.insert(0, 9);
+                v removed = numbers.remove(1);
+                v first = numbers[0]!;
+                println(\"Popped: \" + last);
+                println(\"Removed: \" + removed);
+                println(\"First: \" + first);
+                println(\"Length: \" + arrayLength(numbers));
+                numbers.clean();
+                println(\"Cleaned: \" + arrayLength(numbers));
+            }
+        ";
+        assert_eq!(
+            run(code, 0),
+            "Popped: 4\nRemoved: 1\nFirst: 9\nLength: 3\nCleaned: 0\n"
+        );
+    }
+
+    #[test]
+    fn dynamic_and_valueless_arrays_grow() {
+        let code = "
+            fn main() {
+                v numbers : [int, *] = [1, 2];
+                numbers.push(3);
+                v empty : [int];
+                empty.push(5);
+                v second = numbers.slice(1, 3)[0]!;
+                v pushed = empty[0]!;
+                println(\"Second: \" + second);
+                println(\"Pushed: \" + pushed);
+            }
+        ";
+        assert_eq!(run(code, 0), "Second: 2\nPushed: 5\n");
+    }
+
+    #[test]
+    fn nested_and_string_arrays_grow() {
+        let code = "
+            fn main() {
+                v grid = [[1, 2], [3]];
+                grid.push([4, 5, 6]);
+                v row = grid[2]!;
+                v words = [\"ab\"];
+                words.push(\"cd\");
+                for word : words {
+                    println(\"Word: \" + word);
+                }
+                println(\"Row: \" + arrayLength(row));
+            }
+        ";
+        assert_eq!(run(code, 0), "Word: ab\nWord: cd\nRow: 3\n");
+    }
+
+    #[test]
+    fn fixed_size_array_read() {
+        let code = "
+            fn main() {
+                v numbers : [int, 2] = [1, 2];
+                v second = numbers[1]!;
+                println(\"Second: \" + second + \" Length: \" + arrayLength(numbers));
+                for item : numbers {
+                    println(\"Item: \" + item);
+                }
+            }
+        ";
+        assert_eq!(run(code, 0), "Second: 2 Length: 2\nItem: 1\nItem: 2\n");
+    }
+
+    #[test]
+    fn push_mismatched_entry_with_error() {
+        let code = "
+            fn main() {
+                v numbers = [1, 2];
+                numbers.push(\"three\");
+            }
+        ";
+        assert_eq!(error_codes(code), vec![TYPE_MISMATCH]);
+    }
+
+    #[test]
+    #[should_panic(expected = "WrongEntryLength")]
+    fn grow_fixed_size_array_panics() {
+        let code = "
+            fn main() {
+                v numbers : [int, 2] = [1, 2];
+                numbers.push(3);
+                println(\"Pushed\");
+            }
+        ";
+        run(code, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "IndexOutOfBounds")]
+    fn remove_out_of_bounds_panics() {
+        let code = "
+            fn main() {
+                v numbers = [1, 2];
+                v removed = numbers.remove(5);
+                println(\"Removed: \" + removed);
+            }
+        ";
+        run(code, 0);
+    }
+}
@@ -0,0 +1,82 @@
+#[cfg(test)]
+mod core_library_tests {
+    use crate::utils::{error_codes, run};
+
+    const TYPE_MISMATCH: u8 = 0x02;
+
+    #[test]
+    fn math_functions() {
+        let code = "
+            fn main() {
+                println(\"Abs: \" + abs(-4));
+                println(\"Min: \" + min(3, 7));
+                println(\"Max: \" + max(3, 7));
+                println(\"Clamp: \" + clamp(12, 0, 10));
+                println(\"Pow: \" + pow(2, 5));
+            }
+        ";
+        assert_eq!(run(code, 0), "Abs: 4\nMin: 3\nMax: 7\nClamp: 10\nPow: 32\n");
+    }
+
+    #[test]
+    fn string_functions() {
+        let code = "
+            fn main() {
+                v text = \"  Ellie  \";
+                v trimmed = trim(text);
+                println(\"Trimmed: \" + trimmed);
+                println(\"Length: \" + stringLength(trimmed));
+                println(\"Upper: \" + toUpperCase(trimmed));
+                println(\"Index: \" + indexOf(trimmed, \"l\"));
+                println(\"Part: \" + substring(trimmed, 1, 3));
+                println(\"Repeat: \" + repeat(\"ab\", 2));
+                println(\"Parsed: \" + (parseInt(\"41\") + 1));
+            }
+        ";
+        assert_eq!(run(code, 0), "Trimmed: Ellie\nLength: 5\nUpper: ELLIE\nIndex: 1\nPart: ll\nRepeat: abab\nParsed: 42\n");
+    }
+
+    #[test]
+    fn string_predicates() {
+        let code = "
+            fn main() {
+                if contains(\"Ellie\", \"lli\") {
+                    println(\"Contains\");
+                }
+                if startsWith(\"Ellie\", \"x\") {
+                    println(\"Starts\");
+                } else {
+                    println(\"Does not start\");
+                }
+            }
+        ";
+        assert_eq!(run(code, 0), "Contains\nDoes not start\n");
+    }
+
+    #[test]
+    fn array_functions_with_shared_parameter_names() {
+        let code = "
+            fn scale(x: int, s: int) : int {
+                ret x * s;
+            }
+
+            fn main() {
+                v numbers = [1, 2, 3];
+                println(\"Sum: \" + sum(numbers));
+                println(\"Length: \" + arrayLength(numbers));
+                println(\"Scaled: \" + scale(2, 3));
+            }
+        ";
+        assert_eq!(run(code, 0), "Sum: 6\nLength: 3\nScaled: 6\n");
+    }
+
+    #[test]
+    fn wrong_argument_with_error() {
+        let code = "
+            fn main() {
+                v value = abs(\"four\");
+            }
+        ";
+        assert_eq!(error_codes(code), vec![TYPE_MISMATCH]);
+    }
+}
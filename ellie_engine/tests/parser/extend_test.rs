@@ -0,0 +1,113 @@
+#[cfg(test)]
+mod extend_tests {
+    use crate::utils::{error_codes, run};
+
+    const UNDEFINED: u8 = 0x05;
+    const EXTEND_NON_CLASS: u8 = 0x67;
+
+    #[test]
+    fn class_extended() {
+        let code = "
+            class Counter {
+                co(count);
+                v count : int;
+            }
+
+            extend Counter {
+                fn doubled() : int {
+                    ret self.count * 2;
+                }
+
+                fn quadrupled() : int {
+                    ret self.doubled() * 2;
+                }
+            }
+
+            fn main() {
+                v counter = new Counter(3);
+                println(\"Doubled: \" + counter.doubled());
+                println(\"Quadrupled: \" + counter.quadrupled());
+            }
+        ";
+        assert_eq!(run(code, 0), "Doubled: 6\nQuadrupled: 12\n");
+    }
+
+    #[test]
+    fn generic_class_extended() {
+        let code = "
+            class Box<T> {
+                co(item);
+                v item : T;
+            }
+
+            extend Box<T> {
+                fn get() : T {
+                    ret self.item;
+                }
+            }
+
+            fn main() {
+                v box = new Box<int>(5);
+                println(\"Item: \" + box.get());
+            }
+        ";
+        assert_eq!(run(code, 0), "Item: 5\n");
+    }
+
+    #[test]
+    fn core_classes_extended() {
+        let code = "
+            extend int {
+                fn twice() : int {
+                    ret self * 2;
+                }
+            }
+
+            extend array<T> {
+                fn firstItem() : T {
+                    ret self[0]!;
+                }
+            }
+
+            fn main() {
+                v value = 4;
+                v items = [7, 8];
+                println(\"Twice: \" + value.twice());
+                println(\"First: \" + items.firstItem());
+            }
+        ";
+        assert_eq!(run(code, 0), "Twice: 8\nFirst: 7\n");
+    }
+
+    #[test]
+    fn extend_function_with_error() {
+        let code = "
+            fn helper() {}
+
+            extend helper {
+                fn twice() : int {
+                    ret 2;
+                }
+            }
+
+            fn main() {
+                helper();
+            }
+        ";
+        assert_eq!(error_codes(code), vec![EXTEND_NON_CLASS]);
+    }
+
+    #[test]
+    fn extend_undefined_with_error() {
+        let code = "
+            extend Missing {
+                fn twice() : int {
+                    ret 2;
+                }
+            }
+
+            fn main() {}
+        ";
+        assert_eq!(error_codes(code), vec![UNDEFINED]);
+    }
+}
@@ -0,0 +1,171 @@
+#[cfg(test)]
+mod flow_analysis_tests {
+    use crate::utils::{error_codes, warning_codes};
+
+    const USED_BEFORE_ASSIGNED: u8 = 0x66;
+    const UNNECESSARY_NULL_RESOLVER: u8 = 0x0C;
+    const NULL_RESOLVE_ON_NULL: u8 = 0x0D;
+    const OPERATOR_NOT_IMPLEMENTED: u8 = 0x51;
+
+    #[test]
+    fn assigned_variable_read_with_no_error() {
+        let code = "
+            fn main() {
+                v value : int;
+                value = 2;
+                v copy = value;
+                copy = 3;
+            }
+        ";
+        assert_eq!(error_codes(code), vec![]);
+    }
+
+    #[test]
+    fn read_before_assignment_with_error() {
+        let code = "
+            fn main() {
+                v value : int;
+                v copy = value;
+                value = 2;
+                copy = 3;
+            }
+        ";
+        assert_eq!(error_codes(code), vec![USED_BEFORE_ASSIGNED]);
+    }
+
+    #[test]
+    fn assigned_in_every_branch_with_no_error() {
+        let code = "
+            fn main() {
+                v counter = 0;
+                v value : int;
+                if counter == 0 {
+                    value = 1;
+                } else {
+                    value = 2;
+                }
+                v copy = value;
+                copy = 3;
+            }
+        ";
+        assert_eq!(error_codes(code), vec![]);
+    }
+
+    #[test]
+    fn assigned_in_one_branch_with_error() {
+        let code = "
+            fn main() {
+                v counter = 0;
+                v value : int;
+                if counter == 0 {
+                    value = 1;
+                }
+                v copy = value;
+                copy = 3;
+            }
+        ";
+        assert_eq!(error_codes(code), vec![USED_BEFORE_ASSIGNED]);
+    }
+
+    #[test]
+    fn resolve_unknown_nullable_with_no_warning() {
+        let code = "
+            fn pick(first: ?int) : int {
+                ret first!;
+            }
+
+            fn main() {
+                println(\"Pick: \" + pick(3));
+            }
+        ";
+        assert_eq!(warning_codes(code), vec![]);
+    }
+
+    #[test]
+    fn resolve_null_with_warning() {
+        let code = "
+            fn main() {
+                v value : ?int;
+                v copy : int = value!;
+                println(\"Copy: \" + copy);
+            }
+        ";
+        assert_eq!(warning_codes(code), vec![NULL_RESOLVE_ON_NULL]);
+    }
+
+    #[test]
+    fn resolve_checked_nullable_with_warning() {
+        let code = "
+            fn pick(first: ?int) : int {
+                if first != null {
+                    ret first!;
+                }
+                ret 0;
+            }
+
+            fn main() {
+                println(\"Pick: \" + pick(3));
+            }
+        ";
+        assert_eq!(warning_codes(code), vec![UNNECESSARY_NULL_RESOLVER]);
+    }
+
+    #[test]
+    fn resolve_after_early_return_on_null_with_warning() {
+        let code = "
+            fn pick(first: ?int) : int {
+                if first == null {
+                    ret 0;
+                }
+                ret first!;
+            }
+
+            fn main() {
+                println(\"Pick: \" + pick(null));
+            }
+        ";
+        assert_eq!(warning_codes(code), vec![UNNECESSARY_NULL_RESOLVER]);
+    }
+
+    #[test]
+    fn resolve_nullable_checked_in_other_branch_with_no_warning() {
+        let code = "
+            fn pick(first: ?int) : int {
+                if first == null {
+                    println(\"Empty\");
+                }
+                ret first!;
+            }
+
+            fn main() {
+                println(\"Pick: \" + pick(3));
+            }
+        ";
+        assert_eq!(warning_codes(code), vec![]);
+    }
+
+    #[test]
+    fn resolve_assigned_null_with_warning() {
+        let code = "
+            fn main() {
+                v value : ?int = null;
+                v copy : int = value!;
+                println(\"Copy: \" + copy);
+            }
+        ";
+        assert_eq!(warning_codes(code), vec![NULL_RESOLVE_ON_NULL]);
+    }
+
+    #[test]
+    fn compare_non_nullable_with_null_with_error() {
+        let code = "
+            fn main() {
+                v value = 3;
+                if value == null {
+                    value = 4;
+                }
+            }
+        ";
+        assert_eq!(error_codes(code), vec![OPERATOR_NOT_IMPLEMENTED]);
+    }
+}
@@ -0,0 +1,99 @@
+#[cfg(test)]
+mod for_loop_tests {
+    use crate::utils::{error_codes, run};
+
+    const NOT_ITERABLE: u8 = 0x28;
+    const UNKNOWN_TYPE: u8 = 0x54;
+    const MUTATED_PARAMETER: u8 = 0x58;
+
+    #[test]
+    fn range_iterated() {
+        let code = "
+            fn main() {
+                for index : 3 {
+                    println(\"Index: \" + index);
+                }
+            }
+        ";
+        assert_eq!(run(code, 0), "Index: 0\nIndex: 1\nIndex: 2\n");
+    }
+
+    #[test]
+    fn array_iterated() {
+        let code = "
+            fn main() {
+                v items = [10, 20, 30];
+                for item : items {
+                    println(\"Item: \" + item);
+                }
+            }
+        ";
+        assert_eq!(run(code, 0), "Item: 10\nItem: 20\nItem: 30\n");
+    }
+
+    #[test]
+    fn string_iterated() {
+        let code = "
+            fn main() {
+                for letter : \"ab\" {
+                    println(\"Letter: \" + letter);
+                }
+            }
+        ";
+        assert_eq!(run(code, 0), "Letter: a\nLetter: b\n");
+    }
+
+    #[test]
+    fn break_leaves_loop() {
+        let code = "
+            fn main() {
+                v total = 0;
+                for index : 10 {
+                    if index == 4 {
+                        brk;
+                    }
+                    total += index;
+                }
+                println(\"Total: \" + total);
+            }
+        ";
+        assert_eq!(run(code, 0), "Total: 6\n");
+    }
+
+    #[test]
+    fn assign_loop_variable_with_error() {
+        let code = "
+            fn main() {
+                for index : 3 {
+                    index = 2;
+                }
+            }
+        ";
+        assert_eq!(error_codes(code), vec![MUTATED_PARAMETER]);
+    }
+
+    #[test]
+    fn iterate_non_iterable_with_error() {
+        let code = "
+            fn main() {
+                v value = 3;
+                for item : value == 3 {
+                    println(\"Item\");
+                }
+            }
+        ";
+        assert_eq!(error_codes(code), vec![NOT_ITERABLE]);
+    }
+
+    #[test]
+    fn iterate_untyped_empty_array_with_error() {
+        let code = "
+            fn main() {
+                for item : [] {
+                    println(\"Item\");
+                }
+            }
+        ";
+        assert_eq!(error_codes(code), vec![UNKNOWN_TYPE]);
+    }
+}
@@ -0,0 +1,100 @@
+#[cfg(test)]
+mod generic_function_tests {
+    use crate::utils::{error_codes, run};
+
+    const TYPE_MISMATCH: u8 = 0x02;
+
+    #[test]
+    fn generics_inferred_from_arguments() {
+        let code = "
+            fn firstOf<T>(items: [T]) : T {
+                ret items[0]!;
+            }
+
+            fn same<T>(value: T) : T {
+                ret value;
+            }
+
+            fn main() {
+                v numbers = [4, 5];
+                v words = [\"alpha\", \"beta\"];
+                v number = firstOf(numbers);
+                v word = firstOf(words);
+                println(\"Number: \" + number);
+                println(\"Word: \" + word);
+                println(\"Same: \" + same(3));
+            }
+        ";
+        assert_eq!(run(code, 0), "Number: 4\nWord: alpha\nSame: 3\n");
+    }
+
+    #[test]
+    fn conflicting_arguments_with_error() {
+        let code = "
+            fn pick<T>(first: T, second: T) : T {
+                ret first;
+            }
+
+            fn main() {
+                v picked = pick(1, \"two\");
+            }
+        ";
+        assert_eq!(error_codes(code), vec![TYPE_MISMATCH]);
+    }
+
+    #[test]
+    fn conflicting_array_argument_with_error() {
+        let code = "
+            fn both<T>(value: T, items: [T]) : T {
+                ret value;
+            }
+
+            fn main() {
+                v picked = both(1, [\"a\"]);
+            }
+        ";
+        assert_eq!(error_codes(code), vec![TYPE_MISMATCH]);
+    }
+
+    #[test]
+    fn inferred_return_mismatch_with_error() {
+        let code = "
+            fn firstOf<T>(items: [T]) : T {
+                ret items[0]!;
+            }
+
+            fn main() {
+                v number : string = firstOf([1, 2]);
+            }
+        ";
+        assert_eq!(error_codes(code), vec![TYPE_MISMATCH]);
+    }
+
+    #[test]
+    fn uninferable_argument_with_error() {
+        let code = "
+            fn firstOf<T>(items: [T]) : T {
+                ret items[0]!;
+            }
+
+            fn main() {
+                v number = firstOf(5);
+            }
+        ";
+        assert_eq!(error_codes(code), vec![TYPE_MISMATCH]);
+    }
+
+    #[test]
+    fn generic_not_in_arguments_with_error() {
+        let code = "
+            fn make<T>() : int {
+                ret 1;
+            }
+
+            fn main() {
+                v made = make();
+            }
+        ";
+        assert_eq!(error_codes(code), vec![TYPE_MISMATCH]);
+    }
+}
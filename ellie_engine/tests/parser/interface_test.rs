@@ -0,0 +1,201 @@
+#[cfg(test)]
+mod interface_tests {
+    use crate::utils::{error_codes, run};
+
+    const IMPL_NON_INTERFACE: u8 = 0x69;
+    const MISSING_MEMBER: u8 = 0x6A;
+    const MISMATCHED_MEMBER: u8 = 0x6B;
+    const UNSATISFIED_BOUND: u8 = 0x6C;
+
+    #[test]
+    fn interface_method_dispatched() {
+        let code = "
+            interface Shape {
+                fn area() : int;
+            }
+
+            class Square impl Shape {
+                co(side);
+                v side : int;
+
+                fn area() : int {
+                    ret self.side * self.side;
+                }
+            }
+
+            class Rect impl Shape {
+                co(width, height);
+                v width : int;
+                v height : int;
+
+                fn area() : int {
+                    ret self.width * self.height;
+                }
+            }
+
+            fn areaOf(shape: Shape) : int {
+                ret shape.area();
+            }
+
+            fn main() {
+                v square = new Square(3);
+                v rect = new Rect(2, 5);
+                println(\"Square: \" + areaOf(square));
+                println(\"Rect: \" + areaOf(rect));
+            }
+        ";
+        assert_eq!(run(code, 0), "Square: 9\nRect: 10\n");
+    }
+
+    #[test]
+    fn interface_property_resolved() {
+        let code = "
+            interface Named {
+                v name : string;
+            }
+
+            class Person impl Named {
+                co(name);
+                pub v name : string;
+            }
+
+            fn nameOf(item: Named) : string {
+                ret item.name;
+            }
+
+            fn main() {
+                v person = new Person(\"Ada\");
+                println(\"Name: \" + nameOf(person));
+            }
+        ";
+        assert_eq!(run(code, 0), "Name: Ada\n");
+    }
+
+    #[test]
+    fn bounded_generic_resolved() {
+        let code = "
+            interface Shape {
+                fn area() : int;
+            }
+
+            class Square impl Shape {
+                co(side);
+                v side : int;
+
+                fn area() : int {
+                    ret self.side * self.side;
+                }
+            }
+
+            class Holder<T: Shape> {
+                co(shape);
+                v shape : T;
+
+                fn area() : int {
+                    ret self.shape.area();
+                }
+            }
+
+            fn main() {
+                v holder = new Holder<Square>(new Square(3));
+                println(\"Area: \" + holder.area());
+            }
+        ";
+        assert_eq!(run(code, 0), "Area: 9\n");
+    }
+
+    #[test]
+    fn missing_member_with_error() {
+        let code = "
+            interface Shape {
+                fn area() : int;
+            }
+
+            class Square impl Shape {
+                co(side);
+                v side : int;
+            }
+
+            fn main() {
+                v square = new Square(2);
+            }
+        ";
+        assert_eq!(error_codes(code), vec![MISSING_MEMBER]);
+    }
+
+    #[test]
+    fn mismatched_member_with_error() {
+        let code = "
+            interface Shape {
+                fn area() : int;
+            }
+
+            class Square impl Shape {
+                co(side);
+                v side : int;
+
+                fn area() : string {
+                    ret \"big\";
+                }
+            }
+
+            fn main() {
+                v square = new Square(2);
+            }
+        ";
+        assert_eq!(error_codes(code), vec![MISMATCHED_MEMBER]);
+    }
+
+    #[test]
+    fn implement_class_with_error() {
+        let code = "
+            class Square {
+                co(side);
+                v side : int;
+            }
+
+            class Cube impl Square {
+                co(depth);
+                v depth : int;
+            }
+
+            fn main() {
+                v cube = new Cube(2);
+            }
+        ";
+        assert_eq!(error_codes(code), vec![IMPL_NON_INTERFACE]);
+    }
+
+    #[test]
+    fn unsatisfied_bound_with_error() {
+        let code = "
+            interface Shape {
+                fn area() : int;
+            }
+
+            class Square {
+                co(side);
+                v side : int;
+
+                fn area() : int {
+                    ret self.side * self.side;
+                }
+            }
+
+            class Holder<T: Shape> {
+                co(shape);
+                v shape : T;
+
+                fn area() : int {
+                    ret self.shape.area();
+                }
+            }
+
+            fn main() {
+                v holder = new Holder<Square>(new Square(3));
+                println(\"Area: \" + holder.area());
+            }
+        ";
+        assert_eq!(error_codes(code), vec![UNSATISFIED_BOUND]);
+    }
+}
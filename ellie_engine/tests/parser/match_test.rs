@@ -0,0 +1,159 @@
+#[cfg(test)]
+mod match_tests {
+    use crate::utils::{error_codes, run};
+
+    const TYPE_MISMATCH: u8 = 0x02;
+    const NOT_EXHAUSTIVE: u8 = 0x6D;
+
+    #[test]
+    fn enum_variants_matched() {
+        let code = "
+            enum Color {
+                Red,
+                Green,
+                Blue(int),
+            }
+
+            fn describe(color: Color) : int {
+                match color {
+                    Color.Red => {
+                        ret 1;
+                    }
+                    Color.Green => {
+                        ret 2;
+                    }
+                    Color.Blue(depth) => {
+                        ret depth;
+                    }
+                }
+                ret 0;
+            }
+
+            fn main() {
+                println(\"Red: \" + describe(Color.Red));
+                println(\"Blue: \" + describe(Color.Blue(7)));
+            }
+        ";
+        assert_eq!(run(code, 0), "Red: 1\nBlue: 7\n");
+    }
+
+    #[test]
+    fn literals_matched() {
+        let code = "
+            fn name(value: int) : string {
+                match value {
+                    1 => {
+                        ret \"one\";
+                    }
+                    2 => {
+                        ret \"two\";
+                    }
+                    _ => {
+                        ret \"many\";
+                    }
+                }
+                ret \"none\";
+            }
+
+            fn main() {
+                println(\"1: \" + name(1));
+                println(\"5: \" + name(5));
+            }
+        ";
+        assert_eq!(run(code, 0), "1: one\n5: many\n");
+    }
+
+    #[test]
+    fn nullable_matched() {
+        let code = "
+            fn orZero(value: ?int) : int {
+                match value {
+                    null => {
+                        ret 0;
+                    }
+                    _ => {
+                        ret 1;
+                    }
+                }
+                ret 2;
+            }
+
+            fn main() {
+                println(\"Some: \" + orZero(5));
+            }
+        ";
+        assert_eq!(run(code, 0), "Some: 1\n");
+    }
+
+    #[test]
+    fn missing_variants_with_error() {
+        let code = "
+            enum Color {
+                Red,
+                Green,
+                Blue(int),
+            }
+
+            fn describe(color: Color) : int {
+                match color {
+                    Color.Red => {
+                        ret 1;
+                    }
+                }
+                ret 0;
+            }
+
+            fn main() {
+                println(\"Red: \" + describe(Color.Red));
+            }
+        ";
+        assert_eq!(error_codes(code), vec![NOT_EXHAUSTIVE]);
+    }
+
+    #[test]
+    fn literals_without_wildcard_with_error() {
+        let code = "
+            fn name(value: int) : string {
+                match value {
+                    1 => {
+                        ret \"one\";
+                    }
+                    2 => {
+                        ret \"two\";
+                    }
+                }
+                ret \"none\";
+            }
+
+            fn main() {
+                println(\"1: \" + name(1));
+            }
+        ";
+        assert_eq!(error_codes(code), vec![NOT_EXHAUSTIVE]);
+    }
+
+    #[test]
+    fn mismatched_pattern_with_error() {
+        let code = "
+            fn name(value: int) : string {
+                match value {
+                    1 => {
+                        ret \"one\";
+                    }
+                    \"two\" => {
+                        ret \"two\";
+                    }
+                    _ => {
+                        ret \"many\";
+                    }
+                }
+                ret \"none\";
+            }
+
+            fn main() {
+                println(\"1: \" + name(1));
+            }
+        ";
+        assert_eq!(error_codes(code), vec![TYPE_MISMATCH]);
+    }
+}
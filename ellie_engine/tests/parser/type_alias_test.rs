@@ -0,0 +1,69 @@
+#[cfg(test)]
+mod type_alias_tests {
+    use crate::utils::{error_codes, run};
+
+    const TYPE_MISMATCH: u8 = 0x02;
+    const MISSING_GENERICS: u8 = 0x43;
+    const TYPE_AS_VALUE: u8 = 0x68;
+
+    #[test]
+    fn aliases_resolved() {
+        let code = "
+            type Numbers = [int];
+            type Maybe<T> = ?T;
+
+            fn total(items: Numbers) : int {
+                v result = 0;
+                for item : items {
+                    result += item;
+                }
+                ret result;
+            }
+
+            fn main() {
+                v items : Numbers = [1, 2, 3];
+                v first : Maybe<int> = items[0];
+                v resolved = first!;
+                println(\"Total: \" + total(items));
+                println(\"First: \" + resolved);
+            }
+        ";
+        assert_eq!(run(code, 0), "Total: 6\nFirst: 1\n");
+    }
+
+    #[test]
+    fn value_not_matching_alias_with_error() {
+        let code = "
+            type Numbers = [int];
+
+            fn main() {
+                v items : Numbers = \"text\";
+            }
+        ";
+        assert_eq!(error_codes(code), vec![TYPE_MISMATCH]);
+    }
+
+    #[test]
+    fn generic_alias_without_generics_with_error() {
+        let code = "
+            type Pair<T> = [T];
+
+            fn main() {
+                v items : Pair = [1];
+            }
+        ";
+        assert_eq!(error_codes(code), vec![MISSING_GENERICS]);
+    }
+
+    #[test]
+    fn alias_as_value_with_error() {
+        let code = "
+            type Numbers = [int];
+
+            fn main() {
+                v items = Numbers;
+            }
+        ";
+        assert_eq!(error_codes(code), vec![TYPE_AS_VALUE]);
+    }
+}
@@ -0,0 +1,177 @@
+#[cfg(test)]
+mod usage_analysis_tests {
+    use crate::utils::{library_warning_codes, warning_codes, warning_codes_with_library};
+
+    const UNUSED_VARIABLE: u8 = 0x07;
+    const UNUSED_PARAMETER: u8 = 0x08;
+    const UNUSED_PROPERTY: u8 = 0x09;
+    const UNUSED_IMPORT: u8 = 0x0A;
+    const UNREACHABLE_FUNCTION: u8 = 0x0B;
+
+    #[test]
+    fn used_items_with_no_warning() {
+        let code = "
+            fn twice(value: int) : int {
+                ret value * 2;
+            }
+
+            fn main() {
+                v result = twice(2);
+                println(\"Result: \" + result);
+            }
+        ";
+        assert_eq!(warning_codes(code), vec![]);
+    }
+
+    #[test]
+    fn method_without_self_with_no_warning() {
+        let code = "
+            class Counter {
+                co(count);
+                pub v count : int;
+
+                fn one() : int {
+                    ret 1;
+                }
+            }
+
+            fn main() {
+                v counter = new Counter(1);
+                println(\"Count: \" + counter.count + counter.one());
+            }
+        ";
+        assert_eq!(warning_codes(code), vec![]);
+    }
+
+    #[test]
+    fn unused_variable_with_warning() {
+        let code = "
+            fn main() {
+                v unused = 2;
+            }
+        ";
+        assert_eq!(warning_codes(code), vec![UNUSED_VARIABLE]);
+    }
+
+    #[test]
+    fn unused_parameter_with_warning() {
+        let code = "
+            fn answer(ignored: int) : int {
+                ret 42;
+            }
+
+            fn main() {
+                println(\"Answer: \" + answer(1));
+            }
+        ";
+        assert_eq!(warning_codes(code), vec![UNUSED_PARAMETER]);
+    }
+
+    #[test]
+    fn unused_private_property_with_warning() {
+        let code = "
+            class Counter {
+                co(count, hidden);
+                pub v count : int;
+                pri v hidden : int;
+            }
+
+            fn main() {
+                v counter = new Counter(1, 2);
+                println(\"Count: \" + counter.count);
+            }
+        ";
+        assert_eq!(warning_codes(code), vec![UNUSED_PROPERTY]);
+    }
+
+    #[test]
+    fn unreachable_function_with_warning() {
+        let code = "
+            fn forgotten() {
+                println(\"Never called\");
+            }
+
+            fn main() {
+                println(\"Called\");
+            }
+        ";
+        assert_eq!(warning_codes(code), vec![UNREACHABLE_FUNCTION]);
+    }
+
+    const HELPERS: &str = "
+        pub fn twice(value: int) : int {
+            ret value * 2;
+        }
+    ";
+
+    #[test]
+    fn used_import_with_no_warning() {
+        let code = "
+            import @helpers;
+
+            fn main() {
+                println(\"Result: \" + twice(2));
+            }
+        ";
+        assert_eq!(warning_codes_with_library(code, "helpers", HELPERS), vec![]);
+    }
+
+    #[test]
+    fn unused_import_with_warning() {
+        let code = "
+            import @helpers;
+
+            fn main() {
+                println(\"Result: \" + 4);
+            }
+        ";
+        assert_eq!(
+            warning_codes_with_library(code, "helpers", HELPERS),
+            vec![UNUSED_IMPORT]
+        );
+    }
+
+    #[test]
+    fn public_items_of_library_with_no_warning() {
+        let code = "
+            fn twice(value: int) : int {
+                ret value * 2;
+            }
+
+            pub fn quadruple(value: int) : int {
+                ret twice(twice(value));
+            }
+
+            pub c answer = 42;
+        ";
+        assert_eq!(library_warning_codes(code), vec![]);
+    }
+
+    #[test]
+    fn private_function_unreachable_from_public_items_with_warning() {
+        let code = "
+            fn forgotten() : int {
+                ret 1;
+            }
+
+            pub fn answer() : int {
+                ret 42;
+            }
+        ";
+        assert_eq!(library_warning_codes(code), vec![UNREACHABLE_FUNCTION]);
+    }
+
+    #[test]
+    fn main_of_library_not_a_root() {
+        let code = "
+            fn main() {
+                println(\"Not called\");
+            }
+
+            pub fn answer() : int {
+                ret 42;
+            }
+        ";
+        assert_eq!(library_warning_codes(code), vec![UNREACHABLE_FUNCTION]);
+    }
+}
@@ -0,0 +1,245 @@
+use std::sync::{Arc, Mutex};
+
+use ellie_engine::{
+    compiler::parse_pages,
+    core_library::{compile_core_library, core_library_module, HostCallbacks},
+    ellie_bytecode::{
+        assembler::{AssembleResult, Assembler, PlatformAttributes},
+        optimizer::{constant_folding, peephole},
+    },
+    ellie_core::{
+        defs::{ModuleMap, PlatformArchitecture, Version},
+        error::Error,
+    },
+    ellie_parser::parser::Module,
+    ellie_tokenizer::tokenizer::ResolvedImport,
+    ellie_vm::{
+        channel::ModuleManager,
+        program::{Program, ProgramReadErrors, VmProgram},
+        thread::{Isolate, Thread},
+        utils::{ProgramReader, ThreadExit, ThreadPanicReason},
+    },
+    tokenizer,
+    utils::{CompileOutput, CompilerSettings, MainProgram, ProgramRepository},
+    vm::RFile,
+};
+
+struct Repository {
+    name: String,
+    hash: usize,
+    source: String,
+    modules: Vec<Module>,
+}
+
+impl ProgramRepository for Repository {
+    fn read_main(&mut self) -> MainProgram {
+        MainProgram {
+            file_content: self.source.clone(),
+            file_name: format!("{}.ei", self.name),
+            file_hash: self.hash,
+            start_directory: format!("<ellie_module_{}>", self.name),
+        }
+    }
+
+    fn read_module(
+        &mut self,
+        link_module: bool,
+        _current_path: String,
+        requested_path: String,
+    ) -> ResolvedImport {
+        match self
+            .modules
+            .iter()
+            .find(|module| link_module && module.name == requested_path)
+        {
+            Some(module) => ResolvedImport {
+                found: true,
+                hash: module.hash,
+                path: requested_path,
+                ..Default::default()
+            },
+            None => ResolvedImport {
+                found: false,
+                resolve_error: "Module not found".to_string(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Parse source, ellieCore is linked when `link_core` is set
+pub fn compile(source: &str, link_core: bool) -> Result<CompileOutput, Vec<Error>> {
+    let modules = if link_core {
+        vec![core_library()]
+    } else {
+        Vec::new()
+    };
+    compile_module("test", 0, source, modules, false)
+}
+
+fn core_library() -> Module {
+    compile_core_library()
+        .expect("ellieCore should compile")
+        .module
+}
+
+/// Parse source as module `name`, given modules can be imported with `import @name;`
+fn compile_module(
+    name: &str,
+    hash: usize,
+    source: &str,
+    modules: Vec<Module>,
+    is_lib: bool,
+) -> Result<CompileOutput, Vec<Error>> {
+    let mut repository = Repository {
+        name: name.to_string(),
+        hash,
+        source: source.to_string(),
+        modules: modules.clone(),
+    };
+    let pages = tokenizer::tokenize_file(&mut repository)?;
+    parse_pages(
+        hash,
+        modules.into_iter().map(|module| (module, None)).collect(),
+        pages,
+        CompilerSettings {
+            name: name.to_string(),
+            file_name: format!("{}.ei", name),
+            is_lib,
+            description: String::new(),
+            experimental_features: false,
+            version: Version::build_from_string(&"0.1.0".to_string()),
+            byte_code_architecture: PlatformArchitecture::B64,
+            optimization_level: 0,
+        },
+    )
+}
+
+/// Codes of errors source yields, ellieCore is linked
+pub fn error_codes(source: &str) -> Vec<u8> {
+    match compile(source, true) {
+        Ok(_) => Vec::new(),
+        Err(errors) => errors.iter().map(|error| error.code).collect(),
+    }
+}
+
+/// Codes of warnings source yields, source should compile
+pub fn warning_codes(source: &str) -> Vec<u8> {
+    match compile(source, true) {
+        Ok(output) => output.warnings.iter().map(|warning| warning.code).collect(),
+        Err(errors) => panic!("Expected no errors, found {:#?}", errors),
+    }
+}
+
+/// Codes of warnings source yields while library `name` can be imported with `import @name;`,
+/// ellieCore is linked to both
+pub fn warning_codes_with_library(source: &str, name: &str, library_source: &str) -> Vec<u8> {
+    let core_library = core_library();
+    let library = compile_module(name, 1, library_source, vec![core_library.clone()], true)
+        .unwrap_or_else(|errors| panic!("Expected no errors in library, found {:#?}", errors))
+        .module;
+    match compile_module("test", 0, source, vec![core_library, library], false) {
+        Ok(output) => output.warnings.iter().map(|warning| warning.code).collect(),
+        Err(errors) => panic!("Expected no errors, found {:#?}", errors),
+    }
+}
+
+/// Codes of warnings source yields as a library, ellieCore is linked
+pub fn library_warning_codes(source: &str) -> Vec<u8> {
+    match compile_module("test", 0, source, vec![core_library()], true) {
+        Ok(output) => output.warnings.iter().map(|warning| warning.code).collect(),
+        Err(errors) => panic!("Expected no errors, found {:#?}", errors),
+    }
+}
+
+/// Compile source with ellieCore and assemble it
+pub fn assemble(source: &str, optimization_level: u8) -> AssembleResult {
+    let mut output = compile(source, true).unwrap_or_else(|errors| {
+        panic!("Expected no errors, found {:#?}", errors);
+    });
+    if optimization_level > 0 {
+        constant_folding::fold_module(&mut output.module, PlatformArchitecture::B64);
+    }
+    let mut module_maps = vec![ModuleMap {
+        module_name: output.module.name.clone(),
+        module_hash: output.module.hash,
+        module_path: None,
+    }];
+    module_maps.extend(output.module.modules.iter().map(|module| ModuleMap {
+        module_name: module.name.clone(),
+        module_hash: module.hash,
+        module_path: None,
+    }));
+    let mut assembler = Assembler::new(
+        output.module,
+        PlatformAttributes {
+            architecture: PlatformArchitecture::B64,
+            memory_size: 512000,
+        },
+    );
+    let mut assemble_result = assembler.assemble(module_maps);
+    if optimization_level > 0 {
+        peephole::optimize(&mut assemble_result);
+    }
+    assemble_result
+}
+
+/// Compile source with ellieCore and render it as bytecode file
+pub fn build(source: &str, optimization_level: u8) -> Vec<u8> {
+    assemble(source, optimization_level).render_binary_to_vector(false)
+}
+
+/// Read program from bytecode file
+pub fn read(binary: &[u8]) -> Result<Program, ProgramReadErrors> {
+    let mut binary = binary;
+    let mut reader = RFile::new(&mut binary);
+    let mut program = Program::new();
+    program.build_from_reader(&mut ProgramReader::new(&mut reader))?;
+    Ok(program)
+}
+
+/// Compile source with ellieCore, run it and return what it wrote
+pub fn run(source: &str, optimization_level: u8) -> String {
+    run_binary(&build(source, optimization_level))
+}
+
+/// Run bytecode file with ellieCore and return what it wrote
+pub fn run_binary(binary: &[u8]) -> String {
+    let (written, exit) = execute(binary);
+    if let ThreadExit::Panic(panic) = exit {
+        panic!("Thread panic, reason: {:?}", panic.reason);
+    }
+    written
+}
+
+/// Compile source with ellieCore and run it, returns panic reason of the thread
+pub fn panic_reason(source: &str, optimization_level: u8) -> ThreadPanicReason {
+    match execute(&build(source, optimization_level)) {
+        (_, ThreadExit::Panic(panic)) => panic.reason,
+        (written, ThreadExit::ExitGracefully) => {
+            panic!("Expected thread panic, program wrote {:?}", written)
+        }
+    }
+}
+
+/// Run bytecode file with ellieCore, returns what it wrote and how thread exited
+fn execute(binary: &[u8]) -> (String, ThreadExit) {
+    let program = read(binary).expect("Program should be readable");
+
+    let written = Arc::new(Mutex::new(String::new()));
+    let host_output = written.clone();
+    let mut module_manager = ModuleManager::new();
+    module_manager.register_module(core_library_module(HostCallbacks {
+        write: Arc::new(move |text| host_output.lock().unwrap().push_str(text)),
+        ..Default::default()
+    }));
+
+    let mut vm_program = VmProgram::new();
+    vm_program.fill_from_vector(program.instructions);
+    vm_program.fill_traces(program.native_call_traces);
+    let mut thread = Thread::new(program.main.hash, PlatformArchitecture::B64, Isolate::new());
+    thread.build_thread(program.main);
+    let exit = thread.run(&mut module_manager, &vm_program);
+    let written = written.lock().unwrap().clone();
+    (written, exit)
+}
@@ -0,0 +1,99 @@
+#[cfg(test)]
+mod big_integer_tests {
+    use crate::utils::{panic_reason, run};
+    use ellie_engine::ellie_vm::utils::ThreadPanicReason;
+
+    #[test]
+    fn arithmetic_beyond_int() {
+        let code = "
+            fn main() {
+                v big = 123456789012345678901234567890n;
+                v square = big * big;
+                println(\"square \" + (square as string));
+                v next = square + 1n;
+                println(\"next \" + (next as string));
+                v difference = 10n - 11n;
+                println(\"difference \" + (difference as string));
+                v quotient = square / big;
+                println(\"quotient \" + (quotient as string));
+                v remainder = -7n % 3n;
+                println(\"remainder \" + (remainder as string));
+                v mixed = 18446744073709551616n * 3;
+                println(\"mixed \" + (mixed as string));
+            }
+        ";
+        let expected = "square 15241578753238836750495351562536198787501905199875019052100\n\
+                        next 15241578753238836750495351562536198787501905199875019052101\n\
+                        difference -1\n\
+                        quotient 123456789012345678901234567890\n\
+                        remainder -1\n\
+                        mixed 55340232221128654848\n";
+        assert_eq!(run(code, 0), expected);
+        assert_eq!(run(code, 1), expected);
+    }
+
+    #[test]
+    fn compared_by_value() {
+        let code = "
+            fn main() {
+                v big = 123456789012345678901234567890n;
+                if big > 1n {
+                    println(\"greater\");
+                }
+                if -5n < 1 {
+                    println(\"less\");
+                }
+                if big == 123456789012345678901234567890n {
+                    println(\"equal\");
+                }
+            }
+        ";
+        assert_eq!(run(code, 0), "greater\nless\nequal\n");
+    }
+
+    #[test]
+    fn converted_with_a2bi() {
+        let code = "
+            fn main() {
+                v a = 10 as bigint;
+                println(\"a \" + ((a - 11n) as string));
+                v b = \"99999999999999999999\" as bigint;
+                println(\"b \" + ((b + 1n) as string));
+                v c = 200u8 as bigint;
+                println(\"c \" + (c as string));
+                v d = 12345n as int;
+                println(\"d \" + d);
+            }
+        ";
+        assert_eq!(
+            run(code, 0),
+            "a -1\nb 100000000000000000000\nc 200\nd 12345\n"
+        );
+    }
+
+    #[test]
+    fn division_by_zero_panics() {
+        let code = "
+            fn main() {
+                v a = 10n / 0n;
+            }
+        ";
+        assert!(matches!(
+            panic_reason(code, 0),
+            ThreadPanicReason::IntegerOverflow
+        ));
+    }
+
+    #[test]
+    fn conversion_out_of_int_panics() {
+        let code = "
+            fn main() {
+                v a = 123456789012345678901234567890n as int;
+            }
+        ";
+        assert!(matches!(
+            panic_reason(code, 0),
+            ThreadPanicReason::IntegerOverflow
+        ));
+    }
+}
@@ -0,0 +1,95 @@
+#[cfg(test)]
+mod fixed_width_integer_tests {
+    use crate::utils::{panic_reason, run};
+    use ellie_engine::ellie_vm::utils::ThreadPanicReason;
+
+    #[test]
+    fn arithmetic_in_width() {
+        let code = "
+            fn main() {
+                v a = 200u8;
+                v b = a + 55u8;
+                println(\"b \" + (b as string));
+                v c = -128i8;
+                println(\"c \" + (c as string));
+                v d = 2147483646i32 + 1i32;
+                println(\"d \" + (d as string));
+                v e = 65535u16 - 65534u16;
+                println(\"e \" + (e as string));
+            }
+        ";
+        let expected = "b 255\nc -128\nd 2147483647\ne 1\n";
+        assert_eq!(run(code, 0), expected);
+        assert_eq!(run(code, 1), expected);
+    }
+
+    #[test]
+    fn converted_with_a2n() {
+        let code = "
+            fn main() {
+                v a = 65535 as u16;
+                println(\"a \" + (a as string));
+                v b = 127u8 as i8;
+                println(\"b \" + (b as string));
+                v c = -5i64 as int;
+                println(\"c \" + c);
+            }
+        ";
+        assert_eq!(run(code, 0), "a 65535\nb 127\nc -5\n");
+    }
+
+    #[test]
+    fn byte_width_overflow_panics() {
+        let code = "
+            fn main() {
+                v a = 200u8;
+                v b = a + 100u8;
+            }
+        ";
+        assert!(matches!(
+            panic_reason(code, 0),
+            ThreadPanicReason::ByteOverflow
+        ));
+    }
+
+    #[test]
+    fn integer_width_overflow_panics() {
+        let code = "
+            fn main() {
+                v a = 2147483647i32;
+                v b = a + 1i32;
+            }
+        ";
+        assert!(matches!(
+            panic_reason(code, 0),
+            ThreadPanicReason::IntegerOverflow
+        ));
+    }
+
+    #[test]
+    fn unsigned_underflow_panics() {
+        let code = "
+            fn main() {
+                v a = 0u32;
+                v b = a - 1u32;
+            }
+        ";
+        assert!(matches!(
+            panic_reason(code, 0),
+            ThreadPanicReason::IntegerOverflow
+        ));
+    }
+
+    #[test]
+    fn conversion_out_of_width_panics() {
+        let code = "
+            fn main() {
+                v a = 70000 as u16;
+            }
+        ";
+        assert!(matches!(
+            panic_reason(code, 0),
+            ThreadPanicReason::IntegerOverflow
+        ));
+    }
+}
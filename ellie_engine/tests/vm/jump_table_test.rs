@@ -0,0 +1,100 @@
+#[cfg(test)]
+mod jump_table_tests {
+    use crate::utils::{assemble, run};
+    use ellie_engine::ellie_bytecode::instruction_table::Instructions;
+
+    const ENUM_CODE: &str = "
+        enum Direction {
+            North,
+            East,
+            South(int),
+            West,
+        }
+
+        fn turn(direction: Direction) : int {
+            match direction {
+                Direction.North => { ret 1; }
+                Direction.East => { ret 2; }
+                Direction.South(steps) => { ret steps; }
+                Direction.West => { ret 4; }
+            }
+            ret 0;
+        }
+
+        fn main() {
+            println(\"North \" + turn(Direction.North));
+            println(\"East \" + turn(Direction.East));
+            println(\"South \" + turn(Direction.South(30)));
+            println(\"West \" + turn(Direction.West));
+        }
+    ";
+
+    const INTEGER_CODE: &str = "
+        fn name(value: int) : string {
+            match value {
+                3 => { ret \"three\"; }
+                4 => { ret \"four\"; }
+                6 => { ret \"six\"; }
+                4 => { ret \"second four\"; }
+                _ => { ret \"other\"; }
+            }
+            ret \"none\";
+        }
+
+        fn main() {
+            println(\"2 \" + name(2));
+            println(\"3 \" + name(3));
+            println(\"4 \" + name(4));
+            println(\"5 \" + name(5));
+            println(\"6 \" + name(6));
+            println(\"7 \" + name(7));
+            println(\"-3 \" + name(-3));
+        }
+    ";
+
+    fn jump_tables(code: &str, optimization_level: u8) -> usize {
+        assemble(code, optimization_level)
+            .instructions
+            .iter()
+            .filter(|instruction| matches!(instruction, Instructions::JMPT(_)))
+            .count()
+    }
+
+    #[test]
+    fn enum_variants_dispatched_by_jump_table() {
+        assert_eq!(jump_tables(ENUM_CODE, 0), 1);
+        let expected = "North 1\nEast 2\nSouth 30\nWest 4\n";
+        assert_eq!(run(ENUM_CODE, 0), expected);
+        assert_eq!(run(ENUM_CODE, 1), expected);
+    }
+
+    #[test]
+    fn integers_dispatched_by_jump_table() {
+        assert_eq!(jump_tables(INTEGER_CODE, 0), 1);
+        // Holes and values out of the table go to the wildcard, first arm of a value wins
+        let expected = "2 other\n3 three\n4 four\n5 other\n6 six\n7 other\n-3 other\n";
+        assert_eq!(run(INTEGER_CODE, 0), expected);
+        assert_eq!(run(INTEGER_CODE, 1), expected);
+    }
+
+    #[test]
+    fn sparse_integers_compared_one_by_one() {
+        let code = "
+            fn name(value: int) : string {
+                match value {
+                    1 => { ret \"one\"; }
+                    100 => { ret \"hundred\"; }
+                    1000 => { ret \"thousand\"; }
+                    _ => { ret \"other\"; }
+                }
+                ret \"none\";
+            }
+
+            fn main() {
+                println(name(100) + \" \" + name(7));
+            }
+        ";
+        assert_eq!(jump_tables(code, 0), 0);
+        assert_eq!(run(code, 0), "hundred other\n");
+    }
+}
@@ -0,0 +1,282 @@
+#[cfg(test)]
+mod program_reader_tests {
+    use crate::utils::{assemble, build, read, run_binary};
+    use ellie_engine::{
+        ellie_core::bytecode_format::{checksum, SectionKind, BYTECODE_REVISION},
+        ellie_vm::program::ProgramReadErrors,
+    };
+
+    const CODE: &str = "
+        fn main() {
+            println(\"Hello\");
+        }
+    ";
+
+    /// Position of section count byte, body length, checksum and body follows it
+    fn section_count_pos(binary: &[u8]) -> usize {
+        // magic + revision + architecture + compiler version
+        4 + 2 + 1 + 1 + binary[7] as usize
+    }
+
+    /// Position of the body, tests are built for 64 bit architecture
+    fn body_pos(binary: &[u8]) -> usize {
+        section_count_pos(binary) + 1 + 8 + 4
+    }
+
+    /// Checksum of the file, header fields from revision to body length and the body
+    fn file_checksum(file: &[u8]) -> u32 {
+        let checksum_pos = section_count_pos(file) + 1 + 8;
+        let mut checked = file[4..checksum_pos].to_vec();
+        checked.extend(&file[checksum_pos + 4..]);
+        checksum(&checked)
+    }
+
+    /// Fix checksum of the file so content errors are reached
+    fn with_checksum(mut file: Vec<u8>) -> Vec<u8> {
+        let checksum_pos = section_count_pos(&file) + 1 + 8;
+        let checksum = file_checksum(&file);
+        file[checksum_pos..checksum_pos + 4].copy_from_slice(&checksum.to_le_bytes());
+        file
+    }
+
+    /// Replace body of the file, its length and checksum are updated
+    fn with_body(binary: &[u8], body: &[u8]) -> Vec<u8> {
+        let body_length_pos = section_count_pos(binary) + 1;
+        let mut file = binary[..body_length_pos].to_vec();
+        file.extend(body.len().to_le_bytes());
+        file.extend([0; 4]);
+        file.extend(body);
+        with_checksum(file)
+    }
+
+    /// Split body of the file to its sections
+    fn sections(binary: &[u8]) -> Vec<(u8, Vec<u8>)> {
+        let mut body = &binary[body_pos(binary)..];
+        let mut sections = Vec::new();
+        while !body.is_empty() {
+            let length = usize::from_le_bytes(body[1..9].try_into().unwrap());
+            sections.push((body[0], body[9..9 + length].to_vec()));
+            body = &body[9 + length..];
+        }
+        sections
+    }
+
+    /// Render sections as file, section count, body length and checksum are updated
+    fn with_sections(binary: &[u8], sections: &[(u8, Vec<u8>)]) -> Vec<u8> {
+        let mut body = Vec::new();
+        for (kind, section) in sections {
+            body.push(*kind);
+            body.extend(section.len().to_le_bytes());
+            body.extend(section);
+        }
+        let mut file = with_body(binary, &body);
+        file[section_count_pos(binary)] = sections.len() as u8;
+        with_checksum(file)
+    }
+
+    #[test]
+    fn compiled_program_read_with_no_error() {
+        assert!(read(&build(CODE, 0)).is_ok());
+    }
+
+    #[test]
+    fn empty_file_read_with_error() {
+        assert_eq!(read(&[]).err(), Some(ProgramReadErrors::ReadError));
+    }
+
+    #[test]
+    fn wrong_magic_read_with_error() {
+        let mut binary = build(CODE, 0);
+        binary[1] = b'X';
+        assert_eq!(read(&binary).err(), Some(ProgramReadErrors::InvalidMagic));
+    }
+
+    #[test]
+    fn other_revision_read_with_error() {
+        let mut binary = build(CODE, 0);
+        binary[4..6].copy_from_slice(&(BYTECODE_REVISION + 1).to_le_bytes());
+        assert_eq!(
+            read(&binary).err(),
+            Some(ProgramReadErrors::UnsupportedRevision(
+                BYTECODE_REVISION + 1,
+                BYTECODE_REVISION
+            ))
+        );
+    }
+
+    #[test]
+    fn truncated_header_read_with_error() {
+        let binary = build(CODE, 0);
+        let header_len = body_pos(&binary);
+        for len in 0..header_len {
+            assert_eq!(
+                read(&binary[..len]).err(),
+                Some(ProgramReadErrors::ReadError),
+                "File truncated to {} bytes",
+                len
+            );
+        }
+    }
+
+    #[test]
+    fn truncated_body_read_with_error() {
+        let binary = build(CODE, 0);
+        assert_eq!(
+            read(&binary[..binary.len() - 1]).err(),
+            Some(ProgramReadErrors::ReadError)
+        );
+    }
+
+    #[test]
+    fn data_after_body_not_read() {
+        let mut binary = build(CODE, 0);
+        binary.extend([0xFF; 16]);
+        assert!(read(&binary).is_ok());
+    }
+
+    #[test]
+    fn corrupted_header_read_with_error() {
+        let binary = build(CODE, 0);
+        // Architecture, compiler version and section count are covered by the checksum
+        for pos in [6, 8, section_count_pos(&binary)] {
+            let mut file = binary.clone();
+            file[pos] = match pos {
+                6 => 32,
+                _ => file[pos] ^ 0x01,
+            };
+            assert!(
+                matches!(
+                    read(&file).err(),
+                    Some(ProgramReadErrors::ChecksumMismatch(_, _))
+                ),
+                "Header byte {} corrupted",
+                pos
+            );
+        }
+    }
+
+    #[test]
+    fn corrupted_body_read_with_error() {
+        let mut binary = build(CODE, 0);
+        let last = binary.len() - 1;
+        binary[last] ^= 0xFF;
+        assert!(matches!(
+            read(&binary).err(),
+            Some(ProgramReadErrors::ChecksumMismatch(_, _))
+        ));
+    }
+
+    #[test]
+    fn truncated_body_with_valid_checksum_read_with_error() {
+        let binary = build(CODE, 0);
+        let body = &binary[body_pos(&binary)..];
+        assert_eq!(
+            read(&with_body(&binary, &body[..body.len() - 1])).err(),
+            Some(ProgramReadErrors::ReadError)
+        );
+    }
+
+    #[test]
+    fn exceeding_section_count_read_with_error() {
+        let mut binary = build(CODE, 0);
+        let pos = section_count_pos(&binary);
+        binary[pos] += 1;
+        assert!(matches!(
+            read(&binary).err(),
+            Some(ProgramReadErrors::ChecksumMismatch(_, _))
+        ));
+        assert_eq!(
+            read(&with_checksum(binary)).err(),
+            Some(ProgramReadErrors::ReadError)
+        );
+    }
+
+    #[test]
+    fn missing_section_read_with_error() {
+        let binary = build(CODE, 0);
+        let sections = sections(&binary)
+            .into_iter()
+            .filter(|(kind, _)| *kind != SectionKind::Code.to_byte())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            read(&with_sections(&binary, &sections)).err(),
+            Some(ProgramReadErrors::MissingSection(SectionKind::Code))
+        );
+    }
+
+    #[test]
+    fn duplicate_section_read_with_error() {
+        let binary = build(CODE, 0);
+        let mut sections = sections(&binary);
+        sections.push(sections[0].clone());
+        assert!(matches!(
+            read(&with_sections(&binary, &sections)).err(),
+            Some(ProgramReadErrors::DuplicateSection(_))
+        ));
+    }
+
+    #[test]
+    fn broken_section_read_with_error() {
+        let binary = build(CODE, 0);
+        let mut sections = sections(&binary);
+        let main = sections
+            .iter_mut()
+            .find(|(kind, _)| *kind == SectionKind::Main.to_byte())
+            .unwrap();
+        main.1.pop();
+        assert_eq!(
+            read(&with_sections(&binary, &sections)).err(),
+            Some(ProgramReadErrors::BrokenSection(SectionKind::Main))
+        );
+    }
+
+    #[test]
+    fn unknown_section_read_with_no_error() {
+        let binary = build(CODE, 0);
+        let mut sections = sections(&binary);
+        sections.push((0xF0, vec![1, 2, 3]));
+        assert!(read(&with_sections(&binary, &sections)).is_ok());
+    }
+
+    #[test]
+    fn debug_section_read_as_written() {
+        let assemble_result = assemble(CODE, 0);
+        let program = read(&assemble_result.render_binary_to_vector(false)).unwrap();
+        let debug_info = program.debug_info.expect("Debug info should be read");
+
+        let module_maps = &assemble_result.module_info.module_maps;
+        assert_eq!(debug_info.module_map.len(), module_maps.len());
+        for (read, written) in debug_info.module_map.iter().zip(module_maps) {
+            assert_eq!(read.module_name, written.module_name);
+            assert_eq!(read.module_hash, written.module_hash);
+            assert_eq!(read.module_path, written.module_path);
+        }
+
+        let debug_headers = &assemble_result.debug_headers;
+        assert!(!debug_headers.is_empty());
+        assert_eq!(debug_info.debug_headers.len(), debug_headers.len());
+        for (read, written) in debug_info.debug_headers.iter().zip(debug_headers) {
+            assert_eq!(read.rtype, written.rtype);
+            assert_eq!(read.hash, written.hash);
+            assert_eq!(read.module_name, written.module_name);
+            assert_eq!(read.module_hash, written.module_hash);
+            assert_eq!(read.name, written.name);
+            assert_eq!(read.start_end, written.start_end);
+            assert_eq!(read.pos, written.pos);
+        }
+    }
+
+    #[test]
+    fn stripped_debug_section_not_written() {
+        let assemble_result = assemble(CODE, 0);
+        let binary = assemble_result.render_binary_to_vector(true);
+        assert!(sections(&binary)
+            .iter()
+            .all(|(kind, _)| *kind != SectionKind::Debug.to_byte()));
+        assert!(binary.len() < assemble_result.render_binary_to_vector(false).len());
+
+        let program = read(&binary).unwrap();
+        assert!(program.debug_info.is_none());
+        assert_eq!(run_binary(&binary), "Hello\n");
+    }
+}
@@ -0,0 +1,224 @@
+#[cfg(test)]
+mod verifier_tests {
+    use crate::utils::{build, read};
+    use ellie_engine::ellie_vm::{
+        config::STACK_MEMORY_SIZE,
+        instruction_utils::Instructions,
+        program::Program,
+        utils::AddressingValues,
+        verifier::{verify, VerifyError, VerifyErrorKind},
+    };
+
+    const CODE: &str = "
+        fn add(a: int, b: int) : int {
+            ret a + b;
+        }
+
+        fn main() {
+            v total = 0;
+            for i : 3 {
+                total += add(i, 2);
+            }
+            println(\"Total: \" + total);
+        }
+    ";
+
+    fn program() -> Program {
+        read(&build(CODE, 0)).expect("Program should be readable")
+    }
+
+    /// Location of the first instruction that matches
+    fn find(
+        program: &Program,
+        matcher: impl Fn(&Instructions, &AddressingValues) -> bool,
+    ) -> usize {
+        program
+            .instructions
+            .iter()
+            .position(|e| matcher(&e.instruction, &e.addressing_value))
+            .expect("Instruction should exist")
+    }
+
+    /// Location of the function `add`, the only function main calls
+    fn add_function(program: &Program) -> usize {
+        let call = find(program, |e, _| matches!(e, Instructions::CALL(_)));
+        match program.instructions[call].addressing_value {
+            AddressingValues::Absolute(e) => e,
+            _ => unreachable!(),
+        }
+    }
+
+    fn errors(program: &Program) -> Vec<VerifyError> {
+        verify(program).expect_err("Program should be rejected")
+    }
+
+    #[test]
+    fn compiled_program_verified_with_no_error() {
+        assert_eq!(verify(&program()), Ok(()));
+        assert_eq!(
+            verify(&read(&build(CODE, 1)).expect("Program should be readable")),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn jump_out_of_program_rejected() {
+        let mut program = program();
+        let len = program.instructions.len();
+        let location = find(&program, |e, _| matches!(e, Instructions::JMP(_)));
+        program.instructions[location].addressing_value = AddressingValues::Absolute(len);
+        assert!(errors(&program).contains(&VerifyError {
+            location,
+            kind: VerifyErrorKind::InvalidJumpTarget(len),
+        }));
+    }
+
+    #[test]
+    fn jump_into_other_function_rejected() {
+        let mut program = program();
+        let target = add_function(&program) + 3;
+        let main_start = program.main.start;
+        let location = main_start
+            + program.instructions[main_start..]
+                .iter()
+                .position(|e| matches!(e.instruction, Instructions::JMP(_)))
+                .expect("Main should have a loop");
+        program.instructions[location].addressing_value = AddressingValues::Absolute(target);
+        assert!(errors(&program).contains(&VerifyError {
+            location,
+            kind: VerifyErrorKind::InvalidJumpTarget(target),
+        }));
+    }
+
+    #[test]
+    fn call_to_non_function_rejected() {
+        let mut program = program();
+        let location = find(&program, |e, _| matches!(e, Instructions::CALL(_)));
+        program.instructions[location].addressing_value = AddressingValues::Absolute(location);
+        assert!(errors(&program).contains(&VerifyError {
+            location,
+            kind: VerifyErrorKind::InvalidCallTarget(location),
+        }));
+    }
+
+    #[test]
+    fn address_out_of_program_rejected() {
+        let mut program = program();
+        let len = program.instructions.len();
+        let location = find(&program, |e, value| {
+            matches!(e, Instructions::LDA(_)) && matches!(value, AddressingValues::Absolute(_))
+        });
+        program.instructions[location].addressing_value = AddressingValues::Absolute(len);
+        assert!(errors(&program).contains(&VerifyError {
+            location,
+            kind: VerifyErrorKind::AddressOutOfRange(len),
+        }));
+    }
+
+    #[test]
+    fn illegal_addressing_value_rejected() {
+        let mut program = program();
+        let location = find(&program, |e, _| matches!(e, Instructions::LDA(_)));
+        program.instructions[location].addressing_value = AddressingValues::Implicit;
+        assert!(errors(&program).contains(&VerifyError {
+            location,
+            kind: VerifyErrorKind::IllegalAddressingValue,
+        }));
+    }
+
+    #[test]
+    fn static_reference_to_non_immediate_rejected() {
+        let mut program = program();
+        let target = find(&program, |e, _| matches!(e, Instructions::CALL(_)));
+        let location = find(&program, |e, value| {
+            matches!(e, Instructions::LDA(_)) && matches!(value, AddressingValues::Absolute(_))
+        });
+        program.instructions[location].addressing_value = AddressingValues::AbsoluteStatic(target);
+        assert!(errors(&program).contains(&VerifyError {
+            location,
+            kind: VerifyErrorKind::InvalidStaticReference(target),
+        }));
+    }
+
+    #[test]
+    fn broken_function_header_rejected() {
+        let mut program = program();
+        let location = find(&program, |e, _| matches!(e, Instructions::FN(_)));
+        program.instructions[location + 1].addressing_value = AddressingValues::Implicit;
+        assert!(errors(&program).contains(&VerifyError {
+            location,
+            kind: VerifyErrorKind::BrokenFunctionHeader,
+        }));
+    }
+
+    #[test]
+    fn wrong_main_function_rejected() {
+        let mut program = program();
+        program.main.hash += 1;
+        assert!(errors(&program).contains(&VerifyError {
+            location: program.main.start,
+            kind: VerifyErrorKind::BrokenMainFunction,
+        }));
+    }
+
+    #[test]
+    fn oversized_function_frame_rejected() {
+        let mut program = program();
+        let function = add_function(&program);
+        program.instructions[function + 3].addressing_value =
+            AddressingValues::Absolute(STACK_MEMORY_SIZE);
+        assert!(errors(&program)
+            .iter()
+            .any(|error| error.location == function
+                && matches!(error.kind, VerifyErrorKind::FrameTooLarge(_))));
+        assert_eq!(
+            errors(&program)
+                .iter()
+                .filter(|error| matches!(error.kind, VerifyErrorKind::FrameTooLarge(_)))
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn unknown_native_call_rejected() {
+        let mut program = program();
+        let location = find(&program, |e, _| matches!(e, Instructions::CALLN(_)));
+        let target = match program.instructions[location].addressing_value {
+            AddressingValues::Absolute(e) => e,
+            _ => unreachable!(),
+        };
+        program.native_call_traces.clear();
+        assert!(errors(&program).contains(&VerifyError {
+            location,
+            kind: VerifyErrorKind::UnknownNativeCall(target),
+        }));
+    }
+
+    #[test]
+    fn broken_jump_table_rejected() {
+        let code = "
+            fn name(value: int) : string {
+                match value {
+                    1 => { ret \"one\"; }
+                    2 => { ret \"two\"; }
+                    3 => { ret \"three\"; }
+                    _ => { ret \"other\"; }
+                }
+                ret \"none\";
+            }
+
+            fn main() {
+                println(name(2));
+            }
+        ";
+        let mut program = read(&build(code, 0)).expect("Program should be readable");
+        assert_eq!(verify(&program), Ok(()));
+        let location = find(&program, |e, _| matches!(e, Instructions::JMPT(_)));
+        program.instructions[location + 1] = program.instructions[location];
+        assert!(errors(&program).contains(&VerifyError {
+            location,
+            kind: VerifyErrorKind::BrokenJumpTable,
+        }));
+    }
+}
@@ -1,6 +1,84 @@
 use crate::{processors::EscapeCharEmitter, syntax::items::class};
 use ellie_core::{defs, error, utils};
 
+impl class::Class {
+    /// Collects the `impl Interface, Interface` list between class head and body
+    fn collect_implements(
+        &mut self,
+        errors: &mut Vec<ellie_core::error::Error>,
+        cursor: ellie_core::defs::CursorPosition,
+        last_char: char,
+        letter_char: char,
+    ) {
+        let implements_len = self.implements.len();
+        if letter_char == '{' {
+            if !self.impl_keyword.is_empty()
+                && (implements_len == 0 || self.implements[implements_len - 1].name.is_empty())
+            {
+                errors.push(error::error_list::ERROR_S1.clone().build(
+                    vec![error::ErrorBuildField {
+                        key: "token".to_string(),
+                        value: letter_char.to_string(),
+                    }],
+                    alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
+                    defs::Cursor::build_from_cursor(cursor),
+                ));
+            } else {
+                self.continuum_collected = true;
+            }
+        } else if utils::reliable_name_range(utils::ReliableNameRanges::VariableName, letter_char)
+            .reliable
+        {
+            if self.impl_keyword != "impl" || (implements_len == 0 && last_char != ' ') {
+                self.impl_keyword += &letter_char.to_string();
+                if !"impl".starts_with(&self.impl_keyword) {
+                    errors.push(error::error_list::ERROR_S1.clone().build(
+                        vec![error::ErrorBuildField {
+                            key: "token".to_string(),
+                            value: letter_char.to_string(),
+                        }],
+                        alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
+                        defs::Cursor::build_from_cursor(cursor),
+                    ));
+                }
+            } else {
+                if implements_len == 0 {
+                    self.implements.push(class::Implement::default());
+                }
+                let implement = self.implements.last_mut().unwrap();
+                if implement.name.is_empty() {
+                    implement.pos.range_start = cursor;
+                } else if last_char == ' ' {
+                    errors.push(error::error_list::ERROR_S1.clone().build(
+                        vec![error::ErrorBuildField {
+                            key: "token".to_string(),
+                            value: letter_char.to_string(),
+                        }],
+                        alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
+                        defs::Cursor::build_from_cursor(cursor),
+                    ));
+                }
+                implement.pos.range_end = cursor;
+                implement.name += &letter_char.to_string();
+            }
+        } else if letter_char == ','
+            && implements_len > 0
+            && !self.implements[implements_len - 1].name.is_empty()
+        {
+            self.implements.push(class::Implement::default());
+        } else if letter_char != ' ' {
+            errors.push(error::error_list::ERROR_S1.clone().build(
+                vec![error::ErrorBuildField {
+                    key: "token".to_string(),
+                    value: letter_char.to_string(),
+                }],
+                alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
+                defs::Cursor::build_from_cursor(cursor),
+            ));
+        }
+    }
+}
+
 impl crate::processors::Processor for class::Class {
     fn emits_line_endings(&self) -> EscapeCharEmitter {
         self.iterator.emits_line_endings()
@@ -20,18 +98,16 @@ impl crate::processors::Processor for class::Class {
             {
                 if self.name == "" {
                     self.name_pos.range_start = cursor;
+                    self.name_pos.range_end = cursor;
+                    self.name += &letter_char.to_string();
                 } else if last_char == ' ' {
-                    errors.push(error::error_list::ERROR_S1.clone().build(
-                        vec![error::ErrorBuildField {
-                            key: "token".to_string(),
-                            value: letter_char.to_string(),
-                        }],
-                        alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
-                        defs::Cursor::build_from_cursor(cursor),
-                    ));
+                    self.name_collected = true;
+                    self.generics_collected = true;
+                    self.collect_implements(errors, cursor, last_char, letter_char);
+                } else {
+                    self.name_pos.range_end = cursor;
+                    self.name += &letter_char.to_string();
                 }
-                self.name_pos.range_end = cursor;
-                self.name += &letter_char.to_string();
             } else if letter_char == '{' {
                 self.name_collected = true;
                 self.generics_collected = true;
@@ -59,7 +135,24 @@ impl crate::processors::Processor for class::Class {
                         },
                         name: letter_char.to_string(),
                         hash: utils::generate_hash_usize(),
+                        ..Default::default()
                     });
+                } else if self.collecting_bound {
+                    let generic = &mut self.generic_definings[generic_len - 1];
+                    if generic.bound.is_empty() {
+                        generic.bound_pos.range_start = cursor;
+                    } else if last_char == ' ' {
+                        errors.push(error::error_list::ERROR_S1.clone().build(
+                            vec![error::ErrorBuildField {
+                                key: "token".to_string(),
+                                value: letter_char.to_string(),
+                            }],
+                            alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
+                            defs::Cursor::build_from_cursor(cursor),
+                        ));
+                    }
+                    generic.bound_pos.range_end = cursor;
+                    generic.bound += &letter_char.to_string();
                 } else {
                     if self.generic_definings[generic_len - 1].name == "" {
                         self.generic_definings[generic_len - 1].pos.range_start = cursor;
@@ -76,21 +169,29 @@ impl crate::processors::Processor for class::Class {
                     self.generic_definings[generic_len - 1].pos.range_end = cursor;
                     self.generic_definings[generic_len - 1].name += &letter_char.to_string();
                 }
-            } else if letter_char == ','
+            } else if letter_char == ':'
+                && !self.collecting_bound
                 && generic_len > 0
                 && self.generic_definings[generic_len - 1].name != ""
             {
-                self.generic_definings.last_mut().unwrap().pos.range_end =
-                    cursor.clone().pop_char(1);
-                self.generic_definings
-                    .push(class::GenericDefining::default());
-            } else if letter_char == '>'
+                self.collecting_bound = true;
+            } else if (letter_char == ',' || letter_char == '>')
                 && generic_len > 0
                 && self.generic_definings[generic_len - 1].name != ""
+                && (!self.collecting_bound
+                    || !self.generic_definings[generic_len - 1].bound.is_empty())
             {
-                self.generic_definings.last_mut().unwrap().pos.range_end =
-                    cursor.clone().pop_char(1);
-                self.generics_collected = true;
+                if !self.collecting_bound {
+                    self.generic_definings.last_mut().unwrap().pos.range_end =
+                        cursor.clone().pop_char(1);
+                }
+                self.collecting_bound = false;
+                if letter_char == ',' {
+                    self.generic_definings
+                        .push(class::GenericDefining::default());
+                } else {
+                    self.generics_collected = true;
+                }
             } else if letter_char != ' ' {
                 errors.push(error::error_list::ERROR_S1.clone().build(
                     vec![error::ErrorBuildField {
@@ -102,18 +203,7 @@ impl crate::processors::Processor for class::Class {
                 ));
             }
         } else if !self.continuum_collected {
-            if letter_char == '{' {
-                self.continuum_collected = true;
-            } else if letter_char != ' ' {
-                errors.push(error::error_list::ERROR_S1.clone().build(
-                    vec![error::ErrorBuildField {
-                        key: "token".to_string(),
-                        value: letter_char.to_string(),
-                    }],
-                    alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
-                    defs::Cursor::build_from_cursor(cursor),
-                ));
-            }
+            self.collect_implements(errors, cursor, last_char, letter_char);
         } else if letter_char == '}' && self.brace_count == 0 {
             self.hash = ellie_core::utils::generate_hash_usize();
             self.pos.range_end = cursor;
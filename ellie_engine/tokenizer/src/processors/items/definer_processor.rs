@@ -79,7 +79,24 @@ impl crate::processors::Processor for DefinerCollector {
                         array_type.rtype_pos = array_type.child_cache.definer_type.get_pos();
                     }
                 } else if !array_type.size_collected {
-                    if array_type.size_child_cache.is_complete() && letter_char == ']' {
+                    //Dynamic sized arrays are declared with `*` instead of a size `[T, *]`
+                    if array_type.raw_size == "*" {
+                        if letter_char == ']' {
+                            self.complete = true;
+                        } else if letter_char != ' ' {
+                            errors.push(error::error_list::ERROR_S1.clone().build(
+                                vec![error::ErrorBuildField {
+                                    key: "token".to_string(),
+                                    value: letter_char.to_string(),
+                                }],
+                                "0x00361".to_owned(),
+                                defs::Cursor::build_from_cursor(cursor),
+                            ));
+                        }
+                    } else if letter_char == '*' && array_type.size_pos.range_start.is_zero() {
+                        array_type.raw_size = "*".to_string();
+                        array_type.size_pos = defs::Cursor::build_from_cursor(cursor);
+                    } else if array_type.size_child_cache.is_complete() && letter_char == ']' {
                         array_type.size =
                             Some(Box::new(array_type.size_child_cache.current.to_definite()));
                         array_type.size_child_cache = Box::new(TypeProcessor::default());
@@ -1,6 +1,6 @@
 use crate::{
     processors::EscapeCharEmitter,
-    syntax::items::{definers::DefinerTypes, function},
+    syntax::items::{class, definers::DefinerTypes, function},
 };
 use ellie_core::{defs, error, utils};
 
@@ -37,6 +37,77 @@ impl crate::processors::Processor for function::FunctionCollector {
                 self.data.name += &letter_char.to_string();
             } else if letter_char == '(' && self.data.name != "" {
                 self.name_collected = true;
+                self.generics_collected = true;
+            } else if letter_char == '<' && !self.data.name.is_empty() {
+                self.name_collected = true;
+            } else if letter_char != ' ' {
+                errors.push(error::error_list::ERROR_S1.clone().build(
+                    vec![error::ErrorBuildField {
+                        key: "token".to_string(),
+                        value: letter_char.to_string(),
+                    }],
+                    alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
+                    defs::Cursor::build_from_cursor(cursor),
+                ));
+            }
+        } else if !self.generics_collected {
+            let generic_len = self.data.generic_definings.len();
+            if self.generics_closed {
+                if letter_char == '(' {
+                    self.generics_collected = true;
+                } else if letter_char != ' ' {
+                    errors.push(error::error_list::ERROR_S1.clone().build(
+                        vec![error::ErrorBuildField {
+                            key: "token".to_string(),
+                            value: letter_char.to_string(),
+                        }],
+                        alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
+                        defs::Cursor::build_from_cursor(cursor),
+                    ));
+                }
+            } else if utils::reliable_name_range(utils::ReliableNameRanges::Type, letter_char)
+                .reliable
+            {
+                if generic_len == 0 {
+                    self.data.generic_definings.push(class::GenericDefining {
+                        pos: defs::Cursor {
+                            range_start: cursor,
+                            range_end: cursor,
+                        },
+                        name: letter_char.to_string(),
+                        hash: utils::generate_hash_usize(),
+                        ..Default::default()
+                    });
+                } else {
+                    if self.data.generic_definings[generic_len - 1].name.is_empty() {
+                        self.data.generic_definings[generic_len - 1].pos.range_start = cursor;
+                        self.data.generic_definings[generic_len - 1].hash =
+                            utils::generate_hash_usize();
+                    } else if last_char == ' ' {
+                        errors.push(error::error_list::ERROR_S1.clone().build(
+                            vec![error::ErrorBuildField {
+                                key: "token".to_string(),
+                                value: letter_char.to_string(),
+                            }],
+                            alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
+                            defs::Cursor::build_from_cursor(cursor),
+                        ));
+                    }
+                    self.data.generic_definings[generic_len - 1].pos.range_end = cursor;
+                    self.data.generic_definings[generic_len - 1].name += &letter_char.to_string();
+                }
+            } else if letter_char == ','
+                && generic_len > 0
+                && !self.data.generic_definings[generic_len - 1].name.is_empty()
+            {
+                self.data
+                    .generic_definings
+                    .push(class::GenericDefining::default());
+            } else if letter_char == '>'
+                && generic_len > 0
+                && !self.data.generic_definings[generic_len - 1].name.is_empty()
+            {
+                self.generics_closed = true;
             } else if letter_char != ' ' {
                 errors.push(error::error_list::ERROR_S1.clone().build(
                     vec![error::ErrorBuildField {
@@ -204,6 +275,11 @@ impl crate::processors::Processor for function::FunctionCollector {
                         _ => false,
                     })
                 }),
+                super::Processors::Match(e) => e.arms.iter().any(|x| {
+                    x.body
+                        .iter()
+                        .any(|x| matches!(x, super::Processors::Ret(_)))
+                }),
                 _ => false,
             });
 
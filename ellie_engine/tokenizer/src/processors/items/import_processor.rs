@@ -39,7 +39,7 @@ impl crate::processors::Processor for Import {
                 self.path_pos.range_end = cursor;
                 self.path += &letter_char.to_string();
             } else if (letter_char == ' ' || letter_char == ':' || letter_char == ';')
-                && self.reference != ""
+                && !self.path.is_empty()
             {
                 self.complete = letter_char == ';';
                 self.reference_starter_collected = letter_char == ':';
@@ -0,0 +1,64 @@
+use crate::{processors::EscapeCharEmitter, syntax::items::interface};
+use ellie_core::{defs, error, utils};
+
+impl crate::processors::Processor for interface::Interface {
+    fn emits_line_endings(&self) -> EscapeCharEmitter {
+        self.iterator.emits_line_endings()
+    }
+
+    fn iterate(
+        &mut self,
+        errors: &mut Vec<ellie_core::error::Error>,
+        cursor: ellie_core::defs::CursorPosition,
+        last_char: char,
+        letter_char: char,
+    ) -> bool {
+        let mut hang = false;
+        if !self.name_collected {
+            if utils::reliable_name_range(utils::ReliableNameRanges::VariableName, letter_char)
+                .reliable
+            {
+                if self.name.is_empty() {
+                    self.name_pos.range_start = cursor;
+                } else if last_char == ' ' {
+                    errors.push(error::error_list::ERROR_S1.clone().build(
+                        vec![error::ErrorBuildField {
+                            key: "token".to_string(),
+                            value: letter_char.to_string(),
+                        }],
+                        alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
+                        defs::Cursor::build_from_cursor(cursor),
+                    ));
+                }
+                self.name_pos.range_end = cursor;
+                self.name += &letter_char.to_string();
+            } else if letter_char == '{' && !self.name.is_empty() {
+                self.name_collected = true;
+            } else if letter_char != ' ' {
+                errors.push(error::error_list::ERROR_S1.clone().build(
+                    vec![error::ErrorBuildField {
+                        key: "token".to_string(),
+                        value: letter_char.to_string(),
+                    }],
+                    alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
+                    defs::Cursor::build_from_cursor(cursor),
+                ));
+            }
+        } else if letter_char == '}' && self.brace_count == 0 {
+            self.pos.range_end = cursor;
+            self.complete = true;
+            self.iterator.finalize();
+            errors.extend(self.iterator.errors.clone());
+            self.body = self.iterator.collected.clone();
+        } else {
+            if letter_char == '{' {
+                self.brace_count += 1;
+            } else if letter_char == '}' && self.brace_count != 0 {
+                self.brace_count -= 1;
+            }
+            self.iterator.pos = cursor;
+            hang = self.iterator.iterate(last_char, letter_char);
+        }
+        hang
+    }
+}
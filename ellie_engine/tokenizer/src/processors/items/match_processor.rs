@@ -0,0 +1,121 @@
+use crate::{
+    processors::EscapeCharEmitter,
+    syntax::items::match_type::{Match, MatchArm},
+};
+use ellie_core::{defs, error};
+
+impl crate::processors::Processor for Match {
+    fn emits_line_endings(&self) -> EscapeCharEmitter {
+        if !self.target_collected {
+            self.target.emits_line_endings()
+        } else {
+            match self.arms.last() {
+                Some(arm) if !arm.complete => {
+                    if !arm.pattern_collected {
+                        arm.pattern.emits_line_endings()
+                    } else {
+                        arm.iterator.emits_line_endings()
+                    }
+                }
+                _ => EscapeCharEmitter::dont_emit(),
+            }
+        }
+    }
+
+    fn iterate(
+        &mut self,
+        errors: &mut Vec<error::Error>,
+        cursor: defs::CursorPosition,
+        last_char: char,
+        letter_char: char,
+    ) -> bool {
+        let mut hang = false;
+        if !self.target_collected {
+            if self.target.is_complete() && letter_char == '{' {
+                self.target_collected = true;
+                self.body_pos.range_start = cursor;
+            } else {
+                if letter_char != ' ' {
+                    if self.target_pos.range_start.is_zero() {
+                        self.target_pos.range_start = cursor;
+                    }
+                    self.target_pos.range_end = cursor;
+                }
+                hang = self.target.iterate(errors, cursor, last_char, letter_char);
+            }
+            return hang;
+        }
+
+        //Arms are separated by whitespace or an optional comma
+        if self.arms.last().is_none_or(|arm| arm.complete) {
+            if letter_char == '}' {
+                self.complete = true;
+                self.body_pos.range_end = cursor;
+                self.pos.range_end = cursor;
+                return false;
+            } else if letter_char == ' ' || (letter_char == ',' && !self.arms.is_empty()) {
+                return false;
+            }
+            self.arms.push(MatchArm::default());
+        }
+
+        let arm = self.arms.last_mut().unwrap();
+        if !arm.pattern_collected {
+            if arm.pattern.is_complete() && letter_char == '=' {
+                arm.pattern_collected = true;
+            } else {
+                if letter_char != ' ' {
+                    if arm.pattern_pos.range_start.is_zero() {
+                        arm.pattern_pos.range_start = cursor;
+                    }
+                    arm.pattern_pos.range_end = cursor;
+                }
+                hang = arm.pattern.iterate(errors, cursor, last_char, letter_char);
+            }
+        } else if !arm.arrow_collected {
+            if letter_char == '>' && last_char == '=' {
+                arm.arrow_collected = true;
+            } else {
+                errors.push(error::error_list::ERROR_S1.clone().build(
+                    vec![error::ErrorBuildField {
+                        key: "token".to_string(),
+                        value: letter_char.to_string(),
+                    }],
+                    alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
+                    defs::Cursor::build_from_cursor(cursor),
+                ));
+                hang = true;
+            }
+        } else if !arm.body_started {
+            if letter_char == '{' {
+                arm.body_started = true;
+                arm.body_pos.range_start = cursor;
+            } else if letter_char != ' ' {
+                errors.push(error::error_list::ERROR_S1.clone().build(
+                    vec![error::ErrorBuildField {
+                        key: "token".to_string(),
+                        value: letter_char.to_string(),
+                    }],
+                    alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
+                    defs::Cursor::build_from_cursor(cursor),
+                ));
+                hang = true;
+            }
+        } else if letter_char == '}' && arm.brace_count == 0 {
+            arm.complete = true;
+            arm.body_pos.range_end = cursor;
+            arm.iterator.finalize();
+            errors.extend(arm.iterator.errors.clone());
+            arm.body = arm.iterator.collected.clone();
+        } else {
+            if letter_char == '{' {
+                arm.brace_count += 1;
+            } else if letter_char == '}' && arm.brace_count != 0 {
+                arm.brace_count -= 1;
+            }
+            arm.iterator.pos = cursor;
+            hang = arm.iterator.iterate(last_char, letter_char);
+        }
+        hang
+    }
+}
@@ -22,6 +22,7 @@ mod condition_processor;
 mod constructor_processor;
 mod definer_processor;
 mod enum_processor;
+mod extend_processor;
 mod file_key;
 mod for_loop_processor;
 mod function_processor;
@@ -29,10 +30,13 @@ mod getter_call;
 mod getter_processor;
 mod go_processor;
 mod import_processor;
+mod interface_processor;
 mod loop_processor;
+mod match_processor;
 mod ret_processor;
 mod setter_call;
 mod setter_processor;
+mod type_def_processor;
 mod variable_processor;
 
 #[derive(Debug, Clone, Serialize, Deserialize, EnumAsInner)]
@@ -46,8 +50,12 @@ pub enum Processors {
     Loop(loop_type::Loop),
     ForLoop(for_loop::ForLoop),
     Condition(condition::Condition),
+    Match(match_type::Match),
     Constructor(constructor::Constructor),
     Class(class::Class),
+    Extend(extend::Extend),
+    TypeDef(type_def::TypeDef),
+    Interface(interface::Interface),
     Ret(ret::Ret),
     Brk(brk::Brk),
     Go(go::Go),
@@ -78,9 +86,13 @@ impl Processors {
             Processors::Condition(e) => {
                 e.chains.len() != 0 && e.chains.clone()[e.chains.len() - 1].complete
             }
+            Processors::Match(e) => e.complete,
             Processors::Constructor(e) => e.complete,
             Processors::Ret(e) => e.complete,
             Processors::Class(e) => e.complete,
+            Processors::Extend(e) => e.complete,
+            Processors::TypeDef(e) => e.complete,
+            Processors::Interface(e) => e.complete,
             Processors::GenericItem(_) => panic!("Unexpected behaviour"),
             Processors::FunctionParameter(_) => panic!("Unexpected behaviour"),
             Processors::ConstructorParameter(_) => panic!("Unexpected behaviour"),
@@ -129,10 +141,14 @@ impl Processors {
             Processors::Import(e) => e.pos,
             Processors::ForLoop(e) => e.pos,
             Processors::Condition(e) => e.pos,
+            Processors::Match(e) => e.pos,
             Processors::Enum(e) => e.pos,
             Processors::Constructor(e) => e.pos,
             Processors::Ret(e) => e.pos,
             Processors::Class(e) => e.pos,
+            Processors::Extend(e) => e.pos,
+            Processors::TypeDef(e) => e.pos,
+            Processors::Interface(e) => e.pos,
             Processors::ClassInstance(_) => ellie_core::defs::Cursor::default(),
             Processors::GenericItem(_) => ellie_core::defs::Cursor::default(),
             Processors::FunctionParameter(e) => ellie_core::defs::Cursor {
@@ -161,8 +177,12 @@ impl Processors {
             Processors::Import(e) => Collecting::Import(e.to_definite()),
             Processors::ForLoop(e) => Collecting::ForLoop(e.to_definite()),
             Processors::Condition(e) => Collecting::Condition(e.to_definite()),
+            Processors::Match(e) => Collecting::Match(e.to_definite()),
             Processors::Constructor(e) => Collecting::Constructor(e.to_definite()),
             Processors::Class(e) => Collecting::Class(e.to_definite()),
+            Processors::Extend(e) => Collecting::Extend(e.to_definite()),
+            Processors::TypeDef(e) => Collecting::TypeDef(e.to_definite()),
+            Processors::Interface(e) => Collecting::Interface(e.to_definite()),
             Processors::Ret(e) => Collecting::Ret(e.to_definite()),
             Processors::ClassInstance(_) => panic!("Unexpected behaviour"),
             Processors::GenericItem(_) => panic!("Unexpected behaviour"),
@@ -190,7 +210,17 @@ impl Processors {
             Collecting::Condition(e) => {
                 Processors::Condition(condition::Condition::default().from_definite(e))
             }
+            Collecting::Match(e) => {
+                Processors::Match(match_type::Match::default().from_definite(e))
+            }
             Collecting::Class(e) => Processors::Class(class::Class::default().from_definite(e)),
+            Collecting::Extend(e) => Processors::Extend(extend::Extend::default().from_definite(e)),
+            Collecting::TypeDef(e) => {
+                Processors::TypeDef(type_def::TypeDef::default().from_definite(e))
+            }
+            Collecting::Interface(e) => {
+                Processors::Interface(interface::Interface::default().from_definite(e))
+            }
             Collecting::Ret(e) => Processors::Ret(ret::Ret::default().from_definite(e)),
             Collecting::Constructor(e) => {
                 Processors::Constructor(constructor::Constructor::default().from_definite(e))
@@ -214,6 +244,16 @@ impl Processors {
                     name_pos: e.name_pos,
                     public: e.public,
                     defining: true,
+                    generic_definings: e
+                        .generic_definings
+                        .into_iter()
+                        .map(|x| class::GenericDefining {
+                            name: x.name,
+                            hash: x.hash,
+                            pos: x.pos,
+                            ..Default::default()
+                        })
+                        .collect(),
                     parameters: e
                         .parameters
                         .into_iter()
@@ -310,6 +350,13 @@ impl Processors {
                     None
                 }
             }
+            Processors::TypeDef(type_def) => {
+                if type_def.rtype.complete {
+                    Some(Cursor::build_with_skip_char(type_def.rtype_pos.range_end))
+                } else {
+                    None
+                }
+            }
             Processors::Brk(brk) => Some(brk.pos),
             Processors::Go(go) => Some(go.pos),
             Processors::Function(function) => {
@@ -394,8 +441,12 @@ impl super::Processor for ItemProcessor {
             Processors::Loop(e) => e.emits_line_endings(),
             Processors::ForLoop(e) => e.emits_line_endings(),
             Processors::Condition(e) => e.emits_line_endings(),
+            Processors::Match(e) => e.emits_line_endings(),
             Processors::Constructor(e) => e.emits_line_endings(),
             Processors::Class(e) => e.emits_line_endings(),
+            Processors::Extend(e) => e.emits_line_endings(),
+            Processors::TypeDef(e) => e.emits_line_endings(),
+            Processors::Interface(e) => e.emits_line_endings(),
             Processors::Ret(e) => e.emits_line_endings(),
             Processors::Brk(e) => e.emits_line_endings(),
             Processors::Go(e) => e.emits_line_endings(),
@@ -509,6 +560,26 @@ impl super::Processor for ItemProcessor {
                 pos: self.current.get_pos(),
                 ..Default::default()
             });
+        } else if keyword == "type" && letter_char == ' ' {
+            self.current = Processors::TypeDef(type_def::TypeDef {
+                public: self.used_modifier == Modifier::Pub,
+                pos: self.current.get_pos(),
+                hash: ellie_core::utils::generate_hash_usize(),
+                ..Default::default()
+            });
+        } else if keyword == "interface" && letter_char == ' ' {
+            self.current = Processors::Interface(interface::Interface {
+                public: self.used_modifier == Modifier::Pub,
+                pos: self.current.get_pos(),
+                hash: ellie_core::utils::generate_hash_usize(),
+                ..Default::default()
+            });
+        } else if self.used_modifier == Modifier::None && keyword == "extend" && letter_char == ' '
+        {
+            self.current = Processors::Extend(extend::Extend {
+                pos: self.current.get_pos(),
+                ..Default::default()
+            });
         } else if not_initialized && self.used_modifier == Modifier::None && letter_char == '@' {
             self.current = Processors::FileKey(file_key::FileKey {
                 pos: self.current.get_pos(),
@@ -584,6 +655,11 @@ impl super::Processor for ItemProcessor {
                 pos: self.current.get_pos(),
                 ..Default::default()
             });
+        } else if self.used_modifier == Modifier::None && keyword == "match" && letter_char == ' ' {
+            self.current = Processors::Match(match_type::Match {
+                pos: self.current.get_pos(),
+                ..Default::default()
+            });
         } else if self.used_modifier == Modifier::None && keyword == "loop" && letter_char == ' ' {
             self.current = Processors::Loop(loop_type::Loop {
                 pos: self.current.get_pos(),
@@ -627,9 +703,13 @@ impl super::Processor for ItemProcessor {
             Processors::Enum(e) => e.iterate(errors, cursor, last_char, letter_char),
             Processors::ForLoop(e) => e.iterate(errors, cursor, last_char, letter_char),
             Processors::Condition(e) => e.iterate(errors, cursor, last_char, letter_char),
+            Processors::Match(e) => e.iterate(errors, cursor, last_char, letter_char),
             Processors::Constructor(e) => e.iterate(errors, cursor, last_char, letter_char),
             Processors::Ret(e) => e.iterate(errors, cursor, last_char, letter_char),
             Processors::Class(e) => e.iterate(errors, cursor, last_char, letter_char),
+            Processors::Extend(e) => e.iterate(errors, cursor, last_char, letter_char),
+            Processors::TypeDef(e) => e.iterate(errors, cursor, last_char, letter_char),
+            Processors::Interface(e) => e.iterate(errors, cursor, last_char, letter_char),
             Processors::ClassInstance(_) => unreachable!("Unexpected behaviour"),
             Processors::GenericItem(_) => unreachable!("Unexpected behaviour"),
             Processors::FunctionParameter(_) => unreachable!("Unexpected behaviour"),
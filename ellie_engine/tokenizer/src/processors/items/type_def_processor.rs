@@ -0,0 +1,129 @@
+use crate::syntax::items::{class, type_def};
+use ellie_core::{defs, error, utils};
+
+impl crate::processors::Processor for type_def::TypeDef {
+    fn iterate(
+        &mut self,
+        errors: &mut Vec<ellie_core::error::Error>,
+        cursor: defs::CursorPosition,
+        last_char: char,
+        letter_char: char,
+    ) -> bool {
+        let mut hang = false;
+        if !self.name_collected {
+            if utils::reliable_name_range(utils::ReliableNameRanges::VariableName, letter_char)
+                .reliable
+            {
+                if self.name.is_empty() {
+                    self.name_pos.range_start = cursor;
+                } else if last_char == ' ' {
+                    errors.push(error::error_list::ERROR_S1.clone().build(
+                        vec![error::ErrorBuildField {
+                            key: "token".to_string(),
+                            value: letter_char.to_string(),
+                        }],
+                        alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
+                        defs::Cursor::build_from_cursor(cursor),
+                    ));
+                }
+                self.name_pos.range_end = cursor;
+                self.name += &letter_char.to_string();
+            } else if letter_char == '<' && !self.name.is_empty() {
+                self.name_collected = true;
+            } else if letter_char == '=' && !self.name.is_empty() {
+                self.name_collected = true;
+                self.generics_collected = true;
+                self.assignment_collected = true;
+            } else if letter_char != ' ' {
+                errors.push(error::error_list::ERROR_S1.clone().build(
+                    vec![error::ErrorBuildField {
+                        key: "token".to_string(),
+                        value: letter_char.to_string(),
+                    }],
+                    alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
+                    defs::Cursor::build_from_cursor(cursor),
+                ));
+            }
+        } else if !self.generics_collected {
+            let generic_len = self.generic_definings.len();
+            if utils::reliable_name_range(utils::ReliableNameRanges::Type, letter_char).reliable {
+                if generic_len == 0 {
+                    self.generic_definings.push(class::GenericDefining {
+                        pos: defs::Cursor {
+                            range_start: cursor,
+                            ..Default::default()
+                        },
+                        name: letter_char.to_string(),
+                        hash: utils::generate_hash_usize(),
+                        ..Default::default()
+                    });
+                } else {
+                    if self.generic_definings[generic_len - 1].name.is_empty() {
+                        self.generic_definings[generic_len - 1].pos.range_start = cursor;
+                        self.generic_definings[generic_len - 1].hash = utils::generate_hash_usize();
+                    } else if last_char == ' ' {
+                        errors.push(error::error_list::ERROR_S1.clone().build(
+                            vec![error::ErrorBuildField {
+                                key: "token".to_string(),
+                                value: letter_char.to_string(),
+                            }],
+                            alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
+                            defs::Cursor::build_from_cursor(cursor),
+                        ));
+                    }
+                    self.generic_definings[generic_len - 1].pos.range_end = cursor;
+                    self.generic_definings[generic_len - 1].name += &letter_char.to_string();
+                }
+            } else if letter_char == ','
+                && generic_len > 0
+                && !self.generic_definings[generic_len - 1].name.is_empty()
+            {
+                self.generic_definings.last_mut().unwrap().pos.range_end =
+                    cursor.clone().pop_char(1);
+                self.generic_definings
+                    .push(class::GenericDefining::default());
+            } else if letter_char == '>'
+                && generic_len > 0
+                && !self.generic_definings[generic_len - 1].name.is_empty()
+            {
+                self.generic_definings.last_mut().unwrap().pos.range_end =
+                    cursor.clone().pop_char(1);
+                self.generics_collected = true;
+            } else if letter_char != ' ' {
+                errors.push(error::error_list::ERROR_S1.clone().build(
+                    vec![error::ErrorBuildField {
+                        key: "token".to_string(),
+                        value: letter_char.to_string(),
+                    }],
+                    alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
+                    defs::Cursor::build_from_cursor(cursor),
+                ));
+            }
+        } else if !self.assignment_collected {
+            if letter_char == '=' {
+                self.assignment_collected = true;
+            } else if letter_char != ' ' {
+                errors.push(error::error_list::ERROR_S1.clone().build(
+                    vec![error::ErrorBuildField {
+                        key: "token".to_string(),
+                        value: letter_char.to_string(),
+                    }],
+                    alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
+                    defs::Cursor::build_from_cursor(cursor),
+                ));
+            }
+        } else if self.rtype.complete && letter_char == ';' {
+            self.pos.range_end = cursor;
+            self.complete = true;
+        } else {
+            if self.rtype_pos.range_start.is_zero() && letter_char != ' ' {
+                self.rtype_pos.range_start = cursor;
+            }
+            if letter_char != ' ' {
+                self.rtype_pos.range_end = cursor;
+            }
+            hang = self.rtype.iterate(errors, cursor, last_char, letter_char);
+        }
+        hang
+    }
+}
@@ -0,0 +1,25 @@
+use crate::syntax::types::big_integer_type;
+use ellie_core::{defs, error};
+
+impl crate::processors::Processor for big_integer_type::BigIntegerType {
+    fn iterate(
+        &mut self,
+        errors: &mut Vec<error::Error>,
+        cursor: defs::CursorPosition,
+        _last_char: char,
+        letter_char: char,
+    ) -> bool {
+        // Nothing can follow the `n` suffix
+        if letter_char != ' ' {
+            errors.push(error::error_list::ERROR_S1.clone().build(
+                vec![error::ErrorBuildField {
+                    key: "token".to_string(),
+                    value: letter_char.to_string(),
+                }],
+                alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
+                defs::Cursor::build_from_cursor(cursor),
+            ));
+        }
+        false
+    }
+}
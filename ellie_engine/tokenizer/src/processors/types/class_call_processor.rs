@@ -67,7 +67,7 @@ impl crate::processors::Processor for class_call_type::ClassCallCollector {
                     .pos
                     .range_start
                     .is_zero()
-                    && letter_char == ' '
+                    && letter_char != ' '
                 {
                     self.data.generic_parameters[len - 1].pos.range_start = cursor;
                 }
@@ -1,5 +1,50 @@
 use crate::syntax::types::integer_type;
-use ellie_core::{defs, error};
+use ellie_core::{definite::types::integer::IntegerSize, defs, error};
+
+impl integer_type::IntegerTypeCollector {
+    /// Applies the width suffix once it names a fixed width integer type
+    fn resolve_suffix(&mut self, errors: &mut Vec<error::Error>, cursor: defs::CursorPosition) {
+        match IntegerSize::from_type_name(&self.suffix) {
+            Some(size) if size.is_fixed() => {
+                let wide_value = self.raw.parse::<i128>().unwrap_or_default();
+                if size.fits(wide_value) {
+                    self.data.size = size;
+                    self.data.value = wide_value as isize;
+                    self.complete = true;
+                } else if self.overflow {
+                    // Already reported while collecting digits
+                    self.complete = true;
+                } else {
+                    errors.push(error::error_list::ERROR_S16.clone().build(
+                        vec![error::ErrorBuildField {
+                            key: "val".to_owned(),
+                            value: alloc::format!("{}{}", self.raw, self.suffix),
+                        }],
+                        alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
+                        defs::Cursor::build_from_cursor(cursor),
+                    ));
+                    self.complete = true;
+                }
+            }
+            _ => {
+                self.complete = false;
+                if !IntegerSize::FIXED
+                    .iter()
+                    .any(|size| size.type_name().starts_with(&self.suffix))
+                {
+                    errors.push(error::error_list::ERROR_S1.clone().build(
+                        vec![error::ErrorBuildField {
+                            key: "token".to_string(),
+                            value: self.suffix.clone(),
+                        }],
+                        alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
+                        defs::Cursor::build_from_cursor(cursor),
+                    ));
+                }
+            }
+        }
+    }
+}
 
 impl crate::processors::Processor for integer_type::IntegerTypeCollector {
     fn iterate(
@@ -10,7 +55,11 @@ impl crate::processors::Processor for integer_type::IntegerTypeCollector {
         letter_char: char,
     ) -> bool {
         let is_num = letter_char.to_string().parse::<i8>().is_ok();
-        if is_num {
+        if !self.suffix.is_empty() && is_num && last_char != ' ' {
+            self.suffix += &letter_char.to_string();
+            self.data.pos.range_end = cursor;
+            self.resolve_suffix(errors, cursor);
+        } else if is_num {
             if self.raw == "" {
                 self.data.pos.range_start = cursor;
             } else if last_char == ' ' {
@@ -27,17 +76,41 @@ impl crate::processors::Processor for integer_type::IntegerTypeCollector {
             if let Ok(nm) = self.raw.parse::<isize>() {
                 self.data.value = nm;
             } else {
-                errors.push(error::error_list::ERROR_S16.clone().build(
-                    vec![error::ErrorBuildField {
-                        key: "val".to_owned(),
-                        value: self.raw.clone(),
-                    }],
-                    alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
-                    defs::Cursor::build_from_cursor(cursor),
-                ));
+                if let Ok(nm) = self.raw.parse::<u64>() {
+                    self.data.value = nm as isize;
+                }
+                if !self.overflow {
+                    self.overflow = true;
+                    errors.push(error::error_list::ERROR_S16.clone().build(
+                        vec![error::ErrorBuildField {
+                            key: "val".to_owned(),
+                            value: self.raw.clone(),
+                        }],
+                        alloc::format!("{}:{}:{}", file!().to_owned(), line!(), column!()),
+                        defs::Cursor::build_from_cursor(cursor),
+                    ));
+                }
             }
-            self.data.pos.range_end = cursor;
             self.complete = true;
+            self.data.pos.range_end = cursor;
+        } else if (letter_char == 'i' || letter_char == 'u')
+            && self.suffix.is_empty()
+            && !self.raw.is_empty()
+            && self.raw != "-"
+            && last_char != ' '
+        {
+            self.suffix = letter_char.to_string();
+            self.data.pos.range_end = cursor;
+            self.complete = false;
+            // Values up to u64::MAX are valid with a `u64` suffix, take the overflow error back
+            if self.overflow
+                && letter_char == 'u'
+                && self.raw.parse::<u64>().is_ok()
+                && matches!(errors.last(), Some(error) if error.code == error::error_list::ERROR_S16.code)
+            {
+                errors.pop();
+                self.overflow = false;
+            }
         } else {
             if letter_char == '-' && self.raw == "" {
                 self.raw = "-".to_string();
@@ -1,5 +1,6 @@
 pub mod array_processor;
 pub mod as_processor;
+pub mod big_integer_processor;
 pub mod brace_reference_processor;
 pub mod byte_processor;
 pub mod char_processor;
@@ -28,6 +29,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, Serialize, Deserialize, EnumAsInner)]
 pub enum Processors {
     Integer(integer_type::IntegerTypeCollector),
+    BigInteger(big_integer_type::BigIntegerType),
     Byte(byte_type::ByteType),
     Decimal(decimal_type::DecimalTypeCollector),
     Char(char_type::CharType),
@@ -51,6 +53,9 @@ impl Processors {
     pub fn to_definite(&self) -> ellie_core::definite::types::Types {
         match self.clone() {
             Processors::Integer(e) => ellie_core::definite::types::Types::Integer(e.to_definite()),
+            Processors::BigInteger(e) => {
+                ellie_core::definite::types::Types::BigInteger(e.to_definite())
+            }
             Processors::Byte(e) => ellie_core::definite::types::Types::Byte(e.to_definite()),
             Processors::Decimal(e) => ellie_core::definite::types::Types::Decimal(e.to_definite()),
             Processors::Char(e) => ellie_core::definite::types::Types::Char(e.to_definite()),
@@ -101,6 +106,9 @@ impl Processors {
             definite::types::Types::Byte(e) => {
                 Processors::Byte(byte_type::ByteType::default().from_definite(e))
             }
+            definite::types::Types::BigInteger(e) => {
+                Processors::BigInteger(big_integer_type::BigIntegerType::default().from_definite(e))
+            }
             definite::types::Types::Decimal(e) => {
                 Processors::Decimal(decimal_type::DecimalTypeCollector::default().from_definite(e))
             }
@@ -166,6 +174,7 @@ impl Processors {
     pub fn is_static(&self) -> bool {
         match self {
             Processors::Integer(_) => true,
+            Processors::BigInteger(_) => true,
             Processors::Byte(_) => true,
             Processors::Decimal(_) => true,
             Processors::Char(_) => true,
@@ -204,6 +213,7 @@ impl Processors {
     pub fn is_complete(&self) -> bool {
         match &self {
             Processors::Integer(e) => e.complete,
+            Processors::BigInteger(e) => e.complete,
             Processors::Byte(e) => e.complete,
             Processors::Char(e) => e.complete,
             Processors::String(e) => e.complete,
@@ -227,6 +237,7 @@ impl Processors {
     pub fn is_not_initialized(&self) -> bool {
         match &self {
             Processors::Integer(_) => false,
+            Processors::BigInteger(_) => false,
             Processors::Byte(_) => false,
             Processors::Decimal(_) => false,
             Processors::Char(_) => false,
@@ -275,6 +286,7 @@ impl Processors {
     pub fn get_pos(&self) -> defs::Cursor {
         match self {
             Processors::Integer(e) => e.data.pos,
+            Processors::BigInteger(e) => e.pos,
             Processors::Byte(e) => e.pos,
             Processors::Decimal(e) => e.data.pos,
             Processors::Char(e) => e.pos,
@@ -454,8 +466,29 @@ impl super::Processor for TypeProcessor {
             });
             // Skip to next iteration
             return true;
+        } else if letter_char == 'n'
+            && last_char != ' '
+            && matches!(&self.current, Processors::Integer(x) if x.suffix.is_empty() && !x.raw.is_empty() && x.raw != "-")
+        {
+            // Big integers take any amount of digits, take the overflow error back
+            if matches!(&self.current, Processors::Integer(x) if x.overflow)
+                && matches!(errors.last(), Some(error) if error.code == ellie_core::error::error_list::ERROR_S16.code)
+            {
+                errors.pop();
+            }
+            self.current = Processors::BigInteger(big_integer_type::BigIntegerType {
+                value: self.current.as_integer().unwrap().raw.clone(),
+                pos: defs::Cursor {
+                    range_end: cursor,
+                    ..self.current.get_pos()
+                },
+                complete: true,
+            });
+            // Skip to next iteration
+            return true;
         } else if letter_char == '.'
-            && (not_initalized || matches!(&self.current, Processors::Integer(_)))
+            && (not_initalized
+                || matches!(&self.current, Processors::Integer(e) if e.suffix.is_empty()))
         {
             self.current = Processors::Decimal(decimal_type::DecimalTypeCollector {
                 base: if let Processors::Integer(e) = &self.current {
@@ -575,6 +608,7 @@ impl super::Processor for TypeProcessor {
 
         match &mut self.current {
             Processors::Integer(e) => e.iterate(errors, cursor, last_char, letter_char),
+            Processors::BigInteger(e) => e.iterate(errors, cursor, last_char, letter_char),
             Processors::Byte(e) => e.iterate(errors, cursor, last_char, letter_char),
             Processors::Char(e) => e.iterate(errors, cursor, last_char, letter_char),
             Processors::String(e) => e.iterate(errors, cursor, last_char, letter_char),
@@ -8,6 +8,14 @@ pub struct GenericDefining {
     pub name: String,
     pub hash: usize,
     pub pos: defs::Cursor,
+    pub bound: String,
+    pub bound_pos: defs::Cursor,
+}
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct Implement {
+    pub name: String,
+    pub pos: defs::Cursor,
 }
 
 #[derive(Default, Debug, Clone, Serialize, Deserialize)]
@@ -17,6 +25,9 @@ pub struct Class {
     pub generics_collected: bool,
     pub name_collected: bool,
     pub generic_definings: Vec<GenericDefining>,
+    pub collecting_bound: bool,
+    pub impl_keyword: String,
+    pub implements: Vec<Implement>,
     pub continuum_collected: bool,
     pub name_pos: defs::Cursor,
     #[serde(skip)]
@@ -49,6 +60,20 @@ impl Converter<Class, ellie_core::definite::items::class::Class> for Class {
                     name: x.name.clone(),
                     hash: x.hash,
                     pos: x.pos,
+                    bound: x
+                        .bound
+                        .as_ref()
+                        .map(|bound| bound.name.clone())
+                        .unwrap_or_default(),
+                    bound_pos: x.bound.as_ref().map(|bound| bound.pos).unwrap_or_default(),
+                })
+                .collect::<Vec<_>>(),
+            implements: from
+                .implements
+                .iter()
+                .map(|x| Implement {
+                    name: x.name.clone(),
+                    pos: x.pos,
                 })
                 .collect::<Vec<_>>(),
             ..Default::default()
@@ -0,0 +1,45 @@
+use ellie_core::{definite::Converter, defs};
+use serde::{Deserialize, Serialize};
+
+use crate::{processors::items::Processors, syntax::items::class::GenericDefining};
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct Extend {
+    pub target: String,
+    pub target_collected: bool,
+    pub generics_collected: bool,
+    pub generic_definings: Vec<GenericDefining>,
+    pub continuum_collected: bool,
+    pub target_pos: defs::Cursor,
+    #[serde(skip)]
+    pub iterator: Box<crate::iterator::Iterator>,
+    pub body: Vec<Processors>,
+    pub brace_count: usize,
+    pub pos: defs::Cursor,
+    pub complete: bool,
+}
+
+impl Converter<Extend, ellie_core::definite::items::extend::Extend> for Extend {
+    fn to_definite(self) -> ellie_core::definite::items::extend::Extend {
+        panic!("Not required")
+    }
+
+    fn from_definite(self, from: ellie_core::definite::items::extend::Extend) -> Extend {
+        Extend {
+            target: from.target_name,
+            target_pos: from.target_pos,
+            pos: from.pos,
+            generic_definings: from
+                .target_generics
+                .iter()
+                .map(|x| GenericDefining {
+                    name: x.name.clone(),
+                    hash: x.hash,
+                    pos: x.pos,
+                    ..Default::default()
+                })
+                .collect::<Vec<_>>(),
+            ..Default::default()
+        }
+    }
+}
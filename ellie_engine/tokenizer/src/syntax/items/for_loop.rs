@@ -28,6 +28,7 @@ impl Converter<ForLoop, ellie_core::definite::items::for_loop::ForLoop> for ForL
         ellie_core::definite::items::for_loop::ForLoop {
             variable: self.variable.current.to_definite(),
             iterator: self.target_iterator.current.to_definite(),
+            iterator_type: ellie_core::definite::definers::DefinerCollecting::Dynamic,
             variable_pos: self.variable_pos,
             iterator_pos: self.iterator_pos,
             body_pos: self.body_pos,
@@ -1,4 +1,7 @@
-use crate::{processors::items::Processors, syntax::items::definers};
+use crate::{
+    processors::items::Processors,
+    syntax::items::{class::GenericDefining, definers},
+};
 use ellie_core::{definite::Converter, defs};
 use serde::{Deserialize, Serialize};
 
@@ -18,6 +21,7 @@ pub struct Function {
     pub name_pos: defs::Cursor,
     pub public: bool,
     pub defining: bool,
+    pub generic_definings: Vec<GenericDefining>,
     pub parameters: Vec<FunctionParameter>,
     pub parameters_pos: defs::Cursor,
     pub return_type: definers::DefinerCollector,
@@ -33,6 +37,8 @@ pub struct Function {
 pub struct FunctionCollector {
     pub data: Function,
     pub name_collected: bool,
+    pub generics_closed: bool,
+    pub generics_collected: bool,
     pub parameters_collected: bool,
     pub key_collected: bool,
     pub return_collected: bool,
@@ -49,6 +55,16 @@ impl Converter<FunctionCollector, ellie_core::definite::items::function::Functio
     fn to_definite(self) -> ellie_core::definite::items::function::Function {
         ellie_core::definite::items::function::Function {
             name: self.data.name,
+            generic_definings: self
+                .data
+                .generic_definings
+                .into_iter()
+                .map(|x| ellie_core::definite::items::type_def::GenericDefining {
+                    name: x.name,
+                    hash: x.hash,
+                    pos: x.pos,
+                })
+                .collect(),
             parameters: self
                 .data
                 .parameters
@@ -87,6 +103,16 @@ impl Converter<FunctionCollector, ellie_core::definite::items::function::Functio
                 name: from.name,
                 name_pos: from.name_pos,
                 public: from.public,
+                generic_definings: from
+                    .generic_definings
+                    .into_iter()
+                    .map(|x| GenericDefining {
+                        name: x.name,
+                        hash: x.hash,
+                        pos: x.pos,
+                        ..Default::default()
+                    })
+                    .collect(),
                 parameters: from
                     .parameters
                     .into_iter()
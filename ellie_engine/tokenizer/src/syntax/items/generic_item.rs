@@ -1,4 +1,4 @@
-use ellie_core::defs;
+use ellie_core::{definite::items::interface::InterfaceReference, defs};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -6,4 +6,5 @@ pub struct GenericItem {
     pub generic_name: String,
     pub pos: defs::Cursor,
     pub hash: usize,
+    pub bound: Option<InterfaceReference>,
 }
@@ -0,0 +1,38 @@
+use ellie_core::{definite::Converter, defs};
+use serde::{Deserialize, Serialize};
+
+use crate::processors::items::Processors;
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct Interface {
+    pub name: String,
+    pub public: bool,
+    pub name_collected: bool,
+    pub name_pos: defs::Cursor,
+    #[serde(skip)]
+    pub iterator: Box<crate::iterator::Iterator>,
+    pub body: Vec<Processors>,
+    pub brace_count: usize,
+    pub pos: defs::Cursor,
+    pub hash: usize,
+    pub complete: bool,
+}
+
+impl Converter<Interface, ellie_core::definite::items::interface::Interface> for Interface {
+    fn to_definite(self) -> ellie_core::definite::items::interface::Interface {
+        panic!("Not required")
+    }
+
+    fn from_definite(self, from: ellie_core::definite::items::interface::Interface) -> Interface {
+        Interface {
+            name: from.name,
+            public: from.public,
+            name_collected: true,
+            name_pos: from.name_pos,
+            pos: from.pos,
+            hash: from.hash,
+            complete: true,
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use crate::processors::{items::Processors, types};
+use ellie_core::{definite::Converter, defs};
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct MatchArm {
+    pub pattern: types::TypeProcessor,
+    pub pattern_pos: defs::Cursor,
+    pub pattern_collected: bool,
+    pub arrow_collected: bool,
+    pub body_started: bool,
+    pub body_pos: defs::Cursor,
+    pub body: Vec<Processors>,
+    #[serde(skip)]
+    pub iterator: Box<crate::iterator::Iterator>,
+    pub brace_count: usize,
+    pub complete: bool,
+}
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct Match {
+    pub target: types::TypeProcessor,
+    pub target_pos: defs::Cursor,
+    pub target_collected: bool,
+    pub arms: Vec<MatchArm>,
+    pub body_pos: defs::Cursor,
+    pub pos: defs::Cursor,
+    pub complete: bool,
+}
+
+impl Converter<Match, ellie_core::definite::items::match_type::Match> for Match {
+    fn to_definite(self) -> ellie_core::definite::items::match_type::Match {
+        unreachable!()
+    }
+
+    fn from_definite(self, from: ellie_core::definite::items::match_type::Match) -> Match {
+        Match {
+            target: types::TypeProcessor {
+                current: types::Processors::default().from_definite(from.target),
+                ..Default::default()
+            },
+            target_pos: from.target_pos,
+            target_collected: true,
+            pos: from.pos,
+            complete: true,
+            ..Default::default()
+        }
+    }
+}
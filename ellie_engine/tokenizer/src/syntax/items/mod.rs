@@ -4,6 +4,7 @@ pub mod condition;
 pub mod constructor;
 pub mod definers;
 pub mod enum_type;
+pub mod extend;
 pub mod file_key;
 pub mod for_loop;
 pub mod function;
@@ -11,10 +12,13 @@ pub mod getter;
 pub mod getter_call;
 pub mod go;
 pub mod import;
+pub mod interface;
+pub mod match_type;
 pub mod ret;
 
 pub mod setter;
 pub mod setter_call;
+pub mod type_def;
 pub mod variable;
 
 pub mod comment;
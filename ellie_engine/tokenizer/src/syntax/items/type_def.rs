@@ -0,0 +1,72 @@
+use ellie_core::{definite::Converter, defs};
+use serde::{Deserialize, Serialize};
+
+use crate::syntax::items::{class::GenericDefining, definers};
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct TypeDef {
+    pub name: String,
+    pub public: bool,
+    pub name_collected: bool,
+    pub generics_collected: bool,
+    pub generic_definings: Vec<GenericDefining>,
+    pub assignment_collected: bool,
+    pub rtype: definers::DefinerCollector,
+    pub name_pos: defs::Cursor,
+    pub rtype_pos: defs::Cursor,
+    pub pos: defs::Cursor,
+    pub hash: usize,
+    pub complete: bool,
+}
+
+impl Converter<TypeDef, ellie_core::definite::items::type_def::TypeDef> for TypeDef {
+    fn to_definite(self) -> ellie_core::definite::items::type_def::TypeDef {
+        ellie_core::definite::items::type_def::TypeDef {
+            name: self.name,
+            public: self.public,
+            generic_definings: self
+                .generic_definings
+                .into_iter()
+                .map(|x| ellie_core::definite::items::type_def::GenericDefining {
+                    name: x.name,
+                    hash: x.hash,
+                    pos: x.pos,
+                })
+                .collect(),
+            rtype: self.rtype.definer_type.to_definite(),
+            name_pos: self.name_pos,
+            rtype_pos: self.rtype_pos,
+            pos: self.pos,
+            hash: self.hash,
+        }
+    }
+
+    fn from_definite(self, from: ellie_core::definite::items::type_def::TypeDef) -> TypeDef {
+        TypeDef {
+            name: from.name,
+            public: from.public,
+            name_collected: true,
+            generics_collected: true,
+            generic_definings: from
+                .generic_definings
+                .into_iter()
+                .map(|x| GenericDefining {
+                    name: x.name,
+                    hash: x.hash,
+                    pos: x.pos,
+                    ..Default::default()
+                })
+                .collect(),
+            assignment_collected: true,
+            rtype: definers::DefinerCollector {
+                definer_type: definers::DefinerTypes::default().from_definite(from.rtype),
+                complete: true,
+            },
+            name_pos: from.name_pos,
+            rtype_pos: from.rtype_pos,
+            pos: from.pos,
+            hash: from.hash,
+            complete: true,
+        }
+    }
+}
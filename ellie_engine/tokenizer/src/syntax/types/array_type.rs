@@ -38,6 +38,7 @@ impl definite::Converter<ArrayTypeCollector, definite::types::array::ArrayType>
                     location: x.location,
                 })
                 .collect(),
+            fixed: false,
             pos: self.data.pos,
         }
     }
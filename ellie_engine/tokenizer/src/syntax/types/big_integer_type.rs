@@ -0,0 +1,30 @@
+use alloc::string::String;
+use ellie_core::{definite, defs};
+use serde::{Deserialize, Serialize};
+
+#[derive(PartialEq, Default, Debug, Clone, Serialize, Deserialize)]
+pub struct BigIntegerType {
+    /// Digits before the `n` suffix
+    pub value: String,
+    pub pos: defs::Cursor,
+    pub complete: bool,
+}
+
+impl definite::Converter<BigIntegerType, definite::types::big_integer::BigIntegerType>
+    for BigIntegerType
+{
+    fn to_definite(self) -> definite::types::big_integer::BigIntegerType {
+        definite::types::big_integer::BigIntegerType {
+            value: self.value,
+            pos: self.pos,
+        }
+    }
+
+    fn from_definite(self, from: definite::types::big_integer::BigIntegerType) -> Self {
+        BigIntegerType {
+            value: from.value,
+            pos: from.pos,
+            complete: true,
+        }
+    }
+}
@@ -1,10 +1,11 @@
 use alloc::{fmt::Debug, string::String};
-use ellie_core::{definite, defs};
+use ellie_core::{definite, definite::types::integer::IntegerSize, defs};
 use serde::{Deserialize, Serialize};
 
 #[derive(PartialEq, Default, Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct IntegerType {
     pub value: isize,
+    pub size: IntegerSize,
     pub pos: defs::Cursor,
 }
 
@@ -12,6 +13,10 @@ pub struct IntegerType {
 pub struct IntegerTypeCollector {
     pub data: IntegerType,
     pub raw: String,
+    /// Width suffix such as `u8` in `10u8`
+    pub suffix: String,
+    /// Value didn't fit to `int`, error is taken back if a `u64` suffix follows
+    pub overflow: bool,
     pub complete: bool,
 }
 
@@ -21,6 +26,7 @@ impl definite::Converter<IntegerTypeCollector, definite::types::integer::Integer
     fn to_definite(self) -> definite::types::integer::IntegerType {
         definite::types::integer::IntegerType {
             value: self.data.value,
+            size: self.data.size,
             pos: self.data.pos,
         }
     }
@@ -30,9 +36,16 @@ impl definite::Converter<IntegerTypeCollector, definite::types::integer::Integer
         IntegerTypeCollector {
             data: IntegerType {
                 value,
+                size: from.size,
                 pos: from.pos,
             },
-            raw: value.to_string(),
+            raw: from.wide_value().to_string(),
+            suffix: if from.size.is_fixed() {
+                from.size.type_name().to_string()
+            } else {
+                String::new()
+            },
+            overflow: false,
             complete: true,
         }
     }
@@ -1,5 +1,6 @@
 pub mod array_type;
 pub mod as_keyword;
+pub mod big_integer_type;
 pub mod brace_reference_type;
 pub mod byte_type;
 pub mod char_type;
@@ -38,6 +38,21 @@ pub struct ClassPageType {
     pub page_hash: usize,
 }
 
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+pub struct ExtendPageType {
+    pub target: DefinerCollecting,
+    pub pos: defs::Cursor,
+    pub page_hash: usize,
+}
+
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+pub struct InterfacePageType {
+    pub name: String,
+    pub hash: usize,
+    pub pos: defs::Cursor,
+    pub page_hash: usize,
+}
+
 #[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
 pub struct FunctionPageType {
     pub return_type: DefinerCollecting,
@@ -61,6 +76,8 @@ pub enum PageType {
     RawBody,
     LoopBody,
     ClassBody(ClassPageType),
+    ExtendBody(ExtendPageType),
+    InterfaceBody(InterfacePageType),
     ConditionBody(ConditionPageType),
 }
 
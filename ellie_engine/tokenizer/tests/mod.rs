@@ -1,5 +1,6 @@
 mod types {
     mod array_test;
+    mod big_integer_test;
     mod brace_reference_test;
     mod char_test;
     mod class_call_test;
@@ -0,0 +1,39 @@
+#[cfg(test)]
+mod big_integer_tests {
+    use ellie_core::{defs, error};
+    use ellie_tokenizer::processors::{
+        types::{Processors, TypeProcessor},
+        Processor,
+    };
+
+    fn process(code: &str) -> (TypeProcessor, Vec<error::Error>) {
+        let mut pos = defs::CursorPosition::default();
+        let mut errors: Vec<error::Error> = Vec::new();
+        let mut processor: TypeProcessor = TypeProcessor::default();
+        let mut last_char = '\0';
+        for letter_char in code.chars() {
+            processor.iterate(&mut errors, pos, last_char, letter_char);
+            pos.skip_char(1);
+            last_char = letter_char;
+        }
+        (processor, errors)
+    }
+
+    #[test]
+    fn big_integer_with_no_error() {
+        let (processor, errors) = process("-123456789012345678901234567890n");
+        assert!(errors.is_empty());
+        match processor.current {
+            Processors::BigInteger(e) => {
+                assert!(e.complete && e.value == "-123456789012345678901234567890")
+            }
+            _ => panic!("Unexpected behaviour: {:?}", processor.current),
+        }
+    }
+
+    #[test]
+    fn big_integer_with_trailing_char_with_error() {
+        let (_, errors) = process("10n5");
+        assert!(errors.len() == 1 && errors[0].code == error::error_list::ERROR_S1.code);
+    }
+}
@@ -44,4 +44,43 @@ mod integer_tests {
         format!("{:?}", processor).hash(&mut result_hash);
         assert!(errors.is_empty() && result_hash.finish() == 7962439497365308812);
     }
+
+    #[test]
+    fn integer_with_width_suffix_with_no_error() {
+        let code = "200u8";
+        let mut pos = defs::CursorPosition::default();
+        let mut errors: Vec<error::Error> = Vec::new();
+        let mut processor: integer_type::IntegerTypeCollector =
+            integer_type::IntegerTypeCollector::default();
+        let mut last_char = '\0';
+        for letter_char in code.chars() {
+            processor.iterate(&mut errors, pos, last_char, letter_char);
+            pos.1 += 1;
+            last_char = letter_char;
+        }
+
+        assert!(
+            errors.is_empty()
+                && processor.complete
+                && processor.data.value == 200
+                && processor.data.size == defs::IntegerSize::U8
+        );
+    }
+
+    #[test]
+    fn integer_overflowing_width_suffix_with_error() {
+        let code = "300i8";
+        let mut pos = defs::CursorPosition::default();
+        let mut errors: Vec<error::Error> = Vec::new();
+        let mut processor: integer_type::IntegerTypeCollector =
+            integer_type::IntegerTypeCollector::default();
+        let mut last_char = '\0';
+        for letter_char in code.chars() {
+            processor.iterate(&mut errors, pos, last_char, letter_char);
+            pos.1 += 1;
+            last_char = letter_char;
+        }
+
+        assert!(errors.len() == 1 && errors[0].code == error::error_list::ERROR_S16.code);
+    }
 }
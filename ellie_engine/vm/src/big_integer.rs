@@ -0,0 +1,328 @@
+use alloc::{string::String, vec, vec::Vec};
+use core::{
+    cmp::Ordering,
+    fmt::{Display, Error, Formatter},
+};
+
+/// Largest power of ten fitting to a limb, numbers are parsed and printed in chunks of it
+const DECIMAL_CHUNK: u32 = 1_000_000_000;
+const DECIMAL_CHUNK_DIGITS: usize = 9;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+/// Arbitrary precision integer behind `bigint`
+/// ## Heap layout
+/// * `0`: sign, `1` for negative numbers
+/// * `1..`: little endian 32 bit limbs of the magnitude
+pub struct BigInteger {
+    negative: bool,
+    /// Little endian limbs without trailing zeros, zero has no limbs
+    magnitude: Vec<u32>,
+}
+
+fn compare_magnitude(first: &[u32], second: &[u32]) -> Ordering {
+    first
+        .len()
+        .cmp(&second.len())
+        .then_with(|| first.iter().rev().cmp(second.iter().rev()))
+}
+
+fn add_magnitude(first: &[u32], second: &[u32]) -> Vec<u32> {
+    let mut result = Vec::with_capacity(first.len().max(second.len()) + 1);
+    let mut carry = 0_u64;
+    for idx in 0..first.len().max(second.len()) {
+        let sum =
+            *first.get(idx).unwrap_or(&0) as u64 + *second.get(idx).unwrap_or(&0) as u64 + carry;
+        result.push(sum as u32);
+        carry = sum >> 32;
+    }
+    result.push(carry as u32);
+    result
+}
+
+/// `first` must be greater or equal to `second`
+fn sub_magnitude(first: &[u32], second: &[u32]) -> Vec<u32> {
+    let mut result = Vec::with_capacity(first.len());
+    let mut borrow = 0_i64;
+    for (idx, limb) in first.iter().enumerate() {
+        let mut difference = *limb as i64 - *second.get(idx).unwrap_or(&0) as i64 - borrow;
+        borrow = (difference < 0) as i64;
+        if difference < 0 {
+            difference += 1 << 32;
+        }
+        result.push(difference as u32);
+    }
+    result
+}
+
+fn mul_magnitude(first: &[u32], second: &[u32]) -> Vec<u32> {
+    let mut result = vec![0_u32; first.len() + second.len()];
+    for (first_idx, first_limb) in first.iter().enumerate() {
+        let mut carry = 0_u64;
+        for (second_idx, second_limb) in second.iter().enumerate() {
+            let product = *first_limb as u64 * *second_limb as u64
+                + result[first_idx + second_idx] as u64
+                + carry;
+            result[first_idx + second_idx] = product as u32;
+            carry = product >> 32;
+        }
+        result[first_idx + second.len()] = carry as u32;
+    }
+    result
+}
+
+/// Multiplies `magnitude` by `multiplier` and adds `addition` in place
+fn mul_add_small(magnitude: &mut Vec<u32>, multiplier: u32, addition: u32) {
+    let mut carry = addition as u64;
+    for limb in magnitude.iter_mut() {
+        let product = *limb as u64 * multiplier as u64 + carry;
+        *limb = product as u32;
+        carry = product >> 32;
+    }
+    if carry != 0 {
+        magnitude.push(carry as u32);
+    }
+}
+
+/// Divides `magnitude` by `divisor` in place, returns the remainder
+fn div_small(magnitude: &mut [u32], divisor: u32) -> u32 {
+    let mut remainder = 0_u64;
+    for limb in magnitude.iter_mut().rev() {
+        let current = (remainder << 32) | *limb as u64;
+        *limb = (current / divisor as u64) as u32;
+        remainder = current % divisor as u64;
+    }
+    remainder as u32
+}
+
+/// Binary long division, `divisor` must not be zero
+fn div_rem_magnitude(dividend: &[u32], divisor: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    if divisor.len() == 1 {
+        let mut quotient = dividend.to_vec();
+        let remainder = div_small(&mut quotient, divisor[0]);
+        return (quotient, vec![remainder]);
+    }
+    let mut quotient = vec![0_u32; dividend.len()];
+    let mut remainder = BigInteger::default();
+    for bit in (0..dividend.len() * 32).rev() {
+        mul_add_small(
+            &mut remainder.magnitude,
+            2,
+            (dividend[bit / 32] >> (bit % 32)) & 1,
+        );
+        if compare_magnitude(&remainder.magnitude, divisor) != Ordering::Less {
+            remainder = BigInteger::new(false, sub_magnitude(&remainder.magnitude, divisor));
+            quotient[bit / 32] |= 1 << (bit % 32);
+        }
+    }
+    (quotient, remainder.magnitude)
+}
+
+impl BigInteger {
+    fn new(negative: bool, mut magnitude: Vec<u32>) -> Self {
+        while magnitude.last() == Some(&0) {
+            magnitude.pop();
+        }
+        BigInteger {
+            negative: negative && !magnitude.is_empty(),
+            magnitude,
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.magnitude.is_empty()
+    }
+
+    pub fn from_i128(value: i128) -> Self {
+        let mut absolute = value.unsigned_abs();
+        let mut magnitude = Vec::new();
+        while absolute != 0 {
+            magnitude.push(absolute as u32);
+            absolute >>= 32;
+        }
+        BigInteger::new(value < 0, magnitude)
+    }
+
+    /// Returns [`None`] if the value doesn't fit to [`i128`]
+    pub fn to_i128(&self) -> Option<i128> {
+        if self.magnitude.len() > 4 {
+            return None;
+        }
+        let absolute = self
+            .magnitude
+            .iter()
+            .rev()
+            .fold(0_u128, |absolute, limb| (absolute << 32) | *limb as u128);
+        if self.negative {
+            0_i128.checked_sub_unsigned(absolute)
+        } else {
+            i128::try_from(absolute).ok()
+        }
+    }
+
+    /// Parses decimal digits with an optional sign
+    pub fn parse(text: &str) -> Option<Self> {
+        let (negative, digits) = match text.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, text.strip_prefix('+').unwrap_or(text)),
+        };
+        if digits.is_empty() || !digits.bytes().all(|digit| digit.is_ascii_digit()) {
+            return None;
+        }
+        let mut magnitude = Vec::new();
+        let first_chunk = match digits.len() % DECIMAL_CHUNK_DIGITS {
+            0 => DECIMAL_CHUNK_DIGITS,
+            len => len,
+        };
+        let mut start = 0;
+        let mut end = first_chunk;
+        while start < digits.len() {
+            let chunk = &digits[start..end];
+            mul_add_small(
+                &mut magnitude,
+                10_u32.pow(chunk.len() as u32),
+                chunk.parse::<u32>().ok()?,
+            );
+            start = end;
+            end += DECIMAL_CHUNK_DIGITS;
+        }
+        Some(BigInteger::new(negative, magnitude))
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        match bytes.split_first() {
+            Some((sign, limbs)) => BigInteger::new(
+                *sign == 1,
+                limbs
+                    .chunks(4)
+                    .map(|limb| {
+                        let mut limb_bytes = [0; 4];
+                        limb_bytes[..limb.len()].copy_from_slice(limb);
+                        u32::from_le_bytes(limb_bytes)
+                    })
+                    .collect(),
+            ),
+            None => BigInteger::default(),
+        }
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![self.negative as u8];
+        for limb in &self.magnitude {
+            bytes.extend(limb.to_le_bytes());
+        }
+        bytes
+    }
+
+    fn negate(&self) -> Self {
+        BigInteger::new(!self.negative, self.magnitude.clone())
+    }
+
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        if self.negative == other.negative {
+            return Some(BigInteger::new(
+                self.negative,
+                add_magnitude(&self.magnitude, &other.magnitude),
+            ));
+        }
+        Some(match compare_magnitude(&self.magnitude, &other.magnitude) {
+            Ordering::Less => BigInteger::new(
+                other.negative,
+                sub_magnitude(&other.magnitude, &self.magnitude),
+            ),
+            _ => BigInteger::new(
+                self.negative,
+                sub_magnitude(&self.magnitude, &other.magnitude),
+            ),
+        })
+    }
+
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        self.checked_add(&other.negate())
+    }
+
+    pub fn checked_mul(&self, other: &Self) -> Option<Self> {
+        Some(BigInteger::new(
+            self.negative != other.negative,
+            mul_magnitude(&self.magnitude, &other.magnitude),
+        ))
+    }
+
+    /// Truncated towards zero like [`i128::checked_div`], [`None`] when `other` is zero
+    pub fn checked_div(&self, other: &Self) -> Option<Self> {
+        if other.is_zero() {
+            return None;
+        }
+        let (quotient, _) = div_rem_magnitude(&self.magnitude, &other.magnitude);
+        Some(BigInteger::new(self.negative != other.negative, quotient))
+    }
+
+    /// Remainder has the sign of `self` like [`i128::checked_rem`], [`None`] when `other` is zero
+    pub fn checked_rem(&self, other: &Self) -> Option<Self> {
+        if other.is_zero() {
+            return None;
+        }
+        let (_, remainder) = div_rem_magnitude(&self.magnitude, &other.magnitude);
+        Some(BigInteger::new(self.negative, remainder))
+    }
+
+    /// [`None`] when `exponent` is negative or doesn't fit to [`u32`]
+    pub fn checked_pow(&self, exponent: &Self) -> Option<Self> {
+        let mut exponent = u32::try_from(exponent.to_i128()?).ok()?;
+        let mut base = self.clone();
+        let mut result = BigInteger::from_i128(1);
+        while exponent != 0 {
+            if exponent & 1 == 1 {
+                result = result.checked_mul(&base)?;
+            }
+            exponent >>= 1;
+            if exponent != 0 {
+                base = base.checked_mul(&base)?;
+            }
+        }
+        Some(result)
+    }
+}
+
+impl Ord for BigInteger {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => compare_magnitude(&self.magnitude, &other.magnitude),
+            (true, true) => compare_magnitude(&other.magnitude, &self.magnitude),
+        }
+    }
+}
+
+impl PartialOrd for BigInteger {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for BigInteger {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        let mut magnitude = self.magnitude.clone();
+        let mut chunks = Vec::new();
+        while !magnitude.is_empty() {
+            chunks.push(div_small(&mut magnitude, DECIMAL_CHUNK));
+            while magnitude.last() == Some(&0) {
+                magnitude.pop();
+            }
+        }
+        let mut digits = String::new();
+        if self.negative {
+            digits.push('-');
+        }
+        match chunks.split_last() {
+            Some((first, rest)) => {
+                digits += &alloc::format!("{first}");
+                for chunk in rest.iter().rev() {
+                    digits += &alloc::format!("{chunk:0width$}", width = DECIMAL_CHUNK_DIGITS);
+                }
+            }
+            None => digits.push('0'),
+        }
+        write!(f, "{digits}")
+    }
+}
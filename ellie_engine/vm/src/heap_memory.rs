@@ -55,43 +55,44 @@ impl HeapMemory {
                 "{} : {} = {:?} =! {:?}\n",
                 key.0,
                 value.type_id,
-                match value.type_id.id {
-                    1 => {
-                        isize::from_le_bytes(value.data.clone().try_into().unwrap()).to_string()
-                    }
-                    2 => {
-                        f32::from_le_bytes(value.data.clone().try_into().unwrap()).to_string()
-                    }
-                    3 => {
-                        f64::from_le_bytes(value.data.clone().try_into().unwrap()).to_string()
-                    }
-                    4 => {
-                        u8::from_le_bytes(value.data.clone().try_into().unwrap()).to_string()
-                    }
-                    5 => {
-                        (value.data[0] == 1).to_string()
-                    }
-                    6 => {
-                        let mut new_string = String::new();
-                        for i in value.data.chunks(4) {
-                            let char = u32::from_le_bytes(i.try_into().unwrap());
-                            new_string.push(char::from_u32(char).unwrap());
-                        }
-                        new_string
-                    }
-                    7 => value.to_char().to_string(),
-                    8 => String::from("void"),
-                    9 => String::from("arr"),
-                    10 => String::from("null"),
-                    11 => String::from("class"),
-                    12 => String::from("function"),
-                    13 => String::from("stack_reference"),
-                    14 => String::from("heap_reference"),
-                    _ => unreachable!("Wrong typeid"),
-                },
+                decode_raw_type(&value),
                 value.data,
             ));
         }
         result
     }
 }
+
+/// Decode a raw type to a human readable text
+pub fn decode_raw_type(value: &RawType) -> String {
+    match value.type_id.id {
+        1 => isize::from_le_bytes(value.data.clone().try_into().unwrap()).to_string(),
+        2 => f32::from_le_bytes(value.data.clone().try_into().unwrap()).to_string(),
+        3 => f64::from_le_bytes(value.data.clone().try_into().unwrap()).to_string(),
+        4 => u8::from_le_bytes(value.data.clone().try_into().unwrap()).to_string(),
+        5 => (value.data[0] == 1).to_string(),
+        6 => {
+            let mut new_string = String::new();
+            for i in value.data.chunks(4) {
+                let char = u32::from_le_bytes(i.try_into().unwrap());
+                new_string.push(char::from_u32(char).unwrap());
+            }
+            new_string
+        }
+        7 => value.to_char().to_string(),
+        8 => String::from("void"),
+        9 => String::from("arr"),
+        10 => String::from("null"),
+        11 => String::from("class"),
+        12 => String::from("function"),
+        13 => String::from("stack_reference"),
+        14 => String::from("heap_reference"),
+        15 => String::from("static_array"),
+        16..=23 => match value.to_register_raw() {
+            Ok(e) => e.to_fixed_integer().to_string(),
+            Err(_) => String::from("fixed_integer"),
+        },
+        24 => value.to_big_integer().to_string(),
+        _ => unreachable!("Wrong typeid"),
+    }
+}
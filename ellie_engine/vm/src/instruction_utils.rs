@@ -1,4 +1,4 @@
-//Auto generated from `instructions.json` by `reAssembler.js rev: 3
+//Auto generated from `instructions.json` by `reAssembler.js rev: 6
 
 use crate::{
     heap_memory::HeapMemory,
@@ -244,6 +244,21 @@ pub struct DEA {
     pub addressing_mode: AddressingModes,
 }
 
+#[derive(Clone, Copy, Debug)]
+pub struct A2N {
+    pub addressing_mode: AddressingModes,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct A2BI {
+    pub addressing_mode: AddressingModes,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct JMPT {
+    pub addressing_mode: AddressingModes,
+}
+
 #[derive(Clone, Copy, Debug)]
 pub enum Instructions {
     LDA(LDA),
@@ -293,6 +308,9 @@ pub enum Instructions {
     CO(CO),
     FN(FN),
     DEA(DEA),
+    A2N(A2N),
+    A2BI(A2BI),
+    JMPT(JMPT),
 }
 
 impl Instructions {
@@ -655,6 +673,15 @@ impl Instructions {
             119 => Some(Instructions::DEA(DEA {
                 addressing_mode: AddressingModes::Absolute,
             })),
+            120 => Some(Instructions::A2N(A2N {
+                addressing_mode: AddressingModes::Immediate,
+            })),
+            121 => Some(Instructions::A2BI(A2BI {
+                addressing_mode: AddressingModes::Implicit,
+            })),
+            122 => Some(Instructions::JMPT(JMPT {
+                addressing_mode: AddressingModes::Immediate,
+            })),
             _ => None,
         }
     }
@@ -1045,6 +1072,30 @@ impl Instructions {
                 addressing_value,
                 arch,
             ),
+            Instructions::A2N(e) => e.execute(
+                heap_memory,
+                program,
+                current_stack,
+                stack_memory,
+                addressing_value,
+                arch,
+            ),
+            Instructions::A2BI(e) => e.execute(
+                heap_memory,
+                program,
+                current_stack,
+                stack_memory,
+                addressing_value,
+                arch,
+            ),
+            Instructions::JMPT(e) => e.execute(
+                heap_memory,
+                program,
+                current_stack,
+                stack_memory,
+                addressing_value,
+                arch,
+            ),
         }
     }
     pub fn addressing_mode(&self) -> AddressingModes {
@@ -1096,6 +1147,65 @@ impl Instructions {
             Instructions::CO(e) => e.addressing_mode,
             Instructions::FN(e) => e.addressing_mode,
             Instructions::DEA(e) => e.addressing_mode,
+            Instructions::A2N(e) => e.addressing_mode,
+            Instructions::A2BI(e) => e.addressing_mode,
+            Instructions::JMPT(e) => e.addressing_mode,
+        }
+    }
+
+    /// Name of the instruction
+    pub fn mnemonic(&self) -> &'static str {
+        match &self {
+            Instructions::LDA(_) => "LDA",
+            Instructions::LDB(_) => "LDB",
+            Instructions::LDC(_) => "LDC",
+            Instructions::LDX(_) => "LDX",
+            Instructions::LDY(_) => "LDY",
+            Instructions::STA(_) => "STA",
+            Instructions::STB(_) => "STB",
+            Instructions::STC(_) => "STC",
+            Instructions::STX(_) => "STX",
+            Instructions::STY(_) => "STY",
+            Instructions::EQ(_) => "EQ",
+            Instructions::NE(_) => "NE",
+            Instructions::GT(_) => "GT",
+            Instructions::LT(_) => "LT",
+            Instructions::GQ(_) => "GQ",
+            Instructions::LQ(_) => "LQ",
+            Instructions::AND(_) => "AND",
+            Instructions::OR(_) => "OR",
+            Instructions::ADD(_) => "ADD",
+            Instructions::SUB(_) => "SUB",
+            Instructions::MUL(_) => "MUL",
+            Instructions::EXP(_) => "EXP",
+            Instructions::DIV(_) => "DIV",
+            Instructions::MOD(_) => "MOD",
+            Instructions::JMP(_) => "JMP",
+            Instructions::JMPA(_) => "JMPA",
+            Instructions::CALL(_) => "CALL",
+            Instructions::RET(_) => "RET",
+            Instructions::PUSH(_) => "PUSH",
+            Instructions::SPUS(_) => "SPUS",
+            Instructions::LEN(_) => "LEN",
+            Instructions::A2I(_) => "A2I",
+            Instructions::A2F(_) => "A2F",
+            Instructions::A2D(_) => "A2D",
+            Instructions::A2B(_) => "A2B",
+            Instructions::A2S(_) => "A2S",
+            Instructions::A2C(_) => "A2C",
+            Instructions::A2O(_) => "A2O",
+            Instructions::ARR(_) => "ARR",
+            Instructions::STR(_) => "STR",
+            Instructions::SAR(_) => "SAR",
+            Instructions::POPS(_) => "POPS",
+            Instructions::BRK(_) => "BRK",
+            Instructions::CALLN(_) => "CALLN",
+            Instructions::CO(_) => "CO",
+            Instructions::FN(_) => "FN",
+            Instructions::DEA(_) => "DEA",
+            Instructions::A2N(_) => "A2N",
+            Instructions::A2BI(_) => "A2BI",
+            Instructions::JMPT(_) => "JMPT",
         }
     }
 }
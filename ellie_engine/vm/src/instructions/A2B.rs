@@ -54,6 +54,15 @@ impl super::InstructionExecuter for A2B {
                                 data.to_le_bytes()[0]
                             });
                     }
+                    16..=23 => match u8::try_from(current_stack.registers.A.to_fixed_integer()) {
+                        Ok(data) => current_stack.registers.A = StaticRawType::from_byte(data),
+                        Err(_) => {
+                            return Err(ExecuterPanic {
+                                reason: ThreadPanicReason::ByteOverflow,
+                                code_location: format!("{}:{}", file!(), line!()),
+                            });
+                        }
+                    },
                     4 => (),
                     5 => {
                         let data = current_stack.registers.A.to_bool();
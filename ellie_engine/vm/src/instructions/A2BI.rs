@@ -0,0 +1,81 @@
+use alloc::format;
+use ellie_core::defs::PlatformArchitecture;
+
+use crate::{
+    big_integer::BigInteger,
+    heap_memory::HeapMemory,
+    instruction_utils::A2BI,
+    stack::Stack,
+    stack_memory::StackMemory,
+    utils::{resolve_reference, AddressingValues, ReferenceType, ThreadPanicReason},
+};
+
+use super::{ExecuterPanic, ExecuterResult, StaticProgram};
+
+impl super::InstructionExecuter for A2BI {
+    fn execute(
+        &self,
+        heap_memory: &mut HeapMemory,
+        _program: StaticProgram,
+        current_stack: &mut Stack,
+        stack_memory: &mut StackMemory,
+        addressing_value: &AddressingValues,
+        _arch: PlatformArchitecture,
+    ) -> Result<super::ExecuterResult, super::ExecuterPanic> {
+        match addressing_value {
+            AddressingValues::Implicit => {
+                let source = current_stack.registers.A;
+                let cannot_convert = |type_id: u8| ExecuterPanic {
+                    reason: ThreadPanicReason::CannotConvertToType(type_id, 24),
+                    code_location: format!("{}:{}", file!(), line!()),
+                };
+                let value = match source.type_id.id {
+                    1 => BigInteger::from_i128(source.to_int() as i128),
+                    4 => BigInteger::from_i128(source.to_byte() as i128),
+                    16..=23 => BigInteger::from_i128(source.to_fixed_integer()),
+                    13 | 14 => {
+                        let reference_type = if source.type_id.is_stack_reference() {
+                            ReferenceType::Stack
+                        } else {
+                            ReferenceType::Heap
+                        };
+                        let resolved = match resolve_reference(
+                            reference_type,
+                            source.to_uint(),
+                            heap_memory,
+                            stack_memory,
+                        ) {
+                            Ok(e) => e,
+                            Err(e) => {
+                                return Err(ExecuterPanic {
+                                    reason: ThreadPanicReason::ReferenceError(e),
+                                    code_location: format!("{}:{}", file!(), line!()),
+                                });
+                            }
+                        };
+                        match resolved.as_raw_type() {
+                            Some(raw_type) if raw_type.is_big_integer() => {
+                                raw_type.to_big_integer()
+                            }
+                            Some(raw_type) if raw_type.is_string() => {
+                                match BigInteger::parse(raw_type.to_string().trim()) {
+                                    Some(e) => e,
+                                    None => return Err(cannot_convert(raw_type.type_id.id)),
+                                }
+                            }
+                            _ => return Err(cannot_convert(resolved.type_id().id)),
+                        }
+                    }
+                    e => return Err(cannot_convert(e)),
+                };
+                current_stack.registers.A =
+                    super::store_big_integer(heap_memory, current_stack.get_pos(), Some(value))?;
+                Ok(ExecuterResult::Continue)
+            }
+            _ => Err(ExecuterPanic {
+                reason: ThreadPanicReason::IllegalAddressingValue,
+                code_location: format!("{}:{}", file!(), line!()),
+            }),
+        }
+    }
+}
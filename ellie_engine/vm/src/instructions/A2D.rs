@@ -33,6 +33,10 @@ impl super::InstructionExecuter for A2D {
                         let data = current_stack.registers.A.to_float();
                         current_stack.registers.A = StaticRawType::from_double(data as f64);
                     }
+                    16..=23 => {
+                        let data = current_stack.registers.A.to_fixed_integer();
+                        current_stack.registers.A = StaticRawType::from_double(data as f64);
+                    }
                     3 => (),
                     4 => {
                         let data = current_stack.registers.A.to_byte();
@@ -29,6 +29,10 @@ impl super::InstructionExecuter for A2F {
                         let data = current_stack.registers.A.to_int();
                         current_stack.registers.A = StaticRawType::from_float(data as f32);
                     }
+                    16..=23 => {
+                        let data = current_stack.registers.A.to_fixed_integer();
+                        current_stack.registers.A = StaticRawType::from_float(data as f32);
+                    }
                     2 => (),
                     3 => {
                         let data = current_stack.registers.A.to_byte();
@@ -1,4 +1,4 @@
-use alloc::{format, string::String};
+use alloc::format;
 use ellie_core::defs::PlatformArchitecture;
 
 use crate::{
@@ -7,7 +7,7 @@ use crate::{
     raw_type::StaticRawType,
     stack::Stack,
     stack_memory::StackMemory,
-    utils::{AddressingValues, ThreadPanicReason},
+    utils::{resolve_reference, AddressingValues, ReferenceType, ThreadPanicReason},
 };
 
 use super::{ExecuterPanic, ExecuterResult, StaticProgram};
@@ -18,7 +18,7 @@ impl super::InstructionExecuter for A2I {
         heap_memory: &mut HeapMemory,
         _program: StaticProgram,
         current_stack: &mut Stack,
-        _stack_memory: &mut StackMemory,
+        stack_memory: &mut StackMemory,
         addressing_value: &AddressingValues,
         _arch: PlatformArchitecture,
     ) -> Result<super::ExecuterResult, super::ExecuterPanic> {
@@ -43,26 +43,67 @@ impl super::InstructionExecuter for A2I {
                         current_stack.registers.A =
                             StaticRawType::from_int(if data { 1_isize } else { 0_isize });
                     }
-                    13 => {
-                        let pointer = current_stack.registers.B.to_int() as usize;
-                        let mref = match heap_memory.get(&pointer) {
-                            Some(e) => e.clone(),
-                            None => {
+                    16..=23 => {
+                        match isize::try_from(current_stack.registers.A.to_fixed_integer()) {
+                            Ok(data) => current_stack.registers.A = StaticRawType::from_int(data),
+                            Err(_) => {
                                 return Err(ExecuterPanic {
-                                    reason: ThreadPanicReason::NullReference(pointer),
+                                    reason: ThreadPanicReason::IntegerOverflow,
                                     code_location: format!("{}:{}", file!(), line!()),
-                                })
+                                });
+                            }
+                        }
+                    }
+                    13 | 14 => {
+                        let reference_type =
+                            if current_stack.registers.A.type_id.is_stack_reference() {
+                                ReferenceType::Stack
+                            } else {
+                                ReferenceType::Heap
+                            };
+                        let resolved = match resolve_reference(
+                            reference_type,
+                            current_stack.registers.A.to_uint(),
+                            heap_memory,
+                            stack_memory,
+                        ) {
+                            Ok(e) => e,
+                            Err(e) => {
+                                return Err(ExecuterPanic {
+                                    reason: ThreadPanicReason::ReferenceError(e),
+                                    code_location: format!("{}:{}", file!(), line!()),
+                                });
                             }
                         };
-                        match mref.type_id.id {
-                            6 => {
-                                let a_value = String::from_utf8(mref.data).unwrap();
-                                let integer_value = a_value.parse::<isize>().unwrap();
-                                current_stack.registers.A = StaticRawType::from_int(integer_value);
+                        let data = match resolved.as_raw_type() {
+                            Some(raw_type) if raw_type.is_string() => {
+                                raw_type.to_string().trim().parse::<isize>().ok()
+                            }
+                            Some(raw_type) if raw_type.is_big_integer() => {
+                                match raw_type
+                                    .to_big_integer()
+                                    .to_i128()
+                                    .and_then(|e| isize::try_from(e).ok())
+                                {
+                                    Some(e) => Some(e),
+                                    None => {
+                                        return Err(ExecuterPanic {
+                                            reason: ThreadPanicReason::IntegerOverflow,
+                                            code_location: format!("{}:{}", file!(), line!()),
+                                        });
+                                    }
+                                }
                             }
-                            e => {
+                            _ => None,
+                        };
+                        match data {
+                            Some(data) => current_stack.registers.A = StaticRawType::from_int(data),
+                            None => {
                                 return Err(ExecuterPanic {
-                                    reason: ThreadPanicReason::CannotConvertToType(e, 3),
+                                    reason: ThreadPanicReason::CannotConvertToType(
+                                        resolved.type_id().id,
+                                        1,
+                                    ),
                                     code_location: format!("{}:{}", file!(), line!()),
                                 });
                             }
@@ -0,0 +1,104 @@
+use alloc::format;
+use ellie_core::defs::PlatformArchitecture;
+
+use crate::{
+    heap_memory::HeapMemory,
+    instruction_utils::A2N,
+    raw_type::{StaticRawType, TypeId},
+    stack::Stack,
+    stack_memory::StackMemory,
+    utils::{resolve_reference, AddressingValues, ReferenceType, ThreadPanicReason},
+};
+
+use super::{ExecuterPanic, ExecuterResult, StaticProgram};
+
+impl super::InstructionExecuter for A2N {
+    fn execute(
+        &self,
+        heap_memory: &mut HeapMemory,
+        _program: StaticProgram,
+        current_stack: &mut Stack,
+        stack_memory: &mut StackMemory,
+        addressing_value: &AddressingValues,
+        _arch: PlatformArchitecture,
+    ) -> Result<super::ExecuterResult, super::ExecuterPanic> {
+        match addressing_value {
+            AddressingValues::Immediate(target) => {
+                let source = current_stack.registers.A;
+                let target_id = target.to_int() as u8;
+                let cannot_convert = || ExecuterPanic {
+                    reason: ThreadPanicReason::CannotConvertToType(source.type_id.id, target_id),
+                    code_location: format!("{}:{}", file!(), line!()),
+                };
+                let size = match TypeId::from(target_id, 0).fixed_integer_size() {
+                    Some(size) => size,
+                    None => return Err(cannot_convert()),
+                };
+                let value = match source.type_id.id {
+                    1 => Some(source.to_int() as i128),
+                    // Decimals are truncated towards zero, values out of range overflow
+                    2 => {
+                        let data = source.to_float();
+                        data.is_finite().then_some(data as i128)
+                    }
+                    3 => {
+                        let data = source.to_double();
+                        data.is_finite().then_some(data as i128)
+                    }
+                    4 => Some(source.to_byte() as i128),
+                    5 => Some(source.to_bool() as i128),
+                    16..=23 => Some(source.to_fixed_integer()),
+                    13 | 14 => {
+                        let reference_type = if source.type_id.is_stack_reference() {
+                            ReferenceType::Stack
+                        } else {
+                            ReferenceType::Heap
+                        };
+                        let resolved = match resolve_reference(
+                            reference_type,
+                            source.to_int() as usize,
+                            heap_memory,
+                            stack_memory,
+                        ) {
+                            Ok(e) => e,
+                            Err(e) => {
+                                return Err(ExecuterPanic {
+                                    reason: ThreadPanicReason::ReferenceError(e),
+                                    code_location: format!("{}:{}", file!(), line!()),
+                                });
+                            }
+                        };
+                        match resolved.as_raw_type() {
+                            Some(string) if string.type_id.is_string() => {
+                                match string.to_string().trim().parse::<i128>() {
+                                    Ok(e) => Some(e),
+                                    Err(_) => return Err(cannot_convert()),
+                                }
+                            }
+                            _ => return Err(cannot_convert()),
+                        }
+                    }
+                    _ => return Err(cannot_convert()),
+                };
+                match value {
+                    Some(value) if size.fits(value) => {
+                        current_stack.registers.A = StaticRawType::from_fixed_integer(size, value);
+                        Ok(ExecuterResult::Continue)
+                    }
+                    _ => Err(ExecuterPanic {
+                        reason: if size.byte_size() == 1 {
+                            ThreadPanicReason::ByteOverflow
+                        } else {
+                            ThreadPanicReason::IntegerOverflow
+                        },
+                        code_location: format!("{}:{}", file!(), line!()),
+                    }),
+                }
+            }
+            _ => Err(ExecuterPanic {
+                reason: ThreadPanicReason::IllegalAddressingValue,
+                code_location: format!("{}:{}", file!(), line!()),
+            }),
+        }
+    }
+}
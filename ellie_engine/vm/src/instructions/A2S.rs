@@ -18,7 +18,7 @@ impl super::InstructionExecuter for A2S {
         heap_memory: &mut HeapMemory,
         _program: StaticProgram,
         current_stack: &mut Stack,
-        _stack_memory: &mut StackMemory,
+        stack_memory: &mut StackMemory,
         addressing_value: &AddressingValues,
         _arch: PlatformArchitecture,
     ) -> Result<ExecuterResult, ExecuterPanic> {
@@ -49,12 +49,44 @@ impl super::InstructionExecuter for A2S {
                         current_stack.registers.A =
                             StaticRawType::from_heap_reference(current_stack.get_pos());
                     }
+                    16..=23 => {
+                        let data = current_stack.registers.A.to_fixed_integer().to_string();
+                        heap_memory.set(&current_stack.get_pos(), RawType::generate_string(data));
+                        current_stack.registers.A =
+                            StaticRawType::from_heap_reference(current_stack.get_pos());
+                    }
                     7 => {
                         let data = current_stack.registers.A.to_char().to_string();
                         heap_memory.set(&current_stack.get_pos(), RawType::generate_string(data));
                         current_stack.registers.A =
                             StaticRawType::from_heap_reference(current_stack.get_pos());
                     }
+                    13 | 14 => {
+                        let big_integer = super::resolve_big_integer(
+                            &current_stack.registers.A,
+                            heap_memory,
+                            stack_memory,
+                        );
+                        match big_integer {
+                            Some(big_integer) => {
+                                heap_memory.set(
+                                    &current_stack.get_pos(),
+                                    RawType::generate_string(big_integer.to_string()),
+                                );
+                                current_stack.registers.A =
+                                    StaticRawType::from_heap_reference(current_stack.get_pos());
+                            }
+                            None => {
+                                return Err(ExecuterPanic {
+                                    reason: ThreadPanicReason::CannotConvertToType(
+                                        current_stack.registers.A.type_id.id,
+                                        7,
+                                    ),
+                                    code_location: format!("{}:{}", file!(), line!()),
+                                });
+                            }
+                        }
+                    }
                     e => {
                         return Err(ExecuterPanic {
                             reason: ThreadPanicReason::CannotConvertToType(e, 7),
@@ -26,6 +26,20 @@ impl super::InstructionExecuter for ADD {
     ) -> Result<super::ExecuterResult, super::ExecuterPanic> {
         match &addressing_value {
             AddressingValues::Implicit => {
+                // Big integers live in heap, operands are references to them
+                if let Some((b_value, c_value)) = super::big_integer_operands(
+                    &current_stack.registers.B,
+                    &current_stack.registers.C,
+                    heap_memory,
+                    stack_memory,
+                ) {
+                    current_stack.registers.A = super::store_big_integer(
+                        heap_memory,
+                        current_stack.get_pos(),
+                        b_value.checked_add(&c_value),
+                    )?;
+                    return Ok(ExecuterResult::Continue);
+                }
                 let B = if current_stack.registers.B.type_id.is_stack_reference()
                     || current_stack.registers.B.type_id.is_heap_reference()
                 {
@@ -176,6 +190,39 @@ impl super::InstructionExecuter for ADD {
                         current_stack.registers.A =
                             StaticRawType::from_heap_reference(current_stack.get_pos());
                     }
+                    (6, 16..=23) => {
+                        let mut b_value = String::new();
+                        for i in B.data().unwrap().chunks(4) {
+                            let char = u32::from_le_bytes(i.try_into().unwrap());
+                            b_value.push(char::from_u32(char).unwrap());
+                        }
+                        let c_value = C.as_static_raw_type().unwrap().to_fixed_integer();
+                        let result = format!("{}{}", &b_value, &c_value);
+                        heap_memory
+                            .set(&(current_stack.get_pos()), RawType::generate_string(result));
+                        current_stack.registers.A =
+                            StaticRawType::from_heap_reference(current_stack.get_pos());
+                    }
+                    (16..=23, _) => {
+                        current_stack.registers.A = super::fixed_integer_arithmetic(
+                            &current_stack.registers.B,
+                            &current_stack.registers.C,
+                            i128::checked_add,
+                        )?;
+                    }
+                    (6, 24) => {
+                        let mut b_value = String::new();
+                        for i in B.data().unwrap().chunks(4) {
+                            let char = u32::from_le_bytes(i.try_into().unwrap());
+                            b_value.push(char::from_u32(char).unwrap());
+                        }
+                        let c_value = C.as_raw_type().unwrap().to_big_integer();
+                        let result = format!("{}{}", &b_value, &c_value);
+                        heap_memory
+                            .set(&(current_stack.get_pos()), RawType::generate_string(result));
+                        current_stack.registers.A =
+                            StaticRawType::from_heap_reference(current_stack.get_pos());
+                    }
                     (6, 7) => {
                         let mut b_value = String::new();
                         for i in B.data().unwrap().chunks(4) {
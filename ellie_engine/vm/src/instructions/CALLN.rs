@@ -57,7 +57,7 @@ impl super::InstructionExecuter for CALLN {
                 let _start_position_of_params = current_stack.get_pos() - 2;
 
                 for i in 0..params_length {
-                    let pos = current_stack.get_pos() - (params_length - (-(i as isize) as usize));
+                    let pos = current_stack.get_pos() - params_length + i;
                     let paramater = match stack_memory.get(&pos) {
                         Some(raw_type) => {
                             if raw_type.type_id.is_stack_reference()
@@ -95,9 +95,33 @@ impl super::InstructionExecuter for CALLN {
                     };
                     params.push(paramater);
                 }
+
+                //FN copies heap values to the callee frame, read the parameters
+                //from caller's frame so the references passed are preserved
+                let mut raw_params = Vec::new();
+                if params_length > 0 {
+                    let caller_frame_pos = match current_stack.caller {
+                        Some(caller) => caller.frame_pos,
+                        None => 0,
+                    };
+                    let index_start =
+                        current_stack.registers.X.to_int() as usize + caller_frame_pos;
+                    for i in 0..params_length {
+                        match stack_memory.get(&(index_start + i)) {
+                            Some(raw_type) => raw_params.push(raw_type),
+                            None => {
+                                return Err(ExecuterPanic {
+                                    reason: ThreadPanicReason::NullReference(index_start + i),
+                                    code_location: format!("{}:{}", file!(), line!()),
+                                })
+                            }
+                        }
+                    }
+                }
                 Ok(ExecuterResult::CallNativeFunction(VmNativeCall {
                     hash,
                     params,
+                    raw_params,
                     return_heap_position,
                 }))
             }
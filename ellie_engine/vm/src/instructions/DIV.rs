@@ -15,15 +15,29 @@ use super::{ExecuterPanic, ExecuterResult, StaticProgram};
 impl super::InstructionExecuter for DIV {
     fn execute(
         &self,
-        _heap_memory: &mut HeapMemory,
+        heap_memory: &mut HeapMemory,
         _program: StaticProgram,
         current_stack: &mut Stack,
-        _stack_memory: &mut StackMemory,
+        stack_memory: &mut StackMemory,
         addressing_value: &AddressingValues,
         _arch: PlatformArchitecture,
     ) -> Result<ExecuterResult, ExecuterPanic> {
         match &addressing_value {
             AddressingValues::Implicit => {
+                // Big integers live in heap, operands are references to them
+                if let Some((b_value, c_value)) = super::big_integer_operands(
+                    &current_stack.registers.B,
+                    &current_stack.registers.C,
+                    heap_memory,
+                    stack_memory,
+                ) {
+                    current_stack.registers.A = super::store_big_integer(
+                        heap_memory,
+                        current_stack.get_pos(),
+                        b_value.checked_div(&c_value),
+                    )?;
+                    return Ok(ExecuterResult::Continue);
+                }
                 match (
                     current_stack.registers.B.type_id.id,
                     current_stack.registers.C.type_id.id,
@@ -82,6 +96,13 @@ impl super::InstructionExecuter for DIV {
                             });
                         }
                     }
+                    (16..=23, _) => {
+                        current_stack.registers.A = super::fixed_integer_arithmetic(
+                            &current_stack.registers.B,
+                            &current_stack.registers.C,
+                            i128::checked_div,
+                        )?;
+                    }
                     _ => {
                         return Err(ExecuterPanic {
                             reason: ThreadPanicReason::UnmergebleTypes(
@@ -15,15 +15,25 @@ use super::{ExecuterPanic, ExecuterResult, StaticProgram};
 impl super::InstructionExecuter for GT {
     fn execute(
         &self,
-        _heap_memory: &mut HeapMemory,
+        heap_memory: &mut HeapMemory,
         _program: StaticProgram,
         current_stack: &mut Stack,
-        _stack_memory: &mut StackMemory,
+        stack_memory: &mut StackMemory,
         addressing_value: &AddressingValues,
         _arch: PlatformArchitecture,
     ) -> Result<super::ExecuterResult, super::ExecuterPanic> {
         match &addressing_value {
             AddressingValues::Implicit => {
+                // Big integers live in heap, operands are references to them
+                if let Some((b_value, c_value)) = super::big_integer_comparison_operands(
+                    &current_stack.registers.B,
+                    &current_stack.registers.C,
+                    heap_memory,
+                    stack_memory,
+                ) {
+                    current_stack.registers.A = StaticRawType::from_bool(b_value > c_value);
+                    return Ok(ExecuterResult::Continue);
+                }
                 match (
                     current_stack.registers.B.type_id.id,
                     current_stack.registers.C.type_id.id,
@@ -53,6 +63,17 @@ impl super::InstructionExecuter for GT {
                         let c_value = current_stack.registers.C.to_byte();
                         current_stack.registers.A = StaticRawType::from_bool(b_value > c_value);
                     }
+                    // Fixed width integers compare with same width or int
+                    (16..=23, _) | (_, 16..=23)
+                        if current_stack.registers.B.type_id
+                            == current_stack.registers.C.type_id
+                            || current_stack.registers.B.type_id.is_int()
+                            || current_stack.registers.C.type_id.is_int() =>
+                    {
+                        let b_value = super::wide_integer(&current_stack.registers.B);
+                        let c_value = super::wide_integer(&current_stack.registers.C);
+                        current_stack.registers.A = StaticRawType::from_bool(b_value > c_value);
+                    }
                     _ => {
                         return Err(ExecuterPanic {
                             reason: ThreadPanicReason::UncomparableTypes(
@@ -0,0 +1,57 @@
+use crate::{
+    heap_memory::HeapMemory,
+    instruction_utils::JMPT,
+    stack::Stack,
+    stack_memory::StackMemory,
+    utils::{AddressingValues, ThreadPanicReason},
+};
+use alloc::format;
+use ellie_core::defs::PlatformArchitecture;
+
+use super::{ExecuterPanic, ExecuterResult, StaticProgram};
+
+/// Jump table, immediate is the number of `JMP` entries following the instruction. Entry
+/// `A - B` is taken, values out of the table continue after the last entry
+impl super::InstructionExecuter for JMPT {
+    fn execute(
+        &self,
+        _heap_memory: &mut HeapMemory,
+        _program: StaticProgram,
+        current_stack: &mut Stack,
+        _stack_memory: &mut StackMemory,
+        addressing_value: &AddressingValues,
+        _arch: PlatformArchitecture,
+    ) -> Result<ExecuterResult, ExecuterPanic> {
+        match addressing_value {
+            AddressingValues::Immediate(count) if count.type_id.is_int() => {
+                let registers = &current_stack.registers;
+                if !registers.A.type_id.is_int() || !registers.B.type_id.is_int() {
+                    let type_id = if registers.A.type_id.is_int() {
+                        registers.B.type_id.id
+                    } else {
+                        registers.A.type_id.id
+                    };
+                    return Err(ExecuterPanic {
+                        reason: ThreadPanicReason::UnexpectedType(type_id),
+                        code_location: format!("{}:{}", file!(), line!()),
+                    });
+                }
+                let count = count.to_int() as usize;
+                let entry = registers
+                    .A
+                    .to_int()
+                    .checked_sub(registers.B.to_int())
+                    .and_then(|entry| usize::try_from(entry).ok())
+                    .filter(|entry| *entry < count)
+                    .unwrap_or(count);
+                // Thread moves to next instruction after executing this one
+                current_stack.pos += entry;
+                Ok(ExecuterResult::Continue)
+            }
+            _ => Err(ExecuterPanic {
+                reason: ThreadPanicReason::IllegalAddressingValue,
+                code_location: format!("{}:{}", file!(), line!()),
+            }),
+        }
+    }
+}
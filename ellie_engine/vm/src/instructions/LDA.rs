@@ -117,6 +117,26 @@ impl super::InstructionExecuter for LDA {
                                                 StaticRawType::from_bytes(array_entry);
                                             return Ok(ExecuterResult::Continue);
                                         }
+                                    } else if heap_data.type_id.is_string() {
+                                        let string_chars =
+                                            heap_data.data.chunks(4).collect::<Vec<_>>();
+                                        if index >= string_chars.len() {
+                                            return Err(ExecuterPanic {
+                                                reason: ThreadPanicReason::IndexOutOfBounds(
+                                                    index,
+                                                    string_chars.len(),
+                                                ),
+                                                code_location: format!("{}:{}", file!(), line!()),
+                                            });
+                                        } else {
+                                            let char = char::from_u32(u32::from_le_bytes(
+                                                string_chars[index].try_into().unwrap(),
+                                            ))
+                                            .unwrap_or(char::REPLACEMENT_CHARACTER);
+                                            current_stack.registers.A =
+                                                StaticRawType::from_char(char);
+                                            return Ok(ExecuterResult::Continue);
+                                        }
                                     } else {
                                         return Err(ExecuterPanic {
                                             reason: ThreadPanicReason::UnexpectedType(
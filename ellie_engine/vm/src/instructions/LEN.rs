@@ -1,22 +1,97 @@
+use alloc::format;
 use ellie_core::defs::PlatformArchitecture;
 
 use crate::{
-    heap_memory::HeapMemory, instruction_utils::LEN, stack::Stack, stack_memory::StackMemory,
-    utils::AddressingValues,
+    heap_memory::HeapMemory,
+    instruction_utils::LEN,
+    raw_type::StaticRawType,
+    stack::Stack,
+    stack_memory::StackMemory,
+    utils::{AddressingValues, ThreadPanicReason},
 };
 
-use super::StaticProgram;
+use super::{ExecuterPanic, ExecuterResult, StaticProgram};
 
 impl super::InstructionExecuter for LEN {
     fn execute(
         &self,
-        _heap_memory: &mut HeapMemory,
+        heap_memory: &mut HeapMemory,
         _program: StaticProgram,
-        _current_stack: &mut Stack,
-        _stack_memory: &mut StackMemory,
-        _addressing_value: &AddressingValues,
-        _arch: PlatformArchitecture,
-    ) -> Result<super::ExecuterResult, super::ExecuterPanic> {
-        panic!("This instruction will be removed")
+        current_stack: &mut Stack,
+        stack_memory: &mut StackMemory,
+        addressing_value: &AddressingValues,
+        arch: PlatformArchitecture,
+    ) -> Result<ExecuterResult, ExecuterPanic> {
+        match addressing_value {
+            AddressingValues::Absolute(e) => {
+                let length = match stack_memory.get(&current_stack.calculate_frame_pos(*e)) {
+                    Some(stack_data) => {
+                        if stack_data.type_id.is_static_array() {
+                            match stack_memory.get(&(stack_data.to_uint() + 1)) {
+                                Some(array_size) => array_size.to_uint(),
+                                None => {
+                                    return Err(ExecuterPanic {
+                                        reason: ThreadPanicReason::NullReference(
+                                            stack_data.to_uint(),
+                                        ),
+                                        code_location: format!("{}:{}", file!(), line!()),
+                                    });
+                                }
+                            }
+                        } else if stack_data.type_id.is_heap_reference() {
+                            match heap_memory.get(&stack_data.to_uint()) {
+                                Some(heap_data) => {
+                                    if heap_data.type_id.is_string() {
+                                        heap_data.data.len() / 4
+                                    } else if heap_data.type_id.is_array() {
+                                        let array_entry_size = usize::from_le_bytes(
+                                            heap_data.data[..arch.usize_len() as usize]
+                                                .try_into()
+                                                .unwrap(),
+                                        );
+                                        (heap_data.data.len() - arch.usize_len() as usize)
+                                            / array_entry_size
+                                    } else {
+                                        return Err(ExecuterPanic {
+                                            reason: ThreadPanicReason::UnexpectedType(
+                                                heap_data.type_id.id,
+                                            ),
+                                            code_location: format!("{}:{}", file!(), line!()),
+                                        });
+                                    }
+                                }
+                                None => {
+                                    return Err(ExecuterPanic {
+                                        reason: ThreadPanicReason::NullReference(
+                                            stack_data.to_uint(),
+                                        ),
+                                        code_location: format!("{}:{}", file!(), line!()),
+                                    });
+                                }
+                            }
+                        } else {
+                            return Err(ExecuterPanic {
+                                reason: ThreadPanicReason::UnexpectedType(stack_data.type_id.id),
+                                code_location: format!("{}:{}", file!(), line!()),
+                            });
+                        }
+                    }
+                    None => {
+                        return Err(ExecuterPanic {
+                            reason: ThreadPanicReason::MemoryAccessViolation(*e, current_stack.pos),
+                            code_location: format!("{}:{}", file!(), line!()),
+                        });
+                    }
+                };
+                current_stack.registers.A = StaticRawType::from_int(length as isize);
+            }
+            _ => {
+                return Err(ExecuterPanic {
+                    reason: ThreadPanicReason::IllegalAddressingValue,
+                    code_location: format!("{}:{}", file!(), line!()),
+                })
+            }
+        }
+        Ok(ExecuterResult::Continue)
     }
 }
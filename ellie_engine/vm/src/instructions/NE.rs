@@ -18,12 +18,22 @@ impl super::InstructionExecuter for NE {
         heap_memory: &mut HeapMemory,
         _program: StaticProgram,
         current_stack: &mut Stack,
-        _stack_memory: &mut StackMemory,
+        stack_memory: &mut StackMemory,
         addressing_value: &AddressingValues,
         _arch: PlatformArchitecture,
     ) -> Result<super::ExecuterResult, super::ExecuterPanic> {
         match &addressing_value {
             AddressingValues::Implicit => {
+                // Big integers live in heap, operands are references to them
+                if let Some((b_value, c_value)) = super::big_integer_comparison_operands(
+                    &current_stack.registers.B,
+                    &current_stack.registers.C,
+                    heap_memory,
+                    stack_memory,
+                ) {
+                    current_stack.registers.A = StaticRawType::from_bool(b_value != c_value);
+                    return Ok(ExecuterResult::Continue);
+                }
                 match (
                     current_stack.registers.B.type_id.id,
                     current_stack.registers.C.type_id.id,
@@ -63,7 +73,14 @@ impl super::InstructionExecuter for NE {
                         let c_value = current_stack.registers.C.to_char();
                         current_stack.registers.A = StaticRawType::from_bool(b_value != c_value);
                     }
-                    (13, 13) => {
+                    //Null only equals to null
+                    (10, 10) => {
+                        current_stack.registers.A = StaticRawType::from_bool(false);
+                    }
+                    (10, _) | (_, 10) => {
+                        current_stack.registers.A = StaticRawType::from_bool(true);
+                    }
+                    (13, 13) | (14, 14) => {
                         let b_pointer = current_stack.registers.B.to_int() as usize;
                         let b_ref = match heap_memory.get(&b_pointer) {
                             Some(e) => e.clone(),
@@ -99,6 +116,17 @@ impl super::InstructionExecuter for NE {
                             }
                         }
                     }
+                    // Fixed width integers compare with same width or int
+                    (16..=23, _) | (_, 16..=23)
+                        if current_stack.registers.B.type_id
+                            == current_stack.registers.C.type_id
+                            || current_stack.registers.B.type_id.is_int()
+                            || current_stack.registers.C.type_id.is_int() =>
+                    {
+                        let b_value = super::wide_integer(&current_stack.registers.B);
+                        let c_value = super::wide_integer(&current_stack.registers.C);
+                        current_stack.registers.A = StaticRawType::from_bool(b_value != c_value);
+                    }
                     _ => {
                         return Err(ExecuterPanic {
                             reason: ThreadPanicReason::UncomparableTypes(
@@ -1,10 +1,12 @@
 #![allow(non_snake_case)]
 
 mod A2B;
+mod A2BI;
 mod A2C;
 mod A2D;
 mod A2F;
 mod A2I;
+mod A2N;
 mod A2O;
 mod A2S;
 mod ADD;
@@ -23,6 +25,7 @@ mod GQ;
 mod GT;
 mod JMP;
 mod JMPA;
+mod JMPT;
 mod LDA;
 mod LDB;
 mod LDC;
@@ -48,16 +51,21 @@ mod STY;
 mod SUB;
 mod _MOD;
 
-use alloc::string::String;
+use alloc::{format, string::String};
 use ellie_core::defs::PlatformArchitecture;
 
 use crate::{
+    big_integer::BigInteger,
     config::PROGRAM_MAX_SIZE,
     heap_memory::HeapMemory,
     program::ReadInstruction,
+    raw_type::{RawType, StaticRawType},
     stack::Stack,
     stack_memory::StackMemory,
-    utils::{AddressingValues, ThreadPanicReason, VmNativeCall},
+    utils::{
+        resolve_reference, AddressingValues, ReferenceType, ResolvedReference, ThreadPanicReason,
+        VmNativeCall,
+    },
 };
 
 #[derive(Debug, Clone)]
@@ -99,3 +107,116 @@ pub trait InstructionExecuter {
         arch: PlatformArchitecture,
     ) -> Result<ExecuterResult, ExecuterPanic>;
 }
+
+/// Value of an `int` or fixed width integer operand, [`None`] for other types
+pub fn wide_integer(value: &StaticRawType) -> Option<i128> {
+    if value.type_id.is_int() {
+        Some(value.to_int() as i128)
+    } else if value.type_id.is_fixed_integer() {
+        Some(value.to_fixed_integer())
+    } else {
+        None
+    }
+}
+
+/// Arithmetic over a fixed width integer and an integer of same width or `int`.
+/// Result has the width of `first`, results out of its range panic with
+/// [`ThreadPanicReason::ByteOverflow`] for 8 bit widths and [`ThreadPanicReason::IntegerOverflow`] for others
+pub fn fixed_integer_arithmetic(
+    first: &StaticRawType,
+    second: &StaticRawType,
+    operation: fn(i128, i128) -> Option<i128>,
+) -> Result<StaticRawType, ExecuterPanic> {
+    let size = match first.type_id.fixed_integer_size() {
+        Some(size) if second.type_id == first.type_id || second.type_id.is_int() => size,
+        _ => {
+            return Err(ExecuterPanic {
+                reason: ThreadPanicReason::UnmergebleTypes(first.type_id.id, second.type_id.id),
+                code_location: format!("{}:{}", file!(), line!()),
+            })
+        }
+    };
+    match operation(
+        first.to_fixed_integer(),
+        wide_integer(second).unwrap_or_default(),
+    ) {
+        Some(result) if size.fits(result) => Ok(StaticRawType::from_fixed_integer(size, result)),
+        _ => Err(ExecuterPanic {
+            reason: if size.byte_size() == 1 {
+                ThreadPanicReason::ByteOverflow
+            } else {
+                ThreadPanicReason::IntegerOverflow
+            },
+            code_location: format!("{}:{}", file!(), line!()),
+        }),
+    }
+}
+
+/// Value of a `bigint` behind a stack or heap reference, [`None`] for other types
+pub fn resolve_big_integer(
+    value: &StaticRawType,
+    heap_memory: &HeapMemory,
+    stack_memory: &StackMemory,
+) -> Option<BigInteger> {
+    let reference_type = if value.type_id.is_stack_reference() {
+        ReferenceType::Stack
+    } else if value.type_id.is_heap_reference() {
+        ReferenceType::Heap
+    } else {
+        return None;
+    };
+    match resolve_reference(reference_type, value.to_uint(), heap_memory, stack_memory) {
+        Ok(ResolvedReference::RawType((raw_type, _))) if raw_type.is_big_integer() => {
+            Some(raw_type.to_big_integer())
+        }
+        _ => None,
+    }
+}
+
+/// Operands of an arithmetic where `first` is a `bigint` and `second` is a `bigint` or `int`
+pub fn big_integer_operands(
+    first: &StaticRawType,
+    second: &StaticRawType,
+    heap_memory: &HeapMemory,
+    stack_memory: &StackMemory,
+) -> Option<(BigInteger, BigInteger)> {
+    let first = resolve_big_integer(first, heap_memory, stack_memory)?;
+    let second = if second.type_id.is_int() {
+        BigInteger::from_i128(second.to_int() as i128)
+    } else {
+        resolve_big_integer(second, heap_memory, stack_memory)?
+    };
+    Some((first, second))
+}
+
+/// Operands of a comparison where either side is a `bigint` and the other is a `bigint` or `int`
+pub fn big_integer_comparison_operands(
+    first: &StaticRawType,
+    second: &StaticRawType,
+    heap_memory: &HeapMemory,
+    stack_memory: &StackMemory,
+) -> Option<(BigInteger, BigInteger)> {
+    big_integer_operands(first, second, heap_memory, stack_memory).or_else(|| {
+        big_integer_operands(second, first, heap_memory, stack_memory)
+            .map(|(second, first)| (first, second))
+    })
+}
+
+/// Stores a `bigint` result to heap at `location` and returns its reference.
+/// Operations without a result (division by zero, negative exponent) panic with [`ThreadPanicReason::IntegerOverflow`]
+pub fn store_big_integer(
+    heap_memory: &mut HeapMemory,
+    location: usize,
+    result: Option<BigInteger>,
+) -> Result<StaticRawType, ExecuterPanic> {
+    match result {
+        Some(result) => {
+            heap_memory.set(&location, RawType::generate_big_integer(&result));
+            Ok(StaticRawType::from_heap_reference(location))
+        }
+        None => Err(ExecuterPanic {
+            reason: ThreadPanicReason::IntegerOverflow,
+            code_location: format!("{}:{}", file!(), line!()),
+        }),
+    }
+}
@@ -0,0 +1,389 @@
+use alloc::{format, string::ToString, vec::Vec};
+use ellie_core::defs::PlatformArchitecture;
+
+use crate::{
+    raw_type::{RawType, StaticRawType, TypeId},
+    thread::Isolate,
+    utils::{
+        resolve_reference, ReferenceType, ResolvedReference, ThreadInfo, ThreadPanicReason,
+        VmNativeAnswer, VmNativeCallParameters,
+    },
+};
+
+use super::InternalFunction;
+
+/// Where the array lives, static arrays are fixed size arrays stored in stack memory,
+/// heap arrays are dynamic arrays `[entry_size][entries..]` stored in heap memory
+#[derive(Clone, Copy)]
+enum ArrayLocation {
+    Static(usize),
+    Heap(usize),
+}
+
+fn resolve_parameter(
+    isolate: &Isolate,
+    parameter: &VmNativeCallParameters,
+) -> Result<ResolvedReference, VmNativeAnswer> {
+    let raw = match parameter {
+        VmNativeCallParameters::Static(raw) => *raw,
+        VmNativeCallParameters::Dynamic(_) => {
+            return Err(VmNativeAnswer::RuntimeError(
+                "Signature mismatch expected 'static' argument".to_string(),
+            ))
+        }
+    };
+    if raw.type_id.is_stack_reference() || raw.type_id.is_heap_reference() {
+        resolve_reference(
+            if raw.type_id.is_stack_reference() {
+                ReferenceType::Stack
+            } else {
+                ReferenceType::Heap
+            },
+            raw.to_uint(),
+            &isolate.heap_memory,
+            &isolate.stack_memory,
+        )
+        .map_err(|location| VmNativeAnswer::Panic(ThreadPanicReason::NullReference(location)))
+    } else {
+        Ok(ResolvedReference::StaticRawType((raw, 0)))
+    }
+}
+
+fn array_argument(
+    isolate: &Isolate,
+    parameter: &VmNativeCallParameters,
+) -> Result<ArrayLocation, VmNativeAnswer> {
+    match resolve_parameter(isolate, parameter)? {
+        ResolvedReference::StaticRawType((raw, _)) if raw.type_id.is_static_array() => {
+            Ok(ArrayLocation::Static(raw.to_uint()))
+        }
+        ResolvedReference::RawType((raw, location)) if raw.type_id.is_array() => {
+            Ok(ArrayLocation::Heap(location))
+        }
+        _ => Err(VmNativeAnswer::RuntimeError(
+            "Signature mismatch expected an array".to_string(),
+        )),
+    }
+}
+
+fn index_argument(
+    isolate: &Isolate,
+    parameter: &VmNativeCallParameters,
+) -> Result<usize, VmNativeAnswer> {
+    match resolve_parameter(isolate, parameter)? {
+        ResolvedReference::StaticRawType((raw, _)) if raw.type_id.is_int() => {
+            let index = raw.to_int();
+            if index < 0 {
+                Err(VmNativeAnswer::Panic(
+                    ThreadPanicReason::CannotIndexWithNegative(index),
+                ))
+            } else {
+                Ok(index as usize)
+            }
+        }
+        _ => Err(VmNativeAnswer::RuntimeError(
+            "Signature mismatch expected an int".to_string(),
+        )),
+    }
+}
+
+/// Entries are stored as they are, heap values are stored as references to their location
+fn entry_argument(
+    isolate: &Isolate,
+    parameter: &VmNativeCallParameters,
+) -> Result<StaticRawType, VmNativeAnswer> {
+    Ok(match resolve_parameter(isolate, parameter)? {
+        ResolvedReference::StaticRawType((raw, _)) => raw,
+        ResolvedReference::RawType((_, location)) => StaticRawType::from_heap_reference(location),
+    })
+}
+
+fn read_entries(
+    isolate: &Isolate,
+    array: ArrayLocation,
+    arch: PlatformArchitecture,
+) -> Result<Vec<StaticRawType>, VmNativeAnswer> {
+    let corruption =
+        || VmNativeAnswer::RuntimeError("Memory corruption occurred (array)".to_string());
+    match array {
+        ArrayLocation::Static(location) => {
+            let length = isolate
+                .stack_memory
+                .get(&(location + 1))
+                .ok_or_else(corruption)?
+                .to_uint();
+            (0..length)
+                .map(|index| {
+                    isolate
+                        .stack_memory
+                        .get(&(location + 2 + index))
+                        .ok_or_else(corruption)
+                })
+                .collect()
+        }
+        ArrayLocation::Heap(location) => {
+            let array = isolate.heap_memory.get(&location).ok_or_else(corruption)?;
+            let platform_size = arch.usize_len() as usize;
+            // Arrays created by ARR does not have entry size yet
+            if array.data.len() <= platform_size {
+                return Ok(Vec::new());
+            }
+            let entry_size = usize::from_le_bytes(array.data[..platform_size].try_into().unwrap());
+            if entry_size == 0 {
+                return Err(VmNativeAnswer::Panic(
+                    ThreadPanicReason::ArraySizeCorruption,
+                ));
+            }
+            Ok(array.data[platform_size..]
+                .chunks(entry_size)
+                .map(StaticRawType::from_bytes)
+                .collect())
+        }
+    }
+}
+
+/// Build a dynamic array from entries
+fn heap_array(entries: &[StaticRawType], arch: PlatformArchitecture) -> RawType {
+    let entry_size = StaticRawType::from_void().to_bytes().len();
+    let mut data = entry_size.to_le_bytes()[..arch.usize_len() as usize].to_vec();
+    for entry in entries {
+        data.extend(entry.to_bytes());
+    }
+    RawType {
+        type_id: TypeId::array(entries.len()),
+        data,
+    }
+}
+
+/// Apply `modifier` to array entries and write them back. Static arrays cannot change their length
+fn modify_array<T>(
+    isolate: &mut Isolate,
+    array: ArrayLocation,
+    arch: PlatformArchitecture,
+    modifier: impl FnOnce(&mut Vec<StaticRawType>) -> Result<T, ThreadPanicReason>,
+) -> Result<T, VmNativeAnswer> {
+    let mut entries = read_entries(isolate, array, arch)?;
+    let length = entries.len();
+    let result = modifier(&mut entries).map_err(VmNativeAnswer::Panic)?;
+    match array {
+        ArrayLocation::Static(location) => {
+            if entries.len() != length {
+                return Err(VmNativeAnswer::Panic(ThreadPanicReason::WrongEntryLength(
+                    length,
+                    entries.len(),
+                )));
+            }
+            for (index, entry) in entries.into_iter().enumerate() {
+                isolate.stack_memory.set(&(location + 2 + index), entry);
+            }
+        }
+        ArrayLocation::Heap(location) => {
+            isolate
+                .heap_memory
+                .set(&location, heap_array(&entries, arch));
+        }
+    }
+    Ok(result)
+}
+
+fn expect_arguments(args: &[VmNativeCallParameters], count: usize) -> Result<(), VmNativeAnswer> {
+    if args.len() != count {
+        Err(VmNativeAnswer::RuntimeError(format!(
+            "Signature mismatch expected {} argument(s)",
+            count
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+fn answer(result: Result<VmNativeCallParameters, VmNativeAnswer>) -> VmNativeAnswer {
+    match result {
+        Ok(value) => VmNativeAnswer::Ok(value),
+        Err(answer) => answer,
+    }
+}
+
+fn push(
+    isolate: &mut Isolate,
+    arch: PlatformArchitecture,
+    args: &[VmNativeCallParameters],
+) -> Result<VmNativeCallParameters, VmNativeAnswer> {
+    expect_arguments(args, 2)?;
+    let array = array_argument(isolate, &args[0])?;
+    let item = entry_argument(isolate, &args[1])?;
+    modify_array(isolate, array, arch, |entries| {
+        entries.push(item);
+        Ok(())
+    })?;
+    Ok(VmNativeCallParameters::Static(StaticRawType::from_void()))
+}
+
+pub fn array_push_fn(
+    isolate: &mut Isolate,
+    thread_info: ThreadInfo,
+    args: Vec<VmNativeCallParameters>,
+) -> VmNativeAnswer {
+    answer(push(isolate, thread_info.arch, &args))
+}
+
+fn pop(
+    isolate: &mut Isolate,
+    arch: PlatformArchitecture,
+    args: &[VmNativeCallParameters],
+) -> Result<VmNativeCallParameters, VmNativeAnswer> {
+    expect_arguments(args, 1)?;
+    let array = array_argument(isolate, &args[0])?;
+    let item = modify_array(isolate, array, arch, |entries| {
+        entries
+            .pop()
+            .ok_or(ThreadPanicReason::IndexOutOfBounds(0, 0))
+    })?;
+    Ok(VmNativeCallParameters::Static(item))
+}
+
+pub fn array_pop_fn(
+    isolate: &mut Isolate,
+    thread_info: ThreadInfo,
+    args: Vec<VmNativeCallParameters>,
+) -> VmNativeAnswer {
+    answer(pop(isolate, thread_info.arch, &args))
+}
+
+fn insert(
+    isolate: &mut Isolate,
+    arch: PlatformArchitecture,
+    args: &[VmNativeCallParameters],
+) -> Result<VmNativeCallParameters, VmNativeAnswer> {
+    expect_arguments(args, 3)?;
+    let array = array_argument(isolate, &args[0])?;
+    let index = index_argument(isolate, &args[1])?;
+    let item = entry_argument(isolate, &args[2])?;
+    modify_array(isolate, array, arch, |entries| {
+        if index > entries.len() {
+            return Err(ThreadPanicReason::IndexOutOfBounds(index, entries.len()));
+        }
+        entries.insert(index, item);
+        Ok(())
+    })?;
+    Ok(VmNativeCallParameters::Static(StaticRawType::from_void()))
+}
+
+pub fn array_insert_fn(
+    isolate: &mut Isolate,
+    thread_info: ThreadInfo,
+    args: Vec<VmNativeCallParameters>,
+) -> VmNativeAnswer {
+    answer(insert(isolate, thread_info.arch, &args))
+}
+
+fn remove(
+    isolate: &mut Isolate,
+    arch: PlatformArchitecture,
+    args: &[VmNativeCallParameters],
+) -> Result<VmNativeCallParameters, VmNativeAnswer> {
+    expect_arguments(args, 2)?;
+    let array = array_argument(isolate, &args[0])?;
+    let index = index_argument(isolate, &args[1])?;
+    let item = modify_array(isolate, array, arch, |entries| {
+        if index >= entries.len() {
+            return Err(ThreadPanicReason::IndexOutOfBounds(index, entries.len()));
+        }
+        Ok(entries.remove(index))
+    })?;
+    Ok(VmNativeCallParameters::Static(item))
+}
+
+pub fn array_remove_fn(
+    isolate: &mut Isolate,
+    thread_info: ThreadInfo,
+    args: Vec<VmNativeCallParameters>,
+) -> VmNativeAnswer {
+    answer(remove(isolate, thread_info.arch, &args))
+}
+
+fn clean(
+    isolate: &mut Isolate,
+    arch: PlatformArchitecture,
+    args: &[VmNativeCallParameters],
+) -> Result<VmNativeCallParameters, VmNativeAnswer> {
+    expect_arguments(args, 1)?;
+    let array = array_argument(isolate, &args[0])?;
+    modify_array(isolate, array, arch, |entries| {
+        entries.clear();
+        Ok(())
+    })?;
+    Ok(VmNativeCallParameters::Static(StaticRawType::from_void()))
+}
+
+pub fn array_clean_fn(
+    isolate: &mut Isolate,
+    thread_info: ThreadInfo,
+    args: Vec<VmNativeCallParameters>,
+) -> VmNativeAnswer {
+    answer(clean(isolate, thread_info.arch, &args))
+}
+
+fn slice(
+    isolate: &mut Isolate,
+    arch: PlatformArchitecture,
+    args: &[VmNativeCallParameters],
+) -> Result<VmNativeCallParameters, VmNativeAnswer> {
+    expect_arguments(args, 3)?;
+    let array = array_argument(isolate, &args[0])?;
+    let start = index_argument(isolate, &args[1])?;
+    let end = index_argument(isolate, &args[2])?;
+    let entries = read_entries(isolate, array, arch)?;
+    if end > entries.len() {
+        return Err(VmNativeAnswer::Panic(ThreadPanicReason::IndexOutOfBounds(
+            end,
+            entries.len(),
+        )));
+    } else if start > end {
+        return Err(VmNativeAnswer::Panic(ThreadPanicReason::IndexOutOfBounds(
+            start, end,
+        )));
+    }
+    Ok(VmNativeCallParameters::Dynamic(heap_array(
+        &entries[start..end],
+        arch,
+    )))
+}
+
+pub fn array_slice_fn(
+    isolate: &mut Isolate,
+    thread_info: ThreadInfo,
+    args: Vec<VmNativeCallParameters>,
+) -> VmNativeAnswer {
+    answer(slice(isolate, thread_info.arch, &args))
+}
+
+pub const ARRAY_PUSH: InternalFunction = InternalFunction {
+    name: "array.push",
+    callback: array_push_fn,
+};
+
+pub const ARRAY_POP: InternalFunction = InternalFunction {
+    name: "array.pop",
+    callback: array_pop_fn,
+};
+
+pub const ARRAY_INSERT: InternalFunction = InternalFunction {
+    name: "array.insert",
+    callback: array_insert_fn,
+};
+
+pub const ARRAY_REMOVE: InternalFunction = InternalFunction {
+    name: "array.remove",
+    callback: array_remove_fn,
+};
+
+pub const ARRAY_CLEAN: InternalFunction = InternalFunction {
+    name: "array.clean",
+    callback: array_clean_fn,
+};
+
+pub const ARRAY_SLICE: InternalFunction = InternalFunction {
+    name: "array.slice",
+    callback: array_slice_fn,
+};
@@ -5,6 +5,7 @@ use crate::{
     utils::{ThreadInfo, VmNativeAnswer, VmNativeCallParameters},
 };
 
+mod array_functions;
 mod stack_memory_functions;
 mod static_array_functions;
 
@@ -13,8 +14,14 @@ pub struct InternalFunction {
     pub callback: fn(&mut Isolate, ThreadInfo, Vec<VmNativeCallParameters>) -> VmNativeAnswer,
 }
 
-pub const INTERNAL_FUNCTIONS: [InternalFunction; 3] = [
+pub const INTERNAL_FUNCTIONS: [InternalFunction; 9] = [
     static_array_functions::ARRAY_LEN,
+    array_functions::ARRAY_PUSH,
+    array_functions::ARRAY_POP,
+    array_functions::ARRAY_INSERT,
+    array_functions::ARRAY_REMOVE,
+    array_functions::ARRAY_CLEAN,
+    array_functions::ARRAY_SLICE,
     stack_memory_functions::FRAME_POS,
     stack_memory_functions::CODE_POS,
 ];
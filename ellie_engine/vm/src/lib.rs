@@ -18,6 +18,8 @@ static GLOBAL: MiMalloc = MiMalloc;
 
 extern crate alloc;
 
+/// Ellie VM's arbitrary precision integer
+pub mod big_integer;
 /// Ellie native channels
 pub mod channel;
 /// Memory Stack configuration for VM
@@ -39,5 +41,9 @@ pub mod stack;
 pub mod stack_memory;
 /// Ellie VM's thread
 pub mod thread;
+/// Ellie VM's execution tracing
+pub mod trace;
 /// Ellie VM's utils
 pub mod utils;
+/// Ellie VM's static bytecode verifier
+pub mod verifier;
@@ -2,11 +2,17 @@ use crate::{
     config::PROGRAM_MAX_SIZE,
     instruction_utils::{Instructions, A2B},
     raw_type::{StaticRawType, TypeId},
-    utils::{AddressingModes, AddressingValues, ProgramReader},
+    utils::{AddressingModes, AddressingValues, ProgramReader, SliceReader},
 };
-use alloc::vec::Vec;
+use alloc::{string::String, vec::Vec};
 use core::mem;
-use ellie_core::defs::{NativeCallTrace, PlatformArchitecture};
+use ellie_core::{
+    bytecode_format::{checksum, ExportedSymbol, SectionKind, BYTECODE_MAGIC, BYTECODE_REVISION},
+    defs::{
+        Cursor, CursorPosition, DebugHeader, DebugHeaderType, DebugInfo, ModuleMap,
+        NativeCallTrace, PlatformArchitecture,
+    },
+};
 
 #[derive(Debug, Clone, Copy)]
 pub struct ReadInstruction {
@@ -16,9 +22,19 @@ pub struct ReadInstruction {
     pub op_code: u8,
 }
 
-#[derive(PartialEq, Debug)]
+#[derive(PartialEq, Debug, Clone, Copy)]
 pub enum ProgramReadErrors {
     ReadError,
+    /// File is not an ellie bytecode file
+    InvalidMagic,
+    /// (file revision, vm revision)
+    UnsupportedRevision(u16, u16),
+    /// (checksum in file, checksum of content)
+    ChecksumMismatch(u32, u32),
+    MissingSection(SectionKind),
+    DuplicateSection(SectionKind),
+    /// Section's content does not match its length
+    BrokenSection(SectionKind),
     UnexpectedPlatformArchitecture,
     UnmatchedPlatformArchitecture(PlatformArchitecture, PlatformArchitecture),
     NoMainFunction,
@@ -51,6 +67,11 @@ pub struct MainProgram {
 pub struct Program {
     pub main: MainProgram,
     pub arch: PlatformArchitecture,
+    /// Version of the compiler that built the program
+    pub compiler_version: String,
+    pub symbols: Vec<ExportedSymbol>,
+    /// Debug info, [`None`] if the program is stripped
+    pub debug_info: Option<DebugInfo>,
     pub native_call_traces: Vec<NativeCallTrace>,
     pub instructions: Vec<ReadInstruction>,
 }
@@ -64,6 +85,9 @@ impl Program {
                 length: 0,
             },
             arch: PlatformArchitecture::B32,
+            compiler_version: String::new(),
+            symbols: Vec::new(),
+            debug_info: None,
             instructions: Vec::new(),
             native_call_traces: Vec::new(),
         }
@@ -113,10 +137,30 @@ impl Program {
         Err(3)
     }
 
+    /// Read program from a bytecode file, see [`SectionKind`] for the layout
     pub fn build_from_reader(
         &mut self,
         reader: &mut ProgramReader,
     ) -> Result<(), ProgramReadErrors> {
+        for magic_byte in BYTECODE_MAGIC {
+            match reader.read_u8() {
+                Some(byte) if byte == magic_byte => (),
+                Some(_) => return Err(ProgramReadErrors::InvalidMagic),
+                None => return Err(ProgramReadErrors::ReadError),
+            }
+        }
+
+        let revision = match reader.read_usize(2) {
+            Some(revision) => revision as u16,
+            None => return Err(ProgramReadErrors::ReadError),
+        };
+        if revision != BYTECODE_REVISION {
+            return Err(ProgramReadErrors::UnsupportedRevision(
+                revision,
+                BYTECODE_REVISION,
+            ));
+        }
+
         let arch = match reader.read_u8() {
             Some(byte) => match PlatformArchitecture::from_byte(byte) {
                 Some(e) => e,
@@ -131,65 +175,161 @@ impl Program {
                 PlatformArchitecture::from_byte(mem::size_of::<usize>() as u8 * 8).unwrap(),
             ));
         }
+        self.arch = arch;
 
-        let main_exists = match reader.read_u8() {
-            Some(byte) => byte,
+        let compiler_version_len = match reader.read_u8() {
+            Some(byte) => byte as usize,
             None => return Err(ProgramReadErrors::ReadError),
         };
-
-        if main_exists == 0 {
-            return Err(ProgramReadErrors::NoMainFunction);
-        }
-
-        let start = match reader.read_usize(arch.usize_len()) {
-            Some(byte) => byte,
+        self.compiler_version = match reader.read_string(compiler_version_len) {
+            Some(version) => version,
             None => return Err(ProgramReadErrors::ReadError),
         };
 
-        let end = match reader.read_usize(arch.usize_len()) {
+        let section_count = match reader.read_u8() {
             Some(byte) => byte,
             None => return Err(ProgramReadErrors::ReadError),
         };
 
-        let hash = match reader.read_usize(arch.usize_len()) {
-            Some(byte) => byte,
+        let body_length = match reader.read_usize(arch.usize_len()) {
+            Some(length) => length,
             None => return Err(ProgramReadErrors::ReadError),
         };
-        self.main = MainProgram {
-            hash,
-            start,
-            length: end,
-        };
-        self.arch = arch;
 
-        let native_call_trace_count = match reader.read_usize(arch.usize_len()) {
-            Some(byte) => byte,
+        let expected_checksum = match reader.read_usize(4) {
+            Some(checksum) => checksum as u32,
             None => return Err(ProgramReadErrors::ReadError),
         };
 
-        for _ in 0..native_call_trace_count {
-            let module_name_len = match reader.read_usize(arch.usize_len()) {
-                Some(byte) => byte,
+        // Header fields are checked with the body, in the order they are written
+        let mut checked = Vec::new();
+        checked.extend(revision.to_le_bytes());
+        checked.push(arch.usize_len() * 8);
+        checked.push(compiler_version_len as u8);
+        checked.extend(self.compiler_version.as_bytes());
+        checked.push(section_count);
+        checked.extend(&body_length.to_le_bytes()[..arch.usize_len() as usize]);
+        let body_start = checked.len();
+        for _ in 0..body_length {
+            match reader.read_u8() {
+                Some(byte) => checked.push(byte),
                 None => return Err(ProgramReadErrors::ReadError),
-            };
+            }
+        }
+        let found_checksum = checksum(&checked);
+        if expected_checksum != found_checksum {
+            return Err(ProgramReadErrors::ChecksumMismatch(
+                expected_checksum,
+                found_checksum,
+            ));
+        }
 
-            let module_name = match reader.read_string(module_name_len) {
-                Some(name) => name,
-                None => return Err(ProgramReadErrors::ReadError),
+        let mut body_reader = SliceReader::new(&checked[body_start..]);
+        let mut read_sections: Vec<SectionKind> = Vec::new();
+        for _ in 0..section_count {
+            let (kind, section) = {
+                let mut reader = ProgramReader::new(&mut body_reader);
+                let kind = match reader.read_u8() {
+                    Some(byte) => byte,
+                    None => return Err(ProgramReadErrors::ReadError),
+                };
+                let length = match reader.read_usize(arch.usize_len()) {
+                    Some(length) => length,
+                    None => return Err(ProgramReadErrors::ReadError),
+                };
+                let mut section = Vec::new();
+                for _ in 0..length {
+                    match reader.read_u8() {
+                        Some(byte) => section.push(byte),
+                        None => return Err(ProgramReadErrors::ReadError),
+                    }
+                }
+                (kind, section)
             };
 
-            let function_hash = match reader.read_usize(arch.usize_len()) {
-                Some(byte) => byte,
-                None => return Err(ProgramReadErrors::ReadError),
-            };
-            let function_name_len = match reader.read_usize(arch.usize_len()) {
-                Some(byte) => byte,
-                None => return Err(ProgramReadErrors::ReadError),
-            };
-            let function_name = match reader.read_string(function_name_len) {
-                Some(name) => name,
-                None => return Err(ProgramReadErrors::ReadError),
+            // Unknown sections are optional by definition
+            let kind = match SectionKind::from_byte(kind) {
+                Some(kind) => kind,
+                None => continue,
             };
+            if read_sections.contains(&kind) {
+                return Err(ProgramReadErrors::DuplicateSection(kind));
+            }
+            read_sections.push(kind);
+
+            let mut section_reader = SliceReader::new(&section);
+            match kind {
+                SectionKind::Main => self.read_main_section(&mut section_reader)?,
+                SectionKind::NativeCalls => self.read_native_calls_section(&mut section_reader)?,
+                SectionKind::Symbols => self.read_symbols_section(&mut section_reader)?,
+                SectionKind::Debug => self.read_debug_section(&mut section_reader)?,
+                SectionKind::Code => {
+                    while !section_reader.is_empty() {
+                        let instruction =
+                            self.read_instruction(&mut ProgramReader::new(&mut section_reader))?;
+                        self.instructions.push(instruction);
+                    }
+                }
+            }
+            if !section_reader.is_empty() {
+                return Err(ProgramReadErrors::BrokenSection(kind));
+            }
+        }
+
+        if !body_reader.is_empty() {
+            return Err(ProgramReadErrors::ReadError);
+        }
+
+        for required in [SectionKind::NativeCalls, SectionKind::Code] {
+            if !read_sections.contains(&required) {
+                return Err(ProgramReadErrors::MissingSection(required));
+            }
+        }
+
+        if !read_sections.contains(&SectionKind::Main) {
+            return Err(ProgramReadErrors::NoMainFunction);
+        }
+
+        if self.main.start >= self.instructions.len() || self.main.length >= self.instructions.len()
+        {
+            return Err(ProgramReadErrors::BrokenMainFunction);
+        }
+        Ok(())
+    }
+
+    fn read_main_section(&mut self, reader: &mut SliceReader) -> Result<(), ProgramReadErrors> {
+        let mut reader = ProgramReader::new(reader);
+        let mut read = || {
+            reader
+                .read_usize(self.arch.usize_len())
+                .ok_or(ProgramReadErrors::BrokenSection(SectionKind::Main))
+        };
+        let start = read()?;
+        let end = read()?;
+        let hash = read()?;
+        self.main = MainProgram {
+            hash,
+            start,
+            length: end,
+        };
+        Ok(())
+    }
+
+    fn read_native_calls_section(
+        &mut self,
+        reader: &mut SliceReader,
+    ) -> Result<(), ProgramReadErrors> {
+        let mut reader = ProgramReader::new(reader);
+        let arch = self.arch.usize_len();
+        let broken = ProgramReadErrors::BrokenSection(SectionKind::NativeCalls);
+
+        let native_call_trace_count = reader.read_usize(arch).ok_or(broken)?;
+        for _ in 0..native_call_trace_count {
+            let module_name_len = reader.read_usize(arch).ok_or(broken)?;
+            let module_name = reader.read_string(module_name_len).ok_or(broken)?;
+            let function_hash = reader.read_usize(arch).ok_or(broken)?;
+            let function_name_len = reader.read_usize(arch).ok_or(broken)?;
+            let function_name = reader.read_string(function_name_len).ok_or(broken)?;
 
             self.native_call_traces.push(NativeCallTrace {
                 module_name,
@@ -197,21 +337,88 @@ impl Program {
                 function_name,
             })
         }
+        Ok(())
+    }
+
+    fn read_symbols_section(&mut self, reader: &mut SliceReader) -> Result<(), ProgramReadErrors> {
+        let mut reader = ProgramReader::new(reader);
+        let arch = self.arch.usize_len();
+        let broken = ProgramReadErrors::BrokenSection(SectionKind::Symbols);
+
+        let symbol_count = reader.read_usize(arch).ok_or(broken)?;
+        for _ in 0..symbol_count {
+            let name_len = reader.read_usize(arch).ok_or(broken)?;
+            let name = reader.read_string(name_len).ok_or(broken)?;
+            let hash = reader.read_usize(arch).ok_or(broken)?;
+            let location = reader.read_usize(arch).ok_or(broken)?;
+            self.symbols.push(ExportedSymbol {
+                name,
+                hash,
+                location,
+            });
+        }
+        Ok(())
+    }
 
-        loop {
-            let read_instruction = self.read_instruction(reader);
-            match read_instruction {
-                Ok(instruction) => {
-                    self.instructions.push(instruction);
-                    if self.instructions.len() - 1 == self.main.length {
-                        break;
-                    }
-                }
-                Err(error) => {
-                    return Err(error);
-                }
+    fn read_debug_section(&mut self, reader: &mut SliceReader) -> Result<(), ProgramReadErrors> {
+        let mut reader = ProgramReader::new(reader);
+        let arch = self.arch.usize_len();
+        let broken = ProgramReadErrors::BrokenSection(SectionKind::Debug);
+        let read_str = |reader: &mut ProgramReader| {
+            let len = reader.read_usize(arch).ok_or(broken)?;
+            reader.read_string(len).ok_or(broken)
+        };
+
+        let module_map_count = reader.read_usize(arch).ok_or(broken)?;
+        let mut module_map = Vec::new();
+        for _ in 0..module_map_count {
+            let module_name = read_str(&mut reader)?;
+            let module_hash = reader.read_usize(arch).ok_or(broken)?;
+            let module_path = match reader.read_u8() {
+                Some(0) => None,
+                Some(1) => Some(read_str(&mut reader)?),
+                _ => return Err(broken),
+            };
+            module_map.push(ModuleMap {
+                module_name,
+                module_hash,
+                module_path,
+            });
+        }
+
+        let debug_header_count = reader.read_usize(arch).ok_or(broken)?;
+        let mut debug_headers = Vec::new();
+        for _ in 0..debug_header_count {
+            let rtype = reader
+                .read_u8()
+                .and_then(DebugHeaderType::from_byte)
+                .ok_or(broken)?;
+            let hash = reader.read_usize(arch).ok_or(broken)?;
+            let module_name = read_str(&mut reader)?;
+            let module_hash = reader.read_usize(arch).ok_or(broken)?;
+            let name = read_str(&mut reader)?;
+            let mut positions = [0; 6];
+            for position in positions.iter_mut() {
+                *position = reader.read_usize(arch).ok_or(broken)?;
             }
+            debug_headers.push(DebugHeader {
+                rtype,
+                hash,
+                module_name,
+                module_hash,
+                name,
+                start_end: (positions[0], positions[1]),
+                pos: Cursor {
+                    range_start: CursorPosition(positions[2], positions[3]),
+                    range_end: CursorPosition(positions[4], positions[5]),
+                },
+            });
         }
+
+        self.debug_info = Some(DebugInfo {
+            module_map,
+            debug_headers,
+        });
         Ok(())
     }
 
@@ -247,12 +454,22 @@ impl Program {
                 } else {
                     match addressing_mode {
                         AddressingModes::Immediate => {
-                            let id = reader.read_u8().unwrap();
-                            let size = reader.read_usize(self.arch.usize_len()).unwrap();
+                            let id = match reader.read_u8() {
+                                Some(byte) => byte,
+                                None => return Err(ProgramReadErrors::ReadError),
+                            };
+                            let size = match reader.read_usize(self.arch.usize_len()) {
+                                Some(size) if size <= 8 => size,
+                                Some(_) => return Err(ProgramReadErrors::IllegalOpCode),
+                                None => return Err(ProgramReadErrors::ReadError),
+                            };
                             let type_id = TypeId::from(id, size);
                             let mut data: [u8; 8] = [0; 8];
-                            for i in 0..type_id.size {
-                                data[i] = reader.read_u8().unwrap();
+                            for byte in data.iter_mut().take(type_id.size) {
+                                *byte = match reader.read_u8() {
+                                    Some(byte) => byte,
+                                    None => return Err(ProgramReadErrors::ReadError),
+                                };
                             }
                             addressing_value =
                                 AddressingValues::Immediate(StaticRawType { type_id, data });
@@ -3,7 +3,9 @@ use core::{
     fmt::{Display, Error, Formatter},
     mem,
 };
-use ellie_core::defs::PlatformArchitecture;
+use ellie_core::defs::{IntegerSize, PlatformArchitecture};
+
+use crate::big_integer::BigInteger;
 
 const INTEGER_SIZE: usize = mem::size_of::<usize>();
 const TYPE_SIZE: usize = INTEGER_SIZE + 1;
@@ -25,6 +27,15 @@ pub enum TypeIds {
     StackReference,
     HeapReference,
     StaticArray,
+    I8,
+    I16,
+    I32,
+    I64,
+    U8,
+    U16,
+    U32,
+    U64,
+    BigInteger,
 }
 
 #[derive(Clone, Debug, Copy)]
@@ -46,6 +57,15 @@ pub enum TypeIds {
 /// * `13`: `stack_reference`
 /// * `14`: `heap_reference`
 /// * `15`: `static_array`
+/// * `16`: `i8`
+/// * `17`: `i16`
+/// * `18`: `i32`
+/// * `19`: `i64`
+/// * `20`: `u8`
+/// * `21`: `u16`
+/// * `22`: `u32`
+/// * `23`: `u64`
+/// * `24`: `bigint`
 /// ## Fields
 /// * `id`: The ID of the type.
 /// * `size`: The size of the type.
@@ -78,6 +98,15 @@ impl Display for TypeId {
             13 => write!(f, "StackReference"),
             14 => write!(f, "HeapReference"),
             15 => write!(f, "StaticArray"),
+            16 => write!(f, "I8"),
+            17 => write!(f, "I16"),
+            18 => write!(f, "I32"),
+            19 => write!(f, "I64"),
+            20 => write!(f, "U8"),
+            21 => write!(f, "U16"),
+            22 => write!(f, "U32"),
+            23 => write!(f, "U64"),
+            24 => write!(f, "BigInteger"),
             _ => panic!("Unexpected type_id"),
         }
     }
@@ -119,12 +148,21 @@ impl TypeId {
             13 => TypeIds::StackReference,
             14 => TypeIds::HeapReference,
             15 => TypeIds::StaticArray,
+            16 => TypeIds::I8,
+            17 => TypeIds::I16,
+            18 => TypeIds::I32,
+            19 => TypeIds::I64,
+            20 => TypeIds::U8,
+            21 => TypeIds::U16,
+            22 => TypeIds::U32,
+            23 => TypeIds::U64,
+            24 => TypeIds::BigInteger,
             _ => panic!("Unexpected type_id"),
         }
     }
 
     pub fn is_stack_storable(&self) -> bool {
-        self.id != 6 || self.id != 9
+        self.id != 6 && self.id != 9
     }
 
     pub fn is_int(&self) -> bool {
@@ -187,9 +225,26 @@ impl TypeId {
         self.id == 15
     }
 
+    pub fn is_fixed_integer(&self) -> bool {
+        matches!(self.id, 16..=23)
+    }
+
+    pub fn is_big_integer(&self) -> bool {
+        self.id == 24
+    }
+
+    /// Width of a fixed width integer, ids start from `16` in [`IntegerSize::FIXED`] order
+    pub fn fixed_integer_size(&self) -> Option<IntegerSize> {
+        if self.is_fixed_integer() {
+            Some(IntegerSize::FIXED[self.id as usize - 16])
+        } else {
+            None
+        }
+    }
+
     pub fn is_core_type(&self) -> bool {
         match self.id {
-            1..=12 | 15 => true,
+            1..=12 | 15..=24 => true,
             _ => false,
         }
     }
@@ -268,6 +323,17 @@ impl TypeId {
         }
     }
 
+    pub fn fixed_integer(size: IntegerSize) -> Self {
+        Self {
+            id: 16
+                + IntegerSize::FIXED
+                    .iter()
+                    .position(|fixed| *fixed == size)
+                    .expect("int is not a fixed width integer") as u8,
+            size: size.byte_size(),
+        }
+    }
+
     pub fn from(id: u8, size: usize) -> Self {
         Self { id, size }
     }
@@ -334,7 +400,7 @@ impl RawType {
 
     pub fn to_register_raw(&self) -> Result<StaticRawType, u8> {
         match self.type_id.id {
-            0..=5 | 7 | 8 | 10 | 13 | 14 => Ok(StaticRawType {
+            0..=5 | 7 | 8 | 10 | 13 | 14 | 16..=23 => Ok(StaticRawType {
                 type_id: TypeId {
                     id: self.type_id.id,
                     size: self.type_id.size,
@@ -472,6 +538,17 @@ impl RawType {
         }
     }
 
+    pub fn generate_big_integer(value: &BigInteger) -> RawType {
+        let data = value.to_bytes();
+        RawType {
+            type_id: TypeId {
+                id: 24,
+                size: data.len(),
+            },
+            data,
+        }
+    }
+
     pub fn generate_string(data: String) -> RawType {
         let chars = data.chars().collect::<Vec<_>>();
         let mut data = Vec::new();
@@ -527,6 +604,14 @@ impl RawType {
     pub fn is_string(&self) -> bool {
         self.type_id.id == 6
     }
+
+    pub fn is_big_integer(&self) -> bool {
+        self.type_id.id == 24
+    }
+
+    pub fn to_big_integer(&self) -> BigInteger {
+        BigInteger::from_bytes(&self.data)
+    }
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -611,6 +696,34 @@ impl StaticRawType {
         char::from_u32(u32::from_le_bytes(self.data[0..4].try_into().unwrap())).unwrap()
     }
 
+    /// Value of a fixed width integer, extended by its width
+    pub fn to_fixed_integer(&self) -> i128 {
+        let size = self.type_id.fixed_integer_size().unwrap_or_default();
+        let mut bytes = [0; 16];
+        bytes[0..8].copy_from_slice(&self.data);
+        let value = i128::from_le_bytes(bytes);
+        let width = size.byte_size() as u32 * 8;
+        if size.is_signed() {
+            // Sign extend
+            (value << (128 - width)) >> (128 - width)
+        } else {
+            value & ((1 << width) - 1)
+        }
+    }
+
+    /// Fixed width integer, value is truncated to the width
+    pub fn from_fixed_integer(size: IntegerSize, data: i128) -> StaticRawType {
+        let mut bytes = [0; 8];
+        bytes.copy_from_slice(&data.to_le_bytes()[0..8]);
+        for byte in bytes.iter_mut().skip(size.byte_size()) {
+            *byte = 0;
+        }
+        StaticRawType {
+            type_id: TypeId::fixed_integer(size),
+            data: bytes,
+        }
+    }
+
     pub fn from_int(data: isize) -> StaticRawType {
         let mut bytes = [0; 8];
         bytes[0..mem::size_of::<isize>()].copy_from_slice(&data.to_le_bytes());
@@ -677,7 +790,7 @@ impl StaticRawType {
         let mut bytes = [0; 8];
         bytes[0..4].copy_from_slice(&(data as u32).to_le_bytes());
         StaticRawType {
-            type_id: TypeId { id: 6, size: 4 },
+            type_id: TypeId { id: 7, size: 4 },
             data: bytes,
         }
     }
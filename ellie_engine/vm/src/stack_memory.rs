@@ -58,6 +58,7 @@ impl StackMemory {
                 13 => String::from("stack_reference"),
                 14 => String::from("heap_reference"),
                 15 => String::from("static_array"),
+                16..=23 => value.to_fixed_integer().to_string(),
                 _ => unreachable!("Wrong typeid"),
             };
             result.push_str(&format!(
@@ -1,5 +1,5 @@
 #![allow(non_snake_case)]
-use alloc::{format, string::String};
+use alloc::{format, string::String, vec::Vec};
 use ellie_core::defs::PlatformArchitecture;
 
 use crate::{
@@ -10,6 +10,7 @@ use crate::{
     raw_type::StaticRawType,
     stack::{Caller, Stack, StackArray},
     stack_memory::StackMemory,
+    trace::{register_changes, TraceStep, Tracer},
     utils::{
         StepResult, ThreadExit, ThreadInfo, ThreadPanic, ThreadPanicReason, VmNativeAnswer,
         VmNativeCallParameters,
@@ -182,8 +183,13 @@ impl Thread {
                                         frame_pos: current_stack.frame_pos,
                                         pos: current_stack.pos,
                                         stack_caller: current_stack.caller.map(|c| c.id),
+                                        arch: self.arch,
                                     },
-                                    native_call.params,
+                                    native_call
+                                        .raw_params
+                                        .into_iter()
+                                        .map(VmNativeCallParameters::Static)
+                                        .collect(),
                                 );
                                 match response {
                                     VmNativeAnswer::Ok(return_value) => {
@@ -214,6 +220,13 @@ impl Thread {
                                             },
                                         ));
                                     }
+                                    VmNativeAnswer::Panic(reason) => {
+                                        StepResult::ThreadExit(ThreadExit::Panic(ThreadPanic {
+                                            reason,
+                                            stack_trace: self.stack.clone(),
+                                            code_location: format!("{}:{}", file!(), line!()),
+                                        }))
+                                    }
                                 }
                             } else {
                                 match module_manager
@@ -235,6 +248,7 @@ impl Thread {
                                                             stack_caller: current_stack
                                                                 .caller
                                                                 .map(|c| c.id),
+                                                            arch: self.arch,
                                                         },
                                                         native_call.params,
                                                     );
@@ -275,6 +289,19 @@ impl Thread {
                                                         ),
                                                     }));
                                                         }
+                                                        VmNativeAnswer::Panic(reason) => {
+                                                            StepResult::ThreadExit(
+                                                                ThreadExit::Panic(ThreadPanic {
+                                                                    reason,
+                                                                    stack_trace: self.stack.clone(),
+                                                                    code_location: format!(
+                                                                        "{}:{}",
+                                                                        file!(),
+                                                                        line!()
+                                                                    ),
+                                                                }),
+                                                            )
+                                                        }
                                                     }
                                                 }
                                             },
@@ -330,6 +357,35 @@ impl Thread {
         }
     }
 
+    /// Step the thread and report the executed instruction to the tracer
+    pub fn step_traced(
+        &mut self,
+        module_manager: &mut ModuleManager,
+        loaded_program: &VmProgram,
+        tracer: &mut dyn Tracer,
+    ) -> StepResult {
+        let depth = self.stack.len();
+        let (pos, stack_id, registers) = match self.stack.last() {
+            Some(current_stack) => (current_stack.pos, current_stack.id, current_stack.registers),
+            None => return self.step(module_manager, loaded_program),
+        };
+        let result = self.step(module_manager, loaded_program);
+        if pos < loaded_program.length {
+            let register_changes = match self.stack.get(depth - 1) {
+                Some(current_stack) => register_changes(&registers, &current_stack.registers),
+                None => Vec::new(),
+            };
+            tracer.trace(&TraceStep {
+                pos,
+                stack_id,
+                depth,
+                instruction: loaded_program.instructions[pos],
+                register_changes,
+            });
+        }
+        result
+    }
+
     pub fn run(
         &mut self,
         module_manager: &mut ModuleManager,
@@ -418,8 +474,13 @@ impl Thread {
                                             frame_pos: current_stack.frame_pos,
                                             pos: current_stack.pos,
                                             stack_caller: current_stack.caller.map(|c| c.id),
+                                            arch: self.arch,
                                         },
-                                        native_call.params,
+                                        native_call
+                                            .raw_params
+                                            .into_iter()
+                                            .map(VmNativeCallParameters::Static)
+                                            .collect(),
                                     );
                                     match response {
                                         VmNativeAnswer::Ok(return_value) => {
@@ -447,6 +508,13 @@ impl Thread {
                                                 code_location: format!("{}:{}", file!(), line!()),
                                             });
                                         }
+                                        VmNativeAnswer::Panic(reason) => {
+                                            return ThreadExit::Panic(ThreadPanic {
+                                                reason,
+                                                stack_trace: self.stack.clone(),
+                                                code_location: format!("{}:{}", file!(), line!()),
+                                            });
+                                        }
                                     }
                                 } else {
                                     match module_manager
@@ -469,6 +537,7 @@ impl Thread {
                                                                 stack_caller: current_stack
                                                                     .caller
                                                                     .map(|c| c.id),
+                                                                arch: self.arch,
                                                             },
                                                             native_call.params,
                                                         );
@@ -508,6 +577,21 @@ impl Thread {
                                                             ),
                                                         });
                                                             }
+                                                            VmNativeAnswer::Panic(reason) => {
+                                                                return ThreadExit::Panic(
+                                                                    ThreadPanic {
+                                                                        reason,
+                                                                        stack_trace: self
+                                                                            .stack
+                                                                            .clone(),
+                                                                        code_location: format!(
+                                                                            "{}:{}",
+                                                                            file!(),
+                                                                            line!()
+                                                                        ),
+                                                                    },
+                                                                );
+                                                            }
                                                         }
                                                     }
                                                 },
@@ -0,0 +1,62 @@
+use alloc::vec::Vec;
+
+use crate::{program::ReadInstruction, raw_type::StaticRawType, thread::Registers};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Register {
+    A,
+    B,
+    C,
+    X,
+    Y,
+}
+
+impl Register {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Register::A => "A",
+            Register::B => "B",
+            Register::C => "C",
+            Register::X => "X",
+            Register::Y => "Y",
+        }
+    }
+}
+
+/// A single executed instruction
+#[derive(Debug, Clone)]
+pub struct TraceStep {
+    /// Location of the instruction
+    pub pos: usize,
+    /// Id of the stack that executed the instruction, this is the hash of the running function
+    pub stack_id: usize,
+    /// Stack depth when the instruction is executed, main function is at depth 1
+    pub depth: usize,
+    pub instruction: ReadInstruction,
+    /// Registers of the executing stack that changed by the instruction
+    pub register_changes: Vec<(Register, StaticRawType)>,
+}
+
+/// Receives every step of a thread, see [`crate::thread::Thread::step_traced`]
+pub trait Tracer {
+    fn trace(&mut self, step: &TraceStep);
+}
+
+fn is_same(first: &StaticRawType, second: &StaticRawType) -> bool {
+    first.type_id.id == second.type_id.id && first.data == second.data
+}
+
+/// Registers that differ between two register states
+pub fn register_changes(before: &Registers, after: &Registers) -> Vec<(Register, StaticRawType)> {
+    [
+        (Register::A, &before.A, after.A),
+        (Register::B, &before.B, after.B),
+        (Register::C, &before.C, after.C),
+        (Register::X, &before.X, after.X),
+        (Register::Y, &before.Y, after.Y),
+    ]
+    .into_iter()
+    .filter(|(_, before, after)| !is_same(before, after))
+    .map(|(register, _, after)| (register, after))
+    .collect()
+}
@@ -1,7 +1,7 @@
 use core::mem;
 
 use alloc::{string::String, vec::Vec};
-use ellie_core::defs::CursorPosition;
+use ellie_core::defs::{CursorPosition, PlatformArchitecture};
 
 use crate::{
     heap_memory,
@@ -22,6 +22,9 @@ pub struct VmNativeCall {
     pub hash: usize,
     /// Parameter array
     pub params: Vec<VmNativeCallParameters>,
+    /// Parameters as the caller passed them, references are neither resolved nor copied.
+    /// Internal functions receive these so they can mutate the referenced data
+    pub raw_params: Vec<StaticRawType>,
     /// Return heap position is location of the ret instruction
     /// If a non static value want to be returned, it will be stored in the heap,
     /// and Y register will be referencing to this position,
@@ -33,6 +36,8 @@ pub struct VmNativeCall {
 pub enum VmNativeAnswer {
     Ok(VmNativeCallParameters),
     RuntimeError(String),
+    /// Stops the thread with given panic reason
+    Panic(ThreadPanicReason),
 }
 
 #[derive(Debug, Clone)]
@@ -42,6 +47,7 @@ pub struct ThreadInfo {
     pub frame_pos: usize,
     pub pos: usize,
     pub stack_caller: Option<usize>,
+    pub arch: PlatformArchitecture,
 }
 
 impl ThreadInfo {
@@ -226,6 +232,33 @@ pub trait Reader {
     fn read(&mut self) -> Option<u8>;
 }
 
+/// [`Reader`] over a byte slice
+pub struct SliceReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> SliceReader<'a> {
+    pub fn new(data: &'a [u8]) -> SliceReader<'a> {
+        SliceReader { data, pos: 0 }
+    }
+
+    /// Whether all bytes are read
+    pub fn is_empty(&self) -> bool {
+        self.pos >= self.data.len()
+    }
+}
+
+impl Reader for SliceReader<'_> {
+    fn read(&mut self) -> Option<u8> {
+        let byte = self.data.get(self.pos).copied();
+        if byte.is_some() {
+            self.pos += 1;
+        }
+        byte
+    }
+}
+
 pub struct ProgramReader<'a> {
     reader: &'a mut dyn Reader,
 }
@@ -253,17 +286,13 @@ impl ProgramReader<'_> {
         Some(usize::from_le_bytes(array))
     }
 
+    /// Read utf8 string, returns [`None`] if bytes are not valid utf8
     pub fn read_string(&mut self, string_length: usize) -> Option<String> {
-        let mut string = String::new();
+        let mut bytes = Vec::new();
         for _ in 0..string_length {
-            match self.reader.read() {
-                Some(byte) => {
-                    string.push(byte as char);
-                }
-                None => return None,
-            }
+            bytes.push(self.reader.read()?);
         }
-        Some(string)
+        String::from_utf8(bytes).ok()
     }
 
     pub fn read_isize(&mut self, arch_size: u8) -> Option<isize> {
@@ -0,0 +1,371 @@
+use alloc::{vec, vec::Vec};
+
+use crate::{
+    config::{PROGRAM_MAX_SIZE, STACK_MEMORY_SIZE},
+    instruction_utils::Instructions,
+    program::{Program, ReadInstruction},
+    raw_type::TypeId,
+    utils::AddressingValues,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum VerifyErrorKind {
+    /// Program has more instructions than [`PROGRAM_MAX_SIZE`]
+    ProgramTooLarge(usize),
+    /// Instruction has no executer
+    UnsupportedInstruction,
+    /// Addressing value is not supported by the instruction
+    IllegalAddressingValue,
+    /// Types like string, array and class can't be kept in immediate mode
+    ImmediateUseViolation(u8),
+    /// Immediate value is not the type instruction expects
+    UnexpectedType(u8),
+    /// Function's escape position or parameter count is missing
+    BrokenFunctionHeader,
+    /// Function's escape position is out of program or overlaps with another function
+    InvalidFunctionEnd(usize),
+    /// Jump target is out of program, a function header or outside of the jumping function
+    InvalidJumpTarget(usize),
+    /// Jump table entries are not `JMP` instructions or exceed the jumping function
+    BrokenJumpTable,
+    /// Call target is not a function
+    InvalidCallTarget(usize),
+    /// Native call target's hash is not in the program's native call traces
+    UnknownNativeCall(usize),
+    /// Absolute address is out of program
+    AddressOutOfRange(usize),
+    /// Static address does not point to an immediate value
+    InvalidStaticReference(usize),
+    /// Main function does not match with the function at its start
+    BrokenMainFunction,
+    /// Stack frame does not fit in stack memory
+    FrameTooLarge(usize),
+}
+
+/// Error found by [`verify`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VerifyError {
+    /// Location of the instruction
+    pub location: usize,
+    pub kind: VerifyErrorKind,
+}
+
+fn immediate_int(instruction: &ReadInstruction) -> Option<usize> {
+    match instruction.addressing_value {
+        AddressingValues::Immediate(e) if e.type_id.is_int() && e.to_int() >= 0 => {
+            Some(e.to_int() as usize)
+        }
+        _ => None,
+    }
+}
+
+/// Whether instruction's executer handles the addressing value
+fn is_addressing_value_supported(instruction: &Instructions, value: &AddressingValues) -> bool {
+    match instruction {
+        Instructions::LDA(_) => !matches!(
+            value,
+            AddressingValues::Implicit | AddressingValues::IndirectA
+        ),
+        Instructions::LDB(_) => !matches!(
+            value,
+            AddressingValues::Implicit | AddressingValues::IndirectB
+        ),
+        Instructions::LDC(_) => !matches!(
+            value,
+            AddressingValues::Implicit | AddressingValues::IndirectC
+        ),
+        Instructions::LDX(_) => !matches!(
+            value,
+            AddressingValues::Implicit | AddressingValues::IndirectX
+        ),
+        Instructions::LDY(_) => !matches!(
+            value,
+            AddressingValues::Implicit | AddressingValues::IndirectY
+        ),
+        Instructions::STA(_)
+        | Instructions::STB(_)
+        | Instructions::STC(_)
+        | Instructions::STX(_)
+        | Instructions::STY(_) => matches!(
+            value,
+            AddressingValues::Implicit
+                | AddressingValues::Immediate(_)
+                | AddressingValues::Absolute(_)
+                | AddressingValues::AbsoluteIndex(_, _)
+                | AddressingValues::AbsoluteProperty(_, _)
+        ),
+        Instructions::JMP(_)
+        | Instructions::JMPA(_)
+        | Instructions::CALL(_)
+        | Instructions::CALLN(_)
+        | Instructions::PUSH(_)
+        | Instructions::SPUS(_)
+        | Instructions::CO(_)
+        | Instructions::DEA(_)
+        | Instructions::LEN(_) => matches!(value, AddressingValues::Absolute(_)),
+        Instructions::FN(_)
+        | Instructions::SAR(_)
+        | Instructions::A2N(_)
+        | Instructions::JMPT(_) => {
+            matches!(value, AddressingValues::Immediate(_))
+        }
+        Instructions::POPS(_) | Instructions::BRK(_) => false,
+        _ => matches!(value, AddressingValues::Implicit),
+    }
+}
+
+/// Function boundaries of the program
+struct Functions {
+    /// Escape position of the function starting at the location
+    ends: Vec<Option<usize>>,
+    /// Innermost function that the location belongs to, [`None`] for top level. Functions belong
+    /// to their parent
+    owners: Vec<Option<usize>>,
+}
+
+impl Functions {
+    fn new(program: &Program, errors: &mut Vec<VerifyError>) -> Functions {
+        let len = program.instructions.len();
+        let mut functions = Functions {
+            ends: vec![None; len],
+            owners: vec![None; len],
+        };
+        let mut open: Vec<(usize, usize)> = Vec::new();
+
+        for (location, instruction) in program.instructions.iter().enumerate() {
+            while matches!(open.last(), Some((_, end)) if *end < location) {
+                open.pop();
+            }
+            functions.owners[location] = open.last().map(|(start, _)| *start);
+
+            if !matches!(instruction.instruction, Instructions::FN(_)) {
+                continue;
+            }
+            let header = (
+                program.instructions.get(location + 1),
+                program.instructions.get(location + 2),
+            );
+            let end = match header {
+                (Some(escape), Some(parameter_count))
+                    if matches!(escape.instruction, Instructions::STA(_))
+                        && matches!(parameter_count.instruction, Instructions::STA(_))
+                        && immediate_int(parameter_count).is_some() =>
+                {
+                    match immediate_int(escape) {
+                        Some(end) => end,
+                        None => {
+                            errors.push(VerifyError {
+                                location,
+                                kind: VerifyErrorKind::BrokenFunctionHeader,
+                            });
+                            continue;
+                        }
+                    }
+                }
+                _ => {
+                    errors.push(VerifyError {
+                        location,
+                        kind: VerifyErrorKind::BrokenFunctionHeader,
+                    });
+                    continue;
+                }
+            };
+
+            let overlaps = matches!(open.last(), Some((_, parent_end)) if end > *parent_end);
+            if end <= location + 2 || end >= len || overlaps {
+                errors.push(VerifyError {
+                    location,
+                    kind: VerifyErrorKind::InvalidFunctionEnd(end),
+                });
+                continue;
+            }
+            functions.ends[location] = Some(end);
+            open.push((location, end));
+        }
+        functions
+    }
+
+    fn is_header(&self, location: usize) -> bool {
+        (location > 0 && matches!(self.ends.get(location - 1), Some(Some(_))))
+            || (location > 1 && matches!(self.ends.get(location - 2), Some(Some(_))))
+    }
+}
+
+/// Statically verify a program before running it
+///
+/// Checks that every instruction's addressing value is handled by the instruction, immediates
+/// are stack storable, function headers are well formed and functions nest properly, jumps stay
+/// inside their function, calls land on function headers, native calls land on known natives,
+/// absolute addresses are inside the program and every function's stack frame fits in stack
+/// memory.
+///
+/// ## Arguments
+/// * `program` - [`Program`] to verify
+/// ## Returns
+/// Every [`VerifyError`] found in the program
+pub fn verify(program: &Program) -> Result<(), Vec<VerifyError>> {
+    let mut errors = Vec::new();
+    let instructions = &program.instructions;
+    let len = instructions.len();
+
+    if len > PROGRAM_MAX_SIZE {
+        return Err(vec![VerifyError {
+            location: 0,
+            kind: VerifyErrorKind::ProgramTooLarge(len),
+        }]);
+    }
+
+    let functions = Functions::new(program, &mut errors);
+    let is_function = |location: usize| matches!(functions.ends.get(location), Some(Some(_)));
+
+    for (location, instruction) in instructions.iter().enumerate() {
+        let mut error = |kind| errors.push(VerifyError { location, kind });
+        let value = &instruction.addressing_value;
+
+        if !is_addressing_value_supported(&instruction.instruction, value) {
+            error(
+                if matches!(
+                    instruction.instruction,
+                    Instructions::POPS(_) | Instructions::BRK(_)
+                ) {
+                    VerifyErrorKind::UnsupportedInstruction
+                } else {
+                    VerifyErrorKind::IllegalAddressingValue
+                },
+            );
+            continue;
+        }
+
+        match value {
+            AddressingValues::Immediate(e) => {
+                let id = e.type_id.id;
+                match instruction.instruction {
+                    Instructions::FN(_) | Instructions::JMPT(_) if !e.type_id.is_int() => {
+                        error(VerifyErrorKind::UnexpectedType(id))
+                    }
+                    // Entries follow the table, execution continues after them when no entry
+                    // matches
+                    Instructions::JMPT(_) => {
+                        let after = usize::try_from(e.to_int())
+                            .ok()
+                            .and_then(|count| (location + 1).checked_add(count))
+                            .filter(|after| *after < len);
+                        let is_table = after.is_some_and(|after| {
+                            (location + 1..after).all(|entry| {
+                                matches!(instructions[entry].instruction, Instructions::JMP(_))
+                            }) && functions.owners[after] == functions.owners[location]
+                        });
+                        if !is_table {
+                            error(VerifyErrorKind::BrokenJumpTable)
+                        }
+                    }
+                    Instructions::SAR(_) if !e.type_id.is_static_array() => {
+                        error(VerifyErrorKind::UnexpectedType(id))
+                    }
+                    // Immediate is the id of target fixed width integer type
+                    Instructions::A2N(_)
+                        if !e.type_id.is_int()
+                            || !u8::try_from(e.to_int())
+                                .is_ok_and(|target| TypeId::from(target, 0).is_fixed_integer()) =>
+                    {
+                        error(VerifyErrorKind::UnexpectedType(id))
+                    }
+                    _ if !e.type_id.is_stack_storable() => {
+                        error(VerifyErrorKind::ImmediateUseViolation(id))
+                    }
+                    _ => (),
+                }
+            }
+            AddressingValues::Absolute(address) => match instruction.instruction {
+                Instructions::JMP(_) | Instructions::JMPA(_)
+                    if *address >= len
+                        || functions.is_header(*address)
+                        || functions.owners[*address] != functions.owners[location] =>
+                {
+                    error(VerifyErrorKind::InvalidJumpTarget(*address))
+                }
+                Instructions::CALL(_) | Instructions::CALLN(_) if !is_function(*address) => {
+                    error(VerifyErrorKind::InvalidCallTarget(*address))
+                }
+                Instructions::CALLN(_) => {
+                    let hash = immediate_int(&instructions[*address]);
+                    if !program
+                        .native_call_traces
+                        .iter()
+                        .any(|trace| Some(trace.function_hash) == hash)
+                    {
+                        error(VerifyErrorKind::UnknownNativeCall(*address))
+                    }
+                }
+                _ if *address >= len => error(VerifyErrorKind::AddressOutOfRange(*address)),
+                _ => (),
+            },
+            AddressingValues::AbsoluteIndex(pointer, index) => {
+                for address in [pointer, index] {
+                    if *address >= len {
+                        error(VerifyErrorKind::AddressOutOfRange(*address));
+                    }
+                }
+            }
+            AddressingValues::AbsoluteProperty(pointer, _) if *pointer >= len => {
+                error(VerifyErrorKind::AddressOutOfRange(*pointer))
+            }
+            AddressingValues::AbsoluteStatic(address) => {
+                match instructions.get(*address).map(|e| e.addressing_value) {
+                    Some(AddressingValues::Immediate(_)) => (),
+                    _ => error(VerifyErrorKind::InvalidStaticReference(*address)),
+                }
+            }
+            _ => (),
+        }
+    }
+
+    let main = program.main;
+    let main_hash = instructions
+        .get(main.start)
+        .filter(|e| matches!(e.instruction, Instructions::FN(_)))
+        .and_then(immediate_int);
+    if main_hash != Some(main.hash) || functions.ends.get(main.start) != Some(&Some(main.length)) {
+        errors.push(VerifyError {
+            location: main.start,
+            kind: VerifyErrorKind::BrokenMainFunction,
+        });
+    } else {
+        // Farthest location every function addresses in its frame
+        let mut extents: Vec<usize> = functions.ends.iter().map(|end| end.unwrap_or(0)).collect();
+        for (location, instruction) in instructions.iter().enumerate() {
+            let address = match instruction.addressing_value {
+                AddressingValues::Absolute(e) | AddressingValues::AbsoluteProperty(e, _) => e,
+                AddressingValues::AbsoluteIndex(e, i) => e.max(i),
+                _ => continue,
+            };
+            if let Some(owner) = functions.owners[location] {
+                extents[owner] = extents[owner].max(address);
+            }
+        }
+        // Main frame is placed right after the main function, frames of called functions are placed
+        // after their caller's position so they start at least their length after main frame
+        let main_frame = main.start + main.length;
+        for (location, end) in functions.ends.iter().enumerate() {
+            let Some(end) = end else { continue };
+            let frame_start = if location == main.start {
+                main_frame
+            } else {
+                main_frame + (end - location)
+            };
+            let frame_end = frame_start + extents[location];
+            if frame_end >= STACK_MEMORY_SIZE {
+                errors.push(VerifyError {
+                    location,
+                    kind: VerifyErrorKind::FrameTooLarge(frame_end),
+                });
+            }
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
@@ -0,0 +1,126 @@
+use crate::{compile_file::get_output_path, OutputTypesSelector};
+use ellie_engine::{
+    ellie_bytecode::asm_parser::parse_asm,
+    ellie_renderer_utils::{
+        outputs,
+        utils::{read_file, CliColor, ColorDisplay, Colors},
+    },
+};
+use path_absolutize::Absolutize;
+use std::{fs::File, path::Path};
+
+fn create_file(path: &Path, json_log: bool) -> File {
+    let cli_color = &CliColor;
+    File::create(path).unwrap_or_else(|err| {
+        if json_log {
+            let mut output = outputs::WRITE_FILE_ERROR.clone();
+            output.extra.push(outputs::CliOuputExtraData {
+                key: "path".to_string(),
+                value: format!("{:?}", err),
+            });
+            println!("{}", serde_json::to_string(&output).unwrap())
+        } else {
+            println!(
+                "\nFailed to create file {}{}{}. [{}{:?}{}]",
+                cli_color.color(Colors::Cyan),
+                path.absolutize().unwrap().to_str().unwrap(),
+                cli_color.color(Colors::Reset),
+                cli_color.color(Colors::Red),
+                err,
+                cli_color.color(Colors::Reset),
+            );
+        }
+        std::process::exit(1);
+    })
+}
+
+pub fn assemble(target_path: &Path, output_path: &Path, json_log: bool) {
+    let cli_color = &CliColor;
+    let code = match read_file(target_path) {
+        Ok(code) => code,
+        Err(err) => {
+            if json_log {
+                let mut output = outputs::READ_FILE_ERROR.clone();
+                output
+                    .extra
+                    .push(outputs::CliOuputExtraData { key: 0, value: err });
+                println!("{}", serde_json::to_string(&output).unwrap());
+            } else {
+                println!(
+                    "Unable to read file ~{} [{}]",
+                    target_path.to_str().unwrap(),
+                    err
+                );
+            }
+            std::process::exit(1);
+        }
+    };
+
+    let mut assembler_result = match parse_asm(&code) {
+        Ok(assembler_result) => assembler_result,
+        Err(err) => {
+            if json_log {
+                let mut output = outputs::ASSEMBLE_FAILED.clone();
+                output.extra.push(outputs::CliOuputExtraData {
+                    key: "line".to_string(),
+                    value: err.line.to_string(),
+                });
+                output.extra.push(outputs::CliOuputExtraData {
+                    key: "column".to_string(),
+                    value: err.column.to_string(),
+                });
+                output.extra.push(outputs::CliOuputExtraData {
+                    key: "reason".to_string(),
+                    value: err.reason,
+                });
+                println!("{}", serde_json::to_string(&output).unwrap());
+            } else {
+                println!(
+                    "{}Error{}: {}{}:{}:{}{} {}",
+                    cli_color.color(Colors::Red),
+                    cli_color.color(Colors::Reset),
+                    cli_color.color(Colors::Cyan),
+                    target_path.to_str().unwrap(),
+                    err.line,
+                    err.column,
+                    cli_color.color(Colors::Reset),
+                    err.reason,
+                );
+            }
+            std::process::exit(1);
+        }
+    };
+    assembler_result.module_info.name = target_path
+        .file_stem()
+        .unwrap()
+        .to_str()
+        .unwrap()
+        .to_string();
+
+    let output_path = &get_output_path(target_path, output_path, OutputTypesSelector::ByteCode);
+    let mut output_file = create_file(output_path, json_log);
+    assembler_result.render_binary(&mut output_file, false);
+
+    if json_log {
+        let mut output = outputs::WRITE_BYTE_CODE_SUCCEDED.clone();
+        output.extra.push(outputs::CliOuputExtraData {
+            key: 0,
+            value: output_path
+                .absolutize()
+                .unwrap()
+                .to_str()
+                .unwrap()
+                .to_owned(),
+        });
+        println!("{}", serde_json::to_string(&output).unwrap())
+    } else {
+        println!(
+            "{}[!]{}: ByteCode output written to {}{}{}",
+            cli_color.color(Colors::Green),
+            cli_color.color(Colors::Reset),
+            cli_color.color(Colors::Yellow),
+            output_path.absolutize().unwrap().to_str().unwrap(),
+            cli_color.color(Colors::Reset),
+        );
+    }
+}
@@ -2,7 +2,10 @@ use crate::OutputTypesSelector;
 use bincode::Options;
 use ellie_engine::{
     compiler::parse_pages,
-    ellie_bytecode::assembler::{Assembler, PlatformAttributes},
+    ellie_bytecode::{
+        assembler::{Assembler, PlatformAttributes},
+        optimizer::{constant_folding, peephole},
+    },
     ellie_core::{
         defs::{ModuleMap, PlatformArchitecture},
         module_path::parse_module_import,
@@ -34,6 +37,7 @@ pub struct CliCompilerSettings {
     pub performance_info: bool,
     pub show_debug_lines: bool,
     pub exclude_std: bool,
+    pub strip_debug_info: bool,
     pub compiler_settings: CompilerSettings,
     pub disable_terminal_colors: bool,
 }
@@ -64,7 +68,6 @@ pub fn get_output_path(
                     OutputTypesSelector::Bin => ".eib",
                     OutputTypesSelector::ByteCode => ".eic",
                     OutputTypesSelector::ByteCodeAsm => ".eia",
-                    OutputTypesSelector::ByteCodeDebug => ".eig",
                     _ => ".json",
                 }),
         )
@@ -461,14 +464,6 @@ pub fn compile(
                         let output_path =
                             &get_output_path(target_path, output_path, output_type.clone());
 
-                        let dbg_output_path = output_path.file_name().unwrap().to_str().unwrap();
-
-                        let dbg_output_path = &get_output_path(
-                            target_path,
-                            Path::new(&output_path.to_str().unwrap().replace(dbg_output_path, "")),
-                            OutputTypesSelector::ByteCodeDebug,
-                        );
-
                         match output_type {
                             OutputTypesSelector::Bin => {
                                 let config = bincode::options()
@@ -587,8 +582,15 @@ pub fn compile(
                                         }
                                     );
                                 }
+                                let mut module = compile_output.module.clone();
+                                if cli_settings.compiler_settings.optimization_level > 0 {
+                                    constant_folding::fold_module(
+                                        &mut module,
+                                        cli_settings.compiler_settings.byte_code_architecture,
+                                    );
+                                }
                                 let mut assembler = Assembler::new(
-                                    compile_output.module.clone(),
+                                    module,
                                     PlatformAttributes {
                                         architecture: cli_settings
                                             .compiler_settings
@@ -596,7 +598,10 @@ pub fn compile(
                                         memory_size: 512000, //512kb memory limit
                                     },
                                 );
-                                let assembler_result = assembler.assemble(module_maps.clone());
+                                let mut assembler_result = assembler.assemble(module_maps.clone());
+                                if cli_settings.compiler_settings.optimization_level > 0 {
+                                    peephole::optimize(&mut assembler_result);
+                                }
                                 let mut output_file =
                                     File::create(output_path).unwrap_or_else(|err| {
                                         if cli_settings.json_log {
@@ -619,33 +624,8 @@ pub fn compile(
                                         }
                                         std::process::exit(1);
                                     });
-                                let mut dbg_file =
-                                    File::create(dbg_output_path).unwrap_or_else(|err| {
-                                        if cli_settings.json_log {
-                                            let mut output = outputs::WRITE_FILE_ERROR.clone();
-                                            output.extra.push(outputs::CliOuputExtraData {
-                                                key: "path".to_string(),
-                                                value: format!("{:?}", err),
-                                            });
-                                            println!("{}", serde_json::to_string(&output).unwrap())
-                                        } else {
-                                            println!(
-                                                "\nFailed to create file {}{}{}. [{}{:?}{}]",
-                                                cli_color.color(Colors::Cyan),
-                                                dbg_output_path
-                                                    .absolutize()
-                                                    .unwrap()
-                                                    .to_str()
-                                                    .unwrap(),
-                                                cli_color.color(Colors::Reset),
-                                                cli_color.color(Colors::Red),
-                                                err,
-                                                cli_color.color(Colors::Reset),
-                                            );
-                                        }
-                                        std::process::exit(1);
-                                    });
-                                assembler_result.render_binary(&mut output_file, &mut dbg_file);
+                                assembler_result
+                                    .render_binary(&mut output_file, cli_settings.strip_debug_info);
                                 if cli_settings.json_log {
                                     let mut output = outputs::WRITE_BYTE_CODE_SUCCEDED.clone();
                                     output.extra.push(outputs::CliOuputExtraData {
@@ -667,14 +647,6 @@ pub fn compile(
                                         output_path.absolutize().unwrap().to_str().unwrap(),
                                         cli_color.color(Colors::Reset),
                                     );
-                                    println!(
-                                        "{}[!]{}: ByteCode debug file written to {}{}{}\n",
-                                        cli_color.color(Colors::Green),
-                                        cli_color.color(Colors::Reset),
-                                        cli_color.color(Colors::Yellow),
-                                        dbg_output_path.absolutize().unwrap().to_str().unwrap(),
-                                        cli_color.color(Colors::Reset),
-                                    );
                                 }
                             }
                             OutputTypesSelector::ByteCodeAsm => {
@@ -691,8 +663,15 @@ pub fn compile(
                                         }
                                     );
                                 }
+                                let mut module = compile_output.module.clone();
+                                if cli_settings.compiler_settings.optimization_level > 0 {
+                                    constant_folding::fold_module(
+                                        &mut module,
+                                        cli_settings.compiler_settings.byte_code_architecture,
+                                    );
+                                }
                                 let mut assembler = Assembler::new(
-                                    compile_output.module.clone(),
+                                    module,
                                     PlatformAttributes {
                                         architecture: cli_settings
                                             .compiler_settings
@@ -700,7 +679,10 @@ pub fn compile(
                                         memory_size: 512000, //512kb memory limit
                                     },
                                 );
-                                let assembler_result = assembler.assemble(module_maps.clone());
+                                let mut assembler_result = assembler.assemble(module_maps.clone());
+                                if cli_settings.compiler_settings.optimization_level > 0 {
+                                    peephole::optimize(&mut assembler_result);
+                                }
                                 let mut output_file =
                                     File::create(output_path).unwrap_or_else(|err| {
                                         if cli_settings.json_log {
@@ -748,7 +730,6 @@ pub fn compile(
                                     );
                                 }
                             }
-                            OutputTypesSelector::ByteCodeDebug => unreachable!(),
                             OutputTypesSelector::Nop => (),
                         }
                     }
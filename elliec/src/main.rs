@@ -1,5 +1,6 @@
 use bincode::Options;
 use ellie_engine::{
+    core_library,
     ellie_core::defs::{PlatformArchitecture, Version},
     ellie_parser,
     ellie_renderer_utils::{
@@ -9,6 +10,7 @@ use ellie_engine::{
     engine_constants,
 };
 use std::path::Path;
+mod assemble_file;
 mod compile_file;
 mod tokenize_file;
 mod view_module;
@@ -25,8 +27,6 @@ pub enum OutputTypesSelector {
     ByteCode,
     /// ByteCode assembly text
     ByteCodeAsm,
-    /// ByteCode debug file
-    ByteCodeDebug,
     /// No output
     Nop,
 }
@@ -38,7 +38,6 @@ pub enum OutputTypes {
     Json,
     ByteCode,
     ByteCodeAsm,
-    ByteCodeDebug,
     Nop,
 }
 
@@ -332,7 +331,7 @@ fn main() {
                 }
             };
 
-            let modules = if let Some(modules) = matches.values_of("insertModule") {
+            let mut modules = if let Some(modules) = matches.values_of("insertModule") {
                 let mut parsed_modules = vec![];
 
                 //Iter through all modules
@@ -473,6 +472,27 @@ fn main() {
                 vec![]
             };
 
+            //Link embedded ellieCore unless it's excluded or a build of it is inserted
+            if !matches.is_present("excludeStd")
+                && !modules
+                    .iter()
+                    .any(|(module, _)| module.name == core_library::ELLIE_CORE_LIBRARY_NAME)
+            {
+                match core_library::compile_core_library() {
+                    Ok(compile_output) => modules.push((compile_output.module, None)),
+                    Err(errors) => {
+                        println!(
+                            "{}Error:{} Failed to compile embedded ellieCore v{}, {} error(s) found",
+                            cli_color.color(Colors::Red),
+                            cli_color.color(Colors::Reset),
+                            core_library::ELLIE_CORE_LIBRARY_VERSION,
+                            errors.len(),
+                        );
+                        std::process::exit(1);
+                    }
+                }
+            }
+
             let project_name = match matches.value_of("moduleName") {
                 Some(e) => e.to_string(),
                 None => {
@@ -515,6 +535,7 @@ fn main() {
             let compiler_settings = compile_file::CliCompilerSettings {
                 json_log: matches.is_present("jsonLog"),
                 exclude_std: matches.is_present("excludeStd"),
+                strip_debug_info: matches.is_present("stripDebugInfo"),
                 compiler_settings: ellie_engine::utils::CompilerSettings {
                     description: matches.value_of("description").unwrap().to_string(),
                     name: project_name,
@@ -522,6 +543,11 @@ fn main() {
                     version,
                     experimental_features: matches.is_present("experimentalFeatures"),
                     byte_code_architecture,
+                    optimization_level: matches
+                        .value_of("optimizationLevel")
+                        .unwrap()
+                        .parse::<u8>()
+                        .unwrap(),
                     file_name: Path::new(&target_path)
                         .file_name()
                         .unwrap()
@@ -680,6 +706,25 @@ fn main() {
                 target_arch,
             );
         }
+        Some(("assemble", matches)) => {
+            let target_path = Path::new(matches.value_of("target").unwrap());
+            if !target_path.exists() {
+                println!(
+                    "{}Error:{} Target path does not exist",
+                    cli_color.color(Colors::Red),
+                    cli_color.color(Colors::Reset)
+                );
+                std::process::exit(1);
+            }
+            let output_path = match matches.value_of("outputPath") {
+                Some(output_path) => Path::new(output_path),
+                None => match target_path.parent() {
+                    Some(parent) if !parent.as_os_str().is_empty() => parent,
+                    _ => Path::new("."),
+                },
+            };
+            assemble_file::assemble(target_path, output_path, matches.is_present("jsonLog"));
+        }
         _ => unreachable!("clap should ensure we don't get here"),
     }
 }
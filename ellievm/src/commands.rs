@@ -6,6 +6,8 @@ pub enum DebuggerArgTypes {
     Int,
     Uint,
     Bool,
+    /// Rest of the line with its spaces, has to be the last argument
+    Text,
 }
 
 impl Display for DebuggerArgTypes {
@@ -15,6 +17,7 @@ impl Display for DebuggerArgTypes {
             DebuggerArgTypes::Int => write!(f, "Int"),
             DebuggerArgTypes::Uint => write!(f, "Uint"),
             DebuggerArgTypes::Bool => write!(f, "Bool"),
+            DebuggerArgTypes::Text => write!(f, "Text"),
         }
     }
 }
@@ -46,6 +49,7 @@ pub fn eq_b(a: &BuildDebuggerArgTypes, b: &DebuggerArgTypes) -> bool {
         (BuildDebuggerArgTypes::String(_), DebuggerArgTypes::String) => true,
         (BuildDebuggerArgTypes::Int(_), DebuggerArgTypes::Int) => true,
         (BuildDebuggerArgTypes::Bool(_), DebuggerArgTypes::Bool) => true,
+        (_, DebuggerArgTypes::Text) => true,
         (a, b) => match a {
             BuildDebuggerArgTypes::Int(e) => *b == DebuggerArgTypes::Uint && *e >= 0,
             _ => false,
@@ -80,12 +84,20 @@ pub enum DebuggerCommands {
     // Program Execution
     Run,
     Wait,
+    BreakCondition,
+    BreakHitCount,
+    Watch,
     Step,
+    StepInto,
+    StepOver,
+    StepOut,
+    Continue,
 
     // Information
     ReadVariable,
     GetPaths,
     GetBreakpoints,
+    GetWatchpoints,
     GetRegisters,
     GetStackMemory,
     GetHeapMemory,
@@ -93,6 +105,41 @@ pub enum DebuggerCommands {
     // StepChanges,
 }
 
+/// Split input to command and its arguments, a text argument takes the rest of the line
+pub fn split_input(input: &str) -> (&str, Vec<&str>) {
+    let (command, mut rest) = match input.trim().split_once(char::is_whitespace) {
+        Some((command, rest)) => (command, rest.trim_start()),
+        None => (input.trim(), ""),
+    };
+    let text_position = COMMANDS
+        .iter()
+        .find(|e| e.short == command || e.long == command)
+        .and_then(|e| {
+            e.args
+                .iter()
+                .position(|arg| arg.value_type == DebuggerArgTypes::Text)
+        });
+
+    let mut args = Vec::new();
+    while !rest.is_empty() {
+        if Some(args.len()) == text_position {
+            args.push(rest.trim_end());
+            break;
+        }
+        match rest.split_once(char::is_whitespace) {
+            Some((arg, tail)) => {
+                args.push(arg);
+                rest = tail.trim_start();
+            }
+            None => {
+                args.push(rest);
+                break;
+            }
+        }
+    }
+    (command, args)
+}
+
 pub fn parse_args(args: Vec<&str>) -> Vec<BuildDebuggerArgTypes> {
     let mut parsed_args = Vec::new();
     for arg in args {
@@ -157,7 +204,7 @@ pub fn parse_command(
 }
 
 lazy_static! {
-    pub static ref COMMANDS: [DebuggerCommand; 15] = [
+    pub static ref COMMANDS: [DebuggerCommand; 23] = [
         DebuggerCommand {
             short: "e",
             long: "exit",
@@ -191,26 +238,23 @@ lazy_static! {
             long: "load",
             help: "Load a program",
             command: DebuggerCommands::Load,
-            args: vec![
-                DebuggerArg {
-                    name: "file",
-                    value_type: DebuggerArgTypes::String,
-                    optional: false,
-                },
-                DebuggerArg {
-                    name: "debug_file",
-                    value_type: DebuggerArgTypes::String,
-                    optional: false,
-                }
-            ],
+            args: vec![DebuggerArg {
+                name: "file",
+                value_type: DebuggerArgTypes::String,
+                optional: false,
+            }],
             has_json_output: true,
         },
         DebuggerCommand {
             short: "rm",
             long: "reload-vm",
-            help: "Reset vm to initial state with program loaded",
+            help: "Reload the program and reset vm to initial state, or hot swap changed function bodies without resetting. Layout changes fall back to a reload",
             command: DebuggerCommands::ReloadVm,
-            args: vec![],
+            args: vec![DebuggerArg {
+                name: "hot_swap",
+                value_type: DebuggerArgTypes::Bool,
+                optional: true,
+            }],
             has_json_output: true,
         },
         DebuggerCommand {
@@ -224,7 +268,7 @@ lazy_static! {
         DebuggerCommand {
             short: "w",
             long: "wait",
-            help: "Wait program at given stack position",
+            help: "Wait program at given stack position or line, module path is like '<ellie_module_x>/x.ei', 'x/x.ei' or the source file path",
             command: DebuggerCommands::Wait,
             args: vec![
                 DebuggerArg {
@@ -245,6 +289,63 @@ lazy_static! {
             ],
             has_json_output: true,
         },
+        DebuggerCommand {
+            short: "bc",
+            long: "break-condition",
+            help: "Only stop at breakpoint when condition holds, condition is the rest of the line like 'x >= 3'",
+            command: DebuggerCommands::BreakCondition,
+            args: vec![
+                DebuggerArg {
+                    name: "index",
+                    value_type: DebuggerArgTypes::Uint,
+                    optional: false,
+                },
+                DebuggerArg {
+                    name: "condition",
+                    value_type: DebuggerArgTypes::Text,
+                    optional: false,
+                },
+            ],
+            has_json_output: true,
+        },
+        DebuggerCommand {
+            short: "bh",
+            long: "break-hit-count",
+            help: "Only stop at breakpoint after it is hit given times",
+            command: DebuggerCommands::BreakHitCount,
+            args: vec![
+                DebuggerArg {
+                    name: "index",
+                    value_type: DebuggerArgTypes::Uint,
+                    optional: false,
+                },
+                DebuggerArg {
+                    name: "count",
+                    value_type: DebuggerArgTypes::Uint,
+                    optional: false,
+                },
+            ],
+            has_json_output: true,
+        },
+        DebuggerCommand {
+            short: "wt",
+            long: "watch",
+            help: "Stop the program when data at given stack or heap location changes",
+            command: DebuggerCommands::Watch,
+            args: vec![
+                DebuggerArg {
+                    name: "memory",
+                    value_type: DebuggerArgTypes::String,
+                    optional: false,
+                },
+                DebuggerArg {
+                    name: "location",
+                    value_type: DebuggerArgTypes::Uint,
+                    optional: false,
+                },
+            ],
+            has_json_output: true,
+        },
         DebuggerCommand {
             short: "s",
             long: "step",
@@ -253,6 +354,38 @@ lazy_static! {
             args: vec![],
             has_json_output: true,
         },
+        DebuggerCommand {
+            short: "si",
+            long: "step-into",
+            help: "Run the program to the next source line, entering function calls",
+            command: DebuggerCommands::StepInto,
+            args: vec![],
+            has_json_output: true,
+        },
+        DebuggerCommand {
+            short: "so",
+            long: "step-over",
+            help: "Run the program to the next source line, without entering function calls",
+            command: DebuggerCommands::StepOver,
+            args: vec![],
+            has_json_output: true,
+        },
+        DebuggerCommand {
+            short: "sout",
+            long: "step-out",
+            help: "Run the program until current function returns",
+            command: DebuggerCommands::StepOut,
+            args: vec![],
+            has_json_output: true,
+        },
+        DebuggerCommand {
+            short: "ct",
+            long: "continue",
+            help: "Continue running the program until next breakpoint",
+            command: DebuggerCommands::Continue,
+            args: vec![],
+            has_json_output: true,
+        },
         DebuggerCommand {
             short: "rv",
             long: "read-variable",
@@ -281,6 +414,14 @@ lazy_static! {
             args: vec![],
             has_json_output: true,
         },
+        DebuggerCommand {
+            short: "gw",
+            long: "get-watchpoints",
+            help: "Get list of the watchpoints",
+            command: DebuggerCommands::GetWatchpoints,
+            args: vec![],
+            has_json_output: true,
+        },
         DebuggerCommand {
             short: "gr",
             long: "get-registers",
@@ -315,3 +456,36 @@ lazy_static! {
         },
     ];
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn text_argument_takes_rest_of_line() {
+        assert_eq!(split_input("bc 5 x > 3"), ("bc", vec!["5", "x > 3"]));
+        assert_eq!(
+            split_input("  break-condition  0   name == \"a b\"  "),
+            ("break-condition", vec!["0", "name == \"a b\""])
+        );
+        assert_eq!(split_input("bc 5"), ("bc", vec!["5"]));
+    }
+
+    #[test]
+    fn words_split_without_text_argument() {
+        assert_eq!(split_input("bh 1  3"), ("bh", vec!["1", "3"]));
+        assert_eq!(split_input("wt stack 12"), ("wt", vec!["stack", "12"]));
+        assert_eq!(split_input("r"), ("r", vec![]));
+    }
+
+    #[test]
+    fn condition_command_parsed() {
+        let (command, args) = split_input("bc 2 i >= 1");
+        let matched = parse_command(command, parse_args(args), false).unwrap();
+        assert!(matches!(matched.command, DebuggerCommands::BreakCondition));
+        assert!(matches!(
+            &matched.args[1].value_type,
+            BuildDebuggerArgTypes::String(condition) if condition == "i >= 1"
+        ));
+    }
+}
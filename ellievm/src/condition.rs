@@ -0,0 +1,197 @@
+use std::{cmp::Ordering, fmt::Display};
+
+use crate::utils::VariableValue;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ConditionOperator {
+    Equal,
+    NotEqual,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+}
+
+impl ConditionOperator {
+    const OPERATORS: [(&'static str, ConditionOperator); 6] = [
+        ("==", ConditionOperator::Equal),
+        ("!=", ConditionOperator::NotEqual),
+        ("<=", ConditionOperator::LessOrEqual),
+        (">=", ConditionOperator::GreaterOrEqual),
+        ("<", ConditionOperator::Less),
+        (">", ConditionOperator::Greater),
+    ];
+
+    fn matches(&self, ordering: Ordering) -> bool {
+        match self {
+            ConditionOperator::Equal => ordering == Ordering::Equal,
+            ConditionOperator::NotEqual => ordering != Ordering::Equal,
+            ConditionOperator::Less => ordering == Ordering::Less,
+            ConditionOperator::LessOrEqual => ordering != Ordering::Greater,
+            ConditionOperator::Greater => ordering == Ordering::Greater,
+            ConditionOperator::GreaterOrEqual => ordering != Ordering::Less,
+        }
+    }
+}
+
+/// Value a condition works on
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConditionValue {
+    Int(isize),
+    Float(f64),
+    Bool(bool),
+    Char(char),
+    String(String),
+}
+
+impl ConditionValue {
+    /// Convert a variable read from memory, [`None`] if the type can't be compared
+    pub fn from_variable(value: &VariableValue) -> Option<ConditionValue> {
+        match value {
+            VariableValue::Stack(value) => match value.type_id.id {
+                1 => Some(ConditionValue::Int(value.to_int())),
+                2 => Some(ConditionValue::Float(value.to_float() as f64)),
+                3 => Some(ConditionValue::Float(value.to_double())),
+                4 => Some(ConditionValue::Int(value.to_byte() as isize)),
+                5 => Some(ConditionValue::Bool(value.data[0] == 1)),
+                7 => Some(ConditionValue::Char(value.to_char())),
+                16..=23 => isize::try_from(value.to_fixed_integer())
+                    .ok()
+                    .map(ConditionValue::Int),
+                _ => None,
+            },
+            VariableValue::Heap(value) => match value.type_id.id {
+                6 => Some(ConditionValue::String(value.to_string())),
+                24 => value
+                    .to_big_integer()
+                    .to_i128()
+                    .and_then(|value| isize::try_from(value).ok())
+                    .map(ConditionValue::Int),
+                _ => None,
+            },
+        }
+    }
+
+    fn parse(literal: &str) -> Option<ConditionValue> {
+        if let Ok(value) = literal.parse::<isize>() {
+            Some(ConditionValue::Int(value))
+        } else if let Ok(value) = literal.parse::<f64>() {
+            Some(ConditionValue::Float(value))
+        } else if literal == "true" || literal == "false" {
+            Some(ConditionValue::Bool(literal == "true"))
+        } else if literal.len() >= 2 && literal.starts_with('"') && literal.ends_with('"') {
+            Some(ConditionValue::String(
+                literal[1..literal.len() - 1].to_string(),
+            ))
+        } else if literal.len() >= 2 && literal.starts_with('\'') && literal.ends_with('\'') {
+            let mut chars = literal[1..literal.len() - 1].chars();
+            match (chars.next(), chars.next()) {
+                (Some(char), None) => Some(ConditionValue::Char(char)),
+                _ => None,
+            }
+        } else {
+            None
+        }
+    }
+
+    fn compare(&self, other: &ConditionValue) -> Option<Ordering> {
+        match (self, other) {
+            (ConditionValue::Int(first), ConditionValue::Int(second)) => Some(first.cmp(second)),
+            (ConditionValue::Int(first), ConditionValue::Float(second)) => {
+                (*first as f64).partial_cmp(second)
+            }
+            (ConditionValue::Float(first), ConditionValue::Int(second)) => {
+                first.partial_cmp(&(*second as f64))
+            }
+            (ConditionValue::Float(first), ConditionValue::Float(second)) => {
+                first.partial_cmp(second)
+            }
+            (ConditionValue::Bool(first), ConditionValue::Bool(second)) => Some(first.cmp(second)),
+            (ConditionValue::Char(first), ConditionValue::Char(second)) => Some(first.cmp(second)),
+            (ConditionValue::String(first), ConditionValue::String(second)) => {
+                Some(first.cmp(second))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConditionOperand {
+    /// Variable name, resolved in the scope of the running function
+    Variable(String),
+    Value(ConditionValue),
+}
+
+impl ConditionOperand {
+    fn parse(operand: &str) -> Option<ConditionOperand> {
+        let operand = operand.trim();
+        if operand.is_empty() {
+            return None;
+        }
+        match ConditionValue::parse(operand) {
+            Some(value) => Some(ConditionOperand::Value(value)),
+            None if operand.chars().all(|c| c.is_alphanumeric() || c == '_') => {
+                Some(ConditionOperand::Variable(operand.to_string()))
+            }
+            None => None,
+        }
+    }
+}
+
+/// A comparison between two operands like `x>=3` or `name=="ellie"`
+#[derive(Clone, Debug, PartialEq)]
+pub struct Condition {
+    pub left: ConditionOperand,
+    pub operator: ConditionOperator,
+    pub right: ConditionOperand,
+    /// Condition as written by the user
+    pub source: String,
+}
+
+impl Condition {
+    pub fn parse(source: &str) -> Option<Condition> {
+        let (position, operator, operator_len) = ConditionOperator::OPERATORS
+            .iter()
+            .filter_map(|(text, operator)| {
+                source
+                    .find(text)
+                    .map(|position| (position, *operator, text.len()))
+            })
+            .min_by_key(|(position, _, operator_len)| (*position, usize::MAX - operator_len))?;
+        Some(Condition {
+            left: ConditionOperand::parse(&source[..position])?,
+            operator,
+            right: ConditionOperand::parse(&source[position + operator_len..])?,
+            source: source.to_string(),
+        })
+    }
+
+    /// Evaluate condition, variables are resolved with `resolve`
+    pub fn evaluate<F>(&self, resolve: F) -> Result<bool, String>
+    where
+        F: Fn(&str) -> Result<ConditionValue, String>,
+    {
+        let value = |operand: &ConditionOperand| match operand {
+            ConditionOperand::Variable(name) => resolve(name),
+            ConditionOperand::Value(value) => Ok(value.clone()),
+        };
+        let (left, right) = (value(&self.left)?, value(&self.right)?);
+        match left.compare(&right) {
+            Some(ordering) => Ok(self.operator.matches(ordering)),
+            None => Err(format!("can't compare {} with {}", left, right)),
+        }
+    }
+}
+
+impl Display for ConditionValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConditionValue::Int(value) => write!(f, "{}", value),
+            ConditionValue::Float(value) => write!(f, "{}", value),
+            ConditionValue::Bool(value) => write!(f, "{}", value),
+            ConditionValue::Char(value) => write!(f, "'{}'", value),
+            ConditionValue::String(value) => write!(f, "\"{}\"", value),
+        }
+    }
+}
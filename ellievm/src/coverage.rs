@@ -0,0 +1,181 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    io::Write,
+};
+
+use ellie_engine::{
+    ellie_core::defs::{DebugHeaderType, DebugInfo},
+    ellie_vm::{
+        instruction_utils::Instructions,
+        trace::{TraceStep, Tracer},
+        utils::AddressingValues,
+    },
+};
+
+use crate::utils::get_real_path;
+
+/// Coverage of a single source file
+#[derive(Default)]
+struct FileCoverage {
+    /// Line -> execution count
+    lines: BTreeMap<usize, usize>,
+    /// Name -> (declaration line, call count)
+    functions: BTreeMap<String, (usize, usize)>,
+}
+
+/// Records executed instructions and maps them to source lines with debug headers
+pub struct Coverage {
+    /// Execution count of each instruction location
+    hits: HashMap<usize, usize>,
+    /// Function hash -> call count
+    calls: HashMap<usize, usize>,
+}
+
+impl Coverage {
+    pub fn new() -> Self {
+        Coverage {
+            hits: HashMap::new(),
+            calls: HashMap::new(),
+        }
+    }
+
+    /// Write lcov report, source files of modules without a path are skipped
+    pub fn write_lcov<T: Write>(
+        &self,
+        debug_info: &DebugInfo,
+        writer: &mut T,
+    ) -> std::io::Result<()> {
+        let mut files: BTreeMap<String, FileCoverage> = BTreeMap::new();
+        for header in &debug_info.debug_headers {
+            let path = match get_real_path(header, debug_info) {
+                Some(path) => path,
+                None => continue,
+            };
+            let file = files.entry(path).or_default();
+            let line = header.pos.range_start.0 + 1;
+            match header.rtype {
+                DebugHeaderType::Function => {
+                    let calls = self.calls.get(&header.hash).copied().unwrap_or(0);
+                    file.functions.insert(header.name.clone(), (line, calls));
+                    let entry = file.lines.entry(line).or_insert(0);
+                    *entry = (*entry).max(calls);
+                }
+                // Native functions has no body to cover and parameters are not executed
+                DebugHeaderType::NativeFunction | DebugHeaderType::Parameter => (),
+                // An element is executed when its last instruction is executed, ranges can start
+                // with the jump of a previous branch
+                _ => {
+                    let hits = self.hits.get(&header.start_end.1).copied().unwrap_or(0);
+                    let entry = file.lines.entry(line).or_insert(0);
+                    *entry = (*entry).max(hits);
+                }
+            }
+        }
+
+        for (path, file) in files {
+            writeln!(writer, "TN:")?;
+            writeln!(writer, "SF:{}", path)?;
+            for (name, (line, _)) in &file.functions {
+                writeln!(writer, "FN:{},{}", line, name)?;
+            }
+            for (name, (_, calls)) in &file.functions {
+                writeln!(writer, "FNDA:{},{}", calls, name)?;
+            }
+            writeln!(writer, "FNF:{}", file.functions.len())?;
+            writeln!(
+                writer,
+                "FNH:{}",
+                file.functions
+                    .values()
+                    .filter(|(_, calls)| *calls > 0)
+                    .count()
+            )?;
+            for (line, hits) in &file.lines {
+                writeln!(writer, "DA:{},{}", line, hits)?;
+            }
+            writeln!(writer, "LF:{}", file.lines.len())?;
+            writeln!(
+                writer,
+                "LH:{}",
+                file.lines.values().filter(|hits| **hits > 0).count()
+            )?;
+            writeln!(writer, "end_of_record")?;
+        }
+        Ok(())
+    }
+}
+
+impl Tracer for Coverage {
+    fn trace(&mut self, step: &TraceStep) {
+        *self.hits.entry(step.pos).or_insert(0) += 1;
+        // Functions run their own header on entry, parents run it to skip the function
+        if let (Instructions::FN(_), AddressingValues::Immediate(hash)) = (
+            step.instruction.instruction,
+            step.instruction.addressing_value,
+        ) {
+            if hash.to_int() as usize == step.stack_id {
+                *self.calls.entry(step.stack_id).or_insert(0) += 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{compile, run_traced};
+
+    #[test]
+    fn lcov_report_counts_lines_and_functions() {
+        // Line numbers of the report are positions in this source
+        let program = compile(
+            "fn unused() {
+    println(\"unused\");
+}
+fn twice(a: int) : int {
+    ret a * 2;
+}
+fn main() {
+    v a = twice(1) + twice(2);
+    if a == 2 {
+        println(\"two\");
+    } else {
+        println(\"other\");
+    }
+}
+",
+        );
+        let debug_info = program.debug_info.clone().unwrap();
+        let mut coverage = Coverage::new();
+        assert_eq!(run_traced(program, &mut coverage), "other\n");
+        let mut lcov = Vec::new();
+        coverage.write_lcov(&debug_info, &mut lcov).unwrap();
+        // ellieCore has no path, only the program file is reported
+        assert_eq!(
+            String::from_utf8(lcov).unwrap(),
+            "TN:
+SF:/project/test/test.ei
+FN:7,main
+FN:4,twice
+FN:1,unused
+FNDA:1,main
+FNDA:2,twice
+FNDA:0,unused
+FNF:3
+FNH:2
+DA:1,0
+DA:2,0
+DA:4,2
+DA:5,2
+DA:7,1
+DA:8,1
+DA:9,1
+DA:10,0
+DA:12,1
+LF:9
+LH:6
+end_of_record
+"
+        );
+    }
+}
@@ -0,0 +1,710 @@
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{self, BufRead, Write},
+};
+
+use ellie_engine::{
+    ellie_core::defs::{DebugHeaderType, PlatformArchitecture},
+    ellie_vm::{
+        channel::{EllieModule, ModuleManager},
+        program::Program,
+        thread::{Isolate, Thread},
+        utils::{ProgramReader, ThreadExit},
+        verifier::verify,
+    },
+    vm::RFile,
+};
+use serde_json::{json, Value};
+
+use crate::{
+    condition::Condition,
+    debugger_messages::{render_raw_type, render_static_raw_type},
+    utils::{
+        find_module_file_path, get_line_breakpoint, get_source_line, resolve_module_file_path,
+        BreakPoint, DebuggerEvent, DebuggerState, DebuggerStatus, SourceLine, StepMode,
+        VariableValue,
+    },
+};
+
+/// Ellie VM runs a single thread
+const THREAD_ID: usize = 1;
+
+/// Reads and writes Debug Adapter Protocol messages, each message is a `Content-Length` header
+/// followed by a JSON body
+struct Connection<R: BufRead, W: Write> {
+    reader: R,
+    writer: W,
+    seq: usize,
+}
+
+impl<R: BufRead, W: Write> Connection<R, W> {
+    /// Read next message, [`None`] if the stream is closed
+    fn read(&mut self) -> Option<Value> {
+        let mut content_length = None;
+        loop {
+            let mut header = String::new();
+            if self.reader.read_line(&mut header).ok()? == 0 {
+                return None;
+            }
+            let header = header.trim();
+            if header.is_empty() {
+                break;
+            }
+            if let Some(length) = header.strip_prefix("Content-Length:") {
+                content_length = length.trim().parse::<usize>().ok();
+            }
+        }
+        let mut body = vec![0; content_length?];
+        self.reader.read_exact(&mut body).ok()?;
+        serde_json::from_slice(&body).ok()
+    }
+
+    fn send(&mut self, mut message: Value) {
+        self.seq += 1;
+        message["seq"] = json!(self.seq);
+        let body = message.to_string();
+        write!(
+            self.writer,
+            "Content-Length: {}\r\n\r\n{}",
+            body.len(),
+            body
+        )
+        .unwrap();
+        self.writer.flush().unwrap();
+    }
+
+    fn respond(&mut self, request: &Value, body: Value) {
+        self.send(json!({
+            "type": "response",
+            "request_seq": request["seq"],
+            "success": true,
+            "command": request["command"],
+            "body": body,
+        }));
+    }
+
+    fn respond_error(&mut self, request: &Value, message: &str) {
+        self.send(json!({
+            "type": "response",
+            "request_seq": request["seq"],
+            "success": false,
+            "command": request["command"],
+            "message": message,
+        }));
+    }
+
+    fn event(&mut self, event: &str, body: Value) {
+        self.send(json!({
+            "type": "event",
+            "event": event,
+            "body": body,
+        }));
+    }
+}
+
+fn read_program(path: &str) -> Result<Program, String> {
+    let mut file =
+        File::open(path).map_err(|e| format!("Failed to read the file: '{}' ({})", path, e))?;
+    let mut reader = RFile::new(&mut file);
+    let mut program_reader = ProgramReader::new(&mut reader);
+    let mut program = Program::new();
+    program
+        .build_from_reader(&mut program_reader)
+        .map_err(|e| format!("Failed to read program: {:?}", e))?;
+    verify(&program).map_err(|e| format!("Program failed verification: {:?}", e))?;
+    Ok(program)
+}
+
+fn type_name(type_id: u8) -> &'static str {
+    match type_id {
+        1 => "int",
+        2 => "float",
+        3 => "double",
+        4 => "byte",
+        5 => "bool",
+        6 => "string",
+        7 => "char",
+        8 => "void",
+        9 => "array",
+        10 => "null",
+        11 => "class",
+        12 => "function",
+        13 => "stack_reference",
+        14 => "heap_reference",
+        15 => "static_array",
+        16 => "i8",
+        17 => "i16",
+        18 => "i32",
+        19 => "i64",
+        20 => "u8",
+        21 => "u16",
+        22 => "u32",
+        23 => "u64",
+        24 => "bigint",
+        _ => "unknown",
+    }
+}
+
+/// Debug adapter session, source paths sent by the client are mapped to module file paths like
+/// `<ellie_module_X>/file.ei` of the debug info
+struct Session<'a> {
+    debugger_state: DebuggerStatus<'a>,
+    stop_on_entry: bool,
+    /// Module file path -> client source path
+    sources: HashMap<String, String>,
+}
+
+impl Session<'_> {
+    /// Module file path of a client source path, real paths are matched first then the paths
+    /// relative to their module
+    fn get_module_file_path(&self, path: &str) -> Option<String> {
+        find_module_file_path(self.debugger_state.debug_file.as_ref()?, path)
+    }
+
+    fn source(&self, module_file_path: &str) -> Value {
+        let path = self.sources.get(module_file_path).cloned().or_else(|| {
+            self.debugger_state
+                .debug_file
+                .as_ref()
+                .and_then(|debug_file| resolve_module_file_path(module_file_path, debug_file))
+        });
+        let name = module_file_path
+            .rsplit('/')
+            .next()
+            .unwrap_or(module_file_path);
+        match path {
+            Some(path) => json!({ "name": name, "path": path }),
+            None => json!({ "name": name }),
+        }
+    }
+
+    fn set_breakpoints(&mut self, arguments: &Value) -> Value {
+        let path = arguments["source"]["path"].as_str().unwrap_or_default();
+        let requested = arguments["breakpoints"]
+            .as_array()
+            .map(|breakpoints| {
+                breakpoints
+                    .iter()
+                    .filter(|breakpoint| breakpoint["line"].is_u64())
+                    .cloned()
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        let module_file_path = self.get_module_file_path(path);
+        if let Some(module_file_path) = &module_file_path {
+            self.sources
+                .insert(module_file_path.clone(), path.to_string());
+            self.debugger_state
+                .breakpoints
+                .retain(|break_point| break_point.module_name.as_ref() != Some(module_file_path));
+        }
+
+        let breakpoints = requested
+            .into_iter()
+            .map(|requested| {
+                let line = requested["line"].as_u64().unwrap() as usize;
+                let condition = match requested["condition"].as_str() {
+                    Some(condition) => match Condition::parse(condition) {
+                        Some(condition) => Some(condition),
+                        None => {
+                            return json!({
+                                "verified": false,
+                                "line": line,
+                                "message": format!("Invalid condition '{}'", condition),
+                            })
+                        }
+                    },
+                    None => None,
+                };
+                let hit_count = match requested["hitCondition"].as_str() {
+                    Some(hit_condition) => match hit_condition.trim().parse::<usize>() {
+                        Ok(hit_count) => Some(hit_count),
+                        Err(_) => {
+                            return json!({
+                                "verified": false,
+                                "line": line,
+                                "message": format!("Hit condition '{}' is not a number", hit_condition),
+                            })
+                        }
+                    },
+                    None => None,
+                };
+                let break_point = module_file_path.as_ref().and_then(|module_file_path| {
+                    get_line_breakpoint(
+                        self.debugger_state.debug_file.as_ref()?,
+                        module_file_path,
+                        line,
+                    )
+                });
+                let verified = break_point.is_some();
+                if let Some(break_point) = break_point {
+                    self.debugger_state.breakpoints.push(BreakPoint {
+                        condition,
+                        hit_count,
+                        ..break_point
+                    });
+                }
+                json!({ "verified": verified, "line": line })
+            })
+            .collect::<Vec<_>>();
+        json!({ "breakpoints": breakpoints })
+    }
+
+    fn stack_trace(&self) -> Value {
+        let thread = &self.debugger_state.thread;
+        let debug_file = self.debugger_state.debug_file.as_ref();
+        let frames = thread
+            .stack
+            .clone()
+            .into_iter()
+            .enumerate()
+            .rev()
+            .map(|(frame_id, stack)| {
+                let function = debug_file.and_then(|debug_file| {
+                    debug_file.debug_headers.iter().find(|header| {
+                        header.hash == stack.id && header.rtype == DebugHeaderType::Function
+                    })
+                });
+                let name = self.debugger_state.get_function_name(stack.id);
+                let line: Option<SourceLine> = debug_file
+                    .and_then(|debug_file| get_source_line(debug_file, stack.pos))
+                    .or_else(|| {
+                        function.map(|function| {
+                            (function.module_name.clone(), function.pos.range_start.0 + 1)
+                        })
+                    });
+                match line {
+                    Some((module_file_path, line)) => json!({
+                        "id": frame_id,
+                        "name": name,
+                        "source": self.source(&module_file_path),
+                        "line": line,
+                        "column": 1,
+                    }),
+                    None => json!({
+                        "id": frame_id,
+                        "name": name,
+                        "line": 0,
+                        "column": 0,
+                    }),
+                }
+            })
+            .collect::<Vec<_>>();
+        json!({ "stackFrames": frames, "totalFrames": frames.len() })
+    }
+
+    /// Variables of a frame, variable references are frame id + 1
+    fn variables(&self, variables_reference: usize) -> Value {
+        let stacks = self.debugger_state.thread.stack.clone();
+        let stack = match variables_reference
+            .checked_sub(1)
+            .and_then(|frame_id| stacks.get(frame_id))
+        {
+            Some(stack) => stack,
+            None => return json!({ "variables": [] }),
+        };
+        let variables = self
+            .debugger_state
+            .get_scope_variables(stack)
+            .into_iter()
+            .filter_map(|header| {
+                let (data, type_id) = match self.debugger_state.read_variable(stack, header)? {
+                    VariableValue::Stack(data) => (render_static_raw_type(data), data.type_id.id),
+                    VariableValue::Heap(data) => {
+                        let type_id = data.type_id.id;
+                        (render_raw_type(data), type_id)
+                    }
+                };
+                Some(json!({
+                    "name": header.name,
+                    "value": data["data_text"],
+                    "type": type_name(type_id),
+                    "variablesReference": 0,
+                }))
+            })
+            .collect::<Vec<_>>();
+        json!({ "variables": variables })
+    }
+}
+
+/// Run debugger as a Debug Adapter Protocol server on stdin and stdout
+///
+/// ## Arguments
+/// * `modules` - Native modules available to the program
+pub fn dap(modules: Vec<EllieModule>) {
+    let stdin = io::stdin();
+    let stdout = io::stdout();
+    let mut connection = Connection {
+        reader: stdin.lock(),
+        writer: stdout.lock(),
+        seq: 0,
+    };
+
+    let mut module_manager = ModuleManager::new();
+    for module in modules {
+        module_manager.register_module(module);
+    }
+    serve(&mut connection, module_manager);
+}
+
+/// Answer requests of the connection until it is closed or the client disconnects
+fn serve<R: BufRead, W: Write>(connection: &mut Connection<R, W>, module_manager: ModuleManager) {
+    let isolate = Isolate::new();
+    let mut thread = Thread::new(0, PlatformArchitecture::B64, isolate);
+    let mut session = Session {
+        debugger_state: DebuggerStatus {
+            vm_program: None,
+            program: None,
+            program_path: None,
+            debug_file: None,
+            step_request: None,
+            skip_breakpoint: false,
+            module_manager,
+            thread: &mut thread,
+            state: DebuggerState::ProgramNotLoaded,
+            breakpoints: Vec::new(),
+            watchpoints: Vec::new(),
+        },
+        stop_on_entry: false,
+        sources: HashMap::new(),
+    };
+
+    while let Some(request) = connection.read() {
+        if request["type"] != "request" {
+            continue;
+        }
+        let arguments = &request["arguments"];
+        let is_stopped = matches!(
+            session.debugger_state.state,
+            DebuggerState::Stepped | DebuggerState::WaitingAtBreakpoint(_)
+        );
+
+        match request["command"].as_str().unwrap_or_default() {
+            "initialize" => {
+                connection.respond(
+                    &request,
+                    json!({
+                        "supportsConfigurationDoneRequest": true,
+                        "supportsConditionalBreakpoints": true,
+                        "supportsHitConditionalBreakpoints": true,
+                    }),
+                );
+            }
+            "launch" => {
+                let path = arguments["program"].as_str().unwrap_or_default();
+                match read_program(path) {
+                    Ok(program) => {
+                        session.debugger_state.load_program(program);
+                        session.debugger_state.program_path = Some(path.to_string());
+                        session.stop_on_entry = arguments["stopOnEntry"].as_bool() == Some(true);
+                        connection.respond(&request, json!({}));
+                        connection.event("initialized", json!({}));
+                    }
+                    Err(error) => connection.respond_error(&request, &error),
+                }
+            }
+            "setBreakpoints" => {
+                let body = session.set_breakpoints(arguments);
+                connection.respond(&request, body);
+            }
+            "configurationDone" => {
+                connection.respond(&request, json!({}));
+                if session.debugger_state.state == DebuggerState::ProgramLoaded {
+                    let main = session.debugger_state.program.as_ref().unwrap().main;
+                    session.debugger_state.thread.build_thread(main);
+                    if session.stop_on_entry {
+                        session.debugger_state.state = DebuggerState::Stepped;
+                        connection.event(
+                            "stopped",
+                            json!({ "reason": "entry", "threadId": THREAD_ID }),
+                        );
+                    } else {
+                        session.debugger_state.state = DebuggerState::Running;
+                    }
+                }
+            }
+            "threads" => {
+                connection.respond(
+                    &request,
+                    json!({ "threads": [{ "id": THREAD_ID, "name": "main" }] }),
+                );
+            }
+            "stackTrace" if is_stopped => {
+                let body = session.stack_trace();
+                connection.respond(&request, body);
+            }
+            "scopes" if is_stopped => {
+                let frame_id = arguments["frameId"].as_u64().unwrap_or_default();
+                connection.respond(
+                    &request,
+                    json!({
+                        "scopes": [{
+                            "name": "Locals",
+                            "variablesReference": frame_id + 1,
+                            "expensive": false,
+                        }]
+                    }),
+                );
+            }
+            "variables" if is_stopped => {
+                let variables_reference =
+                    arguments["variablesReference"].as_u64().unwrap_or_default() as usize;
+                let body = session.variables(variables_reference);
+                connection.respond(&request, body);
+            }
+            "continue" if is_stopped => {
+                connection.respond(&request, json!({ "allThreadsContinued": true }));
+                session.debugger_state.resume(None);
+            }
+            command @ ("next" | "stepIn" | "stepOut") if is_stopped => {
+                let mode = match command {
+                    "stepIn" => StepMode::Into,
+                    "stepOut" => StepMode::Out,
+                    _ => StepMode::Over,
+                };
+                if mode != StepMode::Out && session.debugger_state.debug_file.is_none() {
+                    connection.respond_error(
+                        &request,
+                        "Program has no debug info, it is compiled with debug info stripped",
+                    );
+                    continue;
+                }
+                connection.respond(&request, json!({}));
+                session.debugger_state.resume(Some(mode));
+            }
+            "stackTrace" | "scopes" | "variables" | "continue" | "next" | "stepIn" | "stepOut" => {
+                connection.respond_error(
+                    &request,
+                    &format!(
+                        "Debugger is on '{}' state but expected to be on 'WaitingAtBreakpoint' state",
+                        session.debugger_state.state.to_string()
+                    ),
+                );
+            }
+            "disconnect" | "terminate" => {
+                connection.respond(&request, json!({}));
+                break;
+            }
+            command => {
+                connection.respond_error(&request, &format!("Unsupported request '{}'", command));
+            }
+        }
+
+        if session.debugger_state.state == DebuggerState::Running {
+            match session.debugger_state.run() {
+                DebuggerEvent::Stepped(_) => {
+                    connection.event(
+                        "stopped",
+                        json!({ "reason": "step", "threadId": THREAD_ID }),
+                    );
+                }
+                DebuggerEvent::HitBreakpoint(_) => connection.event(
+                    "stopped",
+                    json!({ "reason": "breakpoint", "threadId": THREAD_ID }),
+                ),
+                DebuggerEvent::ConditionError(error, _) => connection.event(
+                    "stopped",
+                    json!({
+                        "reason": "breakpoint",
+                        "threadId": THREAD_ID,
+                        "text": format!("Breakpoint condition can't be evaluated: {}", error),
+                    }),
+                ),
+                DebuggerEvent::WatchpointHit(_, _) => connection.event(
+                    "stopped",
+                    json!({ "reason": "data breakpoint", "threadId": THREAD_ID }),
+                ),
+                DebuggerEvent::ThreadExit(exit) => {
+                    let exit_code = match exit {
+                        ThreadExit::Panic(panic) => {
+                            connection.event(
+                                "output",
+                                json!({
+                                    "category": "stderr",
+                                    "output": format!("Thread panic, reason: {:?}\n", panic.reason),
+                                }),
+                            );
+                            1
+                        }
+                        ThreadExit::ExitGracefully => 0,
+                    };
+                    connection.event("exited", json!({ "exitCode": exit_code }));
+                    connection.event("terminated", json!({}));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{build, module_manager};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    const CODE: &str = "fn main() {
+    v a = 1;
+    v b = a + 2;
+    println(\"b \" + b);
+}
+";
+
+    /// Frame requests as the client does, requests are numbered from 1
+    fn frame(requests: &[Value]) -> Vec<u8> {
+        let mut connection = Connection {
+            reader: io::empty(),
+            writer: Vec::new(),
+            seq: 0,
+        };
+        for request in requests {
+            connection.send(request.clone());
+        }
+        connection.writer
+    }
+
+    /// Serve framed requests, returns messages sent to the client and what program wrote
+    fn session(requests: &[Value]) -> (Vec<Value>, String) {
+        // Tests run in parallel, each session reads its own program file
+        static SESSIONS: AtomicUsize = AtomicUsize::new(0);
+        let program_path = std::env::temp_dir().join(format!(
+            "ellievm_dap_test_{}_{}.eic",
+            std::process::id(),
+            SESSIONS.fetch_add(1, Ordering::Relaxed)
+        ));
+        std::fs::write(&program_path, build(CODE)).unwrap();
+        let requests = requests
+            .iter()
+            .map(|request| {
+                let mut request = request.clone();
+                if request["command"] == "launch" {
+                    request["arguments"]["program"] = json!(program_path.to_str().unwrap());
+                }
+                request
+            })
+            .collect::<Vec<_>>();
+
+        let input = frame(&requests);
+        let (module_manager, output) = module_manager();
+        let mut connection = Connection {
+            reader: input.as_slice(),
+            writer: Vec::new(),
+            seq: 0,
+        };
+        serve(&mut connection, module_manager);
+        std::fs::remove_file(&program_path).unwrap();
+
+        let mut sent = Connection {
+            reader: connection.writer.as_slice(),
+            writer: io::sink(),
+            seq: 0,
+        };
+        let mut messages = Vec::new();
+        while let Some(message) = sent.read() {
+            messages.push(message);
+        }
+        let output = output.lock().unwrap().clone();
+        (messages, output)
+    }
+
+    fn request(command: &str, arguments: Value) -> Value {
+        json!({ "type": "request", "command": command, "arguments": arguments })
+    }
+
+    fn set_breakpoints(lines: &[usize]) -> Value {
+        request(
+            "setBreakpoints",
+            json!({
+                "source": { "path": "/project/test/test.ei" },
+                "breakpoints": lines.iter().map(|line| json!({ "line": line })).collect::<Vec<_>>(),
+            }),
+        )
+    }
+
+    fn response<'a>(messages: &'a [Value], command: &str) -> &'a Value {
+        messages
+            .iter()
+            .find(|message| message["type"] == "response" && message["command"] == command)
+            .unwrap_or_else(|| panic!("No response to '{}'", command))
+    }
+
+    fn events(messages: &[Value]) -> Vec<&str> {
+        messages
+            .iter()
+            .filter(|message| message["type"] == "event")
+            .map(|message| message["event"].as_str().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn initialize_answered_with_capabilities() {
+        let (messages, _) = session(&[request("initialize", json!({}))]);
+        assert_eq!(messages.len(), 1);
+        let initialize = response(&messages, "initialize");
+        assert_eq!(initialize["success"], true);
+        assert_eq!(initialize["request_seq"], 1);
+        assert_eq!(initialize["body"]["supportsConfigurationDoneRequest"], true);
+        assert_eq!(initialize["body"]["supportsConditionalBreakpoints"], true);
+    }
+
+    #[test]
+    fn breakpoints_verified_on_lines_with_code() {
+        let (messages, _) = session(&[
+            request("initialize", json!({})),
+            request("launch", json!({})),
+            set_breakpoints(&[3, 100]),
+        ]);
+        assert_eq!(response(&messages, "launch")["success"], true);
+        assert_eq!(
+            response(&messages, "setBreakpoints")["body"]["breakpoints"],
+            json!([
+                { "verified": true, "line": 3 },
+                { "verified": false, "line": 100 },
+            ])
+        );
+    }
+
+    #[test]
+    fn stopped_at_breakpoint_and_continued_to_exit() {
+        let (messages, output) = session(&[
+            request("initialize", json!({})),
+            request("launch", json!({})),
+            set_breakpoints(&[3]),
+            request("configurationDone", json!({})),
+            request("stackTrace", json!({ "threadId": THREAD_ID })),
+            request("continue", json!({ "threadId": THREAD_ID })),
+            request("disconnect", json!({})),
+        ]);
+        assert_eq!(
+            events(&messages),
+            vec!["initialized", "stopped", "exited", "terminated"]
+        );
+        let stopped = messages
+            .iter()
+            .find(|message| message["event"] == "stopped")
+            .unwrap();
+        assert_eq!(stopped["body"]["reason"], "breakpoint");
+
+        let frames = &response(&messages, "stackTrace")["body"]["stackFrames"];
+        assert_eq!(frames[0]["line"], 3);
+        assert_eq!(frames[0]["source"]["path"], "/project/test/test.ei");
+
+        assert_eq!(response(&messages, "continue")["success"], true);
+        let exited = messages
+            .iter()
+            .find(|message| message["event"] == "exited")
+            .unwrap();
+        assert_eq!(exited["body"]["exitCode"], 0);
+        assert_eq!(output, "b 3\n");
+    }
+
+    #[test]
+    fn continue_rejected_before_stopping() {
+        let (messages, _) = session(&[
+            request("initialize", json!({})),
+            request("continue", json!({ "threadId": THREAD_ID })),
+        ]);
+        assert_eq!(response(&messages, "continue")["success"], false);
+    }
+}
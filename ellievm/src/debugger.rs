@@ -1,29 +1,77 @@
 use std::{
     collections::{HashMap, HashSet},
     fs::File,
-    io::{self, Read, Write},
+    io::{self, Write},
 };
 
 use ellie_engine::{
     ellie_core::defs::PlatformArchitecture,
     ellie_vm::{
-        channel::ModuleManager,
-        program::{Program, VmProgram},
+        channel::{EllieModule, ModuleManager},
+        program::Program,
         raw_type::RawType,
         thread::{Isolate, Thread},
-        utils::{ProgramReader, StepResult, ThreadExit},
+        utils::{ProgramReader, ThreadExit},
+        verifier::verify,
     },
-    vm::{parse_debug_file, RFile},
+    vm::RFile,
 };
 
 use crate::{
-    commands::{parse_args, parse_command, BuildDebuggerArgTypes, DebuggerCommands, COMMANDS},
+    commands::{
+        parse_args, parse_command, split_input, BuildDebuggerArgTypes, DebuggerCommands, COMMANDS,
+    },
+    condition::Condition,
     debugger_messages::*,
     stream::InputStream,
-    utils::{BreakPoint, DebuggerState, DebuggerStatus},
+    utils::{
+        get_line_breakpoint, BreakPoint, DebuggerEvent, DebuggerState, DebuggerStatus,
+        HotSwapError, StepMode, VariableValue, WatchTarget,
+    },
 };
 
-pub fn debug(json_output: bool, imported_commands: Vec<String>) {
+/// Read and verify program at the path
+fn read_program(path: &str) -> Result<Program, EllieMessage> {
+    let mut file = File::open(path).map_err(|e| {
+        EllieMessage::new_with_variables(
+            "error",
+            "Failed to read the file: '{file}' ({error})",
+            5,
+            {
+                let mut hash_map = HashMap::new();
+                hash_map.insert("file".to_string(), path.to_string());
+                hash_map.insert("error".to_string(), e.to_string());
+                hash_map
+            },
+        )
+    })?;
+
+    let mut reader = RFile::new(&mut file);
+    let mut program_reader = ProgramReader::new(&mut reader);
+    let mut program = Program::new();
+    program
+        .build_from_reader(&mut program_reader)
+        .map_err(|e| {
+            EllieMessage::new_with_variables("error", "Failed to read program: {error}", 5, {
+                let mut hash_map = HashMap::new();
+                hash_map.insert("error".to_string(), format!("{:#?}", e));
+                hash_map
+            })
+        })?;
+
+    if let Err(errors) = verify(&program) {
+        let mut verify_error = PROGRAM_VERIFY_ERROR.clone();
+        verify_error.variables = {
+            let mut variables = HashMap::new();
+            variables.insert("error".to_string(), format!("{:?}", errors));
+            Some(variables)
+        };
+        return Err(verify_error);
+    }
+    Ok(program)
+}
+
+pub fn debug(json_output: bool, imported_commands: Vec<String>, modules: Vec<EllieModule>) {
     let output_message = |message: &EllieMessage| {
         if json_output {
             println!("{}", message.build_json_message());
@@ -32,11 +80,11 @@ pub fn debug(json_output: bool, imported_commands: Vec<String>) {
         }
     };
 
-    //let mut module_manager = ModuleManager::new();
-    ////Register incoming modules
-    //for module in debugger_settings.modules {
-    //    module_manager.register_module(module);
-    //}
+    let mut module_manager = ModuleManager::new();
+    //Register incoming modules, they're kept across program reloads
+    for module in modules {
+        module_manager.register_module(module);
+    }
 
     let isolate = Isolate::new();
     let mut thread = Thread::new(0, PlatformArchitecture::B64, isolate);
@@ -44,12 +92,15 @@ pub fn debug(json_output: bool, imported_commands: Vec<String>) {
     let mut debugger_state = DebuggerStatus {
         vm_program: None,
         program: None,
+        program_path: None,
         debug_file: None,
-        step: false,
-        module_manager: ModuleManager::new(),
+        step_request: None,
+        skip_breakpoint: false,
+        module_manager,
         thread: &mut thread,
         state: DebuggerState::ProgramNotLoaded,
         breakpoints: Vec::new(),
+        watchpoints: Vec::new(),
     };
 
     let mut binding = io::stdin().lock();
@@ -61,57 +112,88 @@ pub fn debug(json_output: bool, imported_commands: Vec<String>) {
 
     loop {
         if debugger_state.state == DebuggerState::Running {
-            let thread = &mut debugger_state.thread;
-            let current_stack = thread.stack.last();
-            if debugger_state.step {
-                debugger_state.state = DebuggerState::Stepped;
-                output_message(&STEPPED);
-            } else if let Some(current_stack) = current_stack {
-                let break_point = debugger_state
-                    .breakpoints
-                    .iter()
-                    .find(|break_point| break_point.stack_location == current_stack.pos);
-                if let Some(break_point) = break_point {
-                    debugger_state.state =
-                        DebuggerState::WaitingAtBreakpoint((*break_point).clone());
-                    output_message(&HIT_BREAKPOINT);
-                }
-            }
-
-            let step_info = thread.step(
-                &mut debugger_state.module_manager,
-                debugger_state.vm_program.as_mut().unwrap(),
-            );
-
-            match &step_info {
-                StepResult::Step => (),
-                StepResult::ThreadExit(e) => {
-                    match e {
-                        ThreadExit::Panic(panic) => {
+            let event = debugger_state.run();
+            match &event {
+                DebuggerEvent::Stepped(line)
+                | DebuggerEvent::HitBreakpoint(line)
+                | DebuggerEvent::ConditionError(_, line)
+                | DebuggerEvent::WatchpointHit(_, line) => {
+                    match &event {
+                        DebuggerEvent::ConditionError(error, _) => {
+                            let condition = match &debugger_state.state {
+                                DebuggerState::WaitingAtBreakpoint(break_point) => break_point
+                                    .condition
+                                    .as_ref()
+                                    .map(|condition| condition.source.clone())
+                                    .unwrap_or_default(),
+                                _ => String::new(),
+                            };
                             output_message(&{
-                                let mut thread_panic = THREAD_PANIC.clone();
+                                let mut condition_error = BREAKPOINT_CONDITION_ERROR.clone();
                                 let mut variables = HashMap::new();
-                                variables.insert(
-                                    "panic_reason".to_string(),
-                                    format!("{:?}", panic.reason),
-                                );
-                                variables.insert(
-                                    "panic_code_location".to_string(),
-                                    format!("{:?}", panic.code_location),
-                                );
-                                variables.insert(
-                                    "panic_code_location".to_string(),
-                                    format!("{:?}", panic.stack_trace),
-                                );
-                                thread_panic.variables = Some(variables);
-                                thread_panic
+                                variables.insert("condition".to_string(), condition);
+                                variables.insert("error".to_string(), error.clone());
+                                condition_error.variables = Some(variables);
+                                condition_error
                             });
+                            output_message(&HIT_BREAKPOINT);
                         }
-                        ThreadExit::ExitGracefully => {
-                            output_message(&THREAD_EXITED_GRACEFULLY);
+                        DebuggerEvent::WatchpointHit(changes, _) => {
+                            output_message(&WATCHPOINT_HIT);
+                            for change in changes {
+                                output_message(&{
+                                    let mut change_entry = WATCHPOINT_CHANGE_ENTRY.clone();
+                                    let mut variables = render_watch_target(change.target);
+                                    variables.insert(
+                                        "old_value".to_string(),
+                                        render_variable_value(change.old.clone()),
+                                    );
+                                    variables.insert(
+                                        "new_value".to_string(),
+                                        render_variable_value(change.new.clone()),
+                                    );
+                                    change_entry.variables = Some(variables);
+                                    change_entry
+                                });
+                            }
+                        }
+                        _ if matches!(debugger_state.state, DebuggerState::Stepped) => {
+                            output_message(&STEPPED);
                         }
+                        _ => output_message(&HIT_BREAKPOINT),
+                    }
+                    if let Some((module_file_path, line)) = line.clone() {
+                        output_message({
+                            let mut source_location = SOURCE_LOCATION_ENTRY.clone();
+                            source_location.variables = {
+                                let mut variables = HashMap::new();
+                                variables.insert("module_file_path".to_string(), module_file_path);
+                                variables.insert("line".to_string(), line.to_string());
+                                Some(variables)
+                            };
+                            &source_location.clone()
+                        });
                     }
-                    debugger_state.state = DebuggerState::ProgramCompleted;
+                }
+                DebuggerEvent::ThreadExit(ThreadExit::Panic(panic)) => {
+                    output_message(&{
+                        let mut thread_panic = THREAD_PANIC.clone();
+                        let mut variables = HashMap::new();
+                        variables.insert("panic_reason".to_string(), format!("{:?}", panic.reason));
+                        variables.insert(
+                            "panic_code_location".to_string(),
+                            format!("{:?}", panic.code_location),
+                        );
+                        variables.insert(
+                            "panic_code_location".to_string(),
+                            format!("{:?}", panic.stack_trace),
+                        );
+                        thread_panic.variables = Some(variables);
+                        thread_panic
+                    });
+                }
+                DebuggerEvent::ThreadExit(ThreadExit::ExitGracefully) => {
+                    output_message(&THREAD_EXITED_GRACEFULLY);
                 }
             }
             continue;
@@ -146,9 +228,7 @@ pub fn debug(json_output: bool, imported_commands: Vec<String>) {
         if !json_output {
             println!();
         }
-        let args = input.split_whitespace().collect::<Vec<&str>>();
-        let command = &args[0];
-        let args = args[1..].to_vec();
+        let (command, args) = split_input(&input);
         let args = parse_args(args);
         let command = parse_command(command, args.clone(), json_output);
 
@@ -277,98 +357,16 @@ pub fn debug(json_output: bool, imported_commands: Vec<String>) {
                         _ => unreachable!(),
                     };
 
-                    let debug_file = match &matched.args[1].value_type {
-                        BuildDebuggerArgTypes::String(debug_file) => debug_file.to_string(),
-                        _ => unreachable!(),
-                    };
-
-                    let mut main_file = match File::open(&main_file) {
-                        Ok(e) => e,
-                        Err(e) => {
-                            output_message(&EllieMessage::new_with_variables(
-                                "error",
-                                "Failed to read the file: '{file}' ({error})",
-                                5,
-                                {
-                                    let mut hash_map = HashMap::new();
-                                    hash_map.insert("file".to_string(), main_file);
-                                    hash_map.insert("error".to_string(), e.to_string());
-                                    hash_map
-                                },
-                            ));
-                            continue;
-                        }
-                    };
-
-                    let main_file = {
-                        let mut reader = RFile::new(&mut main_file);
-                        let mut program_reader = ProgramReader::new(&mut reader);
-                        let mut program = Program::new();
-                        match program.build_from_reader(&mut program_reader) {
-                            Ok(_) => program,
-                            Err(e) => {
-                                output_message(&EllieMessage::new_with_variables(
-                                    "error",
-                                    "Failed to read program: {error}",
-                                    5,
-                                    {
-                                        let mut hash_map = HashMap::new();
-                                        hash_map.insert("error".to_string(), format!("{:#?}", e));
-                                        hash_map
-                                    },
-                                ));
-                                continue;
-                            }
-                        }
-                    };
-
-                    let mut debug_file: File = match File::open(&debug_file) {
-                        Ok(e) => e,
-                        Err(e) => {
-                            output_message(&EllieMessage::new_with_variables(
-                                "error",
-                                "Failed to read the debug file: '{file}' ({error})",
-                                5,
-                                {
-                                    let mut hash_map = HashMap::new();
-                                    hash_map.insert("file".to_string(), debug_file);
-                                    hash_map.insert("error".to_string(), e.to_string());
-                                    hash_map
-                                },
-                            ));
+                    let program = match read_program(&main_file) {
+                        Ok(program) => program,
+                        Err(message) => {
+                            output_message(&message);
                             continue;
                         }
                     };
-                    let mut debug_file_text = String::new();
-                    debug_file.read_to_string(&mut debug_file_text).unwrap();
-
-                    let debug_file = {
-                        match parse_debug_file(debug_file_text) {
-                            Ok(e) => e,
-                            Err(e) => {
-                                output_message(&EllieMessage::new_with_variables(
-                                    "error",
-                                    "Failed to parse debug file: {error}",
-                                    7,
-                                    {
-                                        let mut hash_map = HashMap::new();
-                                        hash_map.insert("error".to_string(), e.to_string());
-                                        hash_map
-                                    },
-                                ));
-                                continue;
-                            }
-                        }
-                    };
-                    let mut vm_program = VmProgram::new();
-                    vm_program.fill_from_vector(main_file.instructions.clone());
-                    vm_program.fill_traces(main_file.native_call_traces.clone());
 
-                    debugger_state.program = Some(main_file);
-                    debugger_state.vm_program = Some(vm_program);
-                    debugger_state.debug_file = Some(debug_file);
-
-                    debugger_state.state = DebuggerState::ProgramLoaded;
+                    debugger_state.load_program(program);
+                    debugger_state.program_path = Some(main_file);
                     output_message(&PROGRAM_LOADED);
                 }
                 DebuggerCommands::Run => {
@@ -422,43 +420,225 @@ pub fn debug(json_output: bool, imported_commands: Vec<String>) {
                                 stack_location: *pos as usize,
                                 code_location: None,
                                 module_name: None,
+                                ..Default::default()
                             }
                         } else {
-                            let debug_headers =
-                                &debugger_state.debug_file.as_ref().unwrap().debug_headers;
-                            let filtered_headers_by_path = debug_headers.iter().find(|header| {
-                                header.module_name == *module_path
-                                    && header.pos.range_start.0 == (*pos + -1) as usize
-                            });
-
-                            if filtered_headers_by_path.is_none() {
-                                output_message(&CANT_FIND_ELEMENT_AT_LOCATION);
+                            if debugger_state.debug_file.is_none() {
+                                output_message(&DEBUG_FILE_REQUIRED);
                                 continue;
                             }
-                            let filtered_headers_by_path = filtered_headers_by_path.unwrap();
-                            BreakPoint {
-                                module_name: Some(filtered_headers_by_path.module_name.to_string()),
-                                stack_location: filtered_headers_by_path.start_end.0,
-                                code_location: Some(*pos as usize),
+                            match get_line_breakpoint(
+                                debugger_state.debug_file.as_ref().unwrap(),
+                                module_path,
+                                *pos as usize,
+                            ) {
+                                Some(break_point) => break_point,
+                                None => {
+                                    output_message(&CANT_FIND_ELEMENT_AT_LOCATION);
+                                    continue;
+                                }
                             }
                         }
                     };
                     debugger_state.breakpoints.push(break_point);
                     output_message(&BREAKPOINT_ADDED);
                 }
-                DebuggerCommands::Step => {
+                DebuggerCommands::BreakCondition | DebuggerCommands::BreakHitCount => {
+                    let index = match &matched.args[0].value_type {
+                        BuildDebuggerArgTypes::Int(index) => *index as usize,
+                        _ => unreachable!(),
+                    };
+                    if index >= debugger_state.breakpoints.len() {
+                        output_message(&{
+                            let mut cant_find_breakpoint = CANT_FIND_BREAKPOINT.clone();
+                            let mut variables = HashMap::new();
+                            variables.insert("index".to_string(), index.to_string());
+                            cant_find_breakpoint.variables = Some(variables);
+                            cant_find_breakpoint
+                        });
+                        continue;
+                    }
+                    let break_point = &mut debugger_state.breakpoints[index];
+                    match (&matched.command, &matched.args[1].value_type) {
+                        (
+                            DebuggerCommands::BreakCondition,
+                            BuildDebuggerArgTypes::String(condition),
+                        ) => match Condition::parse(condition) {
+                            Some(condition) => break_point.condition = Some(condition),
+                            None => {
+                                output_message(&INVALID_CONDITION);
+                                continue;
+                            }
+                        },
+                        // A single word is read as a number or bool, it can't be a condition
+                        (DebuggerCommands::BreakCondition, _) => {
+                            output_message(&INVALID_CONDITION);
+                            continue;
+                        }
+                        (_, BuildDebuggerArgTypes::Int(count)) => {
+                            break_point.hit_count = Some(*count as usize);
+                            break_point.hits = 0;
+                        }
+                        _ => unreachable!(),
+                    }
+                    output_message(&BREAKPOINT_UPDATED);
+                }
+                DebuggerCommands::Watch => {
+                    if debugger_state.state == DebuggerState::ProgramNotLoaded {
+                        output_message(&PROGRAM_NOT_LOADED);
+                        continue;
+                    }
+                    let location = match &matched.args[1].value_type {
+                        BuildDebuggerArgTypes::Int(location) => *location as usize,
+                        _ => unreachable!(),
+                    };
+                    let target = match &matched.args[0].value_type {
+                        BuildDebuggerArgTypes::String(memory) if memory == "stack" => {
+                            WatchTarget::Stack(location)
+                        }
+                        BuildDebuggerArgTypes::String(memory) if memory == "heap" => {
+                            WatchTarget::Heap(location)
+                        }
+                        BuildDebuggerArgTypes::String(memory) => {
+                            output_message(&{
+                                let mut invalid_memory = INVALID_MEMORY.clone();
+                                let mut variables = HashMap::new();
+                                variables.insert("memory".to_string(), memory.clone());
+                                invalid_memory.variables = Some(variables);
+                                invalid_memory
+                            });
+                            continue;
+                        }
+                        _ => unreachable!(),
+                    };
+                    debugger_state.add_watchpoint(target);
+                    output_message(&WATCHPOINT_ADDED);
+                }
+                DebuggerCommands::Step
+                | DebuggerCommands::StepInto
+                | DebuggerCommands::StepOver
+                | DebuggerCommands::StepOut => {
+                    if !matches!(
+                        debugger_state.state,
+                        DebuggerState::Stepped | DebuggerState::WaitingAtBreakpoint(_)
+                    ) {
+                        output_message(&NOT_IN_BREAKPOINT);
+                        continue;
+                    }
+                    let mode = match matched.command {
+                        DebuggerCommands::StepInto => StepMode::Into,
+                        DebuggerCommands::StepOver => StepMode::Over,
+                        DebuggerCommands::StepOut => StepMode::Out,
+                        _ => StepMode::Instruction,
+                    };
+                    if mode != StepMode::Instruction && debugger_state.debug_file.is_none() {
+                        output_message(&DEBUG_FILE_REQUIRED);
+                        continue;
+                    }
+                    output_message(&STEP_FORWARD);
+                    debugger_state.resume(Some(mode));
+                }
+                DebuggerCommands::Continue => {
                     if matches!(
                         debugger_state.state,
                         DebuggerState::Stepped | DebuggerState::WaitingAtBreakpoint(_)
                     ) {
-                        output_message(&STEP_FORWARD);
-                        debugger_state.step = true;
-                        debugger_state.state = DebuggerState::Running;
+                        output_message(&CONTINUING);
+                        debugger_state.resume(None);
                     } else {
                         output_message(&NOT_IN_BREAKPOINT);
                     }
                 }
-                DebuggerCommands::ReloadVm => todo!(),
+                DebuggerCommands::ReloadVm => {
+                    let program_path = match &debugger_state.program_path {
+                        Some(program_path) => program_path.clone(),
+                        None => {
+                            output_message(&PROGRAM_NOT_LOADED);
+                            continue;
+                        }
+                    };
+                    let hot_swap = matches!(
+                        matched.args.first().map(|arg| &arg.value_type),
+                        Some(BuildDebuggerArgTypes::Bool(true))
+                    );
+                    if hot_swap
+                        && !matches!(
+                            debugger_state.state,
+                            DebuggerState::Stepped | DebuggerState::WaitingAtBreakpoint(_)
+                        )
+                    {
+                        output_message(&NOT_IN_BREAKPOINT);
+                        continue;
+                    }
+
+                    let program = match read_program(&program_path) {
+                        Ok(program) => program,
+                        Err(message) => {
+                            output_message(&message);
+                            continue;
+                        }
+                    };
+
+                    if hot_swap {
+                        match debugger_state.hot_swap(program.clone()) {
+                            Ok(changed_functions) => {
+                                let function_names = changed_functions
+                                    .iter()
+                                    .map(|hash| debugger_state.get_function_name(*hash))
+                                    .collect::<Vec<_>>();
+                                let mut hot_swapped = PROGRAM_HOT_SWAPPED.clone();
+                                hot_swapped.variables = {
+                                    let mut variables = HashMap::new();
+                                    variables
+                                        .insert("functions".to_string(), function_names.join(", "));
+                                    Some(variables)
+                                };
+                                output_message(&hot_swapped);
+                            }
+                            // Instruction positions moved, program can only start over
+                            Err(HotSwapError::LayoutChanged) => {
+                                debugger_state.load_program(program);
+                                output_message(&HOT_SWAP_FALLBACK);
+                            }
+                            Err(HotSwapError::FunctionRunning(hash)) => {
+                                let mut hot_swap_error = HOT_SWAP_ERROR.clone();
+                                hot_swap_error.variables = {
+                                    let mut variables = HashMap::new();
+                                    variables.insert(
+                                        "error".to_string(),
+                                        format!(
+                                            "changed function '{}' is running",
+                                            debugger_state.get_function_name(hash)
+                                        ),
+                                    );
+                                    Some(variables)
+                                };
+                                output_message(&hot_swap_error);
+                                continue;
+                            }
+                        }
+                    } else {
+                        debugger_state.load_program(program);
+                        output_message(&PROGRAM_RELOADED);
+                    }
+
+                    for break_point in debugger_state.resolve_breakpoints() {
+                        let mut breakpoint_removed = BREAKPOINT_REMOVED.clone();
+                        breakpoint_removed.variables = {
+                            let mut variables = HashMap::new();
+                            variables.insert(
+                                "module_file_path".to_string(),
+                                break_point.module_name.unwrap_or_default(),
+                            );
+                            variables.insert(
+                                "code_location".to_string(),
+                                break_point.code_location.unwrap_or_default().to_string(),
+                            );
+                            Some(variables)
+                        };
+                        output_message(&breakpoint_removed);
+                    }
+                }
                 DebuggerCommands::ReadVariable => {
                     if matches!(
                         debugger_state.state,
@@ -504,59 +684,30 @@ pub fn debug(json_output: bool, imported_commands: Vec<String>) {
                             filtered_headers_by_path.unwrap()
                         };
 
-                        let stack_data = match debugger_state
-                            .thread
-                            .isolate
-                            .stack_memory
-                            .get(&stack.calculate_frame_pos(found_variable.start_end.1))
-                        {
-                            Some(e) => e,
-                            None => {
-                                output_message(&CANT_FIND_DATA_ON_STACK_MEM);
-                                continue;
-                            }
-                        };
-
-                        if stack_data.type_id.is_heap_reference() {
-                            let heap_data = match debugger_state
-                                .thread
-                                .isolate
-                                .heap_memory
-                                .get_def(&stack_data.to_uint())
-                            {
-                                Some(e) => e,
+                        let (read_from, data) =
+                            match debugger_state.read_variable(stack, found_variable) {
+                                Some(VariableValue::Stack(data)) => {
+                                    ("stack_memory", render_static_raw_type(data))
+                                }
+                                Some(VariableValue::Heap(data)) => {
+                                    ("heap_memory", render_raw_type(data))
+                                }
                                 None => {
                                     output_message(&CANT_FIND_DATA_ON_STACK_MEM);
                                     continue;
                                 }
                             };
 
-                            output_message({
-                                let mut read_at_data_entry = READ_AT_DATA_ENTRY.clone();
-                                read_at_data_entry.variables = {
-                                    let mut variables = HashMap::new();
-                                    variables
-                                        .insert("read_from".to_string(), "heap_memory".to_string());
-                                    variables.extend(render_raw_type(heap_data));
-                                    Some(variables)
-                                };
-                                &read_at_data_entry.clone()
-                            });
-                        } else {
-                            output_message({
-                                let mut read_at_data_entry = READ_AT_DATA_ENTRY.clone();
-                                read_at_data_entry.variables = {
-                                    let mut variables = HashMap::new();
-                                    variables.insert(
-                                        "read_from".to_string(),
-                                        "stack_memory".to_string(),
-                                    );
-                                    variables.extend(render_static_raw_type(stack_data));
-                                    Some(variables)
-                                };
-                                &read_at_data_entry.clone()
-                            });
-                        }
+                        output_message({
+                            let mut read_at_data_entry = READ_AT_DATA_ENTRY.clone();
+                            read_at_data_entry.variables = {
+                                let mut variables = HashMap::new();
+                                variables.insert("read_from".to_string(), read_from.to_string());
+                                variables.extend(data);
+                                Some(variables)
+                            };
+                            &read_at_data_entry.clone()
+                        });
                     } else {
                         output_message({
                             let mut wrong_state = DEBUGER_IS_NOT_ON_EXPECTED_STATE.clone();
@@ -585,6 +736,11 @@ pub fn debug(json_output: bool, imported_commands: Vec<String>) {
                         continue;
                     }
 
+                    if debugger_state.debug_file.is_none() {
+                        output_message(&DEBUG_FILE_REQUIRED);
+                        continue;
+                    }
+
                     output_message(&GET_PATHS_START);
 
                     let debug_headers = &debugger_state.debug_file.as_ref().unwrap().debug_headers;
@@ -636,11 +792,25 @@ pub fn debug(json_output: bool, imported_commands: Vec<String>) {
                         continue;
                     }
                     output_message(&GET_BREAKPOINTS_START);
-                    for breakpoint in debugger_state.breakpoints.iter() {
+                    for (index, breakpoint) in debugger_state.breakpoints.iter().enumerate() {
                         output_message({
                             let mut module_entry = GET_BREAKPOINTS_ENTRY.clone();
                             module_entry.variables = {
                                 let mut variables = HashMap::new();
+                                variables.insert("index".to_string(), index.to_string());
+                                variables.insert(
+                                    "condition".to_string(),
+                                    breakpoint
+                                        .condition
+                                        .as_ref()
+                                        .map(|condition| condition.source.clone())
+                                        .unwrap_or_default(),
+                                );
+                                variables.insert(
+                                    "hit_count".to_string(),
+                                    breakpoint.hit_count.unwrap_or_default().to_string(),
+                                );
+                                variables.insert("hits".to_string(), breakpoint.hits.to_string());
                                 variables.insert(
                                     "module_file_path".to_string(),
                                     breakpoint.module_name.clone().unwrap_or_default(),
@@ -661,6 +831,27 @@ pub fn debug(json_output: bool, imported_commands: Vec<String>) {
 
                     output_message(&GET_BREAKPOINTS_END);
                 }
+                DebuggerCommands::GetWatchpoints => {
+                    if debugger_state.state == DebuggerState::ProgramNotLoaded {
+                        output_message(&PROGRAM_NOT_LOADED);
+                        continue;
+                    }
+                    output_message(&GET_WATCHPOINTS_START);
+                    for (index, watchpoint) in debugger_state.watchpoints.iter().enumerate() {
+                        output_message(&{
+                            let mut watchpoint_entry = GET_WATCHPOINTS_ENTRY.clone();
+                            let mut variables = render_watch_target(watchpoint.target);
+                            variables.insert("index".to_string(), index.to_string());
+                            variables.insert(
+                                "value".to_string(),
+                                render_variable_value(watchpoint.value.clone()),
+                            );
+                            watchpoint_entry.variables = Some(variables);
+                            watchpoint_entry
+                        });
+                    }
+                    output_message(&GET_WATCHPOINTS_END);
+                }
                 DebuggerCommands::GetRegisters => {
                     if matches!(
                         debugger_state.state,
@@ -1,6 +1,8 @@
 use ellie_engine::ellie_vm::raw_type::{RawType, StaticRawType, TypeId};
 use std::collections::HashMap;
 
+use crate::utils::{VariableValue, WatchTarget};
+
 #[derive(Debug, Clone)]
 pub struct EllieMessage {
     pub r#type: String,
@@ -123,6 +125,7 @@ pub fn render_static_raw_type(value: StaticRawType) -> HashMap<String, String> {
                 13 => String::from("stack_reference"),
                 14 => String::from("heap_reference"),
                 15 => String::from("static_array"),
+                16..=23 => value.to_fixed_integer().to_string(),
                 _ => unreachable!("Wrong typeid"),
             };
             type_id.to_string()
@@ -156,6 +159,11 @@ pub fn render_raw_type(value: RawType) -> HashMap<String, String> {
                 13 => String::from("stack_reference"),
                 14 => String::from("heap_reference"),
                 15 => String::from("static_array"),
+                16..=23 => match value.to_register_raw() {
+                    Ok(e) => e.to_fixed_integer().to_string(),
+                    Err(_) => String::from("fixed_integer"),
+                },
+                24 => value.to_big_integer().to_string(),
                 _ => unreachable!("Wrong typeid"),
             };
             type_id.to_string()
@@ -165,6 +173,26 @@ pub fn render_raw_type(value: RawType) -> HashMap<String, String> {
     map
 }
 
+pub fn render_watch_target(target: WatchTarget) -> HashMap<String, String> {
+    let (memory, location) = match target {
+        WatchTarget::Stack(location) => ("stack", location),
+        WatchTarget::Heap(location) => ("heap", location),
+    };
+    let mut map = HashMap::new();
+    map.insert("memory".to_string(), memory.to_string());
+    map.insert("location".to_string(), location.to_string());
+    map
+}
+
+pub fn render_variable_value(value: Option<VariableValue>) -> String {
+    let rendered = match value {
+        Some(VariableValue::Stack(value)) => render_static_raw_type(value),
+        Some(VariableValue::Heap(value)) => render_raw_type(value),
+        None => return String::from("(empty)"),
+    };
+    rendered["data_text"].clone()
+}
+
 lazy_static! {
     // Error
     pub static ref UNKNOWN_COMMAND: EllieMessage = EllieMessage::new("error", "Unknown command", 1);
@@ -179,9 +207,15 @@ lazy_static! {
     pub static ref NOT_IN_BREAKPOINT: EllieMessage = EllieMessage::new("error", "Debugger is not on wait state", 25);
     pub static ref DEBUGER_IS_NOT_ON_EXPECTED_STATE: EllieMessage = EllieMessage::new_with_variables("error", "Debugger is on '{current_state}' state but expected to be on '{expected_state}' state", 33, HashMap::new());
     pub static ref CANT_FIND_VARIABLE: EllieMessage = EllieMessage::new("error", "Can't find a variable to read at position", 36);
-    pub static ref DEBUG_FILE_REQUIRED : EllieMessage = EllieMessage::new("error", "Debug file is required for this operation", 37);
+    pub static ref DEBUG_FILE_REQUIRED : EllieMessage = EllieMessage::new("error", "Program has no debug info, it is compiled with debug info stripped", 37);
     pub static ref CANT_FIND_DATA_ON_STACK_MEM : EllieMessage = EllieMessage::new("error", "Can't find data on stack memory", 38);
     pub static ref CANT_FIND_DATA_ON_HEAP_MEM : EllieMessage = EllieMessage::new("error", "Can't find data on heap memory", 39);
+    pub static ref PROGRAM_VERIFY_ERROR: EllieMessage = EllieMessage::new_with_variables("error", "Program failed verification: {error}", 41, HashMap::new());
+    pub static ref HOT_SWAP_ERROR: EllieMessage = EllieMessage::new_with_variables("error", "Program can't be hot swapped: {error}", 44, HashMap::new());
+    pub static ref BREAKPOINT_CONDITION_ERROR: EllieMessage = EllieMessage::new_with_variables("error", "Breakpoint condition '{condition}' can't be evaluated: {error}", 48, HashMap::new());
+    pub static ref INVALID_CONDITION: EllieMessage = EllieMessage::new("error", "Invalid condition, write it like 'x >= 3'", 49);
+    pub static ref CANT_FIND_BREAKPOINT: EllieMessage = EllieMessage::new_with_variables("error", "Can't find breakpoint at index {index}", 50, HashMap::new());
+    pub static ref INVALID_MEMORY: EllieMessage = EllieMessage::new_with_variables("error", "Invalid memory '{memory}', expected 'stack' or 'heap'", 52, HashMap::new());
     // Info
     pub static ref READY: EllieMessage = EllieMessage::new("info", "Ready", 0);
     pub static ref EXIT_MESSAGE: EllieMessage = EllieMessage::new("info", "Debugger exited", 10);
@@ -201,15 +235,28 @@ lazy_static! {
     pub static ref GET_HEAP_MEMORY_END: EllieMessage = EllieMessage::new("info", "Heap memory listing complete", 31);
     pub static ref STEP_FORWARD : EllieMessage = EllieMessage::new("info", "Stepping forward", 32);
     pub static ref STEPPED : EllieMessage = EllieMessage::new("log", "Stepped", 35);
+    pub static ref CONTINUING : EllieMessage = EllieMessage::new("info", "Continuing", 43);
+    pub static ref PROGRAM_RELOADED : EllieMessage = EllieMessage::new("info", "Program reloaded", 45);
+    pub static ref PROGRAM_HOT_SWAPPED : EllieMessage = EllieMessage::new_with_variables("info", "Program hot swapped, changed functions: {functions}", 46, HashMap::new());
+    pub static ref BREAKPOINT_REMOVED : EllieMessage = EllieMessage::new_with_variables("warning", "Breakpoint at {module_file_path}:{code_location} no longer points to an element, it is removed", 47, HashMap::new());
+    pub static ref HOT_SWAP_FALLBACK : EllieMessage = EllieMessage::new("warning", "Program layout is changed, it can't be hot swapped. Program is reloaded from the start", 59);
+    pub static ref BREAKPOINT_UPDATED : EllieMessage = EllieMessage::new("info", "Breakpoint updated", 51);
+    pub static ref WATCHPOINT_ADDED : EllieMessage = EllieMessage::new("info", "Watchpoint added", 53);
+    pub static ref WATCHPOINT_HIT : EllieMessage = EllieMessage::new("info", "Watchpoint hit", 54);
+    pub static ref GET_WATCHPOINTS_START : EllieMessage = EllieMessage::new("info", "Listing watchpoints", 55);
+    pub static ref GET_WATCHPOINTS_END : EllieMessage = EllieMessage::new("info", "Watchpoint listing complete", 57);
 
     // Data Feed
     pub static ref GET_PATHS_ENTRY : EllieMessage = EllieMessage::new("log", "Module Name: {module_name}, File Path: {module_path}, Module File Path: {module_file_path}", 14);
-    pub static ref GET_BREAKPOINTS_ENTRY : EllieMessage = EllieMessage::new("log", "Module File Path: {module_file_path}, Code Location: {code_location}, Stack Location: {stack_location}", 17);
+    pub static ref GET_BREAKPOINTS_ENTRY : EllieMessage = EllieMessage::new("log", "Index: {index}, Module File Path: {module_file_path}, Code Location: {code_location}, Stack Location: {stack_location}, Condition: {condition}, Hit Count: {hit_count}, Hits: {hits}", 17);
     pub static ref THREAD_PANIC : EllieMessage = EllieMessage::new("error", "Thread panic, reason: {panic_reason}, code location: {panic_code_location}", 21);
     pub static ref GET_REGISTERS_ENTRY : EllieMessage = EllieMessage::new("log", "Register {register_name}, TypeID {type_id}, Type Size: {type_size}, Data: {data} Data Text: {data_text}", 23);
     pub static ref GET_STACK_MEMORY_ENTRY : EllieMessage = EllieMessage::new("log", "Stack Location: {stack_location}, TypeID: {type_id}, Type Size: {type_size}, Data: {data}, Data Text: {data_text}", 27);
     pub static ref GET_HEAP_MEMORY_ENTRY : EllieMessage = EllieMessage::new("log", "Heap Location: {heap_location}, TypeID: {type_id}, Type Size: {type_size}, Data: {data}, Data Text: {data_text}", 30);
     pub static ref GET_LOCATION_ENTRY : EllieMessage = EllieMessage::new("log", "Frame Pos: {frame_pos}, Stack Pos: {stack_pos}, Real Pos: {real_pos}", 34);
+    pub static ref SOURCE_LOCATION_ENTRY : EllieMessage = EllieMessage::new("log", "Module File Path: {module_file_path}, Line: {line}", 42);
+    pub static ref GET_WATCHPOINTS_ENTRY : EllieMessage = EllieMessage::new("log", "Index: {index}, Memory: {memory}, Location: {location}, Value: {value}", 56);
+    pub static ref WATCHPOINT_CHANGE_ENTRY : EllieMessage = EllieMessage::new("log", "Memory: {memory}, Location: {location}, Old Value: {old_value}, New Value: {new_value}", 58);
     pub static ref READ_AT_DATA_ENTRY : EllieMessage = EllieMessage::new("log", "Read From: {read_from}, TypeID: {type_id}, Type Size: {type_size}, Data: {data}, Data Text: {data_text}", 40);
 
 }
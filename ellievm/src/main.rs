@@ -1,31 +1,43 @@
 mod commands;
+mod condition;
+mod coverage;
+mod dap;
 pub mod debugger;
 mod debugger_messages;
+mod profiler;
+mod repl;
 mod run;
 mod stream;
+#[cfg(test)]
+mod test_utils;
+mod trace;
 mod utils;
 
 #[macro_use]
 extern crate lazy_static;
 
 use ellie_engine::{
+    core_library,
     ellie_core::defs::PlatformArchitecture,
     ellie_renderer_utils::{
         options, outputs,
         utils::{CliColor, ColorDisplay, Colors, TextStyles},
     },
-    ellie_vm::{
-        channel::{EllieModule, FunctionElement, ModuleElements},
-        program::Program,
-        raw_type::StaticRawType,
-        utils::{ProgramReader, VmNativeAnswer, VmNativeCallParameters},
-    },
+    ellie_vm::{channel::EllieModule, program::Program, utils::ProgramReader, verifier::verify},
     engine_constants,
-    vm::{parse_debug_file, RFile},
+    vm::RFile,
 };
 use run::VmSettings;
 
-use std::{fs::File, io::Read, path::Path, time::SystemTime};
+use std::{fs::File, path::Path, sync::Arc};
+
+/// Native functions of ellieCore, program output goes to stderr
+fn ellie_core_module() -> EllieModule {
+    core_library::core_library_module(core_library::HostCallbacks {
+        write: Arc::new(|text| eprint!("{}", text)),
+        ..Default::default()
+    })
+}
 
 fn main() {
     let app = options::generate_ellievm_options();
@@ -105,6 +117,9 @@ fn main() {
                 json_log: matches.is_present("jsonLog"),
                 warnings: true,
                 heap_dump: matches.is_present("heapDump"),
+                trace_path: matches.value_of("trace").map(|e| e.to_string()),
+                profile_path: matches.value_of("profile").map(|e| e.to_string()),
+                coverage_path: matches.value_of("coverage").map(|e| e.to_string()),
                 architecture: match matches.value_of("targetArchitecture") {
                     Some(e) => {
                         if e == "64" {
@@ -130,98 +145,7 @@ fn main() {
                 modules: Vec::new(),
             };
 
-            let mut ellie_core_module = EllieModule::new("ellieCore".to_string());
-            ellie_core_module.register_element(ModuleElements::Function(FunctionElement::new(
-                "println",
-                Box::new(|_, args| {
-                    if args.len() != 1 {
-                        return VmNativeAnswer::RuntimeError(
-                            "Signature mismatch expected 1 argument(s)".to_string(),
-                        );
-                    }
-                    match &args[0] {
-                        VmNativeCallParameters::Static(_e) => VmNativeAnswer::RuntimeError(
-                            "Signature mismatch, expected 'dynamic' argument".to_string(),
-                        ),
-                        VmNativeCallParameters::Dynamic(dynamic_value) => {
-                            if dynamic_value.is_string() {
-                                eprintln!("{}", dynamic_value.to_string());
-                                VmNativeAnswer::Ok(VmNativeCallParameters::Static(
-                                    StaticRawType::from_void(),
-                                ))
-                            } else {
-                                VmNativeAnswer::RuntimeError(
-                                    "Signature mismatch expected 'string' argument".to_string(),
-                                )
-                            }
-                        }
-                    }
-                }),
-            )));
-
-            ellie_core_module.register_element(ModuleElements::Function(FunctionElement::new(
-                "timestamp",
-                Box::new(|_, args| {
-                    if !args.is_empty() {
-                        return VmNativeAnswer::RuntimeError(
-                            "Signature mismatch expected 0 argument(s)".to_string(),
-                        );
-                    }
-                    VmNativeAnswer::Ok(VmNativeCallParameters::Static(StaticRawType::from_int(
-                        SystemTime::now()
-                            .duration_since(SystemTime::UNIX_EPOCH)
-                            .unwrap()
-                            .as_nanos() as isize,
-                    )))
-                }),
-            )));
-
-            vm_settings.modules.push(ellie_core_module);
-
-            let debug_file = match matches.value_of("debugInfo") {
-                Some(e) => {
-                    let path = Path::new(e);
-                    if path.is_file() {
-                        let mut file_contents = String::new();
-                        match File::open(e) {
-                            Ok(mut e) => {
-                                e.read_to_string(&mut file_contents).unwrap();
-                                match parse_debug_file(file_contents) {
-                                    Ok(e) => Some(e),
-                                    Err(e) => {
-                                        println!(
-                                            "{}Error:{} {}",
-                                            cli_color.color(Colors::Red),
-                                            cli_color.color(Colors::Reset),
-                                            e
-                                        );
-                                        std::process::exit(1);
-                                    }
-                                }
-                            }
-                            Err(e) => {
-                                println!(
-                                    "{}Error:{} Failed to read file {}[{}]{}",
-                                    cli_color.color(Colors::Red),
-                                    cli_color.color(Colors::Reset),
-                                    cli_color.color(Colors::Cyan),
-                                    e,
-                                    cli_color.color(Colors::Reset)
-                                );
-                                std::process::exit(1);
-                            }
-                        }
-                    } else {
-                        println!(
-                            "{}Error:{} Given path is not a file",
-                            cli_color.color(Colors::Red),
-                            cli_color.color(Colors::Reset)
-                        );
-                        std::process::exit(1);
-                    }
-                }
-                None => None,
-            };
+            vm_settings.modules.push(ellie_core_module());
 
             let path = Path::new(matches.value_of("target").unwrap());
             let program = if path.exists() {
@@ -274,6 +198,26 @@ fn main() {
                 );
                 std::process::exit(1);
             };
+
+            if let Err(errors) = verify(&program) {
+                println!(
+                    "{}Error:{} Program failed verification",
+                    cli_color.color(Colors::Red),
+                    cli_color.color(Colors::Reset),
+                );
+                for error in errors {
+                    println!(
+                        "    {}{:?}{} at {}",
+                        cli_color.color(Colors::Cyan),
+                        error.kind,
+                        cli_color.color(Colors::Reset),
+                        error.location,
+                    );
+                }
+                std::process::exit(1);
+            }
+
+            let debug_file = program.debug_info.clone();
             run::run(program, vm_settings, debug_file);
         }
         Some(("debug", matches)) => {
@@ -430,7 +374,67 @@ fn main() {
                 .unwrap_or_default()
                 .map(str::to_string)
                 .collect::<Vec<_>>();
-            debugger::debug(matches.is_present("jsonLog"), imported_commands);
+            debugger::debug(
+                matches.is_present("jsonLog"),
+                imported_commands,
+                vec![ellie_core_module()],
+            );
+        }
+        Some(("dap", _)) => {
+            dap::dap(vec![ellie_core_module()]);
+        }
+        Some(("repl", matches)) => {
+            let architecture = match matches.value_of("targetArchitecture") {
+                Some("32") => PlatformArchitecture::B32,
+                Some("16") => PlatformArchitecture::B16,
+                _ => PlatformArchitecture::B64,
+            };
+            let mut modules = Vec::new();
+            for module in matches.values_of("insertModule").unwrap_or_default() {
+                let path = module.trim().split('=').collect::<Vec<_>>();
+                match repl::read_module(path[0].trim(), architecture) {
+                    Ok(parsed_module) => {
+                        modules.push((parsed_module, path.get(1).map(|e| e.trim().to_string())))
+                    }
+                    Err(e) => {
+                        println!(
+                            "{}Error:{} Failed to read module {}{}{} [{}]",
+                            cli_color.color(Colors::Red),
+                            cli_color.color(Colors::Reset),
+                            cli_color.color(Colors::Cyan),
+                            path[0],
+                            cli_color.color(Colors::Reset),
+                            e,
+                        );
+                        std::process::exit(1);
+                    }
+                }
+            }
+            if !matches.is_present("excludeStd")
+                && !modules
+                    .iter()
+                    .any(|(module, _)| module.name == core_library::ELLIE_CORE_LIBRARY_NAME)
+            {
+                match core_library::compile_core_library() {
+                    Ok(compile_output) => modules.push((compile_output.module, None)),
+                    Err(errors) => {
+                        println!(
+                            "{}Error:{} Failed to compile embedded ellieCore v{}, {} error(s) found",
+                            cli_color.color(Colors::Red),
+                            cli_color.color(Colors::Reset),
+                            core_library::ELLIE_CORE_LIBRARY_VERSION,
+                            errors.len(),
+                        );
+                        std::process::exit(1);
+                    }
+                }
+            }
+            repl::repl(repl::ReplSettings {
+                architecture,
+                modules,
+                native_modules: vec![ellie_core_module()],
+                exclude_std: matches.is_present("excludeStd"),
+            });
         }
         Some(("version", matches)) => {
             if matches.is_present("detailed") {
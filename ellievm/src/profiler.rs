@@ -0,0 +1,187 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    io::Write,
+};
+
+use ellie_engine::{
+    ellie_core::defs::{DebugHeaderType, DebugInfo},
+    ellie_vm::trace::{TraceStep, Tracer},
+};
+
+use crate::utils::module_name_of;
+
+#[derive(Debug, Clone, Default)]
+pub struct FunctionProfile {
+    /// Steps taken in the function and the functions it called
+    pub inclusive: usize,
+    /// Steps taken in the function itself
+    pub exclusive: usize,
+}
+
+/// Aggregates steps into instruction counts, per function step counts and folded stacks
+pub struct Profiler {
+    names: HashMap<usize, String>,
+    call_stack: Vec<usize>,
+    pub total_steps: usize,
+    pub instruction_counts: BTreeMap<&'static str, usize>,
+    pub functions: HashMap<usize, FunctionProfile>,
+    /// Step count of each unique call stack
+    pub stacks: BTreeMap<Vec<usize>, usize>,
+}
+
+impl Profiler {
+    pub fn new(debug_info: Option<&DebugInfo>) -> Self {
+        let mut names = HashMap::new();
+        if let Some(debug_info) = debug_info {
+            for header in &debug_info.debug_headers {
+                if matches!(
+                    header.rtype,
+                    DebugHeaderType::Function | DebugHeaderType::NativeFunction
+                ) {
+                    let module_name =
+                        module_name_of(&header.module_name).unwrap_or(header.module_name.as_str());
+                    names.insert(header.hash, format!("{}::{}", module_name, header.name));
+                }
+            }
+        }
+        Profiler {
+            names,
+            call_stack: Vec::new(),
+            total_steps: 0,
+            instruction_counts: BTreeMap::new(),
+            functions: HashMap::new(),
+            stacks: BTreeMap::new(),
+        }
+    }
+
+    /// Function name from debug headers as `module::function`, class members are qualified with
+    /// their class like `module::class.function`. Hash if the program has no debug info
+    pub fn function_name(&self, hash: usize) -> String {
+        match self.names.get(&hash) {
+            Some(name) => name.clone(),
+            None => format!("fn#{}", hash),
+        }
+    }
+
+    /// Write folded stacks, each line is semicolon separated function names and a step count
+    pub fn write_folded<T: Write>(&self, writer: &mut T) -> std::io::Result<()> {
+        for (stack, count) in &self.stacks {
+            let names = stack
+                .iter()
+                .map(|hash| self.function_name(*hash))
+                .collect::<Vec<_>>();
+            writeln!(writer, "{} {}", names.join(";"), count)?;
+        }
+        Ok(())
+    }
+}
+
+impl Tracer for Profiler {
+    fn trace(&mut self, step: &TraceStep) {
+        self.total_steps += 1;
+        *self
+            .instruction_counts
+            .entry(step.instruction.instruction.mnemonic())
+            .or_insert(0) += 1;
+
+        self.call_stack.truncate(step.depth - 1);
+        self.call_stack.push(step.stack_id);
+
+        self.functions.entry(step.stack_id).or_default().exclusive += 1;
+        let mut counted = Vec::with_capacity(self.call_stack.len());
+        for hash in &self.call_stack {
+            // Recursive calls are counted once
+            if !counted.contains(hash) {
+                counted.push(*hash);
+                self.functions.entry(*hash).or_default().inclusive += 1;
+            }
+        }
+        *self.stacks.entry(self.call_stack.clone()).or_insert(0) += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{compile, run_traced};
+
+    const CODE: &str = "
+        fn add(a: int, b: int) : int {
+            ret a + b;
+        }
+        fn main() {
+            v numbers = [1];
+            numbers.push(2);
+            println(\"sum \" + add(1, 2));
+        }
+    ";
+
+    fn profile() -> Profiler {
+        let program = compile(CODE);
+        let mut profiler = Profiler::new(program.debug_info.as_ref());
+        assert_eq!(run_traced(program, &mut profiler), "sum 3\n");
+        profiler
+    }
+
+    #[test]
+    fn folded_stacks_named_by_module_and_function() {
+        let profiler = profile();
+        let mut folded = Vec::new();
+        profiler.write_folded(&mut folded).unwrap();
+        let mut stacks = String::from_utf8(folded)
+            .unwrap()
+            .lines()
+            .map(|line| line.rsplit_once(' ').unwrap().0.to_string())
+            .collect::<Vec<_>>();
+        stacks.sort();
+        assert_eq!(
+            stacks,
+            vec![
+                "test::main",
+                "test::main;ellieCore::array.push",
+                "test::main;ellieCore::println",
+                "test::main;test::add",
+            ]
+        );
+    }
+
+    #[test]
+    fn steps_counted_per_function() {
+        let profiler = profile();
+        let function = |name: &str| {
+            profiler
+                .functions
+                .iter()
+                .find(|(hash, _)| profiler.function_name(**hash) == name)
+                .map(|(_, profile)| profile.clone())
+                .unwrap()
+        };
+        let main = function("test::main");
+        let add = function("test::add");
+        assert_eq!(main.inclusive, profiler.total_steps);
+        assert!(main.exclusive < main.inclusive);
+        assert_eq!(add.inclusive, add.exclusive);
+        assert_eq!(
+            profiler.stacks.values().sum::<usize>(),
+            profiler.total_steps
+        );
+        assert_eq!(
+            profiler.instruction_counts.values().sum::<usize>(),
+            profiler.total_steps
+        );
+        // main calls add, push and println
+        assert_eq!(profiler.instruction_counts.get("CALL"), Some(&3));
+    }
+
+    #[test]
+    fn functions_named_by_hash_without_debug_info() {
+        let program = compile(CODE);
+        let main_hash = program.main.hash;
+        let mut profiler = Profiler::new(None);
+        run_traced(program, &mut profiler);
+        assert_eq!(
+            profiler.function_name(main_hash),
+            format!("fn#{}", main_hash)
+        );
+    }
+}
@@ -0,0 +1,743 @@
+use std::{
+    collections::HashMap,
+    io::{self, BufRead, Write},
+    panic::{self, AssertUnwindSafe},
+};
+
+use bincode::Options;
+use ellie_engine::{
+    compiler::parse_pages,
+    ellie_bytecode::assembler::{Assembler, PlatformAttributes},
+    ellie_core::{
+        defs::{DebugHeaderType, ModuleMap, PlatformArchitecture, Version},
+        error::Error,
+    },
+    ellie_parser::parser::Module,
+    ellie_renderer_utils::utils::{print_errors, read_file, CliColor, ColorDisplay, Colors},
+    ellie_tokenizer::tokenizer::ResolvedImport,
+    ellie_vm::{
+        channel::{EllieModule, ModuleManager},
+        heap_memory::decode_raw_type,
+        program::{Program, VmProgram},
+        raw_type::{RawType, StaticRawType},
+        stack::Stack,
+        thread::{Isolate, Registers, Thread},
+        utils::{ProgramReader, ThreadExit},
+    },
+    tokenizer,
+    utils::{CompilerSettings, MainProgram, ProgramRepository},
+    vm::RFile,
+};
+
+const REPL_MODULE_NAME: &str = "repl";
+const REPL_FILE_NAME: &str = "repl.ei";
+/// Nested arrays and classes deeper than this are not rendered
+const MAX_RENDER_DEPTH: usize = 16;
+
+pub struct ReplSettings {
+    pub architecture: PlatformArchitecture,
+    /// Parser modules and their code paths
+    pub modules: Vec<(Module, Option<String>)>,
+    /// Native modules registered to vm
+    pub native_modules: Vec<EllieModule>,
+    pub exclude_std: bool,
+}
+
+/// Read a parser module from binary
+pub fn read_module(path: &str, architecture: PlatformArchitecture) -> Result<Module, String> {
+    let file_content = std::fs::read(path).map_err(|e| e.to_string())?;
+    let config = bincode::options()
+        .with_big_endian()
+        .with_fixint_encoding()
+        .with_limit(match architecture {
+            PlatformArchitecture::B16 => 65535,
+            PlatformArchitecture::B32 => 4294967295,
+            PlatformArchitecture::B64 => 18446744073709551615,
+        });
+    config
+        .deserialize::<Module>(file_content.as_slice())
+        .map_err(|e| e.to_string())
+}
+
+enum Entry {
+    /// Top level element like function or class, placed after main
+    Definition(String),
+    /// Statement placed in main
+    Statement(String),
+    /// Expression which result is printed
+    Expression(String),
+}
+
+impl Entry {
+    fn new(input: &str) -> Entry {
+        let input = input.trim();
+        let is_definition = ["fn ", "class ", "enum ", "import ", "pub ", "pri ", "@"]
+            .iter()
+            .any(|keyword| input.starts_with(keyword));
+        if is_definition {
+            Entry::Definition(input.to_string())
+        } else if input.ends_with(';') || input.ends_with('}') {
+            Entry::Statement(input.to_string())
+        } else {
+            Entry::Expression(input.to_string())
+        }
+    }
+}
+
+struct Repository {
+    source: String,
+    modules: Vec<(Module, Option<String>)>,
+    used_modules: Vec<String>,
+}
+
+impl ProgramRepository for Repository {
+    fn read_main(&mut self) -> MainProgram {
+        MainProgram {
+            file_content: self.source.clone(),
+            file_name: REPL_FILE_NAME.to_string(),
+            file_hash: 0,
+            start_directory: format!("<ellie_module_{}>", REPL_MODULE_NAME),
+        }
+    }
+
+    fn read_module(
+        &mut self,
+        link_module: bool,
+        _current_path: String,
+        requested_path: String,
+    ) -> ResolvedImport {
+        if !link_module {
+            return ResolvedImport {
+                found: false,
+                resolve_error: "File imports are not supported in repl".to_string(),
+                ..Default::default()
+            };
+        }
+        match self.modules.iter().find(|(m, _)| m.name == requested_path) {
+            Some((module, _)) => {
+                self.used_modules.push(requested_path.clone());
+                ResolvedImport {
+                    found: true,
+                    hash: module.hash,
+                    path: requested_path,
+                    ..Default::default()
+                }
+            }
+            None => ResolvedImport {
+                found: false,
+                resolve_error: "Module not found".to_string(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+struct Session {
+    settings: ReplSettings,
+    definitions: Vec<String>,
+    statements: Vec<String>,
+    thread: Thread,
+    module_manager: ModuleManager,
+    /// Position main continues from, instructions of new statements start here
+    resume_pos: Option<usize>,
+    result_count: usize,
+    /// Class names by their hash, hashes change every compile so instances of previous
+    /// entries are named by the hashes of their programs
+    class_names: HashMap<usize, String>,
+}
+
+impl Session {
+    fn new(mut settings: ReplSettings) -> Self {
+        let mut module_manager = ModuleManager::new();
+        for module in settings.native_modules.drain(..) {
+            module_manager.register_module(module);
+        }
+        Session {
+            thread: Thread::new(0, settings.architecture, Isolate::new()),
+            settings,
+            definitions: Vec::new(),
+            statements: Vec::new(),
+            module_manager,
+            resume_pos: None,
+            result_count: 0,
+            class_names: HashMap::new(),
+        }
+    }
+
+    /// Build source of the session, main comes first so its instructions keep their positions
+    /// when new definitions are added
+    fn render_source(&self, definition: Option<&str>, statement: Option<&str>) -> String {
+        let mut source = String::from("fn main() {\n");
+        for statement in self.statements.iter().map(String::as_str).chain(statement) {
+            source += statement;
+            source += "\n";
+        }
+        source += "}\n";
+        for definition in self
+            .definitions
+            .iter()
+            .map(String::as_str)
+            .chain(definition)
+        {
+            source += definition;
+            source += "\n";
+        }
+        source
+    }
+
+    /// Compile source, compiler panics are reported as errors so the session survives them
+    fn compile(&self, source: String) -> Result<Program, String> {
+        panic::catch_unwind(AssertUnwindSafe(|| self.compile_source(source))).unwrap_or_else(
+            |payload| {
+                let message = match payload.downcast_ref::<String>() {
+                    Some(message) => message.clone(),
+                    None => payload
+                        .downcast_ref::<&str>()
+                        .map(|message| message.to_string())
+                        .unwrap_or_default(),
+                };
+                Err(format!("Internal compiler error: {}", message))
+            },
+        )
+    }
+
+    fn compile_source(&self, source: String) -> Result<Program, String> {
+        let mut repository = Repository {
+            source: source.clone(),
+            modules: self.settings.modules.clone(),
+            used_modules: Vec::new(),
+        };
+        if !self.settings.exclude_std {
+            repository.used_modules.push("ellieCore".to_string());
+        }
+
+        let pages = tokenizer::tokenize_file(&mut repository)
+            .map_err(|errors| self.render_errors(&errors, &source))?;
+
+        let mut used_modules = Vec::new();
+        for module_name in &repository.used_modules {
+            match self
+                .settings
+                .modules
+                .iter()
+                .find(|(module, _)| module.name == *module_name)
+            {
+                Some(module) => used_modules.push(module.clone()),
+                None => return Err(format!("Could not find imported module '{}'", module_name)),
+            }
+        }
+
+        let compile_output = parse_pages(
+            0,
+            used_modules,
+            pages,
+            CompilerSettings {
+                name: REPL_MODULE_NAME.to_string(),
+                file_name: REPL_FILE_NAME.to_string(),
+                is_lib: false,
+                description: String::new(),
+                experimental_features: false,
+                version: Version::build_from_string(&"0.1.0".to_string()),
+                byte_code_architecture: self.settings.architecture,
+                optimization_level: 0,
+            },
+        )
+        .map_err(|errors| self.render_errors(&errors, &source))?;
+
+        let mut module_maps = vec![ModuleMap {
+            module_name: compile_output.module.name.clone(),
+            module_hash: compile_output.module.hash,
+            module_path: None,
+        }];
+        module_maps.extend(
+            self.settings
+                .modules
+                .iter()
+                .map(|(module, path)| ModuleMap {
+                    module_name: module.name.clone(),
+                    module_hash: module.hash,
+                    module_path: path.clone(),
+                }),
+        );
+
+        let mut assembler = Assembler::new(
+            compile_output.module,
+            PlatformAttributes {
+                architecture: self.settings.architecture,
+                memory_size: 512000,
+            },
+        );
+        let binary = assembler
+            .assemble(module_maps)
+            .render_binary_to_vector(false);
+
+        let mut binary = binary.as_slice();
+        let mut reader = RFile::new(&mut binary);
+        let mut program_reader = ProgramReader::new(&mut reader);
+        let mut program = Program::new();
+        program
+            .build_from_reader(&mut program_reader)
+            .map_err(|e| format!("Failed to read program [{:?}]", e))?;
+        Ok(program)
+    }
+
+    fn render_errors(&self, errors: &Vec<Error>, source: &str) -> String {
+        let modules = &self.settings.modules;
+        print_errors(
+            errors,
+            |path| {
+                let path_starter = path.split('/').next().unwrap().to_string();
+                let module_name = path_starter
+                    .trim_start_matches("<ellie_module_")
+                    .trim_end_matches('>');
+                match modules
+                    .iter()
+                    .find(|(module, _)| module.name == module_name)
+                {
+                    Some((_, Some(module_path))) => {
+                        read_file(path.replace(&path_starter, module_path)).unwrap_or_default()
+                    }
+                    _ => source.to_string(),
+                }
+            },
+            false,
+            |path| path,
+            CliColor,
+        )
+    }
+
+    /// Run instructions main gained since the last run
+    fn execute(&mut self, program: &Program) -> Result<(), String> {
+        if let Some(debug_info) = &program.debug_info {
+            self.class_names.extend(
+                debug_info
+                    .debug_headers
+                    .iter()
+                    .filter(|header| matches!(header.rtype, DebugHeaderType::Class))
+                    .map(|header| (header.hash, header.name.clone())),
+            );
+        }
+
+        let start = self.resume_pos.unwrap_or(program.main.start);
+        if start >= program.main.length {
+            return Ok(());
+        }
+
+        let mut vm_program = VmProgram::new();
+        vm_program.fill_from_vector(program.instructions.clone());
+        vm_program.fill_traces(program.native_call_traces.clone());
+
+        // Main's frame is pinned, so variables from previous entries keep their locations
+        self.thread.stack.push(Stack {
+            pos: start,
+            frame_pos: 0,
+            id: program.main.hash,
+            stack_len: program.main.length,
+            registers: Registers {
+                A: StaticRawType::from_void(),
+                B: StaticRawType::from_void(),
+                C: StaticRawType::from_void(),
+                X: StaticRawType::from_void(),
+                Y: StaticRawType::from_void(),
+            },
+            caller: None,
+        });
+
+        match self.thread.run(&mut self.module_manager, &vm_program) {
+            ThreadExit::ExitGracefully => {
+                self.resume_pos = Some(program.main.length);
+                Ok(())
+            }
+            ThreadExit::Panic(panic) => {
+                while self.thread.stack.len() != 0 {
+                    self.thread.stack.pop();
+                }
+                Err(format!("Thread panic, reason: {:?}", panic.reason))
+            }
+        }
+    }
+
+    /// Read result variable of an expression
+    fn read_result(&self, program: &Program, name: &str) -> Option<String> {
+        let header = program
+            .debug_info
+            .as_ref()?
+            .debug_headers
+            .iter()
+            .find(|header| {
+                matches!(header.rtype, DebugHeaderType::Variable) && header.name == name
+            })?;
+        let value = self.thread.isolate.stack_memory.get(&header.start_end.1)?;
+        Some(self.render_value(value, 0))
+    }
+
+    /// Render a stack value, arrays, strings and classes are read from the memory they live in
+    fn render_value(&self, value: StaticRawType, depth: usize) -> String {
+        if depth > MAX_RENDER_DEPTH {
+            return String::from("...");
+        }
+        let isolate = &self.thread.isolate;
+        if value.type_id.is_heap_reference() {
+            match isolate.heap_memory.get_def(&value.to_uint()) {
+                Some(heap_value) => self.render_heap_value(heap_value, depth),
+                None => String::from("null"),
+            }
+        } else if value.type_id.is_stack_reference() {
+            match isolate.stack_memory.get(&value.to_uint()) {
+                Some(stack_value) => self.render_value(stack_value, depth + 1),
+                None => String::from("null"),
+            }
+        } else if value.type_id.is_static_array() {
+            let location = value.to_uint();
+            let length = isolate
+                .stack_memory
+                .get(&(location + 1))
+                .map(|length| length.to_uint())
+                .unwrap_or(0);
+            let entries = (0..length)
+                .filter_map(|index| isolate.stack_memory.get(&(location + 2 + index)))
+                .collect::<Vec<_>>();
+            self.render_entries(&entries, depth)
+        } else if value.type_id.is_class() {
+            let entries = match isolate.stack_memory.get(&value.to_uint()) {
+                Some(array) if array.type_id.is_heap_reference() => isolate
+                    .heap_memory
+                    .get_def(&array.to_uint())
+                    .map(|array| self.heap_array_entries(array))
+                    .unwrap_or_default(),
+                _ => Vec::new(),
+            };
+            // First entry of a class instance is its class hash, properties follow it
+            let class_name = entries
+                .first()
+                .and_then(|hash| self.class_names.get(&hash.to_uint()));
+            format!(
+                "{}({})",
+                class_name.map_or("class", String::as_str),
+                entries
+                    .iter()
+                    .skip(1)
+                    .map(|entry| self.render_value(*entry, depth + 1))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+        } else if value.type_id.is_string() {
+            format!("{:?}", decode_raw_type(&value.to_raw()))
+        } else if value.type_id.is_char() {
+            format!("{:?}", value.to_char())
+        } else {
+            decode_raw_type(&value.to_raw())
+        }
+    }
+
+    fn render_heap_value(&self, value: RawType, depth: usize) -> String {
+        if value.type_id.is_array() {
+            let entries = self.heap_array_entries(value);
+            self.render_entries(&entries, depth)
+        } else if value.type_id.is_string() {
+            format!("{:?}", decode_raw_type(&value))
+        } else {
+            decode_raw_type(&value)
+        }
+    }
+
+    /// Entries of a dynamic array, its data starts with the size of its entries
+    fn heap_array_entries(&self, array: RawType) -> Vec<StaticRawType> {
+        let platform_size = self.settings.architecture.usize_len() as usize;
+        if array.data.len() <= platform_size {
+            return Vec::new();
+        }
+        let entry_size = usize::from_le_bytes({
+            let mut bytes = [0; std::mem::size_of::<usize>()];
+            bytes[..platform_size].copy_from_slice(&array.data[..platform_size]);
+            bytes
+        });
+        if entry_size == 0 {
+            return Vec::new();
+        }
+        array.data[platform_size..]
+            .chunks(entry_size)
+            .map(StaticRawType::from_bytes)
+            .collect()
+    }
+
+    fn render_entries(&self, entries: &[StaticRawType], depth: usize) -> String {
+        format!(
+            "[{}]",
+            entries
+                .iter()
+                .map(|entry| self.render_value(*entry, depth + 1))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+
+    fn eval(&mut self, entry: Entry) -> Result<Option<String>, String> {
+        match entry {
+            Entry::Definition(definition) => {
+                let program = self.compile(self.render_source(Some(&definition), None))?;
+                self.definitions.push(definition);
+                self.execute(&program).map(|_| None)
+            }
+            Entry::Statement(statement) => {
+                let program = self.compile(self.render_source(None, Some(&statement)))?;
+                self.statements.push(statement);
+                if let Err(e) = self.execute(&program) {
+                    self.statements.pop();
+                    return Err(e);
+                }
+                Ok(None)
+            }
+            Entry::Expression(expression) => {
+                let name = format!("result{}", self.result_count);
+                // Expressions without a value like void function calls are run as statements
+                let void_statement = format!("v {} : void = {};", name, expression);
+                if self
+                    .compile(self.render_source(None, Some(&void_statement)))
+                    .is_ok()
+                {
+                    return self.eval(Entry::Statement(format!("{};", expression)));
+                }
+
+                let statement = format!("v {} = {};", name, expression);
+                match self.compile(self.render_source(None, Some(&statement))) {
+                    Ok(program) => {
+                        self.statements.push(statement);
+                        if let Err(e) = self.execute(&program) {
+                            self.statements.pop();
+                            return Err(e);
+                        }
+                        self.result_count += 1;
+                        Ok(Some(format!(
+                            "{} = {}",
+                            name,
+                            self.read_result(&program, &name)
+                                .unwrap_or_else(|| String::from("void"))
+                        )))
+                    }
+                    // Expression might be a statement without semicolon like an assignment
+                    Err(error) => self
+                        .eval(Entry::Statement(format!("{};", expression)))
+                        .map_err(|_| error),
+                }
+            }
+        }
+    }
+}
+
+/// Count of unclosed braces, strings and chars are skipped
+fn open_braces(input: &str) -> isize {
+    let mut depth = 0;
+    let mut quote = None;
+    let mut escaped = false;
+    for char in input.chars() {
+        match quote {
+            Some(_) if escaped => escaped = false,
+            Some(_) if char == '\\' => escaped = true,
+            Some(q) if char == q => quote = None,
+            Some(_) => (),
+            None => match char {
+                '"' | '\'' => quote = Some(char),
+                '{' => depth += 1,
+                '}' => depth -= 1,
+                _ => (),
+            },
+        }
+    }
+    depth
+}
+
+pub fn repl(settings: ReplSettings) {
+    let cli_color = &CliColor;
+    let mut session = Session::new(settings);
+    // Compiler panics are printed as errors
+    panic::set_hook(Box::new(|_| {}));
+
+    if let Err(error) = session.compile(session.render_source(None, None)) {
+        println!(
+            "{}Error:{} Failed to start repl\n{}",
+            cli_color.color(Colors::Red),
+            cli_color.color(Colors::Reset),
+            error
+        );
+        std::process::exit(1);
+    }
+
+    println!(
+        "Ellie v{} repl, type ':exit' to quit",
+        ellie_engine::engine_constants::ELLIE_ENGINE_VERSION
+    );
+
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    loop {
+        let mut input = String::new();
+        loop {
+            print!("{}", if input.is_empty() { ">>> " } else { "... " });
+            io::stdout().flush().unwrap();
+            match lines.next() {
+                Some(Ok(line)) => {
+                    input += &line;
+                    input += "\n";
+                }
+                _ => return,
+            }
+            if open_braces(&input) <= 0 {
+                break;
+            }
+        }
+
+        match input.trim() {
+            "" => continue,
+            ":exit" => return,
+            ":source" => {
+                println!("{}", session.render_source(None, None));
+                continue;
+            }
+            _ => (),
+        }
+
+        match session.eval(Entry::new(&input)) {
+            Ok(Some(result)) => println!(
+                "{}{}{}",
+                cli_color.color(Colors::Cyan),
+                result,
+                cli_color.color(Colors::Reset)
+            ),
+            Ok(None) => (),
+            Err(error) => println!(
+                "{}Error:{} {}",
+                cli_color.color(Colors::Red),
+                cli_color.color(Colors::Reset),
+                error
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use ellie_engine::core_library::{compile_core_library, core_library_module, HostCallbacks};
+
+    use super::*;
+
+    /// Session with ellieCore, its output is collected to the returned buffer
+    fn session() -> (Session, Arc<Mutex<String>>) {
+        let output = Arc::new(Mutex::new(String::new()));
+        let host_output = output.clone();
+        let session = Session::new(ReplSettings {
+            architecture: PlatformArchitecture::B64,
+            modules: vec![(
+                compile_core_library()
+                    .expect("ellieCore should compile")
+                    .module,
+                None,
+            )],
+            native_modules: vec![core_library_module(HostCallbacks {
+                write: Arc::new(move |text| host_output.lock().unwrap().push_str(text)),
+                ..Default::default()
+            })],
+            exclude_std: false,
+        });
+        (session, output)
+    }
+
+    fn eval(session: &mut Session, input: &str) -> Result<Option<String>, String> {
+        session.eval(Entry::new(input))
+    }
+
+    #[test]
+    fn expression_result_printed() {
+        let (mut session, _) = session();
+        assert_eq!(
+            eval(&mut session, "1 + 2"),
+            Ok(Some("result0 = 3".to_string()))
+        );
+        assert_eq!(
+            eval(&mut session, "result0 * 2"),
+            Ok(Some("result1 = 6".to_string()))
+        );
+    }
+
+    #[test]
+    fn heap_results_decoded() {
+        let (mut session, _) = session();
+        assert_eq!(
+            eval(&mut session, "[1, 2, 3]"),
+            Ok(Some("result0 = [1, 2, 3]".to_string()))
+        );
+        assert_eq!(
+            eval(&mut session, "\"abc\""),
+            Ok(Some("result1 = \"abc\"".to_string()))
+        );
+        assert_eq!(
+            eval(&mut session, "[[1], [2, 3]]"),
+            Ok(Some("result2 = [[1], [2, 3]]".to_string()))
+        );
+    }
+
+    #[test]
+    fn void_expression_run_as_statement() {
+        let (mut session, output) = session();
+        assert_eq!(eval(&mut session, "println(\"hi\")"), Ok(None));
+        assert_eq!(*output.lock().unwrap(), "hi\n");
+        assert_eq!(eval(&mut session, "4"), Ok(Some("result0 = 4".to_string())));
+    }
+
+    #[test]
+    fn statement_variables_kept() {
+        let (mut session, _) = session();
+        assert_eq!(eval(&mut session, "v items = [4, 5];"), Ok(None));
+        assert_eq!(eval(&mut session, "items.push(6)"), Ok(None));
+        assert_eq!(
+            eval(&mut session, "items"),
+            Ok(Some("result0 = [4, 5, 6]".to_string()))
+        );
+    }
+
+    #[test]
+    fn definitions_used_by_entries() {
+        let (mut session, _) = session();
+        assert_eq!(
+            eval(&mut session, "fn twice(x: int) : int { ret x * 2; }"),
+            Ok(None)
+        );
+        assert_eq!(
+            eval(&mut session, "twice(4)"),
+            Ok(Some("result0 = 8".to_string()))
+        );
+        assert_eq!(
+            eval(
+                &mut session,
+                "class Point { co(x, y); pub v x : int; pub v y : int; }"
+            ),
+            Ok(None)
+        );
+        assert_eq!(eval(&mut session, "v point = new Point(1, 2);"), Ok(None));
+        assert_eq!(
+            eval(&mut session, "point"),
+            Ok(Some("result1 = Point(1, 2)".to_string()))
+        );
+        assert_eq!(
+            eval(&mut session, "point.x"),
+            Ok(Some("result2 = 1".to_string()))
+        );
+    }
+
+    #[test]
+    fn session_recovered_after_panic() {
+        let (mut session, _) = session();
+        assert_eq!(eval(&mut session, "v first = 1;"), Ok(None));
+        assert_eq!(
+            eval(&mut session, "v missing = [1, 2][5];"),
+            Err("Thread panic, reason: IndexOutOfBounds(5, 2)".to_string())
+        );
+        assert_eq!(eval(&mut session, "v second = first + 1;"), Ok(None));
+        assert_eq!(
+            eval(&mut session, "second"),
+            Ok(Some("result0 = 2".to_string()))
+        );
+    }
+}
@@ -1,22 +1,107 @@
 use ellie_engine::{
-    ellie_core::defs::{DebugHeader, DebugInfo, PlatformArchitecture},
+    ellie_core::defs::{DebugInfo, PlatformArchitecture},
     ellie_renderer_utils::utils::{CliColor, ColorDisplay, Colors},
     ellie_vm::{
         channel::{EllieModule, ModuleManager},
         program::{Program, VmProgram},
         thread::{Isolate, Thread},
-        utils::{ThreadExit, ThreadPanicReason},
+        trace::{TraceStep, Tracer},
+        utils::{StepResult, ThreadExit, ThreadPanicReason},
     },
 };
+use std::fs::File;
+
+use crate::{coverage::Coverage, profiler::Profiler, trace::TraceWriter, utils::get_real_path};
 
 pub struct VmSettings {
     pub json_log: bool,
     pub warnings: bool,
     pub heap_dump: bool,
+    /// Write execution trace to the path
+    pub trace_path: Option<String>,
+    /// Profile execution and write folded stacks to the path
+    pub profile_path: Option<String>,
+    /// Write lcov coverage report to the path
+    pub coverage_path: Option<String>,
     pub architecture: PlatformArchitecture,
     pub modules: Vec<EllieModule>,
 }
 
+/// Forwards steps to enabled tracers
+struct ExecutionTracers {
+    writer: Option<TraceWriter<File>>,
+    profiler: Option<Profiler>,
+    coverage: Option<Coverage>,
+}
+
+impl Tracer for ExecutionTracers {
+    fn trace(&mut self, step: &TraceStep) {
+        if let Some(writer) = &mut self.writer {
+            writer.trace(step);
+        }
+        if let Some(profiler) = &mut self.profiler {
+            profiler.trace(step);
+        }
+        if let Some(coverage) = &mut self.coverage {
+            coverage.trace(step);
+        }
+    }
+}
+
+fn create_file(path: &str) -> File {
+    let cli_color = &CliColor;
+    File::create(path).unwrap_or_else(|err| {
+        println!(
+            "{}Error:{} Failed to create file {}{}{} [{}]",
+            cli_color.color(Colors::Red),
+            cli_color.color(Colors::Reset),
+            cli_color.color(Colors::Cyan),
+            path,
+            cli_color.color(Colors::Reset),
+            err,
+        );
+        std::process::exit(1);
+    })
+}
+
+fn print_profile(profiler: &Profiler, folded_path: &str) {
+    let cli_color = &CliColor;
+    println!(
+        "{}[VM]{}: Profile, {} steps\n\n  Instructions",
+        cli_color.color(Colors::Yellow),
+        cli_color.color(Colors::Reset),
+        profiler.total_steps,
+    );
+    let mut instructions = profiler.instruction_counts.iter().collect::<Vec<_>>();
+    instructions.sort_by_key(|(_, count)| std::cmp::Reverse(**count));
+    for (mnemonic, count) in instructions {
+        println!("    {:<6}{}", mnemonic, count);
+    }
+
+    println!(
+        "\n  {:<40}{:>12}{:>12}",
+        "Functions", "Inclusive", "Exclusive"
+    );
+    let mut functions = profiler.functions.iter().collect::<Vec<_>>();
+    functions.sort_by_key(|(_, function)| std::cmp::Reverse(function.inclusive));
+    for (hash, function) in functions {
+        println!(
+            "    {:<38}{:>12}{:>12}",
+            profiler.function_name(*hash),
+            function.inclusive,
+            function.exclusive
+        );
+    }
+    println!(
+        "\n{}[VM]{}: Folded stacks written to {}{}{}",
+        cli_color.color(Colors::Yellow),
+        cli_color.color(Colors::Reset),
+        cli_color.color(Colors::Cyan),
+        folded_path,
+        cli_color.color(Colors::Reset),
+    );
+}
+
 pub fn run(program: Program, vm_settings: VmSettings, debug_file: Option<DebugInfo>) {
     let mut vm_program = VmProgram::new();
     vm_program.fill_from_vector(program.instructions);
@@ -33,7 +118,70 @@ pub fn run(program: Program, vm_settings: VmSettings, debug_file: Option<DebugIn
     let isolate = Isolate::new();
     let mut thread = Thread::new(program.main.hash, vm_settings.architecture, isolate);
     thread.build_thread(program.main);
-    let output = thread.run(&mut module_manager, &vm_program);
+    if vm_settings.coverage_path.is_some() && debug_file.is_none() {
+        println!(
+            "{}Error:{} Coverage requires debug info, program is compiled without debug info",
+            cli_color.color(Colors::Red),
+            cli_color.color(Colors::Reset),
+        );
+        std::process::exit(1);
+    }
+
+    let output = if vm_settings.trace_path.is_some()
+        || vm_settings.profile_path.is_some()
+        || vm_settings.coverage_path.is_some()
+    {
+        let mut tracers = ExecutionTracers {
+            writer: vm_settings
+                .trace_path
+                .as_ref()
+                .map(|path| TraceWriter::new(create_file(path))),
+            profiler: vm_settings
+                .profile_path
+                .as_ref()
+                .map(|_| Profiler::new(debug_file.as_ref())),
+            coverage: vm_settings.coverage_path.as_ref().map(|_| Coverage::new()),
+        };
+        let output = loop {
+            match thread.step_traced(&mut module_manager, &vm_program, &mut tracers) {
+                StepResult::Step => (),
+                StepResult::ThreadExit(exit) => break exit,
+            }
+        };
+        if let Some(writer) = &mut tracers.writer {
+            writer.finish();
+            println!(
+                "{}[VM]{}: Trace written to {}{}{}",
+                cli_color.color(Colors::Yellow),
+                cli_color.color(Colors::Reset),
+                cli_color.color(Colors::Cyan),
+                vm_settings.trace_path.as_ref().unwrap(),
+                cli_color.color(Colors::Reset),
+            );
+        }
+        if let Some(profiler) = &tracers.profiler {
+            let path = vm_settings.profile_path.as_ref().unwrap();
+            profiler.write_folded(&mut create_file(path)).unwrap();
+            print_profile(profiler, path);
+        }
+        if let Some(coverage) = &tracers.coverage {
+            let path = vm_settings.coverage_path.as_ref().unwrap();
+            coverage
+                .write_lcov(debug_file.as_ref().unwrap(), &mut create_file(path))
+                .unwrap();
+            println!(
+                "{}[VM]{}: Coverage report written to {}{}{}",
+                cli_color.color(Colors::Yellow),
+                cli_color.color(Colors::Reset),
+                cli_color.color(Colors::Cyan),
+                path,
+                cli_color.color(Colors::Reset),
+            );
+        }
+        output
+    } else {
+        thread.run(&mut module_manager, &vm_program)
+    };
     match output {
         ThreadExit::ExitGracefully => {
             if vm_settings.heap_dump {
@@ -117,38 +265,8 @@ pub fn run(program: Program, vm_settings: VmSettings, debug_file: Option<DebugIn
 
                         match coresponding_header {
                             Some(e) => {
-                                fn get_real_path(
-                                    debug_header: &DebugHeader,
-                                    debug_file: &DebugInfo,
-                                ) -> String {
-                                    let module_name = debug_header
-                                        .module_name
-                                        .split("<ellie_module_")
-                                        .nth(1)
-                                        .unwrap()
-                                        .split('>')
-                                        .next()
-                                        .unwrap();
-                                    let module_path = debug_file
-                                        .module_map
-                                        .iter()
-                                        .find(|map| module_name == map.module_name);
-
-                                    match module_path {
-                                        Some(module_path) => match &module_path.module_path {
-                                            Some(module_path) => {
-                                                let new_path = debug_header.module_name.clone();
-                                                let starter_name =
-                                                    format!("<ellie_module_{}>", module_name);
-                                                new_path.replace(&starter_name, module_path)
-                                            }
-                                            None => debug_header.module_name.clone(),
-                                        },
-                                        None => debug_header.module_name.clone(),
-                                    }
-                                }
-
-                                let real_path = get_real_path(e, debug_file);
+                                let real_path = get_real_path(e, debug_file)
+                                    .unwrap_or_else(|| e.module_name.clone());
 
                                 println!(
                                     "{}    at {}:{}:{}",
@@ -180,7 +298,7 @@ pub fn run(program: Program, vm_settings: VmSettings, debug_file: Option<DebugIn
             }
             if debug_file.is_none() {
                 println!(
-                    "\n{}NoDebugFile{} : {}Given error represents stack locations, provide a debug info file to get more readable info{}",
+                    "\n{}NoDebugInfo{} : {}Given error represents stack locations, program is compiled without debug info{}",
                     cli_color.color(Colors::Yellow),
                     cli_color.color(Colors::Reset),
                     cli_color.color(Colors::Cyan),
@@ -0,0 +1,175 @@
+use std::sync::{Arc, Mutex};
+
+use ellie_engine::{
+    compiler::parse_pages,
+    core_library::{compile_core_library, core_library_module, HostCallbacks},
+    ellie_bytecode::assembler::{Assembler, PlatformAttributes},
+    ellie_core::defs::{ModuleMap, PlatformArchitecture, Version},
+    ellie_parser::parser::Module,
+    ellie_tokenizer::tokenizer::ResolvedImport,
+    ellie_vm::{
+        channel::ModuleManager,
+        program::{Program, VmProgram},
+        thread::{Isolate, Thread},
+        trace::Tracer,
+        utils::{ProgramReader, StepResult},
+    },
+    tokenizer,
+    utils::{CompilerSettings, MainProgram, ProgramRepository},
+    vm::RFile,
+};
+
+use crate::utils::{DebuggerState, DebuggerStatus};
+
+/// Module file path of the compiled source
+pub const MODULE_FILE_PATH: &str = "<ellie_module_test>/test.ei";
+
+struct Repository {
+    source: String,
+    core_library: Module,
+}
+
+impl ProgramRepository for Repository {
+    fn read_main(&mut self) -> MainProgram {
+        MainProgram {
+            file_content: self.source.clone(),
+            file_name: "test.ei".to_string(),
+            file_hash: 0,
+            start_directory: "<ellie_module_test>".to_string(),
+        }
+    }
+
+    fn read_module(
+        &mut self,
+        link_module: bool,
+        _current_path: String,
+        requested_path: String,
+    ) -> ResolvedImport {
+        if link_module && requested_path == self.core_library.name {
+            ResolvedImport {
+                found: true,
+                hash: self.core_library.hash,
+                path: requested_path,
+                ..Default::default()
+            }
+        } else {
+            ResolvedImport {
+                found: false,
+                resolve_error: "Module not found".to_string(),
+                ..Default::default()
+            }
+        }
+    }
+}
+
+/// Compile source with ellieCore as the `test` module at `/project/test` and render it as
+/// bytecode file
+pub fn build(source: &str) -> Vec<u8> {
+    let core_library = compile_core_library()
+        .expect("ellieCore should compile")
+        .module;
+    let mut repository = Repository {
+        source: source.to_string(),
+        core_library: core_library.clone(),
+    };
+    let pages = tokenizer::tokenize_file(&mut repository).expect("Source should tokenize");
+    let output = parse_pages(
+        0,
+        vec![(core_library.clone(), None)],
+        pages,
+        CompilerSettings {
+            name: "test".to_string(),
+            file_name: "test.ei".to_string(),
+            is_lib: false,
+            description: String::new(),
+            experimental_features: false,
+            version: Version::build_from_string(&"0.1.0".to_string()),
+            byte_code_architecture: PlatformArchitecture::B64,
+            optimization_level: 0,
+        },
+    )
+    .unwrap_or_else(|errors| panic!("Expected no errors, found {:#?}", errors));
+
+    let module_maps = vec![
+        ModuleMap {
+            module_name: output.module.name.clone(),
+            module_hash: output.module.hash,
+            module_path: Some("/project/test".to_string()),
+        },
+        ModuleMap {
+            module_name: core_library.name.clone(),
+            module_hash: core_library.hash,
+            module_path: None,
+        },
+    ];
+    Assembler::new(
+        output.module,
+        PlatformAttributes {
+            architecture: PlatformArchitecture::B64,
+            memory_size: 512000,
+        },
+    )
+    .assemble(module_maps)
+    .render_binary_to_vector(false)
+}
+
+/// Compile source with ellieCore as the `test` module at `/project/test`
+pub fn compile(source: &str) -> Program {
+    let binary = build(source);
+    let mut binary = binary.as_slice();
+    let mut reader = RFile::new(&mut binary);
+    let mut program = Program::new();
+    program
+        .build_from_reader(&mut ProgramReader::new(&mut reader))
+        .expect("Program should be readable");
+    program
+}
+
+/// Module manager with ellieCore, its output is collected to the returned buffer
+pub fn module_manager() -> (ModuleManager, Arc<Mutex<String>>) {
+    let output = Arc::new(Mutex::new(String::new()));
+    let host_output = output.clone();
+    let mut module_manager = ModuleManager::new();
+    module_manager.register_module(core_library_module(HostCallbacks {
+        write: Arc::new(move |text| host_output.lock().unwrap().push_str(text)),
+        ..Default::default()
+    }));
+    (module_manager, output)
+}
+
+pub fn thread() -> Thread {
+    Thread::new(0, PlatformArchitecture::B64, Isolate::new())
+}
+
+/// Run program to its end, every step is given to the tracer. Returns what program wrote
+pub fn run_traced(program: Program, tracer: &mut dyn Tracer) -> String {
+    let (mut module_manager, output) = module_manager();
+    let mut vm_program = VmProgram::new();
+    vm_program.fill_from_vector(program.instructions);
+    vm_program.fill_traces(program.native_call_traces);
+    let mut thread = thread();
+    thread.build_thread(program.main);
+    while let StepResult::Step = thread.step_traced(&mut module_manager, &vm_program, tracer) {}
+    let output = output.lock().unwrap().clone();
+    output
+}
+
+/// Debugger with the program loaded, its output is collected to the returned buffer
+pub fn debugger(thread: &mut Thread, program: Program) -> (DebuggerStatus<'_>, Arc<Mutex<String>>) {
+    let (module_manager, output) = module_manager();
+    let mut debugger_state = DebuggerStatus {
+        vm_program: None,
+        program: None,
+        program_path: None,
+        debug_file: None,
+        step_request: None,
+        skip_breakpoint: false,
+        module_manager,
+        thread,
+        state: DebuggerState::ProgramNotLoaded,
+        breakpoints: Vec::new(),
+        watchpoints: Vec::new(),
+    };
+    debugger_state.load_program(program);
+    (debugger_state, output)
+}
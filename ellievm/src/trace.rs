@@ -0,0 +1,107 @@
+use std::io::{BufWriter, Write};
+
+use ellie_engine::ellie_vm::{
+    raw_type::StaticRawType,
+    trace::{TraceStep, Tracer},
+};
+
+/// Writes every step to a trace file
+///
+/// Each line is a step: `pos depth stack_id MNEMONIC op_code` followed by the changed registers
+/// as `REGISTER=type_id:data` where data is hex encoded little endian bytes
+pub struct TraceWriter<W: Write> {
+    writer: BufWriter<W>,
+}
+
+impl<W: Write> TraceWriter<W> {
+    pub fn new(writer: W) -> Self {
+        let mut writer = BufWriter::new(writer);
+        writeln!(writer, "# pos depth stack_id instruction op_code registers").unwrap();
+        TraceWriter { writer }
+    }
+
+    pub fn finish(&mut self) {
+        self.writer.flush().unwrap();
+    }
+}
+
+fn render_value(value: &StaticRawType) -> String {
+    let data: String = value.data.iter().map(|e| format!("{:02x}", e)).collect();
+    format!("{}:{}", value.type_id.id, data)
+}
+
+impl<W: Write> Tracer for TraceWriter<W> {
+    fn trace(&mut self, step: &TraceStep) {
+        write!(
+            self.writer,
+            "{} {} {} {} {}",
+            step.pos,
+            step.depth,
+            step.stack_id,
+            step.instruction.instruction.mnemonic(),
+            step.instruction.op_code,
+        )
+        .unwrap();
+        for (register, value) in &step.register_changes {
+            write!(self.writer, " {}={}", register.name(), render_value(value)).unwrap();
+        }
+        writeln!(self.writer).unwrap();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{compile, run_traced};
+
+    #[test]
+    fn every_step_written() {
+        let program = compile(
+            "
+            fn main() {
+                v total = 1 + 2;
+                println(\"total \" + total);
+            }
+            ",
+        );
+        let main = program.main;
+        let mut trace = Vec::new();
+        let mut writer = TraceWriter::new(&mut trace);
+        assert_eq!(run_traced(program, &mut writer), "total 3\n");
+        writer.finish();
+        drop(writer);
+
+        let trace = String::from_utf8(trace).unwrap();
+        let mut lines = trace.lines();
+        assert_eq!(
+            lines.next(),
+            Some("# pos depth stack_id instruction op_code registers")
+        );
+        let steps = lines
+            .map(|line| line.split(' ').collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            steps[0][..4],
+            [
+                main.start.to_string(),
+                "1".to_string(),
+                main.hash.to_string(),
+                "FN".to_string()
+            ]
+        );
+        for step in &steps {
+            assert!(step.len() >= 5);
+            for register in &step[5..] {
+                let (name, value) = register.split_once('=').unwrap();
+                assert!(["A", "B", "C", "X", "Y"].contains(&name));
+                let (type_id, data) = value.split_once(':').unwrap();
+                assert!(type_id.parse::<u8>().is_ok());
+                assert!(data.len() % 2 == 0 && data.chars().all(|c| c.is_ascii_hexdigit()));
+            }
+        }
+        // Addition loads its operands to B and C, result is written to A
+        let add = steps.iter().find(|step| step[3] == "ADD").unwrap();
+        assert_eq!(add[5].split_once(':').unwrap().0, "A=1");
+        assert!(steps.iter().any(|step| step[3] == "CALLN"));
+    }
+}
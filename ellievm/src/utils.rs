@@ -1,30 +1,706 @@
+use std::path::Path;
+
+use crate::condition::{Condition, ConditionValue};
 use ellie_engine::{
-    ellie_core::defs::DebugInfo,
+    ellie_core::defs::{DebugHeader, DebugHeaderType, DebugInfo},
     ellie_vm::{
         channel::ModuleManager,
-        program::{Program, VmProgram},
-        thread::Thread,
+        instruction_utils::Instructions,
+        program::{Program, ReadInstruction, VmProgram},
+        raw_type::{RawType, StaticRawType},
+        stack::Stack,
+        thread::{Isolate, Thread},
+        utils::{AddressingValues, StepResult, ThreadExit},
     },
 };
+use path_absolutize::Absolutize;
 
 #[derive(Clone, Debug, PartialEq, Default)]
 pub struct BreakPoint {
     pub module_name: Option<String>,
     pub stack_location: usize,
     pub code_location: Option<usize>,
+    /// Only stop when condition holds
+    pub condition: Option<Condition>,
+    /// Only stop after breakpoint is hit this many times
+    pub hit_count: Option<usize>,
+    /// Times breakpoint is hit, hits with a false condition are not counted
+    pub hits: usize,
+}
+
+/// Memory location watched for changes
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WatchTarget {
+    Stack(usize),
+    Heap(usize),
+}
+
+pub struct Watchpoint {
+    pub target: WatchTarget,
+    /// Value at the last check
+    pub value: Option<VariableValue>,
+}
+
+/// A watched location changed by the last executed instruction
+pub struct WatchChange {
+    pub target: WatchTarget,
+    pub old: Option<VariableValue>,
+    pub new: Option<VariableValue>,
+}
+
+/// How far a step command runs the program
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StepMode {
+    /// Single instruction
+    Instruction,
+    /// Next source line, entering function calls
+    Into,
+    /// Next source line in the current or a caller function
+    Over,
+    /// Until current function returns
+    Out,
+}
+
+/// Source line of an instruction, module file path and line
+pub type SourceLine = (String, usize);
+
+pub struct StepRequest {
+    pub mode: StepMode,
+    /// Stack depth when step is requested
+    pub depth: usize,
+    /// Source line when step is requested
+    pub line: Option<SourceLine>,
+    /// Instructions executed since step is requested
+    pub steps: usize,
+}
+
+impl StepRequest {
+    /// Whether program should stop before executing an instruction at given depth and line
+    pub fn should_stop(&self, depth: usize, line: Option<&SourceLine>) -> bool {
+        if self.steps == 0 {
+            return false;
+        }
+        let line_changed = line.is_some() && (line != self.line.as_ref() || depth != self.depth);
+        match self.mode {
+            StepMode::Instruction => true,
+            StepMode::Into => line_changed,
+            StepMode::Over => depth <= self.depth && line_changed,
+            StepMode::Out => depth < self.depth,
+        }
+    }
+}
+
+/// Find source line of the instruction at given position
+///
+/// Elements cover instructions after their start up to and including their end, element
+/// ranges start with the last instruction of the previous element. Functions and parameters
+/// don't point to a line that runs.
+pub fn get_source_line(debug_file: &DebugInfo, pos: usize) -> Option<SourceLine> {
+    debug_file
+        .debug_headers
+        .iter()
+        .filter(|header| {
+            !matches!(
+                header.rtype,
+                DebugHeaderType::Function
+                    | DebugHeaderType::NativeFunction
+                    | DebugHeaderType::Parameter
+            )
+        })
+        .filter(|header| {
+            let (start, end) = header.start_end;
+            (start < pos && pos <= end) || (start == end && start == pos)
+        })
+        .min_by_key(|header| header.start_end.1 - header.start_end.0)
+        .map(|header| (header.module_name.clone(), header.pos.range_start.0 + 1))
+}
+
+/// Normalize a file path to compare it with other paths, relative paths are resolved from the
+/// current directory
+fn normalize_path(path: &str) -> String {
+    Path::new(&path.replace('\\', "/"))
+        .absolutize()
+        .map_or_else(
+            |_| path.to_string(),
+            |path| path.to_string_lossy().to_string(),
+        )
+        .replace('\\', "/")
+}
+
+/// Find the module file path like `<ellie_module_X>/file.ei` of a path given by the user
+///
+/// Path can be a module file path, module name and file like `X/file.ei` or path of the source
+/// file. If none of them matches, the longest module file path that path ends with is used.
+pub fn find_module_file_path(debug_file: &DebugInfo, path: &str) -> Option<String> {
+    let mut module_file_paths = debug_file
+        .debug_headers
+        .iter()
+        .map(|header| header.module_name.as_str())
+        .collect::<Vec<_>>();
+    module_file_paths.sort_unstable();
+    module_file_paths.dedup();
+
+    let path = path.replace('\\', "/");
+    let real_path = normalize_path(&path);
+    module_file_paths
+        .iter()
+        .find(|module_file_path| **module_file_path == path)
+        .or_else(|| {
+            module_file_paths.iter().find(|module_file_path| {
+                module_file_path
+                    .strip_prefix("<ellie_module_")
+                    .and_then(|module_file_path| module_file_path.split_once('>'))
+                    .is_some_and(|(module_name, file)| path == format!("{}{}", module_name, file))
+            })
+        })
+        .or_else(|| {
+            module_file_paths.iter().find(|module_file_path| {
+                resolve_module_file_path(module_file_path, debug_file)
+                    .is_some_and(|source_path| normalize_path(&source_path) == real_path)
+            })
+        })
+        .or_else(|| {
+            module_file_paths
+                .iter()
+                .filter(|module_file_path| {
+                    module_file_path
+                        .split_once('>')
+                        .is_some_and(|(_, file)| path.ends_with(file))
+                })
+                .max_by_key(|module_file_path| module_file_path.len())
+        })
+        .map(|module_file_path| module_file_path.to_string())
+}
+
+/// Find the breakpoint location of an element at given path and line, path is resolved with
+/// [`find_module_file_path`]
+pub fn get_line_breakpoint(debug_file: &DebugInfo, path: &str, line: usize) -> Option<BreakPoint> {
+    let module_file_path = find_module_file_path(debug_file, path)?;
+    let header = debug_file.debug_headers.iter().find(|header| {
+        header.module_name == module_file_path && header.pos.range_start.0 + 1 == line
+    })?;
+    Some(BreakPoint {
+        module_name: Some(header.module_name.to_string()),
+        // Element's range starts with the last instruction of the previous element
+        stack_location: header.start_end.0 + 1,
+        code_location: Some(line),
+        ..Default::default()
+    })
+}
+
+/// Value of a variable read from thread memory
+#[derive(Clone)]
+pub enum VariableValue {
+    Stack(StaticRawType),
+    Heap(RawType),
+}
+
+/// Why a program can't be hot swapped by [`DebuggerStatus::hot_swap`]
+#[derive(Debug)]
+pub enum HotSwapError {
+    /// Instruction count, main function or a function header is changed, so instruction
+    /// positions of the running program no longer match
+    LayoutChanged,
+    /// Body of a function on the call stack is changed
+    FunctionRunning(usize),
+}
+
+fn is_same_instruction(first: &ReadInstruction, second: &ReadInstruction) -> bool {
+    let is_same_value = match (first.addressing_value, second.addressing_value) {
+        (AddressingValues::Immediate(first), AddressingValues::Immediate(second)) => {
+            first.type_id.id == second.type_id.id && first.data == second.data
+        }
+        (AddressingValues::Absolute(first), AddressingValues::Absolute(second))
+        | (AddressingValues::AbsoluteStatic(first), AddressingValues::AbsoluteStatic(second)) => {
+            first == second
+        }
+        (
+            AddressingValues::AbsoluteIndex(first, first_index),
+            AddressingValues::AbsoluteIndex(second, second_index),
+        )
+        | (
+            AddressingValues::AbsoluteProperty(first, first_index),
+            AddressingValues::AbsoluteProperty(second, second_index),
+        ) => first == second && first_index == second_index,
+        (first, second) => std::mem::discriminant(&first) == std::mem::discriminant(&second),
+    };
+    first.op_code == second.op_code && is_same_value
+}
+
+/// Function start, hash and escape position of every function in the program
+fn get_functions(program: &Program) -> Vec<(usize, usize, usize)> {
+    program
+        .instructions
+        .iter()
+        .enumerate()
+        .filter_map(|(start, instruction)| {
+            let hash = match (instruction.instruction, instruction.addressing_value) {
+                (Instructions::FN(_), AddressingValues::Immediate(hash)) => hash.to_int() as usize,
+                _ => return None,
+            };
+            match program.instructions.get(start + 1)?.addressing_value {
+                AddressingValues::Immediate(end) => Some((start, hash, end.to_int() as usize)),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// Functions of a program matched with the functions of its recompiled version
+pub struct ProgramChanges {
+    /// Old hash and new hash of every function
+    pub hashes: Vec<(usize, usize)>,
+    /// Old hashes of the functions whose body differs
+    pub changed_functions: Vec<usize>,
+}
+
+/// Match functions of two programs and find the functions whose body differs
+///
+/// Function hashes are generated on every compile, so functions are matched by their position.
+/// Programs must have the same layout: same instruction count, main function position and
+/// function headers at the same positions. Changes outside of function bodies are layout
+/// changes.
+pub fn get_program_changes(old: &Program, new: &Program) -> Result<ProgramChanges, HotSwapError> {
+    let old_functions = get_functions(old);
+    let new_functions = get_functions(new);
+    let is_same_layout = old.instructions.len() == new.instructions.len()
+        && old.main.start == new.main.start
+        && old.main.length == new.main.length
+        && old_functions.len() == new_functions.len()
+        && old_functions.iter().zip(new_functions.iter()).all(
+            |((old_start, _, old_end), (new_start, _, new_end))| {
+                old_start == new_start
+                    && old_end == new_end
+                    // Parameter count
+                    && is_same_instruction(
+                        &old.instructions[old_start + 2],
+                        &new.instructions[new_start + 2],
+                    )
+            },
+        );
+    if !is_same_layout {
+        return Err(HotSwapError::LayoutChanged);
+    }
+
+    let mut changed_functions = Vec::new();
+    for (pos, (old_instruction, new_instruction)) in old
+        .instructions
+        .iter()
+        .zip(new.instructions.iter())
+        .enumerate()
+    {
+        // Function headers are compared with the layout
+        if old_functions
+            .iter()
+            .any(|(start, _, _)| (*start..=start + 2).contains(&pos))
+            || is_same_instruction(old_instruction, new_instruction)
+        {
+            continue;
+        }
+        // Innermost function owns the instruction
+        let owner = old_functions
+            .iter()
+            .filter(|(start, _, end)| *start < pos && pos <= *end)
+            .max_by_key(|(start, _, _)| *start);
+        match owner {
+            Some((_, hash, _)) if !changed_functions.contains(hash) => {
+                changed_functions.push(*hash)
+            }
+            Some(_) => (),
+            None => return Err(HotSwapError::LayoutChanged),
+        }
+    }
+    Ok(ProgramChanges {
+        hashes: old_functions
+            .iter()
+            .zip(new_functions.iter())
+            .map(|((_, old_hash, _), (_, new_hash, _))| (*old_hash, *new_hash))
+            .collect(),
+        changed_functions,
+    })
+}
+
+impl VariableValue {
+    fn is_same(&self, other: &VariableValue) -> bool {
+        match (self, other) {
+            (VariableValue::Stack(first), VariableValue::Stack(second)) => {
+                first.type_id.id == second.type_id.id && first.data == second.data
+            }
+            (VariableValue::Heap(first), VariableValue::Heap(second)) => {
+                first.type_id.id == second.type_id.id && first.data == second.data
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Why [`DebuggerStatus::run`] returned
+pub enum DebuggerEvent {
+    Stepped(Option<SourceLine>),
+    HitBreakpoint(Option<SourceLine>),
+    /// Breakpoint's condition can't be evaluated, program stops at the breakpoint
+    ConditionError(String, Option<SourceLine>),
+    WatchpointHit(Vec<WatchChange>, Option<SourceLine>),
+    ThreadExit(ThreadExit),
 }
 
 pub struct DebuggerStatus<'a> {
     pub vm_program: Option<VmProgram>,
     pub program: Option<Program>,
-    pub step: bool,
+    /// Path of the loaded program, used to reload it
+    pub program_path: Option<String>,
+    pub step_request: Option<StepRequest>,
+    /// Don't stop at breakpoint of the current instruction, set when program resumes from it
+    pub skip_breakpoint: bool,
     pub debug_file: Option<DebugInfo>,
     pub thread: &'a mut Thread,
     pub state: DebuggerState,
     pub breakpoints: Vec<BreakPoint>,
+    pub watchpoints: Vec<Watchpoint>,
     pub module_manager: ModuleManager,
 }
 
+impl DebuggerStatus<'_> {
+    /// Load a verified program and reset the thread, program is ready to run
+    ///
+    /// Program starts over, so breakpoint hits are reset and watched values are read again
+    pub fn load_program(&mut self, program: Program) {
+        let mut vm_program = VmProgram::new();
+        vm_program.fill_from_vector(program.instructions.clone());
+        vm_program.fill_traces(program.native_call_traces.clone());
+
+        self.debug_file = program.debug_info.clone();
+        self.program = Some(program);
+        self.vm_program = Some(vm_program);
+        *self.thread = Thread::new(self.thread.id, self.thread.arch, Isolate::new());
+        self.step_request = None;
+        self.state = DebuggerState::ProgramLoaded;
+        for break_point in &mut self.breakpoints {
+            break_point.hits = 0;
+        }
+        for index in 0..self.watchpoints.len() {
+            self.watchpoints[index].value = self.read_memory(self.watchpoints[index].target);
+        }
+    }
+
+    /// Replace changed function bodies of the running program, thread keeps running where it is
+    ///
+    /// ## Returns
+    /// Hashes of the swapped functions in the new program
+    pub fn hot_swap(&mut self, program: Program) -> Result<Vec<usize>, HotSwapError> {
+        let changes = get_program_changes(self.program.as_ref().unwrap(), &program)?;
+        if let Some(stack) = self
+            .thread
+            .stack
+            .clone()
+            .into_iter()
+            .find(|stack| changes.changed_functions.contains(&stack.id))
+        {
+            return Err(HotSwapError::FunctionRunning(stack.id));
+        }
+
+        // Running stacks are identified with the function hashes of the new program
+        let new_hash = |hash: usize| {
+            changes
+                .hashes
+                .iter()
+                .find(|(old_hash, _)| *old_hash == hash)
+                .map_or(hash, |(_, new_hash)| *new_hash)
+        };
+        for index in 0..self.thread.stack.len() {
+            let stack = self.thread.stack.get(index).unwrap();
+            stack.id = new_hash(stack.id);
+            if let Some(caller) = stack.caller.as_mut() {
+                caller.id = new_hash(caller.id);
+            }
+        }
+
+        let vm_program = self.vm_program.as_mut().unwrap();
+        vm_program.fill_from_vector(program.instructions.clone());
+        vm_program.fill_traces(program.native_call_traces.clone());
+        self.debug_file = program.debug_info.clone();
+        self.program = Some(program);
+        Ok(changes
+            .changed_functions
+            .into_iter()
+            .map(new_hash)
+            .collect())
+    }
+
+    /// Resolve code location breakpoints again with current debug headers, conditions and hits
+    /// are kept
+    ///
+    /// ## Returns
+    /// Breakpoints that no longer point to an element, they are removed
+    pub fn resolve_breakpoints(&mut self) -> Vec<BreakPoint> {
+        let mut removed = Vec::new();
+        let breakpoints = std::mem::take(&mut self.breakpoints);
+        for break_point in breakpoints {
+            let (module_name, code_location) =
+                match (&break_point.module_name, break_point.code_location) {
+                    (Some(module_name), Some(code_location)) => (module_name, code_location),
+                    // Stack position breakpoints are kept as they are
+                    _ => {
+                        self.breakpoints.push(break_point);
+                        continue;
+                    }
+                };
+            match self
+                .debug_file
+                .as_ref()
+                .and_then(|debug_file| get_line_breakpoint(debug_file, module_name, code_location))
+            {
+                Some(resolved) => self.breakpoints.push(BreakPoint {
+                    condition: break_point.condition,
+                    hit_count: break_point.hit_count,
+                    hits: break_point.hits,
+                    ..resolved
+                }),
+                None => removed.push(break_point),
+            }
+        }
+        removed
+    }
+
+    /// Resume a stopped program with given step mode, [`None`] runs until next breakpoint
+    pub fn resume(&mut self, mode: Option<StepMode>) {
+        self.step_request = mode.map(|mode| {
+            let stack = self.thread.stack.last().unwrap();
+            StepRequest {
+                mode,
+                depth: self.thread.stack.len(),
+                line: self
+                    .debug_file
+                    .as_ref()
+                    .and_then(|debug_file| get_source_line(debug_file, stack.pos)),
+                steps: 0,
+            }
+        });
+        self.skip_breakpoint = true;
+        self.state = DebuggerState::Running;
+    }
+
+    /// Source line of the instruction the thread runs next
+    fn get_current_line(&self) -> Option<SourceLine> {
+        let stack = self.thread.stack.last()?;
+        get_source_line(self.debug_file.as_ref()?, stack.pos)
+    }
+
+    /// Find the breakpoint that stops the program at the position, conditions and hit counts are
+    /// checked here
+    ///
+    /// ## Returns
+    /// Breakpoint and the error if its condition can't be evaluated
+    fn check_breakpoints(&mut self, pos: usize) -> Option<(BreakPoint, Option<String>)> {
+        for index in 0..self.breakpoints.len() {
+            if self.breakpoints[index].stack_location != pos {
+                continue;
+            }
+            let mut error = None;
+            if let Some(condition) = &self.breakpoints[index].condition {
+                match self.evaluate_condition(condition) {
+                    Ok(true) => (),
+                    Ok(false) => continue,
+                    Err(e) => error = Some(e),
+                }
+            }
+            let break_point = &mut self.breakpoints[index];
+            if error.is_none() {
+                break_point.hits += 1;
+                if break_point
+                    .hit_count
+                    .is_some_and(|hit_count| break_point.hits < hit_count)
+                {
+                    continue;
+                }
+            }
+            return Some((break_point.clone(), error));
+        }
+        None
+    }
+
+    /// Update watched values, changed ones are returned
+    fn check_watchpoints(&mut self) -> Vec<WatchChange> {
+        let mut changes = Vec::new();
+        for index in 0..self.watchpoints.len() {
+            let new = self.read_memory(self.watchpoints[index].target);
+            let watchpoint = &mut self.watchpoints[index];
+            let is_changed = match (&watchpoint.value, &new) {
+                (Some(old), Some(new)) => !old.is_same(new),
+                (None, None) => false,
+                _ => true,
+            };
+            if is_changed {
+                changes.push(WatchChange {
+                    target: watchpoint.target,
+                    old: std::mem::replace(&mut watchpoint.value, new.clone()),
+                    new,
+                });
+            }
+        }
+        changes
+    }
+
+    /// Run program until step request is satisfied, a breakpoint or watchpoint is hit or thread
+    /// exits
+    pub fn run(&mut self) -> DebuggerEvent {
+        loop {
+            if let Some(current_stack) = self.thread.stack.last() {
+                let depth = self.thread.stack.len();
+                let pos = current_stack.pos;
+                let line = self.get_current_line();
+
+                if self
+                    .step_request
+                    .as_ref()
+                    .is_some_and(|request| request.should_stop(depth, line.as_ref()))
+                {
+                    self.step_request = None;
+                    self.state = DebuggerState::Stepped;
+                    return DebuggerEvent::Stepped(line);
+                }
+
+                if !self.skip_breakpoint {
+                    if let Some((break_point, error)) = self.check_breakpoints(pos) {
+                        self.step_request = None;
+                        self.state = DebuggerState::WaitingAtBreakpoint(break_point);
+                        return match error {
+                            Some(error) => DebuggerEvent::ConditionError(error, line),
+                            None => DebuggerEvent::HitBreakpoint(line),
+                        };
+                    }
+                }
+            }
+            self.skip_breakpoint = false;
+            if let Some(request) = self.step_request.as_mut() {
+                request.steps += 1;
+            }
+
+            if let StepResult::ThreadExit(exit) = self
+                .thread
+                .step(&mut self.module_manager, self.vm_program.as_mut().unwrap())
+            {
+                self.state = DebuggerState::ProgramCompleted;
+                return DebuggerEvent::ThreadExit(exit);
+            }
+
+            let changes = self.check_watchpoints();
+            if !changes.is_empty() {
+                self.step_request = None;
+                self.state = DebuggerState::Stepped;
+                return DebuggerEvent::WatchpointHit(changes, self.get_current_line());
+            }
+        }
+    }
+
+    /// Read a memory location
+    pub fn read_memory(&self, target: WatchTarget) -> Option<VariableValue> {
+        match target {
+            WatchTarget::Stack(location) => self
+                .thread
+                .isolate
+                .stack_memory
+                .get(&location)
+                .map(VariableValue::Stack),
+            WatchTarget::Heap(location) => self
+                .thread
+                .isolate
+                .heap_memory
+                .get(&location)
+                .map(VariableValue::Heap),
+        }
+    }
+
+    /// Start watching a memory location
+    pub fn add_watchpoint(&mut self, target: WatchTarget) {
+        let value = self.read_memory(target);
+        self.watchpoints.push(Watchpoint { target, value });
+    }
+
+    /// Evaluate a condition in the scope of the running function
+    pub fn evaluate_condition(&self, condition: &Condition) -> Result<bool, String> {
+        let stack = self
+            .thread
+            .stack
+            .last()
+            .ok_or_else(|| "thread is not running".to_string())?;
+        condition.evaluate(|name| {
+            // Latest declaration before the current position wins, so shadowed variables
+            // resolve to the visible one
+            let header = self
+                .get_scope_variables(stack)
+                .into_iter()
+                .filter(|header| header.name == name)
+                .min_by_key(|header| {
+                    (
+                        header.start_end.1 >= stack.pos,
+                        usize::MAX - header.start_end.1,
+                    )
+                })
+                .ok_or_else(|| format!("can't find variable '{}'", name))?;
+            let value = self
+                .read_variable(stack, header)
+                .ok_or_else(|| format!("can't read variable '{}'", name))?;
+            ConditionValue::from_variable(&value)
+                .ok_or_else(|| format!("type of variable '{}' can't be compared", name))
+        })
+    }
+
+    /// Function name from debug headers, hash if the program has no debug info
+    pub fn get_function_name(&self, hash: usize) -> String {
+        self.debug_file
+            .as_ref()
+            .and_then(|debug_file| {
+                debug_file
+                    .debug_headers
+                    .iter()
+                    .find(|header| header.hash == hash && header.rtype == DebugHeaderType::Function)
+            })
+            .map_or(format!("fn#{}", hash), |header| header.name.clone())
+    }
+
+    /// Variable and parameter headers of the function running on the stack
+    pub fn get_scope_variables(&self, stack: &Stack) -> Vec<&DebugHeader> {
+        let debug_headers = match &self.debug_file {
+            Some(debug_file) => &debug_file.debug_headers,
+            None => return Vec::new(),
+        };
+        let scope = match debug_headers.iter().find(|header| header.hash == stack.id) {
+            Some(scope) => scope,
+            None => return Vec::new(),
+        };
+        debug_headers
+            .iter()
+            .filter(|header| {
+                matches!(
+                    header.rtype,
+                    DebugHeaderType::Variable | DebugHeaderType::Parameter
+                ) && scope.start_end.0 <= header.start_end.0
+                    && header.start_end.1 <= scope.start_end.1
+            })
+            .collect()
+    }
+
+    /// Read a variable of the stack, heap references are followed
+    pub fn read_variable(&self, stack: &Stack, header: &DebugHeader) -> Option<VariableValue> {
+        let stack_data = self
+            .thread
+            .isolate
+            .stack_memory
+            .get(&stack.calculate_frame_pos(header.start_end.1))?;
+
+        if stack_data.type_id.is_heap_reference() {
+            self.thread
+                .isolate
+                .heap_memory
+                .get_def(&stack_data.to_uint())
+                .map(VariableValue::Heap)
+        } else {
+            Some(VariableValue::Stack(stack_data))
+        }
+    }
+}
+
 #[derive(PartialEq, Debug)]
 pub enum DebuggerState {
     ProgramNotLoaded,
@@ -47,3 +723,377 @@ impl DebuggerState {
         }
     }
 }
+
+/// Resolve the source file of a debug header, [`None`] if the module has no path
+pub fn get_real_path(debug_header: &DebugHeader, debug_file: &DebugInfo) -> Option<String> {
+    resolve_module_file_path(&debug_header.module_name, debug_file)
+}
+
+/// Module name `X` of a module file path like `<ellie_module_X>/file.ei`
+pub fn module_name_of(module_file_path: &str) -> Option<&str> {
+    module_file_path
+        .split("<ellie_module_")
+        .nth(1)?
+        .split('>')
+        .next()
+}
+
+/// Resolve a module file path like `<ellie_module_X>/file.ei` to the source file
+pub fn resolve_module_file_path(module_file_path: &str, debug_file: &DebugInfo) -> Option<String> {
+    let module_name = module_name_of(module_file_path)?;
+    let module_path = debug_file
+        .module_map
+        .iter()
+        .find(|map| module_name == map.module_name)?
+        .module_path
+        .as_ref()?;
+    let starter_name = format!("<ellie_module_{}>", module_name);
+    Some(module_file_path.replace(&starter_name, module_path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+
+    const CODE: &str = "fn main() {
+    v total = 0;
+    for i : 3 {
+        v next = total + i;
+        total = next;
+    }
+    println(\"total \" + total);
+}
+";
+
+    /// Build main thread of the loaded program
+    fn start(debugger_state: &mut DebuggerStatus) {
+        let main = debugger_state.program.as_ref().unwrap().main;
+        debugger_state.thread.build_thread(main);
+        debugger_state.state = DebuggerState::Running;
+    }
+
+    fn add_line_breakpoint(debugger_state: &mut DebuggerStatus, line: usize) {
+        let break_point = get_line_breakpoint(
+            debugger_state.debug_file.as_ref().unwrap(),
+            MODULE_FILE_PATH,
+            line,
+        )
+        .expect("Line should have an element");
+        debugger_state.breakpoints.push(break_point);
+    }
+
+    /// Stack location of a variable of the running function
+    fn variable_location(debugger_state: &DebuggerStatus, name: &str) -> usize {
+        let stack = debugger_state.thread.stack.last().unwrap();
+        let header = debugger_state
+            .get_scope_variables(stack)
+            .into_iter()
+            .find(|header| header.name == name)
+            .unwrap();
+        stack.calculate_frame_pos(header.start_end.1)
+    }
+
+    #[test]
+    fn reload_resets_breakpoint_hits() {
+        let mut thread = thread();
+        let (mut debugger_state, _) = debugger(&mut thread, compile(CODE));
+        add_line_breakpoint(&mut debugger_state, 4);
+        start(&mut debugger_state);
+        for _ in 0..2 {
+            assert!(matches!(
+                debugger_state.run(),
+                DebuggerEvent::HitBreakpoint(_)
+            ));
+            debugger_state.resume(None);
+        }
+        assert_eq!(debugger_state.breakpoints[0].hits, 2);
+
+        debugger_state.load_program(compile(CODE));
+        assert!(debugger_state.resolve_breakpoints().is_empty());
+        assert_eq!(debugger_state.breakpoints[0].hits, 0);
+    }
+
+    #[test]
+    fn hot_swap_keeps_breakpoint_hits() {
+        let mut thread = thread();
+        let (mut debugger_state, _) = debugger(&mut thread, compile(CODE));
+        add_line_breakpoint(&mut debugger_state, 4);
+        start(&mut debugger_state);
+        assert!(matches!(
+            debugger_state.run(),
+            DebuggerEvent::HitBreakpoint(_)
+        ));
+
+        assert!(debugger_state.hot_swap(compile(CODE)).unwrap().is_empty());
+        assert!(debugger_state.resolve_breakpoints().is_empty());
+        assert_eq!(debugger_state.breakpoints[0].hits, 1);
+        debugger_state.resume(None);
+        assert!(matches!(
+            debugger_state.run(),
+            DebuggerEvent::HitBreakpoint(_)
+        ));
+        assert_eq!(debugger_state.breakpoints[0].hits, 2);
+    }
+
+    #[test]
+    fn reload_reads_watchpoint_baselines() {
+        let mut thread = thread();
+        let (mut debugger_state, _) = debugger(&mut thread, compile(CODE));
+        add_line_breakpoint(&mut debugger_state, 4);
+        start(&mut debugger_state);
+        assert!(matches!(
+            debugger_state.run(),
+            DebuggerEvent::HitBreakpoint(_)
+        ));
+        let location = variable_location(&debugger_state, "total");
+        debugger_state.add_watchpoint(WatchTarget::Stack(location));
+        assert!(debugger_state.watchpoints[0].value.is_some());
+
+        debugger_state.load_program(compile(CODE));
+        debugger_state.breakpoints.clear();
+        let void = VariableValue::Stack(StaticRawType::from_void());
+        assert!(debugger_state.watchpoints[0]
+            .value
+            .as_ref()
+            .is_some_and(|value| value.is_same(&void)));
+        start(&mut debugger_state);
+        match debugger_state.run() {
+            DebuggerEvent::WatchpointHit(changes, _) => {
+                assert!(changes[0]
+                    .old
+                    .as_ref()
+                    .is_some_and(|value| value.is_same(&void)));
+                assert!(matches!(
+                    &changes[0].new,
+                    Some(VariableValue::Stack(value)) if value.to_int() == 0
+                ));
+            }
+            _ => panic!("Expected watchpoint to be hit"),
+        }
+    }
+
+    #[test]
+    fn changed_function_body_found() {
+        let changes = get_program_changes(
+            &compile(CODE),
+            &compile(&CODE.replace("v total = 0;", "v total = 5;")),
+        )
+        .unwrap();
+        assert_eq!(changes.changed_functions.len(), 1);
+    }
+
+    #[test]
+    fn changed_layout_with_error() {
+        assert!(matches!(
+            get_program_changes(
+                &compile(CODE),
+                &compile(&CODE.replace("v total = 0;", "v total = 0;\n    v other = 1;")),
+            ),
+            Err(HotSwapError::LayoutChanged)
+        ));
+    }
+
+    #[test]
+    fn conditional_breakpoint_stops_when_condition_holds() {
+        let mut thread = thread();
+        let (mut debugger_state, _) = debugger(&mut thread, compile(CODE));
+        add_line_breakpoint(&mut debugger_state, 4);
+        debugger_state.breakpoints[0].condition = Condition::parse("i > 1");
+        start(&mut debugger_state);
+        assert!(matches!(
+            debugger_state.run(),
+            DebuggerEvent::HitBreakpoint(_)
+        ));
+        assert_eq!(
+            debugger_state.evaluate_condition(&Condition::parse("i == 2").unwrap()),
+            Ok(true)
+        );
+        // Hits with a false condition are not counted
+        assert_eq!(debugger_state.breakpoints[0].hits, 1);
+        debugger_state.resume(None);
+        assert!(matches!(
+            debugger_state.run(),
+            DebuggerEvent::ThreadExit(ThreadExit::ExitGracefully)
+        ));
+    }
+
+    #[test]
+    fn condition_error_stops_program() {
+        let mut thread = thread();
+        let (mut debugger_state, _) = debugger(&mut thread, compile(CODE));
+        add_line_breakpoint(&mut debugger_state, 4);
+        debugger_state.breakpoints[0].condition = Condition::parse("missing > 1");
+        start(&mut debugger_state);
+        match debugger_state.run() {
+            DebuggerEvent::ConditionError(error, _) => {
+                assert_eq!(error, "can't find variable 'missing'")
+            }
+            _ => panic!("Expected condition error"),
+        }
+    }
+
+    #[test]
+    fn hit_count_breakpoint_stops_after_given_hits() {
+        let mut thread = thread();
+        let (mut debugger_state, _) = debugger(&mut thread, compile(CODE));
+        add_line_breakpoint(&mut debugger_state, 4);
+        debugger_state.breakpoints[0].hit_count = Some(3);
+        start(&mut debugger_state);
+        assert!(matches!(
+            debugger_state.run(),
+            DebuggerEvent::HitBreakpoint(_)
+        ));
+        assert_eq!(debugger_state.breakpoints[0].hits, 3);
+        assert_eq!(
+            debugger_state.evaluate_condition(&Condition::parse("i == 2").unwrap()),
+            Ok(true)
+        );
+        debugger_state.resume(None);
+        assert!(matches!(
+            debugger_state.run(),
+            DebuggerEvent::ThreadExit(ThreadExit::ExitGracefully)
+        ));
+    }
+
+    #[test]
+    fn watchpoint_stops_when_value_changes() {
+        let mut thread = thread();
+        let (mut debugger_state, output) = debugger(&mut thread, compile(CODE));
+        add_line_breakpoint(&mut debugger_state, 4);
+        start(&mut debugger_state);
+        assert!(matches!(
+            debugger_state.run(),
+            DebuggerEvent::HitBreakpoint(_)
+        ));
+        debugger_state.breakpoints.clear();
+        let location = variable_location(&debugger_state, "total");
+        debugger_state.add_watchpoint(WatchTarget::Stack(location));
+
+        let mut values = Vec::new();
+        debugger_state.resume(None);
+        loop {
+            match debugger_state.run() {
+                DebuggerEvent::WatchpointHit(changes, _) => {
+                    assert_eq!(changes.len(), 1);
+                    match &changes[0].new {
+                        Some(VariableValue::Stack(value)) => values.push(value.to_int()),
+                        _ => panic!("Expected stack value"),
+                    }
+                    debugger_state.resume(None);
+                }
+                DebuggerEvent::ThreadExit(ThreadExit::ExitGracefully) => break,
+                _ => panic!("Expected watchpoint hit or exit"),
+            }
+        }
+        // 0 + 1 is written when the loop runs for the second time
+        assert_eq!(values, vec![1, 3]);
+        assert_eq!(*output.lock().unwrap(), "total 3\n");
+    }
+
+    const CALL_CODE: &str = "fn add(a: int, b: int) : int {
+    v result = a + b;
+    ret result;
+}
+fn main() {
+    v first = add(1, 2);
+    v second = first + 1;
+    println(\"second \" + second);
+}
+";
+
+    /// Stop at line 6 of [`CALL_CODE`] and return the stop line of the step
+    fn step_from_call(mode: StepMode) -> (Option<SourceLine>, usize) {
+        let mut thread = thread();
+        let (mut debugger_state, _) = debugger(&mut thread, compile(CALL_CODE));
+        add_line_breakpoint(&mut debugger_state, 6);
+        start(&mut debugger_state);
+        assert!(matches!(
+            debugger_state.run(),
+            DebuggerEvent::HitBreakpoint(Some((_, 6)))
+        ));
+        debugger_state.resume(Some(mode));
+        match debugger_state.run() {
+            DebuggerEvent::Stepped(line) => (line, debugger_state.thread.stack.len()),
+            _ => panic!("Expected step to stop"),
+        }
+    }
+
+    #[test]
+    fn line_breakpoint_found_by_any_path() {
+        let program = compile(CALL_CODE);
+        let debug_file = program.debug_info.as_ref().unwrap();
+        for path in [
+            MODULE_FILE_PATH,
+            "test/test.ei",
+            "/project/test/test.ei",
+            "/project/test/../test/test.ei",
+            "\\project\\test\\test.ei",
+        ] {
+            let break_point = get_line_breakpoint(debug_file, path, 7)
+                .unwrap_or_else(|| panic!("Expected breakpoint at {}", path));
+            assert_eq!(break_point.module_name.as_deref(), Some(MODULE_FILE_PATH));
+            assert_eq!(break_point.stack_location, 77);
+        }
+        assert!(get_line_breakpoint(debug_file, "other/other.ei", 7).is_none());
+        assert!(get_line_breakpoint(debug_file, "test/test.ei", 4).is_none());
+    }
+
+    #[test]
+    fn step_into_enters_function() {
+        let (line, depth) = step_from_call(StepMode::Into);
+        assert_eq!(line, Some((MODULE_FILE_PATH.to_string(), 2)));
+        assert_eq!(depth, 2);
+    }
+
+    #[test]
+    fn step_over_skips_function() {
+        let (line, depth) = step_from_call(StepMode::Over);
+        assert_eq!(line, Some((MODULE_FILE_PATH.to_string(), 7)));
+        assert_eq!(depth, 1);
+    }
+
+    #[test]
+    fn step_out_returns_to_caller() {
+        let mut thread = thread();
+        let (mut debugger_state, _) = debugger(&mut thread, compile(CALL_CODE));
+        add_line_breakpoint(&mut debugger_state, 2);
+        start(&mut debugger_state);
+        assert!(matches!(
+            debugger_state.run(),
+            DebuggerEvent::HitBreakpoint(Some((_, 2)))
+        ));
+        assert_eq!(debugger_state.thread.stack.len(), 2);
+        debugger_state.resume(Some(StepMode::Out));
+        assert!(matches!(
+            debugger_state.run(),
+            DebuggerEvent::Stepped(Some((_, 6)))
+        ));
+        assert_eq!(debugger_state.thread.stack.len(), 1);
+    }
+
+    #[test]
+    fn continue_runs_to_next_breakpoint() {
+        let mut thread = thread();
+        let (mut debugger_state, output) = debugger(&mut thread, compile(CALL_CODE));
+        add_line_breakpoint(&mut debugger_state, 2);
+        add_line_breakpoint(&mut debugger_state, 8);
+        start(&mut debugger_state);
+        assert!(matches!(
+            debugger_state.run(),
+            DebuggerEvent::HitBreakpoint(Some((_, 2)))
+        ));
+        debugger_state.resume(None);
+        assert!(matches!(
+            debugger_state.run(),
+            DebuggerEvent::HitBreakpoint(Some((_, 8)))
+        ));
+        assert!(output.lock().unwrap().is_empty());
+        debugger_state.resume(None);
+        assert!(matches!(
+            debugger_state.run(),
+            DebuggerEvent::ThreadExit(ThreadExit::ExitGracefully)
+        ));
+        assert_eq!(*output.lock().unwrap(), "second 4\n");
+    }
+}
@@ -260,7 +260,7 @@ fn run_from_file() -> Program {
 }
 
 fn get_program_from_result(assembler_result: &AssembleResult) -> Program {
-    let mut program_arr = assembler_result.render_binary_to_vector();
+    let mut program_arr = assembler_result.render_binary_to_vector(false);
     let mut binding = VecReader::new(&mut program_arr);
     let mut r_file = RFile::new(&mut binding);
     let mut program_reader = ProgramReader::new(&mut r_file);
@@ -59,6 +59,5 @@ pub fn save_file(assembler_result: &AssembleResult) {
         ".(test.eic",
     )
     .unwrap();
-    let mut dfile = File::create("./test.eig").unwrap();
-    assembler_result.render_binary(&mut file, &mut dfile);
+    assembler_result.render_binary(&mut file, false);
 }
\ No newline at end of file